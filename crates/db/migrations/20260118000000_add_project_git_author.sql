@@ -0,0 +1,3 @@
+-- Per-project git identity used for commits made by coding agents
+ALTER TABLE projects ADD COLUMN git_author_name TEXT;
+ALTER TABLE projects ADD COLUMN git_author_email TEXT;
@@ -0,0 +1,2 @@
+-- Free-form local tags for organizing tasks (JSON array), independent of Linear labels
+ALTER TABLE tasks ADD COLUMN tags TEXT;
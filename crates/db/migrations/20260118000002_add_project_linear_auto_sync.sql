@@ -0,0 +1,2 @@
+-- Opt-in periodic refresh of Linear issue states for linked tasks
+ALTER TABLE projects ADD COLUMN linear_auto_sync INTEGER NOT NULL DEFAULT 0;
@@ -0,0 +1,2 @@
+-- Per-project overrides mapping Linear state names/types to local task statuses (JSON object)
+ALTER TABLE projects ADD COLUMN linear_state_map TEXT;
@@ -0,0 +1,4 @@
+-- Named setup profiles (JSON object of name -> script) run before the coding agent,
+-- e.g. {"node18": "nvm install 18"}; tasks pick one by name
+ALTER TABLE projects ADD COLUMN setup_profiles TEXT;
+ALTER TABLE tasks ADD COLUMN setup_profile TEXT;
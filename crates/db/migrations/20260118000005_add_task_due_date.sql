@@ -0,0 +1,2 @@
+-- Optional deadline for a task (YYYY-MM-DD)
+ALTER TABLE tasks ADD COLUMN due_date TEXT;
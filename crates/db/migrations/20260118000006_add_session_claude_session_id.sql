@@ -0,0 +1,4 @@
+-- Claude Code session id for sessions imported from ~/.claude, used to skip re-imports
+ALTER TABLE sessions ADD COLUMN claude_session_id TEXT;
+
+CREATE INDEX idx_sessions_claude_session_id ON sessions(claude_session_id);
@@ -0,0 +1,2 @@
+-- Archived tasks are kept but no longer listed on the board
+ALTER TABLE tasks ADD COLUMN archived_at TEXT;
@@ -0,0 +1,2 @@
+-- Exact command line a coding agent was started with, for reproducing a run
+ALTER TABLE execution_processes ADD COLUMN command TEXT;
@@ -0,0 +1,3 @@
+-- Outgoing webhook fired when a task in the project changes status
+ALTER TABLE projects ADD COLUMN webhook_url TEXT;
+ALTER TABLE projects ADD COLUMN webhook_secret TEXT;
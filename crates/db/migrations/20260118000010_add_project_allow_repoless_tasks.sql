@@ -0,0 +1,2 @@
+-- Lets tasks with no repositories start in an empty scratch directory
+ALTER TABLE projects ADD COLUMN allow_repoless_tasks INTEGER NOT NULL DEFAULT 0;
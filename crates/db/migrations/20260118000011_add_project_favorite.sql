@@ -0,0 +1,2 @@
+-- Starred projects are listed before the rest
+ALTER TABLE projects ADD COLUMN favorite INTEGER NOT NULL DEFAULT 0;
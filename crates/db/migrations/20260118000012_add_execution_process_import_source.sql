@@ -0,0 +1,3 @@
+-- Claude Code session file and slug an imported process was read from
+ALTER TABLE execution_processes ADD COLUMN source_path TEXT;
+ALTER TABLE execution_processes ADD COLUMN source_slug TEXT;
@@ -0,0 +1,2 @@
+-- Move a task to Done when its PR merges; off for teams with a step after merge
+ALTER TABLE projects ADD COLUMN auto_done_on_merge INTEGER NOT NULL DEFAULT 1;
@@ -0,0 +1,20 @@
+-- Status history for the task activity timeline. Recorded by a trigger so
+-- every path that moves a task (board, agent completion, PR merge) is covered
+CREATE TABLE task_status_changes (
+    task_id     BLOB NOT NULL,
+    from_status TEXT NOT NULL,
+    to_status   TEXT NOT NULL,
+    changed_at  TEXT NOT NULL DEFAULT (datetime('now', 'subsec')),
+    FOREIGN KEY (task_id) REFERENCES tasks(id) ON DELETE CASCADE
+);
+
+CREATE INDEX idx_task_status_changes_task_id ON task_status_changes(task_id);
+
+CREATE TRIGGER IF NOT EXISTS trg_task_status_changes
+AFTER UPDATE OF status ON tasks
+FOR EACH ROW
+WHEN OLD.status <> NEW.status
+BEGIN
+    INSERT INTO task_status_changes (task_id, from_status, to_status)
+    VALUES (NEW.id, OLD.status, NEW.status);
+END;
@@ -0,0 +1,2 @@
+-- Commit message convention for agent commits, e.g. "[{short_task_id}] {message}"
+ALTER TABLE projects ADD COLUMN commit_message_template TEXT;
@@ -75,6 +75,12 @@ pub struct ExecutionProcess {
     /// history view (due to restore/trimming). Hidden from logs/timeline;
     /// still listed in the Processes tab.
     pub dropped: bool,
+    /// Resolved command line the coding agent was started with
+    pub command: Option<String>,
+    /// Claude Code session file an imported process was read from
+    pub source_path: Option<String>,
+    /// Slug of that Claude Code session
+    pub source_slug: Option<String>,
     pub started_at: DateTime<Utc>,
     pub completed_at: Option<DateTime<Utc>>,
     pub created_at: DateTime<Utc>,
@@ -146,6 +152,9 @@ impl ExecutionProcess {
                     ep.status as "status!: ExecutionProcessStatus",
                     ep.exit_code,
                     ep.dropped as "dropped!: bool",
+                    ep.command,
+                    ep.source_path,
+                    ep.source_slug,
                     ep.started_at as "started_at!: DateTime<Utc>",
                     ep.completed_at as "completed_at?: DateTime<Utc>",
                     ep.created_at as "created_at!: DateTime<Utc>",
@@ -221,6 +230,9 @@ impl ExecutionProcess {
                     ep.status as "status!: ExecutionProcessStatus",
                     ep.exit_code,
                     ep.dropped as "dropped!: bool",
+                    ep.command,
+                    ep.source_path,
+                    ep.source_slug,
                     ep.started_at as "started_at!: DateTime<Utc>",
                     ep.completed_at as "completed_at?: DateTime<Utc>",
                     ep.created_at as "created_at!: DateTime<Utc>",
@@ -249,6 +261,9 @@ impl ExecutionProcess {
                       ep.status          as "status!: ExecutionProcessStatus",
                       ep.exit_code,
                       ep.dropped as "dropped!: bool",
+                      ep.command,
+                      ep.source_path,
+                      ep.source_slug,
                       ep.started_at      as "started_at!: DateTime<Utc>",
                       ep.completed_at    as "completed_at?: DateTime<Utc>",
                       ep.created_at      as "created_at!: DateTime<Utc>",
@@ -280,6 +295,9 @@ impl ExecutionProcess {
                       ep.status          as "status!: ExecutionProcessStatus",
                       ep.exit_code,
                       ep.dropped as "dropped!: bool",
+                      ep.command,
+                      ep.source_path,
+                      ep.source_slug,
                       ep.started_at      as "started_at!: DateTime<Utc>",
                       ep.completed_at    as "completed_at?: DateTime<Utc>",
                       ep.created_at      as "created_at!: DateTime<Utc>",
@@ -306,6 +324,9 @@ impl ExecutionProcess {
                     ep.status as "status!: ExecutionProcessStatus",
                     ep.exit_code,
                     ep.dropped as "dropped!: bool",
+                    ep.command,
+                    ep.source_path,
+                    ep.source_slug,
                     ep.started_at as "started_at!: DateTime<Utc>",
                     ep.completed_at as "completed_at?: DateTime<Utc>",
                     ep.created_at as "created_at!: DateTime<Utc>",
@@ -325,7 +346,7 @@ impl ExecutionProcess {
             ExecutionProcess,
             r#"SELECT ep.id as "id!: Uuid", ep.session_id as "session_id!: Uuid", ep.commander_session_id as "commander_session_id?: Uuid", ep.run_reason as "run_reason!: ExecutionProcessRunReason", ep.executor_action as "executor_action!: sqlx::types::Json<ExecutorActionField>",
                       ep.status as "status!: ExecutionProcessStatus", ep.exit_code,
-                      ep.dropped as "dropped!: bool", ep.started_at as "started_at!: DateTime<Utc>", ep.completed_at as "completed_at?: DateTime<Utc>", ep.created_at as "created_at!: DateTime<Utc>", ep.updated_at as "updated_at!: DateTime<Utc>"
+                      ep.dropped as "dropped!: bool", ep.command, ep.source_path, ep.source_slug, ep.started_at as "started_at!: DateTime<Utc>", ep.completed_at as "completed_at?: DateTime<Utc>", ep.created_at as "created_at!: DateTime<Utc>", ep.updated_at as "updated_at!: DateTime<Utc>"
                FROM execution_processes ep
                JOIN sessions s ON ep.session_id = s.id
                JOIN workspaces w ON s.workspace_id = w.id
@@ -374,6 +395,9 @@ impl ExecutionProcess {
             ep.status as "status!: ExecutionProcessStatus",
             ep.exit_code,
             ep.dropped as "dropped!: bool",
+            ep.command,
+            ep.source_path,
+            ep.source_slug,
             ep.started_at as "started_at!: DateTime<Utc>",
             ep.completed_at as "completed_at?: DateTime<Utc>",
             ep.created_at as "created_at!: DateTime<Utc>",
@@ -437,6 +461,9 @@ impl ExecutionProcess {
                     ep.status as "status!: ExecutionProcessStatus",
                     ep.exit_code,
                     ep.dropped as "dropped!: bool",
+                    ep.command,
+                    ep.source_path,
+                    ep.source_slug,
                     ep.started_at as "started_at!: DateTime<Utc>",
                     ep.completed_at as "completed_at?: DateTime<Utc>",
                     ep.created_at as "created_at!: DateTime<Utc>",
@@ -468,6 +495,9 @@ impl ExecutionProcess {
                     ep.status as "status!: ExecutionProcessStatus",
                     ep.exit_code,
                     ep.dropped as "dropped!: bool",
+                    ep.command,
+                    ep.source_path,
+                    ep.source_slug,
                     ep.started_at as "started_at!: DateTime<Utc>",
                     ep.completed_at as "completed_at?: DateTime<Utc>",
                     ep.created_at as "created_at!: DateTime<Utc>",
@@ -574,6 +604,40 @@ impl ExecutionProcess {
         false
     }
 
+    /// Record the command line the process was started with
+    pub async fn set_command(
+        pool: &SqlitePool,
+        id: Uuid,
+        command: &str,
+    ) -> Result<(), sqlx::Error> {
+        sqlx::query!(
+            r#"UPDATE execution_processes SET command = $1 WHERE id = $2"#,
+            command,
+            id
+        )
+        .execute(pool)
+        .await?;
+        Ok(())
+    }
+
+    /// Record the Claude Code session file an imported process came from
+    pub async fn set_import_source(
+        pool: &SqlitePool,
+        id: Uuid,
+        source_path: &str,
+        source_slug: Option<&str>,
+    ) -> Result<(), sqlx::Error> {
+        sqlx::query!(
+            r#"UPDATE execution_processes SET source_path = $1, source_slug = $2 WHERE id = $3"#,
+            source_path,
+            source_slug,
+            id
+        )
+        .execute(pool)
+        .await?;
+        Ok(())
+    }
+
     /// Update execution process status and completion info
     pub async fn update_completion(
         pool: &SqlitePool,
@@ -732,6 +796,9 @@ impl ExecutionProcess {
                     ep.status as "status!: ExecutionProcessStatus",
                     ep.exit_code,
                     ep.dropped as "dropped!: bool",
+                    ep.command,
+                    ep.source_path,
+                    ep.source_slug,
                     ep.started_at as "started_at!: DateTime<Utc>",
                     ep.completed_at as "completed_at?: DateTime<Utc>",
                     ep.created_at as "created_at!: DateTime<Utc>",
@@ -1,10 +1,29 @@
+use std::sync::LazyLock;
+
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use sqlx::{FromRow, SqlitePool};
+use tokio::sync::broadcast;
 use ts_rs::TS;
 use utils::log_msg::LogMsg;
 use uuid::Uuid;
 
+/// Appends that a slow live-tail subscriber may fall behind by before it lags
+const APPENDED_LOGS_CAPACITY: usize = 1024;
+
+static APPENDED_LOGS: LazyLock<broadcast::Sender<AppendedLogs>> =
+    LazyLock::new(|| broadcast::channel(APPENDED_LOGS_CAPACITY).0);
+
+/// A row just appended to `execution_process_logs`, published for live tails
+#[derive(Debug, Clone)]
+pub struct AppendedLogs {
+    /// SQLite rowid of the row; rows read before subscribing have lower ones
+    pub rowid: i64,
+    pub execution_id: Uuid,
+    /// JSONL lines, one `LogMsg` each
+    pub logs: String,
+}
+
 #[derive(Debug, Clone, FromRow, Serialize, Deserialize, TS)]
 pub struct ExecutionProcessLogs {
     pub execution_id: Uuid,
@@ -28,13 +47,64 @@ impl ExecutionProcessLogs {
                 inserted_at as "inserted_at!: DateTime<Utc>"
                FROM execution_process_logs 
                WHERE execution_id = $1
-               ORDER BY inserted_at ASC"#,
+               ORDER BY inserted_at ASC, rowid ASC"#,
             execution_id
         )
         .fetch_all(pool)
         .await
     }
 
+    /// Like `find_by_execution_id`, with each row's rowid to line it up with
+    /// `AppendedLogs` received by a subscriber
+    pub async fn find_by_execution_id_with_rowids(
+        pool: &SqlitePool,
+        execution_id: Uuid,
+    ) -> Result<Vec<(i64, Self)>, sqlx::Error> {
+        let rows = sqlx::query!(
+            r#"SELECT
+                rowid as "rowid!: i64",
+                execution_id as "execution_id!: Uuid",
+                logs,
+                byte_size,
+                inserted_at as "inserted_at!: DateTime<Utc>"
+               FROM execution_process_logs
+               WHERE execution_id = $1
+               ORDER BY inserted_at ASC, rowid ASC"#,
+            execution_id
+        )
+        .fetch_all(pool)
+        .await?;
+
+        Ok(rows
+            .into_iter()
+            .map(|row| {
+                (
+                    row.rowid,
+                    Self {
+                        execution_id: row.execution_id,
+                        logs: row.logs,
+                        byte_size: row.byte_size,
+                        inserted_at: row.inserted_at,
+                    },
+                )
+            })
+            .collect())
+    }
+
+    /// Receive every row appended from now on, for all execution processes
+    pub fn subscribe_appended() -> broadcast::Receiver<AppendedLogs> {
+        APPENDED_LOGS.subscribe()
+    }
+
+    fn publish_appended(rowid: i64, execution_id: Uuid, logs: String) {
+        // Sending only fails when nobody is tailing
+        let _ = APPENDED_LOGS.send(AppendedLogs {
+            rowid,
+            execution_id,
+            logs,
+        });
+    }
+
     /// Parse JSONL logs back into Vec<LogMsg>
     pub fn parse_logs(records: &[Self]) -> Result<Vec<LogMsg>, serde_json::Error> {
         let mut messages = Vec::new();
@@ -54,7 +124,7 @@ impl ExecutionProcessLogs {
         jsonl_line: &str,
     ) -> Result<(), sqlx::Error> {
         let byte_size = jsonl_line.len() as i64;
-        sqlx::query!(
+        let result = sqlx::query!(
             r#"INSERT INTO execution_process_logs (execution_id, logs, byte_size, inserted_at)
                VALUES ($1, $2, $3, datetime('now', 'subsec'))"#,
             execution_id,
@@ -64,6 +134,11 @@ impl ExecutionProcessLogs {
         .execute(pool)
         .await?;
 
+        Self::publish_appended(
+            result.last_insert_rowid(),
+            execution_id,
+            jsonl_line.to_string(),
+        );
         Ok(())
     }
 
@@ -78,7 +153,7 @@ impl ExecutionProcessLogs {
         }
         let combined = jsonl_lines.join("\n");
         let byte_size = combined.len() as i64;
-        sqlx::query!(
+        let result = sqlx::query!(
             r#"INSERT INTO execution_process_logs (execution_id, logs, byte_size, inserted_at)
                VALUES ($1, $2, $3, datetime('now', 'subsec'))"#,
             execution_id,
@@ -88,6 +163,7 @@ impl ExecutionProcessLogs {
         .execute(pool)
         .await?;
 
+        Self::publish_appended(result.last_insert_rowid(), execution_id, combined);
         Ok(())
     }
 }
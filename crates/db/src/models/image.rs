@@ -1,6 +1,6 @@
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
-use sqlx::{FromRow, SqlitePool};
+use sqlx::{Executor, FromRow, Sqlite, SqlitePool};
 use ts_rs::TS;
 use uuid::Uuid;
 
@@ -184,23 +184,36 @@ impl TaskImage {
         image_ids: &[Uuid],
     ) -> Result<(), sqlx::Error> {
         for &image_id in image_ids {
-            let id = Uuid::new_v4();
-            sqlx::query!(
-                r#"INSERT INTO task_images (id, task_id, image_id)
-                   SELECT $1, $2, $3
-                   WHERE NOT EXISTS (
-                       SELECT 1 FROM task_images WHERE task_id = $2 AND image_id = $3
-                   )"#,
-                id,
-                task_id,
-                image_id
-            )
-            .execute(pool)
-            .await?;
+            Self::associate_dedup(pool, task_id, image_id).await?;
         }
         Ok(())
     }
 
+    /// Attach one image to a task unless it is already attached
+    pub async fn associate_dedup<'e, E>(
+        executor: E,
+        task_id: Uuid,
+        image_id: Uuid,
+    ) -> Result<(), sqlx::Error>
+    where
+        E: Executor<'e, Database = Sqlite>,
+    {
+        let id = Uuid::new_v4();
+        sqlx::query!(
+            r#"INSERT INTO task_images (id, task_id, image_id)
+               SELECT $1, $2, $3
+               WHERE NOT EXISTS (
+                   SELECT 1 FROM task_images WHERE task_id = $2 AND image_id = $3
+               )"#,
+            id,
+            task_id,
+            image_id
+        )
+        .execute(executor)
+        .await?;
+        Ok(())
+    }
+
     pub async fn delete_by_task_id(pool: &SqlitePool, task_id: Uuid) -> Result<(), sqlx::Error> {
         sqlx::query!(r#"DELETE FROM task_images WHERE task_id = $1"#, task_id)
             .execute(pool)
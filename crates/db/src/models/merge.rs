@@ -328,6 +328,43 @@ impl Merge {
 
         Ok(())
     }
+    /// URL of the most recent PR opened from any of the task's workspaces
+    pub async fn find_latest_pr_url_for_task(
+        pool: &SqlitePool,
+        task_id: Uuid,
+    ) -> Result<Option<String>, sqlx::Error> {
+        let pr_url = sqlx::query_scalar!(
+            r#"SELECT m.pr_url
+               FROM merges m
+               JOIN workspaces w ON w.id = m.workspace_id
+               WHERE w.task_id = $1 AND m.merge_type = 'pr'
+               ORDER BY m.created_at DESC
+               LIMIT 1"#,
+            task_id
+        )
+        .fetch_optional(pool)
+        .await?;
+
+        Ok(pr_url.flatten())
+    }
+
+    /// True when a PR opened from any of the task's workspaces has been merged
+    pub async fn task_has_merged_pr(pool: &SqlitePool, task_id: Uuid) -> Result<bool, sqlx::Error> {
+        let merged = sqlx::query_scalar!(
+            r#"SELECT EXISTS(
+                   SELECT 1
+                   FROM merges m
+                   JOIN workspaces w ON w.id = m.workspace_id
+                   WHERE w.task_id = $1 AND m.merge_type = 'pr' AND m.pr_status = 'merged'
+               ) as "merged!: bool""#,
+            task_id
+        )
+        .fetch_one(pool)
+        .await?;
+
+        Ok(merged)
+    }
+
     /// Find all merges for a workspace (returns both direct and PR merges)
     pub async fn find_by_workspace_id(
         pool: &SqlitePool,
@@ -12,5 +12,6 @@ pub mod scratch;
 pub mod session;
 pub mod tag;
 pub mod task;
+pub mod task_timeline;
 pub mod workspace;
 pub mod workspace_repo;
@@ -1,3 +1,5 @@
+use std::collections::HashMap;
+
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use sqlx::{Executor, FromRow, Sqlite, SqlitePool};
@@ -31,12 +33,55 @@ pub struct Project {
     #[serde(skip_serializing)] // Don't expose assignee ID to frontend
     #[ts(skip)]
     pub linear_assignee_id: Option<String>,
+    /// Git author name used for agent commits in this project (falls back to global git config)
+    pub git_author_name: Option<String>,
+    /// Git author email used for agent commits in this project (falls back to global git config)
+    pub git_author_email: Option<String>,
+    /// Subject line convention for agent commits. `{task_title}`, `{task_id}`,
+    /// `{short_task_id}` and `{branch}` are filled in; `{message}` stands for the
+    /// agent's own subject, which is appended when the placeholder is missing.
+    pub commit_message_template: Option<String>,
+    /// Periodically refresh the status of Linear-linked tasks from Linear
+    pub linear_auto_sync: bool,
+    /// JSON object mapping Linear state names or types to task statuses,
+    /// e.g. `{"In Review": "inreview"}`. Consulted before the built-in mapping.
+    pub linear_state_map: Option<String>,
+    /// JSON object of named setup profiles (name -> script), e.g. `{"node18": "nvm install 18"}`.
+    /// A task's selected profile runs before the coding agent.
+    pub setup_profiles: Option<String>,
+    /// URL that receives a POST whenever a task in this project changes status
+    pub webhook_url: Option<String>,
+    #[serde(skip_serializing)] // Don't expose signing secret to frontend
+    #[ts(skip)]
+    pub webhook_secret: Option<String>,
+    /// Start tasks without repositories in an empty scratch directory instead of rejecting them
+    pub allow_repoless_tasks: bool,
+    /// Move tasks to Done when their PR merges; when off, merged tasks keep their status
+    pub auto_done_on_merge: bool,
+    /// Starred projects are listed first
+    pub favorite: bool,
     #[ts(type = "Date")]
     pub created_at: DateTime<Utc>,
     #[ts(type = "Date")]
     pub updated_at: DateTime<Utc>,
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize, TS)]
+pub struct ProjectWithHealth {
+    #[serde(flatten)]
+    #[ts(flatten)]
+    pub project: Project,
+    /// False when a repository path no longer exists or is no longer a git repository
+    pub valid: bool,
+}
+
+impl std::ops::Deref for ProjectWithHealth {
+    type Target = Project;
+    fn deref(&self) -> &Self::Target {
+        &self.project
+    }
+}
+
 #[derive(Debug, Clone, Deserialize, TS)]
 pub struct CreateProject {
     pub name: String,
@@ -51,6 +96,20 @@ pub struct UpdateProject {
     pub default_agent_working_dir: Option<String>,
     pub linear_api_key: Option<String>,
     pub linear_assignee_id: Option<String>,
+    pub git_author_name: Option<String>,
+    pub git_author_email: Option<String>,
+    pub commit_message_template: Option<String>,
+    /// None keeps the current setting
+    pub linear_auto_sync: Option<bool>,
+    pub linear_state_map: Option<String>,
+    pub setup_profiles: Option<String>,
+    pub webhook_url: Option<String>,
+    /// Key for the `X-Vibe-Signature` HMAC; None keeps the current secret
+    pub webhook_secret: Option<String>,
+    /// None keeps the current setting
+    pub allow_repoless_tasks: Option<bool>,
+    /// None keeps the current setting
+    pub auto_done_on_merge: Option<bool>,
 }
 
 #[derive(Debug, Serialize, TS)]
@@ -85,10 +144,21 @@ impl Project {
                       remote_project_id as "remote_project_id: Uuid",
                       linear_api_key,
                       linear_assignee_id,
+                      git_author_name,
+                      git_author_email,
+                      commit_message_template,
+                      linear_auto_sync as "linear_auto_sync!: bool",
+                      linear_state_map,
+                      setup_profiles,
+                      webhook_url,
+                      webhook_secret,
+                      allow_repoless_tasks as "allow_repoless_tasks!: bool",
+                      auto_done_on_merge as "auto_done_on_merge!: bool",
+                      favorite as "favorite!: bool",
                       created_at as "created_at!: DateTime<Utc>",
                       updated_at as "updated_at!: DateTime<Utc>"
                FROM projects
-               ORDER BY created_at DESC"#
+               ORDER BY favorite DESC, created_at DESC"#
         )
         .fetch_all(pool)
         .await
@@ -104,6 +174,17 @@ impl Project {
                    p.remote_project_id as "remote_project_id: Uuid",
                    p.linear_api_key,
                    p.linear_assignee_id,
+                   p.git_author_name,
+                   p.git_author_email,
+                   p.commit_message_template,
+                   p.linear_auto_sync as "linear_auto_sync!: bool",
+                   p.linear_state_map,
+                   p.setup_profiles,
+                   p.webhook_url,
+                   p.webhook_secret,
+                   p.allow_repoless_tasks as "allow_repoless_tasks!: bool",
+                   p.auto_done_on_merge as "auto_done_on_merge!: bool",
+                   p.favorite as "favorite!: bool",
                    p.created_at as "created_at!: DateTime<Utc>", p.updated_at as "updated_at!: DateTime<Utc>"
             FROM projects p
             WHERE p.id IN (
@@ -131,6 +212,17 @@ impl Project {
                       remote_project_id as "remote_project_id: Uuid",
                       linear_api_key,
                       linear_assignee_id,
+                      git_author_name,
+                      git_author_email,
+                      commit_message_template,
+                      linear_auto_sync as "linear_auto_sync!: bool",
+                      linear_state_map,
+                      setup_profiles,
+                      webhook_url,
+                      webhook_secret,
+                      allow_repoless_tasks as "allow_repoless_tasks!: bool",
+                      auto_done_on_merge as "auto_done_on_merge!: bool",
+                      favorite as "favorite!: bool",
                       created_at as "created_at!: DateTime<Utc>",
                       updated_at as "updated_at!: DateTime<Utc>"
                FROM projects
@@ -152,6 +244,17 @@ impl Project {
                       remote_project_id as "remote_project_id: Uuid",
                       linear_api_key,
                       linear_assignee_id,
+                      git_author_name,
+                      git_author_email,
+                      commit_message_template,
+                      linear_auto_sync as "linear_auto_sync!: bool",
+                      linear_state_map,
+                      setup_profiles,
+                      webhook_url,
+                      webhook_secret,
+                      allow_repoless_tasks as "allow_repoless_tasks!: bool",
+                      auto_done_on_merge as "auto_done_on_merge!: bool",
+                      favorite as "favorite!: bool",
                       created_at as "created_at!: DateTime<Utc>",
                       updated_at as "updated_at!: DateTime<Utc>"
                FROM projects
@@ -176,6 +279,17 @@ impl Project {
                       remote_project_id as "remote_project_id: Uuid",
                       linear_api_key,
                       linear_assignee_id,
+                      git_author_name,
+                      git_author_email,
+                      commit_message_template,
+                      linear_auto_sync as "linear_auto_sync!: bool",
+                      linear_state_map,
+                      setup_profiles,
+                      webhook_url,
+                      webhook_secret,
+                      allow_repoless_tasks as "allow_repoless_tasks!: bool",
+                      auto_done_on_merge as "auto_done_on_merge!: bool",
+                      favorite as "favorite!: bool",
                       created_at as "created_at!: DateTime<Utc>",
                       updated_at as "updated_at!: DateTime<Utc>"
                FROM projects
@@ -208,6 +322,17 @@ impl Project {
                           remote_project_id as "remote_project_id: Uuid",
                           linear_api_key,
                           linear_assignee_id,
+                          git_author_name,
+                          git_author_email,
+                          commit_message_template,
+                          linear_auto_sync as "linear_auto_sync!: bool",
+                          linear_state_map,
+                          setup_profiles,
+                          webhook_url,
+                          webhook_secret,
+                          allow_repoless_tasks as "allow_repoless_tasks!: bool",
+                          auto_done_on_merge as "auto_done_on_merge!: bool",
+                          favorite as "favorite!: bool",
                           created_at as "created_at!: DateTime<Utc>",
                           updated_at as "updated_at!: DateTime<Utc>""#,
             project_id,
@@ -233,11 +358,32 @@ impl Project {
         // Treat same as other optional fields - None clears it
         let linear_api_key = payload.linear_api_key.clone();
         let linear_assignee_id = payload.linear_assignee_id.clone();
+        let git_author_name = payload.git_author_name.clone();
+        let git_author_email = payload.git_author_email.clone();
+        let commit_message_template = payload.commit_message_template.clone();
+        let linear_auto_sync = payload
+            .linear_auto_sync
+            .unwrap_or(existing.linear_auto_sync);
+        let linear_state_map = payload.linear_state_map.clone();
+        let setup_profiles = payload.setup_profiles.clone();
+        let webhook_url = payload.webhook_url.clone();
+        // The secret is never sent to the frontend, so None keeps it and "" clears it
+        let webhook_secret = match payload.webhook_secret.as_deref() {
+            None => existing.webhook_secret,
+            Some("") => None,
+            Some(secret) => Some(secret.to_string()),
+        };
+        let allow_repoless_tasks = payload
+            .allow_repoless_tasks
+            .unwrap_or(existing.allow_repoless_tasks);
+        let auto_done_on_merge = payload
+            .auto_done_on_merge
+            .unwrap_or(existing.auto_done_on_merge);
 
         sqlx::query_as!(
             Project,
             r#"UPDATE projects
-               SET name = $2, dev_script = $3, dev_script_working_dir = $4, default_agent_working_dir = $5, linear_api_key = $6, linear_assignee_id = $7
+               SET name = $2, dev_script = $3, dev_script_working_dir = $4, default_agent_working_dir = $5, linear_api_key = $6, linear_assignee_id = $7, git_author_name = $8, git_author_email = $9, linear_auto_sync = $10, linear_state_map = $11, setup_profiles = $12, webhook_url = $13, webhook_secret = $14, allow_repoless_tasks = $15, auto_done_on_merge = $16, commit_message_template = $17
                WHERE id = $1
                RETURNING id as "id!: Uuid",
                          name,
@@ -247,6 +393,17 @@ impl Project {
                          remote_project_id as "remote_project_id: Uuid",
                          linear_api_key,
                          linear_assignee_id,
+                         git_author_name,
+                         git_author_email,
+                         commit_message_template,
+                         linear_auto_sync as "linear_auto_sync!: bool",
+                         linear_state_map,
+                         setup_profiles,
+                         webhook_url,
+                         webhook_secret,
+                         allow_repoless_tasks as "allow_repoless_tasks!: bool",
+                         auto_done_on_merge as "auto_done_on_merge!: bool",
+                         favorite as "favorite!: bool",
                          created_at as "created_at!: DateTime<Utc>",
                          updated_at as "updated_at!: DateTime<Utc>""#,
             id,
@@ -256,11 +413,53 @@ impl Project {
             default_agent_working_dir,
             linear_api_key,
             linear_assignee_id,
+            git_author_name,
+            git_author_email,
+            linear_auto_sync,
+            linear_state_map,
+            setup_profiles,
+            webhook_url,
+            webhook_secret,
+            allow_repoless_tasks,
+            auto_done_on_merge,
+            commit_message_template,
         )
         .fetch_one(pool)
         .await
     }
 
+    /// Projects that opted into periodic Linear state refresh
+    pub async fn find_linear_auto_sync(pool: &SqlitePool) -> Result<Vec<Self>, sqlx::Error> {
+        sqlx::query_as!(
+            Project,
+            r#"SELECT id as "id!: Uuid",
+                      name,
+                      dev_script,
+                      dev_script_working_dir,
+                      default_agent_working_dir,
+                      remote_project_id as "remote_project_id: Uuid",
+                      linear_api_key,
+                      linear_assignee_id,
+                      git_author_name,
+                      git_author_email,
+                      commit_message_template,
+                      linear_auto_sync as "linear_auto_sync!: bool",
+                      linear_state_map,
+                      setup_profiles,
+                      webhook_url,
+                      webhook_secret,
+                      allow_repoless_tasks as "allow_repoless_tasks!: bool",
+                      auto_done_on_merge as "auto_done_on_merge!: bool",
+                      favorite as "favorite!: bool",
+                      created_at as "created_at!: DateTime<Utc>",
+                      updated_at as "updated_at!: DateTime<Utc>"
+               FROM projects
+               WHERE linear_auto_sync = 1"#
+        )
+        .fetch_all(pool)
+        .await
+    }
+
     pub async fn clear_default_agent_working_dir(
         pool: &SqlitePool,
         id: Uuid,
@@ -276,6 +475,42 @@ impl Project {
         Ok(())
     }
 
+    /// Whether merging a PR of the task should move it to Done
+    pub async fn auto_done_on_merge_for_task(
+        pool: &SqlitePool,
+        task_id: Uuid,
+    ) -> Result<bool, sqlx::Error> {
+        let enabled = sqlx::query_scalar!(
+            r#"SELECT p.auto_done_on_merge as "auto_done_on_merge!: bool"
+               FROM projects p
+               JOIN tasks t ON t.project_id = p.id
+               WHERE t.id = $1"#,
+            task_id
+        )
+        .fetch_optional(pool)
+        .await?;
+
+        Ok(enabled.unwrap_or(true))
+    }
+
+    pub async fn set_favorite(
+        pool: &SqlitePool,
+        id: Uuid,
+        favorite: bool,
+    ) -> Result<(), sqlx::Error> {
+        sqlx::query!(
+            r#"UPDATE projects
+               SET favorite = $2
+               WHERE id = $1"#,
+            id,
+            favorite
+        )
+        .execute(pool)
+        .await?;
+
+        Ok(())
+    }
+
     pub async fn set_remote_project_id(
         pool: &SqlitePool,
         id: Uuid,
@@ -322,4 +557,18 @@ impl Project {
             .await?;
         Ok(result.rows_affected())
     }
+
+    /// Parse the `setup_profiles` JSON object into profile name -> script
+    pub fn parse_setup_profiles(json: &str) -> Result<HashMap<String, String>, serde_json::Error> {
+        serde_json::from_str(json)
+    }
+
+    /// Script for the named setup profile, if the project defines it
+    pub fn setup_profile_script(&self, name: &str) -> Option<String> {
+        let profiles = Self::parse_setup_profiles(self.setup_profiles.as_deref()?).ok()?;
+        profiles
+            .get(name)
+            .filter(|script| !script.trim().is_empty())
+            .cloned()
+    }
 }
@@ -127,4 +127,64 @@ impl Session {
         .fetch_one(pool)
         .await?)
     }
+
+    /// Remember which Claude Code session an imported session came from
+    pub async fn set_claude_session_id(
+        pool: &SqlitePool,
+        id: Uuid,
+        claude_session_id: &str,
+    ) -> Result<(), sqlx::Error> {
+        sqlx::query!(
+            "UPDATE sessions SET claude_session_id = $2, updated_at = CURRENT_TIMESTAMP WHERE id = $1",
+            id,
+            claude_session_id
+        )
+        .execute(pool)
+        .await?;
+        Ok(())
+    }
+
+    /// Session created by an earlier import of a Claude Code session into a project
+    pub async fn find_imported(
+        pool: &SqlitePool,
+        project_id: Uuid,
+        claude_session_id: &str,
+    ) -> Result<Option<Self>, sqlx::Error> {
+        sqlx::query_as!(
+            Session,
+            r#"SELECT s.id AS "id!: Uuid",
+                      s.workspace_id AS "workspace_id!: Uuid",
+                      s.executor,
+                      s.created_at AS "created_at!: DateTime<Utc>",
+                      s.updated_at AS "updated_at!: DateTime<Utc>"
+               FROM sessions s
+               JOIN workspaces w ON w.id = s.workspace_id
+               JOIN tasks t ON t.id = w.task_id
+               WHERE t.project_id = $1 AND s.claude_session_id = $2
+               ORDER BY s.created_at ASC
+               LIMIT 1"#,
+            project_id,
+            claude_session_id
+        )
+        .fetch_optional(pool)
+        .await
+    }
+
+    /// Claude Code session ids already imported into a project
+    pub async fn find_imported_claude_session_ids(
+        pool: &SqlitePool,
+        project_id: Uuid,
+    ) -> Result<Vec<String>, sqlx::Error> {
+        let rows = sqlx::query!(
+            r#"SELECT s.claude_session_id AS "claude_session_id!"
+               FROM sessions s
+               JOIN workspaces w ON w.id = s.workspace_id
+               JOIN tasks t ON t.id = w.task_id
+               WHERE t.project_id = $1 AND s.claude_session_id IS NOT NULL"#,
+            project_id
+        )
+        .fetch_all(pool)
+        .await?;
+        Ok(rows.into_iter().map(|r| r.claude_session_id).collect())
+    }
 }
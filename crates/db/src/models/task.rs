@@ -1,11 +1,21 @@
-use chrono::{DateTime, Utc};
+use chrono::{DateTime, NaiveDate, Utc};
 use serde::{Deserialize, Serialize};
 use sqlx::{Executor, FromRow, Sqlite, SqlitePool, Type};
 use strum_macros::{Display, EnumString};
+use thiserror::Error;
 use ts_rs::TS;
 use uuid::Uuid;
 
-use super::{project::Project, workspace::Workspace};
+use super::{image::TaskImage, project::Project, workspace::Workspace};
+
+#[derive(Debug, Error)]
+pub enum TaskBatchError {
+    #[error(transparent)]
+    Database(#[from] sqlx::Error),
+    /// Nothing from the batch was kept
+    #[error("Failed to create task {index}: {source}")]
+    TaskFailed { index: usize, source: sqlx::Error },
+}
 
 #[derive(
     Debug, Clone, Type, Serialize, Deserialize, PartialEq, TS, EnumString, Display, Default,
@@ -35,6 +45,9 @@ pub struct Task {
     pub linear_issue_id: Option<String>, // Linear issue ID for synced tasks
     pub linear_url: Option<String>,      // Linear issue URL for sharing
     pub linear_labels: Option<String>,   // JSON array of Linear labels
+    pub tags: Option<String>,            // JSON array of local tags
+    pub setup_profile: Option<String>,   // Name of a project setup profile to run first
+    pub due_date: Option<NaiveDate>,     // Optional deadline, flagged as overdue once passed
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
 }
@@ -160,6 +173,9 @@ pub struct UpdateTask {
     /// If true, sync the status change to Linear (for tasks with linear_issue_id)
     #[serde(default)]
     pub sync_to_linear: bool,
+    /// Due date as YYYY-MM-DD; empty string clears it, omitted keeps the current value
+    #[serde(default)]
+    pub due_date: Option<String>,
 }
 
 impl Task {
@@ -179,6 +195,20 @@ impl Task {
         pool: &SqlitePool,
         project_id: Uuid,
     ) -> Result<Vec<TaskWithAttemptStatus>, sqlx::Error> {
+        Self::find_by_project_id_with_attempt_status_paged(pool, project_id, None, None, 0).await
+    }
+
+    /// A page of a project's tasks, newest first. Without `limit` every task from
+    /// `offset` on is returned; `updated_since` skips tasks not touched since then.
+    pub async fn find_by_project_id_with_attempt_status_paged(
+        pool: &SqlitePool,
+        project_id: Uuid,
+        updated_since: Option<DateTime<Utc>>,
+        limit: Option<i64>,
+        offset: i64,
+    ) -> Result<Vec<TaskWithAttemptStatus>, sqlx::Error> {
+        // SQLite treats a negative LIMIT as no limit
+        let limit = limit.unwrap_or(-1);
         let records = sqlx::query!(
             r#"SELECT
   t.id                            AS "id!: Uuid",
@@ -191,6 +221,9 @@ impl Task {
   t.linear_issue_id,
   t.linear_url,
   t.linear_labels,
+  t.tags,
+  t.setup_profile,
+  t.due_date                      AS "due_date: NaiveDate",
   t.created_at                    AS "created_at!: DateTime<Utc>",
   t.updated_at                    AS "updated_at!: DateTime<Utc>",
 
@@ -283,8 +316,14 @@ impl Task {
 
 FROM tasks t
 WHERE t.project_id = $1
-ORDER BY t.created_at DESC"#,
-            project_id
+  AND t.archived_at IS NULL
+  AND ($2 IS NULL OR datetime(t.updated_at) >= datetime($2))
+ORDER BY t.created_at DESC
+LIMIT $3 OFFSET $4"#,
+            project_id,
+            updated_since,
+            limit,
+            offset
         )
         .fetch_all(pool)
         .await?;
@@ -303,6 +342,9 @@ ORDER BY t.created_at DESC"#,
                     linear_issue_id: rec.linear_issue_id,
                     linear_url: rec.linear_url,
                     linear_labels: rec.linear_labels,
+                    tags: rec.tags,
+                    setup_profile: rec.setup_profile,
+                    due_date: rec.due_date,
                     created_at: rec.created_at,
                     updated_at: rec.updated_at,
                 },
@@ -321,6 +363,26 @@ ORDER BY t.created_at DESC"#,
         Ok(tasks)
     }
 
+    /// Number of a project's unarchived tasks, counted with the same filter as
+    /// `find_by_project_id_with_attempt_status_paged`
+    pub async fn count_by_project_id(
+        pool: &SqlitePool,
+        project_id: Uuid,
+        updated_since: Option<DateTime<Utc>>,
+    ) -> Result<i64, sqlx::Error> {
+        sqlx::query_scalar!(
+            r#"SELECT COUNT(*) as "count!: i64"
+               FROM tasks
+               WHERE project_id = $1
+                 AND archived_at IS NULL
+                 AND ($2 IS NULL OR datetime(updated_at) >= datetime($2))"#,
+            project_id,
+            updated_since
+        )
+        .fetch_one(pool)
+        .await
+    }
+
     /// Fetch all tasks across all projects with attempt status information.
     /// Used for the unified "Show All Projects" view.
     pub async fn find_all_with_attempt_status(
@@ -338,6 +400,9 @@ ORDER BY t.created_at DESC"#,
   t.linear_issue_id,
   t.linear_url,
   t.linear_labels,
+  t.tags,
+  t.setup_profile,
+  t.due_date                      AS "due_date: NaiveDate",
   t.created_at                    AS "created_at!: DateTime<Utc>",
   t.updated_at                    AS "updated_at!: DateTime<Utc>",
 
@@ -429,6 +494,7 @@ ORDER BY t.created_at DESC"#,
   )                                 AS "pr_has_conflicts: bool"
 
 FROM tasks t
+WHERE t.archived_at IS NULL
 ORDER BY t.created_at DESC"#
         )
         .fetch_all(pool)
@@ -448,6 +514,9 @@ ORDER BY t.created_at DESC"#
                     linear_issue_id: rec.linear_issue_id,
                     linear_url: rec.linear_url,
                     linear_labels: rec.linear_labels,
+                    tags: rec.tags,
+                    setup_profile: rec.setup_profile,
+                    due_date: rec.due_date,
                     created_at: rec.created_at,
                     updated_at: rec.updated_at,
                 },
@@ -469,7 +538,7 @@ ORDER BY t.created_at DESC"#
     pub async fn find_by_id(pool: &SqlitePool, id: Uuid) -> Result<Option<Self>, sqlx::Error> {
         sqlx::query_as!(
             Task,
-            r#"SELECT id as "id!: Uuid", project_id as "project_id!: Uuid", title, description, status as "status!: TaskStatus", parent_workspace_id as "parent_workspace_id: Uuid", shared_task_id as "shared_task_id: Uuid", linear_issue_id, linear_url, linear_labels, created_at as "created_at!: DateTime<Utc>", updated_at as "updated_at!: DateTime<Utc>"
+            r#"SELECT id as "id!: Uuid", project_id as "project_id!: Uuid", title, description, status as "status!: TaskStatus", parent_workspace_id as "parent_workspace_id: Uuid", shared_task_id as "shared_task_id: Uuid", linear_issue_id, linear_url, linear_labels, tags, setup_profile, due_date as "due_date: NaiveDate", created_at as "created_at!: DateTime<Utc>", updated_at as "updated_at!: DateTime<Utc>"
                FROM tasks
                WHERE id = $1"#,
             id
@@ -481,7 +550,7 @@ ORDER BY t.created_at DESC"#
     pub async fn find_by_rowid(pool: &SqlitePool, rowid: i64) -> Result<Option<Self>, sqlx::Error> {
         sqlx::query_as!(
             Task,
-            r#"SELECT id as "id!: Uuid", project_id as "project_id!: Uuid", title, description, status as "status!: TaskStatus", parent_workspace_id as "parent_workspace_id: Uuid", shared_task_id as "shared_task_id: Uuid", linear_issue_id, linear_url, linear_labels, created_at as "created_at!: DateTime<Utc>", updated_at as "updated_at!: DateTime<Utc>"
+            r#"SELECT id as "id!: Uuid", project_id as "project_id!: Uuid", title, description, status as "status!: TaskStatus", parent_workspace_id as "parent_workspace_id: Uuid", shared_task_id as "shared_task_id: Uuid", linear_issue_id, linear_url, linear_labels, tags, setup_profile, due_date as "due_date: NaiveDate", created_at as "created_at!: DateTime<Utc>", updated_at as "updated_at!: DateTime<Utc>"
                FROM tasks
                WHERE rowid = $1"#,
             rowid
@@ -499,7 +568,7 @@ ORDER BY t.created_at DESC"#
     {
         sqlx::query_as!(
             Task,
-            r#"SELECT id as "id!: Uuid", project_id as "project_id!: Uuid", title, description, status as "status!: TaskStatus", parent_workspace_id as "parent_workspace_id: Uuid", shared_task_id as "shared_task_id: Uuid", linear_issue_id, linear_url, linear_labels, created_at as "created_at!: DateTime<Utc>", updated_at as "updated_at!: DateTime<Utc>"
+            r#"SELECT id as "id!: Uuid", project_id as "project_id!: Uuid", title, description, status as "status!: TaskStatus", parent_workspace_id as "parent_workspace_id: Uuid", shared_task_id as "shared_task_id: Uuid", linear_issue_id, linear_url, linear_labels, tags, setup_profile, due_date as "due_date: NaiveDate", created_at as "created_at!: DateTime<Utc>", updated_at as "updated_at!: DateTime<Utc>"
                FROM tasks
                WHERE shared_task_id = $1
                LIMIT 1"#,
@@ -512,7 +581,7 @@ ORDER BY t.created_at DESC"#
     pub async fn find_all_shared(pool: &SqlitePool) -> Result<Vec<Self>, sqlx::Error> {
         sqlx::query_as!(
             Task,
-            r#"SELECT id as "id!: Uuid", project_id as "project_id!: Uuid", title, description, status as "status!: TaskStatus", parent_workspace_id as "parent_workspace_id: Uuid", shared_task_id as "shared_task_id: Uuid", linear_issue_id, linear_url, linear_labels, created_at as "created_at!: DateTime<Utc>", updated_at as "updated_at!: DateTime<Utc>"
+            r#"SELECT id as "id!: Uuid", project_id as "project_id!: Uuid", title, description, status as "status!: TaskStatus", parent_workspace_id as "parent_workspace_id: Uuid", shared_task_id as "shared_task_id: Uuid", linear_issue_id, linear_url, linear_labels, tags, setup_profile, due_date as "due_date: NaiveDate", created_at as "created_at!: DateTime<Utc>", updated_at as "updated_at!: DateTime<Utc>"
                FROM tasks
                WHERE shared_task_id IS NOT NULL"#
         )
@@ -520,17 +589,20 @@ ORDER BY t.created_at DESC"#
         .await
     }
 
-    pub async fn create(
-        pool: &SqlitePool,
+    pub async fn create<'e, E>(
+        executor: E,
         data: &CreateTask,
         task_id: Uuid,
-    ) -> Result<Self, sqlx::Error> {
+    ) -> Result<Self, sqlx::Error>
+    where
+        E: Executor<'e, Database = Sqlite>,
+    {
         let status = data.status.clone().unwrap_or_default();
         sqlx::query_as!(
             Task,
             r#"INSERT INTO tasks (id, project_id, title, description, status, parent_workspace_id, shared_task_id, linear_issue_id, linear_url)
                VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9)
-               RETURNING id as "id!: Uuid", project_id as "project_id!: Uuid", title, description, status as "status!: TaskStatus", parent_workspace_id as "parent_workspace_id: Uuid", shared_task_id as "shared_task_id: Uuid", linear_issue_id, linear_url, linear_labels, created_at as "created_at!: DateTime<Utc>", updated_at as "updated_at!: DateTime<Utc>""#,
+               RETURNING id as "id!: Uuid", project_id as "project_id!: Uuid", title, description, status as "status!: TaskStatus", parent_workspace_id as "parent_workspace_id: Uuid", shared_task_id as "shared_task_id: Uuid", linear_issue_id, linear_url, linear_labels, tags, setup_profile, due_date as "due_date: NaiveDate", created_at as "created_at!: DateTime<Utc>", updated_at as "updated_at!: DateTime<Utc>""#,
             task_id,
             data.project_id,
             data.title,
@@ -541,10 +613,33 @@ ORDER BY t.created_at DESC"#
             data.linear_issue_id,
             data.linear_url
         )
-        .fetch_one(pool)
+        .fetch_one(executor)
         .await
     }
 
+    /// Create the tasks and attach their images in one transaction. If any task
+    /// fails the whole batch is rolled back and its index is reported.
+    pub async fn create_many(
+        pool: &SqlitePool,
+        tasks: &[CreateTask],
+    ) -> Result<Vec<Self>, TaskBatchError> {
+        let mut tx = pool.begin().await?;
+        let mut created = Vec::with_capacity(tasks.len());
+        for (index, data) in tasks.iter().enumerate() {
+            let task = Self::create(&mut *tx, data, Uuid::new_v4())
+                .await
+                .map_err(|source| TaskBatchError::TaskFailed { index, source })?;
+            for &image_id in data.image_ids.iter().flatten() {
+                TaskImage::associate_dedup(&mut *tx, task.id, image_id)
+                    .await
+                    .map_err(|source| TaskBatchError::TaskFailed { index, source })?;
+            }
+            created.push(task);
+        }
+        tx.commit().await?;
+        Ok(created)
+    }
+
     pub async fn update(
         pool: &SqlitePool,
         id: Uuid,
@@ -559,7 +654,7 @@ ORDER BY t.created_at DESC"#
             r#"UPDATE tasks
                SET title = $3, description = $4, status = $5, parent_workspace_id = $6
                WHERE id = $1 AND project_id = $2
-               RETURNING id as "id!: Uuid", project_id as "project_id!: Uuid", title, description, status as "status!: TaskStatus", parent_workspace_id as "parent_workspace_id: Uuid", shared_task_id as "shared_task_id: Uuid", linear_issue_id, linear_url, linear_labels, created_at as "created_at!: DateTime<Utc>", updated_at as "updated_at!: DateTime<Utc>""#,
+               RETURNING id as "id!: Uuid", project_id as "project_id!: Uuid", title, description, status as "status!: TaskStatus", parent_workspace_id as "parent_workspace_id: Uuid", shared_task_id as "shared_task_id: Uuid", linear_issue_id, linear_url, linear_labels, tags, setup_profile, due_date as "due_date: NaiveDate", created_at as "created_at!: DateTime<Utc>", updated_at as "updated_at!: DateTime<Utc>""#,
             id,
             project_id,
             title,
@@ -605,6 +700,22 @@ ORDER BY t.created_at DESC"#
         Ok(())
     }
 
+    /// Point a task at a Linear issue id, e.g. when it was linked by URL only
+    pub async fn update_linear_issue_id(
+        pool: &SqlitePool,
+        id: Uuid,
+        linear_issue_id: &str,
+    ) -> Result<(), sqlx::Error> {
+        sqlx::query!(
+            "UPDATE tasks SET linear_issue_id = $2, updated_at = CURRENT_TIMESTAMP WHERE id = $1",
+            id,
+            linear_issue_id
+        )
+        .execute(pool)
+        .await?;
+        Ok(())
+    }
+
     /// Update the linear_labels field for a task (JSON string of labels)
     pub async fn update_linear_labels(
         pool: &SqlitePool,
@@ -621,6 +732,93 @@ ORDER BY t.created_at DESC"#
         Ok(())
     }
 
+    /// Update the local tags for a task (JSON string of tags)
+    pub async fn update_tags(
+        pool: &SqlitePool,
+        id: Uuid,
+        tags: Option<&str>,
+    ) -> Result<(), sqlx::Error> {
+        sqlx::query!(
+            "UPDATE tasks SET tags = $2, updated_at = CURRENT_TIMESTAMP WHERE id = $1",
+            id,
+            tags
+        )
+        .execute(pool)
+        .await?;
+        Ok(())
+    }
+
+    /// Reassign a task to another project. The parent workspace link is cleared
+    /// since it points at a workspace in the old project.
+    pub async fn move_to_project(
+        pool: &SqlitePool,
+        id: Uuid,
+        project_id: Uuid,
+    ) -> Result<(), sqlx::Error> {
+        sqlx::query!(
+            "UPDATE tasks SET project_id = $2, parent_workspace_id = NULL, updated_at = CURRENT_TIMESTAMP WHERE id = $1",
+            id,
+            project_id
+        )
+        .execute(pool)
+        .await?;
+        Ok(())
+    }
+
+    pub async fn update_due_date(
+        pool: &SqlitePool,
+        id: Uuid,
+        due_date: Option<NaiveDate>,
+    ) -> Result<(), sqlx::Error> {
+        sqlx::query!(
+            "UPDATE tasks SET due_date = $2, updated_at = CURRENT_TIMESTAMP WHERE id = $1",
+            id,
+            due_date
+        )
+        .execute(pool)
+        .await?;
+        Ok(())
+    }
+
+    /// Archive a project's Done and Cancelled tasks, optionally only those last
+    /// updated before `before`. Runs as a single statement, so either every
+    /// matching task is archived or none is. Returns the number archived.
+    pub async fn archive_completed(
+        pool: &SqlitePool,
+        project_id: Uuid,
+        before: Option<DateTime<Utc>>,
+    ) -> Result<u64, sqlx::Error> {
+        let result = sqlx::query!(
+            r#"UPDATE tasks
+               SET archived_at = CURRENT_TIMESTAMP
+               WHERE project_id = $1
+                 AND status IN ('done', 'cancelled')
+                 AND archived_at IS NULL
+                 AND ($2 IS NULL OR updated_at < $2)"#,
+            project_id,
+            before
+        )
+        .execute(pool)
+        .await?;
+        Ok(result.rows_affected())
+    }
+
+    /// Select the project setup profile that runs before the coding agent
+    pub async fn update_setup_profile(
+        pool: &SqlitePool,
+        id: Uuid,
+        setup_profile: Option<&str>,
+    ) -> Result<(), sqlx::Error> {
+        sqlx::query!(
+            "UPDATE tasks SET setup_profile = $2, updated_at = CURRENT_TIMESTAMP WHERE id = $1",
+            id,
+            setup_profile
+        )
+        .execute(pool)
+        .await?;
+        Ok(())
+    }
+
     /// Update the parent_workspace_id field for a task
     pub async fn update_parent_workspace_id(
         pool: &SqlitePool,
@@ -737,7 +935,7 @@ ORDER BY t.created_at DESC"#
         // Find only child tasks that have this workspace as their parent
         sqlx::query_as!(
             Task,
-            r#"SELECT id as "id!: Uuid", project_id as "project_id!: Uuid", title, description, status as "status!: TaskStatus", parent_workspace_id as "parent_workspace_id: Uuid", shared_task_id as "shared_task_id: Uuid", linear_issue_id, linear_url, linear_labels, created_at as "created_at!: DateTime<Utc>", updated_at as "updated_at!: DateTime<Utc>"
+            r#"SELECT id as "id!: Uuid", project_id as "project_id!: Uuid", title, description, status as "status!: TaskStatus", parent_workspace_id as "parent_workspace_id: Uuid", shared_task_id as "shared_task_id: Uuid", linear_issue_id, linear_url, linear_labels, tags, setup_profile, due_date as "due_date: NaiveDate", created_at as "created_at!: DateTime<Utc>", updated_at as "updated_at!: DateTime<Utc>"
                FROM tasks
                WHERE parent_workspace_id = $1
                ORDER BY created_at DESC"#,
@@ -754,7 +952,7 @@ ORDER BY t.created_at DESC"#
     ) -> Result<Option<Self>, sqlx::Error> {
         sqlx::query_as!(
             Task,
-            r#"SELECT id as "id!: Uuid", project_id as "project_id!: Uuid", title, description, status as "status!: TaskStatus", parent_workspace_id as "parent_workspace_id: Uuid", shared_task_id as "shared_task_id: Uuid", linear_issue_id, linear_url, linear_labels, created_at as "created_at!: DateTime<Utc>", updated_at as "updated_at!: DateTime<Utc>"
+            r#"SELECT id as "id!: Uuid", project_id as "project_id!: Uuid", title, description, status as "status!: TaskStatus", parent_workspace_id as "parent_workspace_id: Uuid", shared_task_id as "shared_task_id: Uuid", linear_issue_id, linear_url, linear_labels, tags, setup_profile, due_date as "due_date: NaiveDate", created_at as "created_at!: DateTime<Utc>", updated_at as "updated_at!: DateTime<Utc>"
                FROM tasks
                WHERE project_id = $1 AND linear_issue_id = $2
                LIMIT 1"#,
@@ -765,6 +963,40 @@ ORDER BY t.created_at DESC"#
         .await
     }
 
+    pub async fn find_by_linear_url(
+        pool: &SqlitePool,
+        project_id: Uuid,
+        linear_url: &str,
+    ) -> Result<Option<Self>, sqlx::Error> {
+        sqlx::query_as!(
+            Task,
+            r#"SELECT id as "id!: Uuid", project_id as "project_id!: Uuid", title, description, status as "status!: TaskStatus", parent_workspace_id as "parent_workspace_id: Uuid", shared_task_id as "shared_task_id: Uuid", linear_issue_id, linear_url, linear_labels, tags, setup_profile, due_date as "due_date: NaiveDate", created_at as "created_at!: DateTime<Utc>", updated_at as "updated_at!: DateTime<Utc>"
+               FROM tasks
+               WHERE project_id = $1 AND linear_url = $2
+               LIMIT 1"#,
+            project_id,
+            linear_url
+        )
+        .fetch_optional(pool)
+        .await
+    }
+
+    /// All tasks in a project that are linked to a Linear issue
+    pub async fn find_linear_linked_by_project_id(
+        pool: &SqlitePool,
+        project_id: Uuid,
+    ) -> Result<Vec<Self>, sqlx::Error> {
+        sqlx::query_as!(
+            Task,
+            r#"SELECT id as "id!: Uuid", project_id as "project_id!: Uuid", title, description, status as "status!: TaskStatus", parent_workspace_id as "parent_workspace_id: Uuid", shared_task_id as "shared_task_id: Uuid", linear_issue_id, linear_url, linear_labels, tags, setup_profile, due_date as "due_date: NaiveDate", created_at as "created_at!: DateTime<Utc>", updated_at as "updated_at!: DateTime<Utc>"
+               FROM tasks
+               WHERE project_id = $1 AND linear_issue_id IS NOT NULL"#,
+            project_id
+        )
+        .fetch_all(pool)
+        .await
+    }
+
     pub async fn find_relationships_for_workspace(
         pool: &SqlitePool,
         workspace: &Workspace,
@@ -0,0 +1,206 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::SqlitePool;
+use ts_rs::TS;
+use uuid::Uuid;
+
+use super::{
+    execution_process::{ExecutionProcess, ExecutionProcessRunReason, ExecutionProcessStatus},
+    merge::{Merge, MergeStatus},
+    session::Session,
+    task::{Task, TaskStatus},
+    workspace::{Workspace, WorkspaceError},
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, TS)]
+#[serde(rename_all = "snake_case")]
+pub enum TaskTimelineEventKind {
+    Created,
+    StatusChanged,
+    AttemptStarted,
+    ProcessStarted,
+    ProcessCompleted,
+    ProcessFailed,
+    ProcessStopped,
+    PrOpened,
+    PrMerged,
+    Merged,
+}
+
+/// One entry of a task's activity timeline
+#[derive(Debug, Clone, Serialize, Deserialize, TS)]
+pub struct TaskTimelineEvent {
+    pub kind: TaskTimelineEventKind,
+    pub at: DateTime<Utc>,
+    /// What happened, e.g. "Coding agent finished" or "PR #12 merged"
+    pub summary: String,
+    pub workspace_id: Option<Uuid>,
+    pub execution_process_id: Option<Uuid>,
+}
+
+impl TaskTimelineEvent {
+    fn new(kind: TaskTimelineEventKind, at: DateTime<Utc>, summary: String) -> Self {
+        Self {
+            kind,
+            at,
+            summary,
+            workspace_id: None,
+            execution_process_id: None,
+        }
+    }
+
+    /// Activity of `task` oldest first: its creation, status changes, attempts,
+    /// the processes they ran and their merges. Dev servers are left out since
+    /// they say nothing about the task's progress.
+    pub async fn find_for_task(
+        pool: &SqlitePool,
+        task: &Task,
+    ) -> Result<Vec<Self>, WorkspaceError> {
+        let mut events = vec![Self::new(
+            TaskTimelineEventKind::Created,
+            task.created_at,
+            "Task created".to_string(),
+        )];
+
+        let status_changes = sqlx::query!(
+            r#"SELECT from_status as "from_status!: TaskStatus",
+                      to_status as "to_status!: TaskStatus",
+                      changed_at as "changed_at!: DateTime<Utc>"
+               FROM task_status_changes
+               WHERE task_id = $1
+               ORDER BY rowid ASC"#,
+            task.id
+        )
+        .fetch_all(pool)
+        .await?;
+        for change in status_changes {
+            events.push(Self::new(
+                TaskTimelineEventKind::StatusChanged,
+                change.changed_at,
+                format!(
+                    "Moved from {} to {}",
+                    status_label(&change.from_status),
+                    status_label(&change.to_status)
+                ),
+            ));
+        }
+
+        for workspace in Workspace::fetch_all(pool, Some(task.id)).await? {
+            events.push(Self {
+                workspace_id: Some(workspace.id),
+                ..Self::new(
+                    TaskTimelineEventKind::AttemptStarted,
+                    workspace.created_at,
+                    format!("Attempt started on {}", workspace.branch),
+                )
+            });
+
+            for session in Session::find_by_workspace_id(pool, workspace.id).await? {
+                for process in ExecutionProcess::find_by_session_id(pool, session.id, false).await?
+                {
+                    if process.run_reason != ExecutionProcessRunReason::DevServer {
+                        events.extend(process_events(workspace.id, &process));
+                    }
+                }
+            }
+
+            for merge in Merge::find_by_workspace_id(pool, workspace.id).await? {
+                events.extend(merge_events(workspace.id, &merge));
+            }
+        }
+
+        events.sort_by_key(|event| event.at);
+        Ok(events)
+    }
+}
+
+fn process_events(workspace_id: Uuid, process: &ExecutionProcess) -> Vec<TaskTimelineEvent> {
+    let label = run_reason_label(&process.run_reason);
+    let event = |kind, at, summary| TaskTimelineEvent {
+        workspace_id: Some(workspace_id),
+        execution_process_id: Some(process.id),
+        ..TaskTimelineEvent::new(kind, at, summary)
+    };
+
+    let mut events = vec![event(
+        TaskTimelineEventKind::ProcessStarted,
+        process.started_at,
+        format!("{} started", label),
+    )];
+    if let Some(completed_at) = process.completed_at {
+        let (kind, summary) = match process.status {
+            ExecutionProcessStatus::Running | ExecutionProcessStatus::Completed => (
+                TaskTimelineEventKind::ProcessCompleted,
+                format!("{} finished", label),
+            ),
+            ExecutionProcessStatus::Failed => (
+                TaskTimelineEventKind::ProcessFailed,
+                match process.exit_code {
+                    Some(code) => format!("{} failed (exit code {})", label, code),
+                    None => format!("{} failed", label),
+                },
+            ),
+            ExecutionProcessStatus::Killed => (
+                TaskTimelineEventKind::ProcessStopped,
+                format!("{} stopped", label),
+            ),
+        };
+        events.push(event(kind, completed_at, summary));
+    }
+    events
+}
+
+/// A PR's close time isn't stored, so only its opening and merge show up
+fn merge_events(workspace_id: Uuid, merge: &Merge) -> Vec<TaskTimelineEvent> {
+    let event = |kind, at, summary| TaskTimelineEvent {
+        workspace_id: Some(workspace_id),
+        ..TaskTimelineEvent::new(kind, at, summary)
+    };
+
+    match merge {
+        Merge::Direct(direct) => vec![event(
+            TaskTimelineEventKind::Merged,
+            direct.created_at,
+            format!("Merged into {}", direct.target_branch_name),
+        )],
+        Merge::Pr(pr) => {
+            let number = pr.pr_info.number;
+            let mut events = vec![event(
+                TaskTimelineEventKind::PrOpened,
+                pr.created_at,
+                format!("PR #{} opened", number),
+            )];
+            if pr.pr_info.status == MergeStatus::Merged {
+                events.push(event(
+                    TaskTimelineEventKind::PrMerged,
+                    pr.pr_info.merged_at.unwrap_or(pr.created_at),
+                    format!("PR #{} merged", number),
+                ));
+            }
+            events
+        }
+    }
+}
+
+fn status_label(status: &TaskStatus) -> &'static str {
+    match status {
+        TaskStatus::Backlog => "Backlog",
+        TaskStatus::Todo => "To Do",
+        TaskStatus::InProgress => "In Progress",
+        TaskStatus::InReview => "In Review",
+        TaskStatus::Done => "Done",
+        TaskStatus::Cancelled => "Cancelled",
+    }
+}
+
+fn run_reason_label(run_reason: &ExecutionProcessRunReason) -> &'static str {
+    match run_reason {
+        ExecutionProcessRunReason::SetupScript => "Setup script",
+        ExecutionProcessRunReason::CleanupScript => "Cleanup script",
+        ExecutionProcessRunReason::CodingAgent => "Coding agent",
+        ExecutionProcessRunReason::DevServer => "Dev server",
+        ExecutionProcessRunReason::QuickCommand => "Command",
+        ExecutionProcessRunReason::SlashCommand => "Slash command",
+        ExecutionProcessRunReason::ImportedSession => "Imported session",
+    }
+}
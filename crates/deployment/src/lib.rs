@@ -25,7 +25,8 @@ use services::services::{
     filesystem_watcher::FilesystemWatcherError,
     git::{GitService, GitServiceError},
     image::{ImageError, ImageService},
-    pr_monitor::PrMonitorService,
+    linear_sync::LinearSyncService,
+    pr_monitor::{PrMonitorService, PrMonitorStatusHandle},
     project::ProjectService,
     queued_message::QueuedMessageService,
     repo::RepoService,
@@ -108,10 +109,19 @@ pub trait Deployment: Clone + Send + Sync + 'static {
 
     fn share_publisher(&self) -> Result<SharePublisher, RemoteClientNotConfigured>;
 
+    fn pr_monitor_status(&self) -> &PrMonitorStatusHandle;
+
     async fn spawn_pr_monitor_service(&self) -> tokio::task::JoinHandle<()> {
         let db = self.db().clone();
         let publisher = self.share_publisher().ok();
-        PrMonitorService::spawn(db, publisher).await
+        let status = self.pr_monitor_status().clone();
+        PrMonitorService::spawn(db, publisher, status).await
+    }
+
+    async fn spawn_linear_sync_service(&self) -> tokio::task::JoinHandle<()> {
+        let db = self.db().clone();
+        let publisher = self.share_publisher().ok();
+        LinearSyncService::spawn(db, publisher).await
     }
 
     /// Trigger background auto-setup of default projects for new users
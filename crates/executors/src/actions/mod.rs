@@ -1,4 +1,4 @@
-use std::{path::Path, sync::Arc};
+use std::{path::Path, sync::Arc, time::Duration};
 
 use async_trait::async_trait;
 use enum_dispatch::enum_dispatch;
@@ -13,6 +13,7 @@ use crate::{
     approvals::ExecutorApprovalService,
     env::ExecutionEnv,
     executors::{BaseCodingAgent, ExecutorError, SpawnedChild},
+    profile::ExecutorConfigs,
 };
 pub mod coding_agent_follow_up;
 pub mod coding_agent_initial;
@@ -63,6 +64,18 @@ impl ExecutorAction {
             ExecutorActionType::ScriptRequest(_) => None,
         }
     }
+
+    /// Max runtime configured on the coding agent's profile; scripts run unbounded
+    pub fn max_runtime(&self) -> Option<Duration> {
+        let executor_profile_id = match self.typ() {
+            ExecutorActionType::CodingAgentInitialRequest(request) => &request.executor_profile_id,
+            ExecutorActionType::CodingAgentFollowUpRequest(request) => &request.executor_profile_id,
+            ExecutorActionType::ScriptRequest(_) => return None,
+        };
+        ExecutorConfigs::get_cached()
+            .get_coding_agent(executor_profile_id)?
+            .max_runtime()
+    }
 }
 
 #[async_trait]
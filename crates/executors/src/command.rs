@@ -1,4 +1,7 @@
-use std::{collections::HashMap, path::PathBuf};
+use std::{
+    collections::HashMap,
+    path::{Path, PathBuf},
+};
 
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
@@ -38,6 +41,14 @@ impl CommandParts {
     }
 }
 
+/// Shell-quoted command line for a resolved program and its arguments, recorded
+/// so the exact agent invocation can be copied and re-run by hand.
+pub fn command_line(program: &Path, args: &[String]) -> String {
+    let program = program.to_string_lossy();
+    let parts = std::iter::once(program.as_ref()).chain(args.iter().map(String::as_str));
+    shlex::try_join(parts.clone()).unwrap_or_else(|_| parts.collect::<Vec<_>>().join(" "))
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, TS, JsonSchema, Default)]
 pub struct CmdOverrides {
     #[schemars(
@@ -58,6 +69,12 @@ pub struct CmdOverrides {
     )]
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub env: Option<HashMap<String, String>>,
+    #[schemars(
+        title = "Max Runtime (minutes)",
+        description = "Kill the agent if a single run takes longer than this many minutes"
+    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_runtime_minutes: Option<u32>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, TS, JsonSchema)]
@@ -160,3 +177,21 @@ pub fn apply_overrides(builder: CommandBuilder, overrides: &CmdOverrides) -> Com
         builder
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_command_line_quotes_args() {
+        let args = vec![
+            "-p".to_string(),
+            "--model".to_string(),
+            "my model".to_string(),
+        ];
+        assert_eq!(
+            command_line(Path::new("/usr/local/bin/claude"), &args),
+            "/usr/local/bin/claude -p --model 'my model'"
+        );
+    }
+}
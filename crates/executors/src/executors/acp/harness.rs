@@ -20,7 +20,7 @@ use workspace_utils::{approvals::ApprovalStatus, stream_lines::LinesStreamExt};
 use super::{AcpClient, SessionManager};
 use crate::{
     approvals::ExecutorApprovalService,
-    command::{CmdOverrides, CommandParts},
+    command::{CmdOverrides, CommandParts, command_line},
     env::ExecutionEnv,
     executors::{ExecutorError, ExecutorExitResult, SpawnedChild, acp::AcpEvent},
 };
@@ -78,6 +78,7 @@ impl AcpAgentHarness {
         approvals: Option<std::sync::Arc<dyn ExecutorApprovalService>>,
     ) -> Result<SpawnedChild, ExecutorError> {
         let (program_path, args) = command_parts.into_resolved().await?;
+        let command_line = command_line(&program_path, &args);
         let mut command = Command::new(program_path);
         command
             .kill_on_drop(true)
@@ -112,6 +113,7 @@ impl AcpAgentHarness {
             child,
             exit_signal: Some(exit_rx),
             interrupt_sender: None,
+            command: Some(command_line),
         })
     }
 
@@ -127,6 +129,7 @@ impl AcpAgentHarness {
         approvals: Option<std::sync::Arc<dyn ExecutorApprovalService>>,
     ) -> Result<SpawnedChild, ExecutorError> {
         let (program_path, args) = command_parts.into_resolved().await?;
+        let command_line = command_line(&program_path, &args);
         let mut command = Command::new(program_path);
         command
             .kill_on_drop(true)
@@ -161,6 +164,7 @@ impl AcpAgentHarness {
             child,
             exit_signal: Some(exit_rx),
             interrupt_sender: None,
+            command: Some(command_line),
         })
     }
 
@@ -9,7 +9,7 @@ use ts_rs::TS;
 use workspace_utils::msg_store::MsgStore;
 
 use crate::{
-    command::{CmdOverrides, CommandBuilder, apply_overrides},
+    command::{CmdOverrides, CommandBuilder, apply_overrides, command_line},
     env::ExecutionEnv,
     executors::{
         AppendPrompt, ExecutorError, SpawnedChild, StandardCodingAgentExecutor,
@@ -53,6 +53,7 @@ impl StandardCodingAgentExecutor for Amp {
     ) -> Result<SpawnedChild, ExecutorError> {
         let command_parts = self.build_command_builder().build_initial()?;
         let (executable_path, args) = command_parts.into_resolved().await?;
+        let command_line = command_line(&executable_path, &args);
 
         let combined_prompt = self.append_prompt.combine_prompt(prompt);
 
@@ -77,7 +78,7 @@ impl StandardCodingAgentExecutor for Amp {
             stdin.shutdown().await?;
         }
 
-        Ok(child.into())
+        Ok(SpawnedChild::from(child).with_command(command_line))
     }
 
     async fn spawn_follow_up(
@@ -126,6 +127,7 @@ impl StandardCodingAgentExecutor for Amp {
             new_thread_id.clone(),
         ])?;
         let (continue_program, continue_args) = continue_line.into_resolved().await?;
+        let command_line = command_line(&continue_program, &continue_args);
 
         let combined_prompt = self.append_prompt.combine_prompt(prompt);
 
@@ -150,7 +152,7 @@ impl StandardCodingAgentExecutor for Amp {
             stdin.shutdown().await?;
         }
 
-        Ok(child.into())
+        Ok(SpawnedChild::from(child).with_command(command_line))
     }
 
     fn normalize_logs(&self, msg_store: Arc<MsgStore>, current_dir: &Path) {
@@ -24,7 +24,7 @@ use self::{
 };
 use crate::{
     approvals::ExecutorApprovalService,
-    command::{CmdOverrides, CommandBuilder, CommandParts, apply_overrides},
+    command::{CmdOverrides, CommandBuilder, CommandParts, apply_overrides, command_line},
     env::ExecutionEnv,
     executors::{
         AppendPrompt, AvailabilityInfo, ExecutorError, SpawnedChild, StandardCodingAgentExecutor,
@@ -239,6 +239,7 @@ impl ClaudeCode {
         env: &ExecutionEnv,
     ) -> Result<SpawnedChild, ExecutorError> {
         let (program_path, args) = command_parts.into_resolved().await?;
+        let command_line = command_line(&program_path, &args);
         let combined_prompt = self.append_prompt.combine_prompt(prompt);
 
         let mut command = Command::new(program_path);
@@ -308,6 +309,7 @@ impl ClaudeCode {
             child,
             exit_signal: None,
             interrupt_sender: Some(interrupt_tx),
+            command: Some(command_line),
         })
     }
 }
@@ -2015,6 +2017,7 @@ mod tests {
                 base_command_override: None,
                 additional_params: None,
                 env: None,
+                max_runtime_minutes: None,
             },
             approvals_service: None,
             disable_api_key: None,
@@ -31,7 +31,7 @@ use self::{
 };
 use crate::{
     approvals::ExecutorApprovalService,
-    command::{CmdOverrides, CommandBuilder, CommandParts, apply_overrides},
+    command::{CmdOverrides, CommandBuilder, CommandParts, apply_overrides, command_line},
     env::ExecutionEnv,
     executors::{
         AppendPrompt, AvailabilityInfo, ExecutorError, ExecutorExitResult, SpawnedChild,
@@ -301,6 +301,7 @@ impl Codex {
     ) -> Result<SpawnedChild, ExecutorError> {
         let combined_prompt = self.append_prompt.combine_prompt(prompt);
         let (program_path, args) = command_parts.into_resolved().await?;
+        let command_line = command_line(&program_path, &args);
 
         let mut process = Command::new(program_path);
         process
@@ -390,6 +391,7 @@ impl Codex {
             child,
             exit_signal: Some(exit_signal_rx),
             interrupt_sender: None,
+            command: Some(command_line),
         })
     }
 
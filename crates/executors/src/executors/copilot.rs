@@ -22,7 +22,7 @@ use uuid::Uuid;
 use workspace_utils::{msg_store::MsgStore, path::get_vibe_kanban_temp_dir};
 
 use crate::{
-    command::{CmdOverrides, CommandBuilder, apply_overrides},
+    command::{CmdOverrides, CommandBuilder, apply_overrides, command_line},
     env::ExecutionEnv,
     executors::{
         AppendPrompt, AvailabilityInfo, ExecutorError, SpawnedChild, StandardCodingAgentExecutor,
@@ -109,6 +109,7 @@ impl StandardCodingAgentExecutor for Copilot {
             .build_command_builder(&log_dir.to_string_lossy())
             .build_initial()?;
         let (program_path, args) = command_parts.into_resolved().await?;
+        let command_line = command_line(&program_path, &args);
 
         let combined_prompt = self.append_prompt.combine_prompt(prompt);
 
@@ -137,7 +138,7 @@ impl StandardCodingAgentExecutor for Copilot {
         let (_, appender) = stdout_dup::tee_stdout_with_appender(&mut child)?;
         Self::send_session_id(log_dir, appender);
 
-        Ok(child.into())
+        Ok(SpawnedChild::from(child).with_command(command_line))
     }
 
     async fn spawn_follow_up(
@@ -152,6 +153,7 @@ impl StandardCodingAgentExecutor for Copilot {
             .build_command_builder(&log_dir.to_string_lossy())
             .build_follow_up(&["--resume".to_string(), session_id.to_string()])?;
         let (program_path, args) = command_parts.into_resolved().await?;
+        let command_line = command_line(&program_path, &args);
 
         let combined_prompt = self.append_prompt.combine_prompt(prompt);
 
@@ -181,7 +183,7 @@ impl StandardCodingAgentExecutor for Copilot {
         let (_, appender) = stdout_dup::tee_stdout_with_appender(&mut child)?;
         Self::send_session_id(log_dir, appender);
 
-        Ok(child.into())
+        Ok(SpawnedChild::from(child).with_command(command_line))
     }
 
     /// Parses both stderr and stdout logs for Copilot executor using PlainTextLogProcessor.
@@ -16,7 +16,7 @@ use workspace_utils::{
 };
 
 use crate::{
-    command::{CmdOverrides, CommandBuilder, apply_overrides},
+    command::{CmdOverrides, CommandBuilder, apply_overrides, command_line},
     env::ExecutionEnv,
     executors::{
         AppendPrompt, AvailabilityInfo, ExecutorError, SpawnedChild, StandardCodingAgentExecutor,
@@ -80,6 +80,7 @@ impl StandardCodingAgentExecutor for CursorAgent {
         let command_parts = self.build_command_builder().build_initial()?;
 
         let (executable_path, args) = command_parts.into_resolved().await?;
+        let command_line = command_line(&executable_path, &args);
 
         let combined_prompt = self.append_prompt.combine_prompt(prompt);
 
@@ -103,7 +104,7 @@ impl StandardCodingAgentExecutor for CursorAgent {
             stdin.shutdown().await?;
         }
 
-        Ok(child.into())
+        Ok(SpawnedChild::from(child).with_command(command_line))
     }
 
     async fn spawn_follow_up(
@@ -119,6 +120,7 @@ impl StandardCodingAgentExecutor for CursorAgent {
             .build_command_builder()
             .build_follow_up(&["--resume".to_string(), session_id.to_string()])?;
         let (executable_path, args) = command_parts.into_resolved().await?;
+        let command_line = command_line(&executable_path, &args);
 
         let combined_prompt = self.append_prompt.combine_prompt(prompt);
 
@@ -142,7 +144,7 @@ impl StandardCodingAgentExecutor for CursorAgent {
             stdin.shutdown().await?;
         }
 
-        Ok(child.into())
+        Ok(SpawnedChild::from(child).with_command(command_line))
     }
 
     fn normalize_logs(&self, msg_store: Arc<MsgStore>, worktree_path: &Path) {
@@ -10,7 +10,7 @@ use ts_rs::TS;
 use workspace_utils::msg_store::MsgStore;
 
 use crate::{
-    command::CommandParts,
+    command::{CommandParts, command_line},
     env::ExecutionEnv,
     executors::{AppendPrompt, ExecutorError, SpawnedChild, StandardCodingAgentExecutor},
     logs::utils::EntryIndexProvider,
@@ -113,6 +113,7 @@ async fn spawn_droid(
     cmd_overrides: &crate::command::CmdOverrides,
 ) -> Result<SpawnedChild, ExecutorError> {
     let (program_path, args) = command_parts.into_resolved().await?;
+    let command_line = command_line(&program_path, &args);
 
     let mut command = Command::new(program_path);
     command
@@ -134,7 +135,7 @@ async fn spawn_droid(
         stdin.shutdown().await?;
     }
 
-    Ok(child.into())
+    Ok(SpawnedChild::from(child).with_command(command_line))
 }
 
 #[async_trait]
@@ -1,4 +1,4 @@
-use std::{path::Path, sync::Arc};
+use std::{path::Path, sync::Arc, time::Duration};
 
 use async_trait::async_trait;
 use command_group::AsyncGroupChild;
@@ -15,7 +15,7 @@ use workspace_utils::msg_store::MsgStore;
 use crate::{
     actions::ExecutorAction,
     approvals::ExecutorApprovalService,
-    command::CommandBuildError,
+    command::{CmdOverrides, CommandBuildError},
     env::ExecutionEnv,
     executors::{
         amp::Amp, claude::ClaudeCode, codex::Codex, copilot::Copilot, cursor::CursorAgent,
@@ -170,6 +170,28 @@ impl CodingAgent {
             Self::Copilot(_) => vec![],
         }
     }
+
+    pub fn cmd_overrides(&self) -> &CmdOverrides {
+        match self {
+            Self::ClaudeCode(executor) => &executor.cmd,
+            Self::Amp(executor) => &executor.cmd,
+            Self::Gemini(executor) => &executor.cmd,
+            Self::Codex(executor) => &executor.cmd,
+            Self::Opencode(executor) => &executor.cmd,
+            Self::CursorAgent(executor) => &executor.cmd,
+            Self::QwenCode(executor) => &executor.cmd,
+            Self::Copilot(executor) => &executor.cmd,
+            Self::Droid(executor) => &executor.cmd,
+        }
+    }
+
+    /// How long a single run may take before it is killed; None (or 0) means no limit
+    pub fn max_runtime(&self) -> Option<Duration> {
+        self.cmd_overrides()
+            .max_runtime_minutes
+            .filter(|minutes| *minutes > 0)
+            .map(|minutes| Duration::from_secs(u64::from(minutes) * 60))
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, TS)]
@@ -256,6 +278,15 @@ pub struct SpawnedChild {
     pub exit_signal: Option<ExecutorExitSignal>,
     /// Container → Executor: signals when container wants to interrupt
     pub interrupt_sender: Option<InterruptSender>,
+    /// Resolved command line the agent was started with
+    pub command: Option<String>,
+}
+
+impl SpawnedChild {
+    pub fn with_command(mut self, command: String) -> Self {
+        self.command = Some(command);
+        self
+    }
 }
 
 impl From<AsyncGroupChild> for SpawnedChild {
@@ -264,6 +295,7 @@ impl From<AsyncGroupChild> for SpawnedChild {
             child,
             exit_signal: None,
             interrupt_sender: None,
+            command: None,
         }
     }
 }
@@ -322,4 +354,18 @@ mod tests {
         assert!(result.is_ok(), "CURSOR should deserialize via serde");
         assert_eq!(result.unwrap(), BaseCodingAgent::CursorAgent);
     }
+
+    #[test]
+    fn test_max_runtime_from_profile() {
+        let agent: CodingAgent =
+            serde_json::from_str(r#"{"AMP": {"max_runtime_minutes": 30}}"#).unwrap();
+        assert_eq!(agent.max_runtime(), Some(Duration::from_secs(30 * 60)));
+
+        let agent: CodingAgent = serde_json::from_str(r#"{"AMP": {}}"#).unwrap();
+        assert_eq!(agent.max_runtime(), None);
+
+        let agent: CodingAgent =
+            serde_json::from_str(r#"{"AMP": {"max_runtime_minutes": 0}}"#).unwrap();
+        assert_eq!(agent.max_runtime(), None);
+    }
 }
@@ -401,6 +401,41 @@ impl ExecutorConfigs {
             .cloned()
     }
 
+    /// Check that a profile names a configured executor and variant before anything is
+    /// started with it; the error lists the variants that exist
+    pub fn validate_profile(
+        &self,
+        executor_profile_id: &ExecutorProfileId,
+    ) -> Result<(), ProfileError> {
+        let executor = self
+            .executors
+            .get(&executor_profile_id.executor)
+            .ok_or_else(|| {
+                ProfileError::Validation(format!(
+                    "Executor '{}' is not configured",
+                    executor_profile_id.executor
+                ))
+            })?;
+        let Some(variant) = &executor_profile_id.variant else {
+            return Ok(());
+        };
+        if executor.get_variant(variant).is_some() {
+            return Ok(());
+        }
+
+        let mut variants = executor.variant_names();
+        variants.sort();
+        let valid: Vec<&str> = std::iter::once("DEFAULT")
+            .chain(variants.into_iter().map(String::as_str))
+            .collect();
+        Err(ProfileError::Validation(format!(
+            "Unknown variant '{}' for executor '{}'; valid variants: {}",
+            variant,
+            executor_profile_id.executor,
+            valid.join(", ")
+        )))
+    }
+
     pub fn get_coding_agent_or_default(
         &self,
         executor_profile_id: &ExecutorProfileId,
@@ -482,3 +517,29 @@ pub fn to_default_variant(id: &ExecutorProfileId) -> ExecutorProfileId {
         variant: None,
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_validate_profile_lists_valid_variants() {
+        let configs = ExecutorConfigs::from_defaults();
+        let claude = |variant: Option<&str>| ExecutorProfileId {
+            executor: BaseCodingAgent::ClaudeCode,
+            variant: variant.map(String::from),
+        };
+
+        assert!(configs.validate_profile(&claude(None)).is_ok());
+        assert!(configs.validate_profile(&claude(Some("DEFAULT"))).is_ok());
+        assert!(configs.validate_profile(&claude(Some("PLAN"))).is_ok());
+
+        let err = configs
+            .validate_profile(&claude(Some("PALN")))
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains("Unknown variant 'PALN'"), "{}", err);
+        assert!(err.contains("valid variants: DEFAULT, "), "{}", err);
+        assert!(err.contains("PLAN"), "{}", err);
+    }
+}
@@ -0,0 +1,241 @@
+//! Project commit message templates for agent commits.
+//!
+//! Agent processes get the rendered template in `VK_COMMIT_MESSAGE_TEMPLATE` and a
+//! `core.hooksPath` override through git's `GIT_CONFIG_*` variables. The hooks in
+//! that directory apply the template in `commit-msg` and otherwise forward to the
+//! repository's own hooks, so nothing is installed into the user's repositories.
+
+use std::{fs, io, path::PathBuf};
+
+use db::models::task::Task;
+use executors::env::ExecutionEnv;
+
+const TEMPLATE_ENV: &str = "VK_COMMIT_MESSAGE_TEMPLATE";
+
+/// Client-side hooks git may run during an agent's work
+const GIT_HOOKS: &[&str] = &[
+    "applypatch-msg",
+    "pre-applypatch",
+    "post-applypatch",
+    "pre-commit",
+    "pre-merge-commit",
+    "prepare-commit-msg",
+    "commit-msg",
+    "post-commit",
+    "pre-rebase",
+    "post-checkout",
+    "post-merge",
+    "pre-push",
+    "post-rewrite",
+    "reference-transaction",
+    "pre-auto-gc",
+    "post-index-change",
+];
+
+/// Rewrites the subject line: the template with `{message}` replaced by the
+/// agent's subject, or the template followed by it. Amends that already carry
+/// the template are left alone.
+const APPLY_TEMPLATE: &str = r#"if [ -n "$VK_COMMIT_MESSAGE_TEMPLATE" ]; then
+    case "$VK_COMMIT_MESSAGE_TEMPLATE" in
+        *"{message}"*)
+            prefix=${VK_COMMIT_MESSAGE_TEMPLATE%%"{message}"*}
+            suffix=${VK_COMMIT_MESSAGE_TEMPLATE#*"{message}"}
+            ;;
+        *)
+            prefix="$VK_COMMIT_MESSAGE_TEMPLATE "
+            suffix=
+            ;;
+    esac
+    subject=$(head -n 1 "$1")
+    case "$subject" in
+        "$prefix"*"$suffix") ;;
+        *)
+            { printf '%s%s%s\n' "$prefix" "$subject" "$suffix"; tail -n +2 "$1"; } > "$1.vk" &&
+                mv "$1.vk" "$1"
+            ;;
+    esac
+fi
+"#;
+
+/// Fill in the task placeholders, leaving `{message}` for the hook
+fn render(template: &str, task: &Task, branch: &str) -> String {
+    let task_id = task.id.to_string();
+    template
+        .replace("{task_title}", &task.title)
+        .replace("{short_task_id}", &task_id[..8])
+        .replace("{task_id}", &task_id)
+        .replace("{branch}", branch)
+}
+
+/// Run the repository's own hook, looked up without our `core.hooksPath` override
+fn forward_script(hook: &str) -> String {
+    format!(
+        r#"hook="$(GIT_CONFIG_COUNT=0 git rev-parse --path-format=absolute --git-path hooks)/{hook}"
+if [ -x "$hook" ]; then
+    exec "$hook" "$@"
+fi
+"#
+    )
+}
+
+fn hook_script(hook: &str) -> String {
+    let apply = if hook == "commit-msg" {
+        APPLY_TEMPLATE
+    } else {
+        ""
+    };
+    format!(
+        "#!/bin/sh\n# Generated by vibe-kanban for agent processes\n{}{}",
+        apply,
+        forward_script(hook)
+    )
+}
+
+/// Write the shared hooks directory, rewriting only hooks whose content changed
+fn ensure_hooks_dir() -> io::Result<PathBuf> {
+    let dir = utils::assets::asset_dir().join("git-hooks");
+    fs::create_dir_all(&dir)?;
+
+    for hook in GIT_HOOKS {
+        let path = dir.join(hook);
+        let script = hook_script(hook);
+        if fs::read_to_string(&path).ok().as_deref() != Some(script.as_str()) {
+            fs::write(&path, script)?;
+        }
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+            fs::set_permissions(&path, fs::Permissions::from_mode(0o755))?;
+        }
+    }
+
+    Ok(dir)
+}
+
+/// Make commits by the agent follow the project's template
+pub(crate) fn apply_to_env(env: &mut ExecutionEnv, template: &str, task: &Task, branch: &str) {
+    let hooks_dir = match ensure_hooks_dir() {
+        Ok(dir) => dir,
+        Err(e) => {
+            tracing::warn!("Failed to write commit message hooks: {}", e);
+            return;
+        }
+    };
+
+    env.insert(TEMPLATE_ENV, render(template, task, branch));
+    env.insert("GIT_CONFIG_COUNT", "1");
+    env.insert("GIT_CONFIG_KEY_0", "core.hooksPath");
+    env.insert("GIT_CONFIG_VALUE_0", hooks_dir.to_string_lossy());
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{fs, process::Command};
+
+    use db::models::task::TaskStatus;
+    use sqlx::types::chrono::Utc;
+    use tempfile::TempDir;
+    use uuid::Uuid;
+
+    use super::*;
+
+    fn task(title: &str) -> Task {
+        Task {
+            id: Uuid::parse_str("1b4e28ba-2fa1-11d2-883f-0016d3cca427").unwrap(),
+            project_id: Uuid::new_v4(),
+            title: title.to_string(),
+            description: None,
+            status: TaskStatus::Todo,
+            parent_workspace_id: None,
+            shared_task_id: None,
+            linear_issue_id: None,
+            linear_url: None,
+            linear_labels: None,
+            tags: None,
+            setup_profile: None,
+            due_date: None,
+            created_at: Utc::now(),
+            updated_at: Utc::now(),
+        }
+    }
+
+    /// Run `APPLY_TEMPLATE` against a commit message file, like git's commit-msg hook
+    fn apply(template: &str, message: &str) -> String {
+        let dir = TempDir::new().unwrap();
+        let path = dir.path().join("COMMIT_EDITMSG");
+        fs::write(&path, message).unwrap();
+
+        let status = Command::new("sh")
+            .arg("-c")
+            .arg(APPLY_TEMPLATE)
+            .arg("commit-msg")
+            .arg(&path)
+            .env(TEMPLATE_ENV, template)
+            .status()
+            .unwrap();
+        assert!(status.success());
+
+        fs::read_to_string(&path).unwrap()
+    }
+
+    #[test]
+    fn test_render_fills_task_placeholders() {
+        let rendered = render(
+            "[{short_task_id}] {message} ({task_title} on {branch}, {task_id})",
+            &task("Fix login"),
+            "vk/1b4e-fix-login",
+        );
+        assert_eq!(
+            rendered,
+            "[1b4e28ba] {message} (Fix login on vk/1b4e-fix-login, 1b4e28ba-2fa1-11d2-883f-0016d3cca427)"
+        );
+    }
+
+    #[test]
+    fn test_render_without_placeholders() {
+        assert_eq!(render("chore:", &task("Fix login"), "main"), "chore:");
+    }
+
+    #[test]
+    fn test_commit_msg_hook_applies_template() {
+        let script = hook_script("commit-msg");
+        assert!(script.starts_with("#!/bin/sh\n"));
+        assert!(script.contains(APPLY_TEMPLATE));
+        assert!(script.ends_with(&forward_script("commit-msg")));
+        assert!(script.contains("--git-path hooks)/commit-msg\""));
+    }
+
+    #[test]
+    fn test_other_hooks_only_forward() {
+        for hook in GIT_HOOKS.iter().filter(|hook| **hook != "commit-msg") {
+            let script = hook_script(hook);
+            assert!(
+                !script.contains(TEMPLATE_ENV),
+                "{hook} applies the template"
+            );
+            assert!(script.ends_with(&forward_script(hook)));
+            assert!(script.contains(&format!("--git-path hooks)/{hook}\"")));
+        }
+    }
+
+    #[test]
+    fn test_apply_template_wraps_subject() {
+        assert_eq!(
+            apply("[1b4e28ba] {message} (vk)", "Fix login\n\nBody line\n"),
+            "[1b4e28ba] Fix login (vk)\n\nBody line\n"
+        );
+    }
+
+    #[test]
+    fn test_apply_template_prefixes_without_message_placeholder() {
+        assert_eq!(apply("[1b4e28ba]", "Fix login\n"), "[1b4e28ba] Fix login\n");
+    }
+
+    #[test]
+    fn test_apply_template_leaves_templated_amend_alone() {
+        assert_eq!(
+            apply("[1b4e28ba] {message}", "[1b4e28ba] Fix login\n"),
+            "[1b4e28ba] Fix login\n"
+        );
+    }
+}
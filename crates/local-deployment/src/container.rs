@@ -60,7 +60,7 @@ use utils::{
 };
 use uuid::Uuid;
 
-use crate::{command, copy};
+use crate::{command, commit_template, copy};
 
 #[derive(Clone)]
 pub struct LocalContainerService {
@@ -334,11 +334,13 @@ impl LocalContainerService {
     }
 
     /// Spawn a background task that polls the child process for completion and
-    /// cleans up the execution entry when it exits.
+    /// cleans up the execution entry when it exits. A process still running after
+    /// `max_runtime` is killed.
     pub fn spawn_exit_monitor(
         &self,
         exec_id: &Uuid,
         exit_signal: Option<ExecutorExitSignal>,
+        max_runtime: Option<Duration>,
     ) -> JoinHandle<()> {
         let exec_id = *exec_id;
         let child_store = self.child_store.clone();
@@ -353,6 +355,9 @@ impl LocalContainerService {
             let mut exit_signal_future = exit_signal
                 .map(|rx| rx.boxed()) // wait for result
                 .unwrap_or_else(|| std::future::pending().boxed()); // no signal, stall forever
+            let mut max_runtime_future = max_runtime
+                .map(|limit| tokio::time::sleep(limit).boxed())
+                .unwrap_or_else(|| std::future::pending().boxed());
 
             let status_result: std::io::Result<std::process::ExitStatus>;
 
@@ -381,6 +386,40 @@ impl LocalContainerService {
                 exit_status_result = &mut process_exit_rx => {
                     status_result = exit_status_result.unwrap_or_else(|e| Err(std::io::Error::other(e)));
                 }
+                // Runaway agent: stop it the same way a user-initiated stop does
+                _ = &mut max_runtime_future => {
+                    let minutes = max_runtime.unwrap_or_default().as_secs() / 60;
+                    tracing::warn!(
+                        "Execution process {} exceeded its max runtime of {} minutes, killing it",
+                        exec_id,
+                        minutes
+                    );
+                    if let Some(msg_store) = msg_stores.read().await.get(&exec_id) {
+                        msg_store.push_stderr(format!(
+                            "Killed after exceeding the max runtime of {} minutes",
+                            minutes
+                        ));
+                    }
+                    match ExecutionProcess::find_by_id(&db.pool, exec_id).await {
+                        Ok(Some(process)) => {
+                            if let Err(e) = container
+                                .stop_execution(&process, ExecutionProcessStatus::Killed)
+                                .await
+                            {
+                                tracing::error!(
+                                    "Failed to kill execution process {}: {}",
+                                    exec_id,
+                                    e
+                                );
+                            }
+                        }
+                        Ok(None) => {}
+                        Err(e) => {
+                            tracing::error!("Failed to load execution process {}: {}", exec_id, e)
+                        }
+                    }
+                    status_result = Ok(failure_exit_status());
+                }
             }
 
             let (exit_code, status) = match status_result {
@@ -433,11 +472,19 @@ impl LocalContainerService {
                         }
                     };
 
+                    // A pipeline's next agent runs even when this one only planned
+                    let next_is_coding_agent = ctx
+                        .execution_process
+                        .executor_action()
+                        .ok()
+                        .and_then(|action| action.next_action())
+                        .is_some_and(|next| next.base_executor().is_some());
+
                     let should_start_next = if matches!(
                         ctx.execution_process.run_reason,
                         ExecutionProcessRunReason::CodingAgent
                     ) {
-                        changes_committed
+                        changes_committed || next_is_coding_agent
                     } else {
                         true
                     };
@@ -709,6 +756,26 @@ impl LocalContainerService {
         Ok(())
     }
 
+    /// Empty working directory for a task that runs without any repositories
+    async fn create_scratch_dir(
+        &self,
+        workspace_dir: &Path,
+        workspace: &Workspace,
+    ) -> Result<ContainerRef, ContainerError> {
+        tokio::fs::create_dir_all(workspace_dir).await?;
+
+        self.copy_files_and_images(workspace_dir, workspace).await?;
+
+        Workspace::update_container_ref(
+            &self.db.pool,
+            workspace.id,
+            &workspace_dir.to_string_lossy(),
+        )
+        .await?;
+
+        Ok(workspace_dir.to_string_lossy().to_string())
+    }
+
     /// Create workspace-level CLAUDE.md and AGENTS.md files that import from each repo.
     /// Uses the @import syntax to reference each repo's config files.
     /// Skips creating files if they already exist or if no repos have the source file.
@@ -885,9 +952,16 @@ impl ContainerService for LocalContainerService {
         let workspace_repos =
             WorkspaceRepo::find_by_workspace_id(&self.db.pool, workspace.id).await?;
         if workspace_repos.is_empty() {
-            return Err(ContainerError::Other(anyhow!(
-                "Workspace has no repositories configured"
-            )));
+            let allow_repoless = task
+                .parent_project(&self.db.pool)
+                .await?
+                .is_some_and(|project| project.allow_repoless_tasks);
+            if !allow_repoless {
+                return Err(ContainerError::Other(anyhow!(
+                    "Workspace has no repositories configured"
+                )));
+            }
+            return self.create_scratch_dir(&workspace_dir, workspace).await;
         }
 
         let repositories =
@@ -951,9 +1025,8 @@ impl ContainerService for LocalContainerService {
             if let Some(container_ref) = &workspace.container_ref {
                 return Ok(container_ref.clone());
             }
-            return Err(ContainerError::Other(anyhow!(
-                "Workspace has no repositories configured"
-            )));
+            // Repo-less tasks get a fresh scratch dir after cleanup; create() rejects the rest
+            return self.create(workspace).await;
         }
 
         let workspace_dir = if let Some(container_ref) = &workspace.container_ref {
@@ -1065,6 +1138,27 @@ impl ContainerService for LocalContainerService {
         env.insert("VK_WORKSPACE_ID", workspace.id.to_string());
         env.insert("VK_WORKSPACE_BRANCH", &workspace.branch);
 
+        // Per-project git identity for commits made by the agent
+        if let Some(name) = project.git_author_name.as_deref().filter(|s| !s.is_empty()) {
+            env.insert("GIT_AUTHOR_NAME", name);
+            env.insert("GIT_COMMITTER_NAME", name);
+        }
+        if let Some(email) = project
+            .git_author_email
+            .as_deref()
+            .filter(|s| !s.is_empty())
+        {
+            env.insert("GIT_AUTHOR_EMAIL", email);
+            env.insert("GIT_COMMITTER_EMAIL", email);
+        }
+        if let Some(template) = project
+            .commit_message_template
+            .as_deref()
+            .filter(|s| !s.trim().is_empty())
+        {
+            commit_template::apply_to_env(&mut env, template, &task, &workspace.branch);
+        }
+
         // Create the child and stream, add to execution tracker with timeout
         let mut spawned = tokio::time::timeout(
             Duration::from_secs(30),
@@ -1077,6 +1171,17 @@ impl ContainerService for LocalContainerService {
             ))
         })??;
 
+        if let Some(command) = &spawned.command
+            && let Err(e) =
+                ExecutionProcess::set_command(&self.db.pool, execution_process.id, command).await
+        {
+            tracing::warn!(
+                "Failed to record command for execution process {}: {}",
+                execution_process.id,
+                e
+            );
+        }
+
         self.track_child_msgs_in_store(execution_process.id, &mut spawned.child)
             .await;
 
@@ -1090,7 +1195,11 @@ impl ContainerService for LocalContainerService {
         }
 
         // Spawn unified exit monitor: watches OS exit and optional executor signal
-        let _hn = self.spawn_exit_monitor(&execution_process.id, spawned.exit_signal);
+        let _hn = self.spawn_exit_monitor(
+            &execution_process.id,
+            spawned.exit_signal,
+            executor_action.max_runtime(),
+        );
 
         Ok(())
     }
@@ -15,6 +15,7 @@ use services::services::{
     git::GitService,
     image::ImageService,
     oauth_credentials::OAuthCredentials,
+    pr_monitor::PrMonitorStatusHandle,
     project::ProjectService,
     queued_message::QueuedMessageService,
     remote_client::{RemoteClient, RemoteClientError},
@@ -31,6 +32,7 @@ use uuid::Uuid;
 
 use crate::container::LocalContainerService;
 mod command;
+mod commit_template;
 pub mod container;
 mod copy;
 
@@ -77,6 +79,7 @@ pub struct LocalDeployment {
     file_search_cache: Arc<FileSearchCache>,
     approvals: Approvals,
     queued_message_service: QueuedMessageService,
+    pr_monitor_status: PrMonitorStatusHandle,
     share_publisher: Result<SharePublisher, RemoteClientNotConfigured>,
     share_config: Option<ShareConfig>,
     remote_client: Result<RemoteClient, RemoteClientNotConfigured>,
@@ -221,6 +224,7 @@ impl Deployment for LocalDeployment {
             file_search_cache,
             approvals,
             queued_message_service,
+            pr_monitor_status: PrMonitorStatusHandle::default(),
             share_publisher,
             share_config: share_config.clone(),
             remote_client,
@@ -287,6 +291,10 @@ impl Deployment for LocalDeployment {
         self.share_publisher.clone()
     }
 
+    fn pr_monitor_status(&self) -> &PrMonitorStatusHandle {
+        &self.pr_monitor_status
+    }
+
     fn auth_context(&self) -> &AuthContext {
         &self.auth_context
     }
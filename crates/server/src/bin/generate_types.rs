@@ -16,6 +16,7 @@ fn generate_types_content() -> String {
         remote::db::tasks::SharedTask::decl(),
         remote::db::users::UserData::decl(),
         db::models::project::Project::decl(),
+        db::models::project::ProjectWithHealth::decl(),
         db::models::project::CreateProject::decl(),
         db::models::project::UpdateProject::decl(),
         db::models::project::SearchResult::decl(),
@@ -36,6 +37,8 @@ fn generate_types_content() -> String {
         db::models::task::TaskRelationships::decl(),
         db::models::task::CreateTask::decl(),
         db::models::task::UpdateTask::decl(),
+        db::models::task_timeline::TaskTimelineEventKind::decl(),
+        db::models::task_timeline::TaskTimelineEvent::decl(),
         db::models::scratch::DraftFollowUpData::decl(),
         db::models::scratch::ScratchPayload::decl(),
         db::models::scratch::ScratchType::decl(),
@@ -120,11 +123,18 @@ fn generate_types_content() -> String {
         server::routes::shared_tasks::AssignSharedTaskRequest::decl(),
         server::routes::commander::CreateFollowUpRequest::decl(),
         server::routes::tasks::ShareTaskResponse::decl(),
+        server::routes::tasks::UpdateTaskStatusRequest::decl(),
+        server::routes::tasks::SetTaskTagsRequest::decl(),
+        server::routes::tasks::SetTaskSetupProfileRequest::decl(),
+        server::routes::tasks::MoveTaskRequest::decl(),
+        server::routes::tasks::AgentPipelineStep::decl(),
+        server::routes::tasks::CreateTasksBatchRequest::decl(),
         server::routes::tasks::CreateAndStartTaskRequest::decl(),
         server::routes::tasks::ImportTaskFromPrRequest::decl(),
         server::routes::tasks::ImportTaskFromPrError::decl(),
         server::routes::tasks::LinearIssueStateResponse::decl(),
         server::claude_session::ExtractedTask::decl(),
+        server::claude_session::SessionFormat::decl(),
         server::claude_session::SessionInfo::decl(),
         server::claude_session::PreviewClaudeSessionRequest::decl(),
         server::claude_session::PreviewClaudeSessionResponse::decl(),
@@ -132,6 +142,8 @@ fn generate_types_content() -> String {
         server::claude_session::ImportFromClaudeSessionResponse::decl(),
         server::claude_session::ImportWithHistoryRequest::decl(),
         server::claude_session::ImportWithHistoryResponse::decl(),
+        server::claude_session::ImportAllSessionsRequest::decl(),
+        server::claude_session::ImportAllSessionsResponse::decl(),
         server::claude_session::ListClaudeSessionsRequest::decl(),
         server::claude_session::ListClaudeSessionsResponse::decl(),
         services::services::linear::LinearIssueWithState::decl(),
@@ -167,6 +179,10 @@ fn generate_types_content() -> String {
         services::services::github::PrListItem::decl(),
         services::services::github::PrListAuthor::decl(),
         server::routes::task_attempts::RepoBranchStatus::decl(),
+        server::routes::task_attempts::mergeability::MergeBlocker::decl(),
+        server::routes::task_attempts::mergeability::RepoMergeability::decl(),
+        server::routes::task_attempts::mergeability::WorkspaceMergeability::decl(),
+        server::routes::task_attempts::RepoCommits::decl(),
         services::services::filesystem::DirectoryEntry::decl(),
         services::services::filesystem::DirectoryListResponse::decl(),
         services::services::config::Config::decl(),
@@ -180,8 +196,10 @@ fn generate_types_content() -> String {
         services::services::config::UiLanguage::decl(),
         services::services::config::ShowcaseState::decl(),
         services::services::git::GitBranch::decl(),
+        services::services::git::GitCommitInfo::decl(),
         services::services::share::SharedTaskDetails::decl(),
         services::services::queued_message::QueuedMessage::decl(),
+        services::services::pr_monitor::PrMonitorStatus::decl(),
         services::services::queued_message::QueueStatus::decl(),
         services::services::git::ConflictOp::decl(),
         executors::actions::ExecutorAction::decl(),
@@ -232,6 +250,8 @@ fn generate_types_content() -> String {
         serde_json::Value::decl(),
         server::routes::slash_commands::SlashCommand::decl(),
         server::routes::slash_commands::SlashCommandSource::decl(),
+        server::routes::slash_commands::SlashCommandsQuery::decl(),
+        server::routes::slash_commands::SlashCommandDetail::decl(),
     ];
 
     let body = decls
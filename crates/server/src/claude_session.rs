@@ -1,10 +1,14 @@
 use std::collections::HashMap;
+use std::fs::File;
+use std::io::{BufRead, BufReader, Lines};
 use std::path::{Path, PathBuf};
 
 use serde::{Deserialize, Serialize};
 use thiserror::Error;
 use ts_rs::TS;
 
+use crate::codex_session::CodexSessionParser;
+
 #[derive(Debug, Error)]
 pub enum ClaudeSessionError {
     #[error("IO error: {0}")]
@@ -13,6 +17,10 @@ pub enum ClaudeSessionError {
     JsonParse { line: usize, error: String },
     #[error("Invalid session path: {0}")]
     InvalidPath(String),
+    #[error("Message uuid not found in session: {0}")]
+    UuidNotFound(String),
+    #[error("Invalid import range: {0}")]
+    InvalidRange(String),
 }
 
 #[derive(Debug, Clone, Deserialize)]
@@ -53,13 +61,22 @@ enum ContentValue {
     Array(Vec<ContentBlock>),
 }
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Default, Deserialize)]
 struct ContentBlock {
     #[serde(rename = "type")]
     block_type: Option<String>,
     text: Option<String>,
     #[serde(default)]
     content: Option<ContentBlockContent>,
+    /// Tool name of a tool_use block
+    #[serde(default)]
+    name: Option<String>,
+    /// Tool arguments of a tool_use block
+    #[serde(default)]
+    input: Option<serde_json::Value>,
+    /// Set on tool_result blocks of failed tool calls
+    #[serde(default)]
+    is_error: Option<bool>,
 }
 
 /// Content field in ContentBlock can be a string or nested array (tool_result blocks)
@@ -89,6 +106,47 @@ pub struct ExtractedTask {
     pub session_id: Option<String>,
 }
 
+/// The agent whose session file is being read, chosen by the import request
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, TS)]
+#[serde(rename_all = "lowercase")]
+pub enum SessionFormat {
+    /// Claude Code sessions (~/.claude/projects/<project>/<session>.jsonl)
+    #[default]
+    Claude,
+    /// Codex CLI rollouts (~/.codex/sessions/YYYY/MM/DD/rollout-*.jsonl)
+    Codex,
+}
+
+impl SessionFormat {
+    pub fn parser(self) -> &'static dyn SessionParser {
+        match self {
+            SessionFormat::Claude => &ClaudeSessionParser,
+            SessionFormat::Codex => &CodexSessionParser,
+        }
+    }
+}
+
+/// Reads the work items out of one agent's session files
+pub trait SessionParser: Sync {
+    /// Prompts that start a piece of work, each a candidate task
+    fn parse_session_file(&self, path: &Path) -> Result<Vec<ExtractedTask>, ClaudeSessionError>;
+
+    /// Summary the agent wrote for the session, if its format has one
+    fn get_session_summary(&self, path: &Path) -> Result<Option<String>, ClaudeSessionError>;
+}
+
+pub struct ClaudeSessionParser;
+
+impl SessionParser for ClaudeSessionParser {
+    fn parse_session_file(&self, path: &Path) -> Result<Vec<ExtractedTask>, ClaudeSessionError> {
+        parse_session_file(path)
+    }
+
+    fn get_session_summary(&self, path: &Path) -> Result<Option<String>, ClaudeSessionError> {
+        get_session_summary(path)
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, TS)]
 #[serde(rename_all = "camelCase")]
 pub struct SessionInfo {
@@ -106,6 +164,10 @@ pub struct SessionInfo {
 #[serde(rename_all = "camelCase")]
 pub struct PreviewClaudeSessionRequest {
     pub session_path: String,
+    /// Agent that wrote the session file; Claude Code when omitted
+    #[serde(default)]
+    #[ts(optional)]
+    pub format: Option<SessionFormat>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, TS)]
@@ -121,6 +183,10 @@ pub struct ImportFromClaudeSessionRequest {
     pub session_path: String,
     pub selected_item_ids: Vec<String>,
     pub default_status: Option<String>,
+    /// Agent that wrote the session file; Claude Code when omitted
+    #[serde(default)]
+    #[ts(optional)]
+    pub format: Option<SessionFormat>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, TS)]
@@ -148,6 +214,10 @@ pub struct ImportWithHistoryRequest {
     pub session_path: String,
     pub task_title: Option<String>,
     pub default_status: Option<String>,
+    /// Import starting at the message with this uuid (inclusive)
+    pub from_uuid: Option<String>,
+    /// Stop importing after the message with this uuid (inclusive)
+    pub to_uuid: Option<String>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, TS)]
@@ -158,6 +228,25 @@ pub struct ImportWithHistoryResponse {
     pub session_id: String,
     pub execution_process_id: String,
     pub log_lines_imported: usize,
+    /// The session had already been imported into the project; the ids are the earlier import's
+    pub already_imported: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, TS)]
+#[serde(rename_all = "camelCase")]
+pub struct ImportAllSessionsRequest {
+    /// Project directory whose Claude Code sessions should be imported
+    pub project_path: String,
+    pub default_status: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, TS)]
+#[serde(rename_all = "camelCase")]
+pub struct ImportAllSessionsResponse {
+    pub imported_count: usize,
+    /// Sessions skipped because they were already imported into this project
+    pub skipped_count: usize,
+    pub errors: Vec<String>,
 }
 
 /// Metadata extracted from a Claude Code session file in a single pass.
@@ -170,18 +259,24 @@ pub struct SessionMetadata {
     pub slug: Option<String>,
 }
 
+/// Read a session file line by line. Sessions with heavy tool output can be
+/// hundreds of MB, so they are never loaded into memory as a whole.
+pub(crate) fn read_lines(path: &Path) -> Result<Lines<BufReader<File>>, ClaudeSessionError> {
+    Ok(BufReader::new(File::open(path)?).lines())
+}
+
 /// Extract all session metadata in a single pass.
 /// Stops early once all fields are found.
 pub fn parse_session_metadata(path: &Path) -> Result<SessionMetadata, ClaudeSessionError> {
-    let content = std::fs::read_to_string(path)?;
 
     let mut metadata = SessionMetadata::default();
 
-    for line in content.lines() {
+    for line in read_lines(path)? {
+        let line = line?;
         if line.trim().is_empty() {
             continue;
         }
-        if let Ok(msg) = serde_json::from_str::<RawMessage>(line) {
+        if let Ok(msg) = serde_json::from_str::<RawMessage>(&line) {
             if metadata.session_id.is_none() {
                 metadata.session_id = msg.session_id.clone();
             }
@@ -250,7 +345,103 @@ fn extract_content_block_text(content: &Option<ContentBlockContent>) -> Option<S
     }
 }
 
-fn truncate_title(text: &str, max_len: usize) -> String {
+/// Longest tool input summary or tool result line kept in imported logs
+const TOOL_SUMMARY_MAX_CHARS: usize = 120;
+
+/// Render a message as log lines for import: text blocks are joined under the
+/// speaker, tool calls become `Tool[Bash]: git status` and tool results
+/// `Result: <first line>` (`Error: ...` for failed calls).
+fn extract_log_lines(content: &ContentValue, speaker: &str) -> Vec<String> {
+    let blocks = match content {
+        ContentValue::String(s) if s.trim().is_empty() => return Vec::new(),
+        ContentValue::String(s) => return vec![format!("{}: {}", speaker, s)],
+        ContentValue::Array(blocks) => blocks,
+    };
+
+    let mut lines = Vec::new();
+    let mut text: Vec<&str> = Vec::new();
+    let flush_text = |lines: &mut Vec<String>, text: &mut Vec<&str>| {
+        if !text.is_empty() {
+            lines.push(format!("{}: {}", speaker, text.join("\n")));
+            text.clear();
+        }
+    };
+
+    for block in blocks {
+        match block.block_type.as_deref() {
+            Some("text") => {
+                if let Some(t) = block.text.as_deref().filter(|t| !t.trim().is_empty()) {
+                    text.push(t);
+                }
+            }
+            Some("tool_use") => {
+                flush_text(&mut lines, &mut text);
+                let name = block.name.as_deref().unwrap_or("unknown");
+                let summary = block
+                    .input
+                    .as_ref()
+                    .map(summarize_tool_input)
+                    .unwrap_or_default();
+                let line = format!("Tool[{}]: {}", name, summary);
+                lines.push(line.trim_end().to_string());
+            }
+            Some("tool_result") => {
+                flush_text(&mut lines, &mut text);
+                let output = extract_content_block_text(&block.content).unwrap_or_default();
+                let first_line = output.lines().find(|l| !l.trim().is_empty()).unwrap_or("");
+                let label = if block.is_error == Some(true) {
+                    "Error"
+                } else {
+                    "Result"
+                };
+                let line = format!(
+                    "{}: {}",
+                    label,
+                    truncate_chars(first_line.trim(), TOOL_SUMMARY_MAX_CHARS)
+                );
+                lines.push(line.trim_end().to_string());
+            }
+            _ => {}
+        }
+    }
+    flush_text(&mut lines, &mut text);
+    lines
+}
+
+/// The argument that says what a tool call did: the command for Bash, the path
+/// for file tools, the pattern for searches; otherwise the first string argument
+fn summarize_tool_input(input: &serde_json::Value) -> String {
+    const KEYS: [&str; 7] = [
+        "command",
+        "file_path",
+        "notebook_path",
+        "pattern",
+        "url",
+        "query",
+        "description",
+    ];
+    let Some(args) = input.as_object() else {
+        return truncate_chars(&input.to_string(), TOOL_SUMMARY_MAX_CHARS);
+    };
+    let summary = KEYS
+        .iter()
+        .find_map(|key| args.get(*key).and_then(|v| v.as_str()))
+        .or_else(|| args.values().find_map(|v| v.as_str()))
+        .map(|s| s.lines().next().unwrap_or("").to_string())
+        .unwrap_or_else(|| input.to_string());
+    truncate_chars(summary.trim(), TOOL_SUMMARY_MAX_CHARS)
+}
+
+fn truncate_chars(text: &str, max_chars: usize) -> String {
+    if text.chars().count() <= max_chars {
+        text.to_string()
+    } else {
+        let truncated: String = text.chars().take(max_chars.saturating_sub(3)).collect();
+        format!("{}...", truncated)
+    }
+}
+
+pub(crate) fn truncate_title(text: &str, max_len: usize) -> String {
     let first_line = text.lines().next().unwrap_or(text);
     let trimmed = first_line.trim();
     if trimmed.len() <= max_len {
@@ -261,19 +452,20 @@ fn truncate_title(text: &str, max_len: usize) -> String {
 }
 
 pub fn parse_session_file(path: &Path) -> Result<Vec<ExtractedTask>, ClaudeSessionError> {
-    let content = std::fs::read_to_string(path)?;
     let mut tasks = Vec::new();
     let mut summaries: Vec<String> = Vec::new();
 
-    for (line_num, line) in content.lines().enumerate() {
+    for (line_num, line) in read_lines(path)?.enumerate() {
+        let line = line?;
         if line.trim().is_empty() {
             continue;
         }
 
-        let msg: RawMessage = serde_json::from_str(line).map_err(|e| ClaudeSessionError::JsonParse {
-            line: line_num + 1,
-            error: e.to_string(),
-        })?;
+        let msg: RawMessage =
+            serde_json::from_str(&line).map_err(|e| ClaudeSessionError::JsonParse {
+                line: line_num + 1,
+                error: e.to_string(),
+            })?;
 
         // Skip sidechain messages (agent warmups, etc.)
         if msg.is_sidechain == Some(true) || msg.agent_id.is_some() {
@@ -366,26 +558,7 @@ pub fn list_available_sessions(project_path: Option<&str>) -> Result<Vec<Session
             }
         }
 
-        // Find .jsonl files in this project directory
-        for file_entry in std::fs::read_dir(&project_dir)? {
-            let file_entry = file_entry?;
-            let file_path = file_entry.path();
-
-            // Skip agent session files (named like agent-*.jsonl)
-            let file_name = file_path
-                .file_name()
-                .and_then(|n| n.to_str())
-                .unwrap_or("");
-            if file_name.starts_with("agent-") {
-                continue;
-            }
-
-            if file_path.extension().map_or(false, |ext| ext == "jsonl") {
-                if let Some(session_info) = parse_session_info(&file_path)? {
-                    sessions.push(session_info);
-                }
-            }
-        }
+        collect_sessions_in_dir(&project_dir, &mut sessions)?;
     }
 
     // Sort by last modified, most recent first
@@ -394,12 +567,60 @@ pub fn list_available_sessions(project_path: Option<&str>) -> Result<Vec<Session
     Ok(sessions)
 }
 
-fn parse_session_info(path: &Path) -> Result<Option<SessionInfo>, ClaudeSessionError> {
-    use std::io::{BufRead, BufReader};
+/// Claude Code stores sessions under ~/.claude/projects/ in a directory named after the
+/// project path with every non-alphanumeric character replaced by '-'
+pub fn claude_project_dir_name(project_path: &str) -> String {
+    project_path
+        .chars()
+        .map(|c| if c.is_ascii_alphanumeric() { c } else { '-' })
+        .collect()
+}
+
+/// List the sessions of exactly one project, oldest first
+pub fn list_project_sessions(project_path: &str) -> Result<Vec<SessionInfo>, ClaudeSessionError> {
+    let project_dir = dirs::home_dir()
+        .ok_or_else(|| ClaudeSessionError::InvalidPath("Cannot find home directory".to_string()))?
+        .join(".claude")
+        .join("projects")
+        .join(claude_project_dir_name(project_path.trim_end_matches('/')));
+
+    let mut sessions = Vec::new();
+    if project_dir.is_dir() {
+        collect_sessions_in_dir(&project_dir, &mut sessions)?;
+    }
+    sessions.sort_by(|a, b| a.last_modified.cmp(&b.last_modified));
+
+    Ok(sessions)
+}
+
+/// Find .jsonl session files in a Claude project directory
+fn collect_sessions_in_dir(
+    project_dir: &Path,
+    sessions: &mut Vec<SessionInfo>,
+) -> Result<(), ClaudeSessionError> {
+    for file_entry in std::fs::read_dir(project_dir)? {
+        let file_entry = file_entry?;
+        let file_path = file_entry.path();
+
+        // Skip agent session files (named like agent-*.jsonl)
+        let file_name = file_path
+            .file_name()
+            .and_then(|n| n.to_str())
+            .unwrap_or("");
+        if file_name.starts_with("agent-") {
+            continue;
+        }
 
-    let file = std::fs::File::open(path)?;
-    let reader = BufReader::new(file);
+        if file_path.extension().map_or(false, |ext| ext == "jsonl") {
+            if let Some(session_info) = parse_session_info(&file_path)? {
+                sessions.push(session_info);
+            }
+        }
+    }
+    Ok(())
+}
 
+fn parse_session_info(path: &Path) -> Result<Option<SessionInfo>, ClaudeSessionError> {
     let mut session_id = None;
     let mut git_branch = None;
     let mut last_summary = None;
@@ -409,8 +630,8 @@ fn parse_session_info(path: &Path) -> Result<Option<SessionInfo>, ClaudeSessionE
     let mut slug = None;
     let mut has_any_line = false;
 
-    for line_result in reader.lines() {
-        let line = line_result?;
+    for line in read_lines(path)? {
+        let line = line?;
         if line.trim().is_empty() {
             continue;
         }
@@ -488,25 +709,14 @@ fn parse_session_info(path: &Path) -> Result<Option<SessionInfo>, ClaudeSessionE
     }))
 }
 
-/// Extract text content from a MessageContent, handling tool calls gracefully
-fn extract_message_content(message: &Option<MessageContent>) -> Option<String> {
+/// Log lines of a MessageContent, see `extract_log_lines`
+fn extract_message_log_lines(message: &Option<MessageContent>, speaker: &str) -> Vec<String> {
     match message {
-        Some(MessageContent::Object { content, .. }) => {
-            let text = extract_text_content(content);
-            if text.trim().is_empty() {
-                None
-            } else {
-                Some(text)
-            }
-        }
+        Some(MessageContent::Object { content, .. }) => extract_log_lines(content, speaker),
         Some(MessageContent::String(s)) => {
-            if s.trim().is_empty() {
-                None
-            } else {
-                Some(s.clone())
-            }
+            extract_log_lines(&ContentValue::String(s.clone()), speaker)
         }
-        None => None,
+        None => Vec::new(),
     }
 }
 
@@ -519,57 +729,51 @@ fn get_message_id(message: &Option<MessageContent>) -> Option<String> {
 }
 
 /// Extract all conversation log lines from a session file for import.
-/// Returns formatted conversation turns for display.
+/// Returns formatted conversation turns for display, with tool calls and their
+/// results as separate `Tool[..]:` and `Result:` lines.
 /// Assistant messages are aggregated by message.id to avoid duplicate chunks from streaming.
 pub fn extract_session_logs(path: &Path) -> Result<Vec<String>, ClaudeSessionError> {
-    let content = std::fs::read_to_string(path)?;
 
     // Track seen message IDs to deduplicate assistant messages
-    // Key: message.id, Value: (timestamp, formatted_content)
-    let mut assistant_messages: HashMap<String, (String, String)> = HashMap::new();
+    // Key: message.id, Value: (timestamp, log lines)
+    let mut assistant_messages: HashMap<String, (String, Vec<String>)> = HashMap::new();
     let mut logs: Vec<(String, String)> = Vec::new(); // (timestamp, content)
 
-    for line in content.lines() {
+    for line in read_lines(path)? {
+        let line = line?;
         if line.trim().is_empty() {
             continue;
         }
 
-        if let Ok(msg) = serde_json::from_str::<RawMessage>(line) {
+        if let Ok(msg) = serde_json::from_str::<RawMessage>(&line) {
             // Skip sidechain messages (agent warmups, etc.)
             if msg.is_sidechain == Some(true) || msg.agent_id.is_some() {
                 continue;
             }
 
+            let timestamp = msg.timestamp.clone().unwrap_or_default();
             match msg.msg_type.as_str() {
                 "user" => {
                     // User messages don't have message.id streaming, emit directly
-                    if let Some(content) = extract_message_content(&msg.message) {
-                        logs.push((
-                            msg.timestamp.clone().unwrap_or_default(),
-                            format!("User: {}", content),
-                        ));
+                    for content in extract_message_log_lines(&msg.message, "User") {
+                        logs.push((timestamp.clone(), content));
                     }
                 }
                 "assistant" => {
+                    let lines = extract_message_log_lines(&msg.message, "Assistant");
+                    if lines.is_empty() {
+                        continue;
+                    }
                     // Assistant messages: aggregate by message.id
                     if let Some(msg_id) = get_message_id(&msg.message) {
-                        if let Some(content) = extract_message_content(&msg.message) {
-                            // Replace previous entry with same ID (later entry has more content)
-                            assistant_messages.insert(
-                                msg_id,
-                                (
-                                    msg.timestamp.clone().unwrap_or_default(),
-                                    format!("Assistant: {}", content),
-                                ),
-                            );
-                        }
+                        let (_, merged) = assistant_messages
+                            .entry(msg_id)
+                            .or_insert_with(|| (timestamp, Vec::new()));
+                        merge_streamed_lines(merged, lines);
                     } else {
                         // No message ID, emit directly
-                        if let Some(content) = extract_message_content(&msg.message) {
-                            logs.push((
-                                msg.timestamp.clone().unwrap_or_default(),
-                                format!("Assistant: {}", content),
-                            ));
+                        for content in lines {
+                            logs.push((timestamp.clone(), content));
                         }
                     }
                 }
@@ -579,24 +783,45 @@ pub fn extract_session_logs(path: &Path) -> Result<Vec<String>, ClaudeSessionErr
     }
 
     // Merge assistant messages into logs
-    logs.extend(assistant_messages.into_values());
+    for (timestamp, lines) in assistant_messages.into_values() {
+        logs.extend(
+            lines
+                .into_iter()
+                .map(|content| (timestamp.clone(), content)),
+        );
+    }
 
-    // Sort by timestamp
+    // Sort by timestamp; stable, so the lines of one message keep their order
     logs.sort_by(|a, b| a.0.cmp(&b.0));
 
     Ok(logs.into_iter().map(|(_, content)| content).collect())
 }
 
+/// Streamed chunks of one message either repeat earlier content with more text
+/// appended or carry only the next content block. A line that extends the last
+/// one replaces it; lines already seen are skipped; anything else is appended.
+fn merge_streamed_lines(merged: &mut Vec<String>, lines: Vec<String>) {
+    for line in lines {
+        if merged.contains(&line) {
+            continue;
+        }
+        match merged.last_mut() {
+            Some(last) if line.starts_with(last.as_str()) => *last = line,
+            _ => merged.push(line),
+        }
+    }
+}
+
 /// Get the first user message content for use as task title/description
 pub fn get_first_user_message(path: &Path) -> Result<Option<(String, String)>, ClaudeSessionError> {
-    let content = std::fs::read_to_string(path)?;
 
-    for line in content.lines() {
+    for line in read_lines(path)? {
+        let line = line?;
         if line.trim().is_empty() {
             continue;
         }
 
-        if let Ok(msg) = serde_json::from_str::<RawMessage>(line) {
+        if let Ok(msg) = serde_json::from_str::<RawMessage>(&line) {
             if msg.is_sidechain == Some(true) || msg.agent_id.is_some() {
                 continue;
             }
@@ -619,15 +844,15 @@ pub fn get_first_user_message(path: &Path) -> Result<Option<(String, String)>, C
 }
 
 pub fn get_session_summary(path: &Path) -> Result<Option<String>, ClaudeSessionError> {
-    let content = std::fs::read_to_string(path)?;
 
     // Find the last summary in the file
     let mut last_summary = None;
-    for line in content.lines() {
+    for line in read_lines(path)? {
+        let line = line?;
         if line.trim().is_empty() {
             continue;
         }
-        if let Ok(msg) = serde_json::from_str::<RawMessage>(line) {
+        if let Ok(msg) = serde_json::from_str::<RawMessage>(&line) {
             if msg.msg_type == "summary" {
                 last_summary = msg.summary;
             }
@@ -637,26 +862,111 @@ pub fn get_session_summary(path: &Path) -> Result<Option<String>, ClaudeSessionE
     Ok(last_summary)
 }
 
-/// Extract raw JSONL lines from a session file for 1:1 import.
-/// Returns the raw lines as-is from the Claude Code session file.
-pub fn extract_raw_session_logs(path: &Path) -> Result<Vec<String>, ClaudeSessionError> {
-    let content = std::fs::read_to_string(path)?;
-    Ok(content
-        .lines()
-        .filter(|line| !line.trim().is_empty())
-        .map(|line| line.to_string())
-        .collect())
+/// Stream raw JSONL lines from a session file for 1:1 import.
+/// Yields the non-empty lines as-is from the Claude Code session file.
+pub fn extract_raw_session_logs(
+    path: &Path,
+) -> Result<impl Iterator<Item = Result<String, ClaudeSessionError>> + use<>, ClaudeSessionError> {
+    Ok(read_lines(path)?.filter_map(|line| match line {
+        Ok(line) if line.trim().is_empty() => None,
+        line => Some(line.map_err(ClaudeSessionError::from)),
+    }))
+}
+
+/// Slice raw JSONL lines to the range between two message uuids (both inclusive).
+/// Missing bounds default to the start/end of the session. Lines are streamed;
+/// a bound that never shows up is reported as an error at the end.
+pub fn slice_session_logs_by_uuid<I>(
+    lines: I,
+    from_uuid: Option<&str>,
+    to_uuid: Option<&str>,
+) -> SessionLogSlice<I::IntoIter>
+where
+    I: IntoIterator<Item = Result<String, ClaudeSessionError>>,
+{
+    SessionLogSlice {
+        lines: lines.into_iter(),
+        from_uuid: from_uuid.map(str::to_string),
+        to_uuid: to_uuid.map(str::to_string),
+        done: false,
+    }
+}
+
+/// Iterator returned by [`slice_session_logs_by_uuid`]
+pub struct SessionLogSlice<I> {
+    lines: I,
+    /// Cleared once the start line is reached
+    from_uuid: Option<String>,
+    to_uuid: Option<String>,
+    done: bool,
+}
+
+impl<I> Iterator for SessionLogSlice<I>
+where
+    I: Iterator<Item = Result<String, ClaudeSessionError>>,
+{
+    type Item = Result<String, ClaudeSessionError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+
+        loop {
+            let line = match self.lines.next() {
+                Some(Ok(line)) => line,
+                Some(Err(e)) => {
+                    self.done = true;
+                    return Some(Err(e));
+                }
+                None => {
+                    self.done = true;
+                    return self
+                        .from_uuid
+                        .take()
+                        .or_else(|| self.to_uuid.take())
+                        .map(|uuid| Err(ClaudeSessionError::UuidNotFound(uuid)));
+                }
+            };
+
+            let uuid = if self.from_uuid.is_some() || self.to_uuid.is_some() {
+                serde_json::from_str::<RawMessage>(&line)
+                    .ok()
+                    .and_then(|msg| msg.uuid)
+            } else {
+                None
+            };
+
+            if let Some(from_uuid) = &self.from_uuid {
+                if uuid.as_ref() != Some(from_uuid) {
+                    if uuid.is_some() && uuid == self.to_uuid {
+                        self.done = true;
+                        return Some(Err(ClaudeSessionError::InvalidRange(
+                            "from_uuid appears after to_uuid".to_string(),
+                        )));
+                    }
+                    continue;
+                }
+                self.from_uuid = None;
+            }
+
+            if uuid.is_some() && uuid == self.to_uuid {
+                self.done = true;
+            }
+            return Some(Ok(line));
+        }
+    }
 }
 
 /// Extract the session slug from a Claude Code session file.
 /// The slug is used to locate the corresponding plan file.
 pub fn get_session_slug(path: &Path) -> Result<Option<String>, ClaudeSessionError> {
-    let content = std::fs::read_to_string(path)?;
-    for line in content.lines() {
+    for line in read_lines(path)? {
+        let line = line?;
         if line.trim().is_empty() {
             continue;
         }
-        if let Ok(msg) = serde_json::from_str::<RawMessage>(line) {
+        if let Ok(msg) = serde_json::from_str::<RawMessage>(&line) {
             if let Some(slug) = msg.slug {
                 return Ok(Some(slug));
             }
@@ -687,12 +997,12 @@ pub fn get_plan_path(session_path: &Path) -> Result<Option<PathBuf>, ClaudeSessi
 /// Extract the working directory (cwd) from a Claude Code session file.
 /// The cwd is stored in "system" type entries.
 pub fn get_session_cwd(path: &Path) -> Result<Option<String>, ClaudeSessionError> {
-    let content = std::fs::read_to_string(path)?;
-    for line in content.lines() {
+    for line in read_lines(path)? {
+        let line = line?;
         if line.trim().is_empty() {
             continue;
         }
-        if let Ok(msg) = serde_json::from_str::<RawMessage>(line) {
+        if let Ok(msg) = serde_json::from_str::<RawMessage>(&line) {
             if msg.msg_type == "system" {
                 if let Some(cwd) = msg.cwd {
                     return Ok(Some(cwd));
@@ -707,6 +1017,15 @@ pub fn get_session_cwd(path: &Path) -> Result<Option<String>, ClaudeSessionError
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_claude_project_dir_name() {
+        assert_eq!(claude_project_dir_name("/root/crate"), "-root-crate");
+        assert_eq!(
+            claude_project_dir_name("/home/me/my.app_v2"),
+            "-home-me-my-app-v2"
+        );
+    }
+
     #[test]
     fn test_truncate_title() {
         assert_eq!(truncate_title("Hello world", 100), "Hello world");
@@ -727,27 +1046,27 @@ mod tests {
             ContentBlock {
                 block_type: Some("text".to_string()),
                 text: Some("Hello".to_string()),
-                content: None,
+                ..Default::default()
             },
             ContentBlock {
                 block_type: Some("text".to_string()),
                 text: Some("World".to_string()),
-                content: None,
+                ..Default::default()
             },
         ]);
         assert_eq!(extract_text_content(&content), "Hello\nWorld");
     }
 
     #[test]
-    fn test_extract_message_content_object() {
+    fn test_extract_message_log_lines_object() {
         let msg = Some(MessageContent::Object {
             role: "user".to_string(),
             content: ContentValue::String("Hello world".to_string()),
             id: None,
         });
         assert_eq!(
-            extract_message_content(&msg),
-            Some("Hello world".to_string())
+            extract_message_log_lines(&msg, "User"),
+            ["User: Hello world"]
         );
     }
 
@@ -774,20 +1093,129 @@ mod tests {
     }
 
     #[test]
-    fn test_extract_message_content_string() {
+    fn test_extract_message_log_lines_string() {
         let msg = Some(MessageContent::String("Hello world".to_string()));
-        assert_eq!(extract_message_content(&msg), Some("Hello world".to_string()));
+        assert_eq!(
+            extract_message_log_lines(&msg, "User"),
+            ["User: Hello world"]
+        );
     }
 
     #[test]
-    fn test_extract_message_content_empty() {
+    fn test_extract_message_log_lines_empty() {
         let msg = Some(MessageContent::String("   ".to_string()));
-        assert_eq!(extract_message_content(&msg), None);
+        assert!(extract_message_log_lines(&msg, "User").is_empty());
+    }
+
+    #[test]
+    fn test_extract_message_log_lines_none() {
+        assert!(extract_message_log_lines(&None, "User").is_empty());
+    }
+
+    #[test]
+    fn test_extract_session_logs_tool_calls() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("session.jsonl");
+        std::fs::write(
+            &path,
+            [
+                r#"{"type":"user","timestamp":"2026-01-01T00:00:00Z","message":{"role":"user","content":"What changed?"}}"#,
+                // Streamed chunks of one assistant message: text first, then the tool call
+                r#"{"type":"assistant","timestamp":"2026-01-01T00:00:01Z","message":{"id":"msg_1","role":"assistant","content":[{"type":"text","text":"Let me check."}]}}"#,
+                r#"{"type":"assistant","timestamp":"2026-01-01T00:00:02Z","message":{"id":"msg_1","role":"assistant","content":[{"type":"tool_use","id":"toolu_1","name":"Bash","input":{"command":"git status","description":"Show status"}}]}}"#,
+                r#"{"type":"user","timestamp":"2026-01-01T00:00:03Z","message":{"role":"user","content":[{"type":"tool_result","tool_use_id":"toolu_1","content":"On branch main\nnothing to commit"}]}}"#,
+                r#"{"type":"assistant","timestamp":"2026-01-01T00:00:04Z","message":{"id":"msg_2","role":"assistant","content":[{"type":"tool_use","id":"toolu_2","name":"Read","input":{"file_path":"/repo/src/main.rs"}}]}}"#,
+                r#"{"type":"user","timestamp":"2026-01-01T00:00:05Z","message":{"role":"user","content":[{"type":"tool_result","tool_use_id":"toolu_2","is_error":true,"content":[{"type":"text","text":"File does not exist."}]}]}}"#,
+            ]
+            .join("\n"),
+        )
+        .unwrap();
+
+        assert_eq!(
+            extract_session_logs(&path).unwrap(),
+            [
+                "User: What changed?",
+                "Assistant: Let me check.",
+                "Tool[Bash]: git status",
+                "Result: On branch main",
+                "Tool[Read]: /repo/src/main.rs",
+                "Error: File does not exist.",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_slice_session_logs_by_uuid() {
+        let lines: Vec<String> = vec![
+            r#"{"type":"user","uuid":"a"}"#,
+            r#"{"type":"assistant","uuid":"b"}"#,
+            r#"{"type":"summary","summary":"s"}"#,
+            r#"{"type":"user","uuid":"c"}"#,
+            r#"{"type":"assistant","uuid":"d"}"#,
+        ]
+        .into_iter()
+        .map(String::from)
+        .collect();
+        let slice = |from: Option<&str>, to: Option<&str>| {
+            slice_session_logs_by_uuid(lines.iter().cloned().map(Ok), from, to)
+                .collect::<Result<Vec<_>, _>>()
+        };
+
+        let all = slice(None, None).unwrap();
+        assert_eq!(all.len(), 5);
+
+        let middle = slice(Some("b"), Some("c")).unwrap();
+        assert_eq!(middle, lines[1..4].to_vec());
+
+        let head = slice(None, Some("b")).unwrap();
+        assert_eq!(head, lines[0..2].to_vec());
+
+        let tail = slice(Some("c"), None).unwrap();
+        assert_eq!(tail, lines[3..].to_vec());
+
+        assert!(matches!(
+            slice(Some("missing"), None),
+            Err(ClaudeSessionError::UuidNotFound(_))
+        ));
+        assert!(matches!(
+            slice(Some("b"), Some("missing")),
+            Err(ClaudeSessionError::UuidNotFound(uuid)) if uuid == "missing"
+        ));
+        assert!(matches!(
+            slice(Some("d"), Some("a")),
+            Err(ClaudeSessionError::InvalidRange(_))
+        ));
     }
 
     #[test]
-    fn test_extract_message_content_none() {
-        assert_eq!(extract_message_content(&None), None);
+    fn test_streamed_session_parsing() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("session.jsonl");
+        std::fs::write(
+            &path,
+            concat!(
+                r#"{"type":"system","cwd":"/repo","slug":"fix-login"}"#,
+                "\n\n",
+                r#"{"type":"summary","summary":"Fix login"}"#,
+                "\r\n",
+                "not json\n",
+            ),
+        )
+        .unwrap();
+
+        let raw: Vec<String> = extract_raw_session_logs(&path)
+            .unwrap()
+            .collect::<Result<_, _>>()
+            .unwrap();
+        assert_eq!(raw.len(), 3);
+        assert_eq!(raw[1], r#"{"type":"summary","summary":"Fix login"}"#);
+        assert_eq!(get_session_cwd(&path).unwrap().as_deref(), Some("/repo"));
+        assert_eq!(get_session_slug(&path).unwrap().as_deref(), Some("fix-login"));
+        assert_eq!(get_session_summary(&path).unwrap().as_deref(), Some("Fix login"));
+        assert!(matches!(
+            parse_session_file(&path),
+            Err(ClaudeSessionError::JsonParse { line: 4, .. })
+        ));
     }
 
     #[test]
@@ -815,8 +1243,10 @@ mod tests {
         // Check that all entries are properly formatted
         for log in &logs {
             assert!(
-                log.starts_with("User: ") || log.starts_with("Assistant: "),
-                "Log entry should start with 'User: ' or 'Assistant: ', got: {}...",
+                ["User: ", "Assistant: ", "Tool[", "Result: ", "Error: "]
+                    .iter()
+                    .any(|prefix| log.starts_with(prefix)),
+                "Log entry should be a turn, tool call or tool result, got: {}...",
                 &log[..log.len().min(50)]
             );
         }
@@ -1007,8 +1437,10 @@ mod tests {
         // Verify log format for imported sessions (stored as {"Stdout":"User: ..."})
         for log in &logs {
             assert!(
-                log.starts_with("User: ") || log.starts_with("Assistant: "),
-                "All logs should be User: or Assistant: format, got: {}...",
+                ["User: ", "Assistant: ", "Tool[", "Result: ", "Error: "]
+                    .iter()
+                    .any(|prefix| log.starts_with(prefix)),
+                "All logs should be User:, Assistant:, Tool[..]:, Result: or Error: format, got: {}...",
                 &log[..log.len().min(50)]
             );
         }
@@ -1034,7 +1466,10 @@ mod tests {
         }
 
         // Extract raw logs
-        let raw_logs = extract_raw_session_logs(path).unwrap();
+        let raw_logs: Vec<String> = extract_raw_session_logs(path)
+            .unwrap()
+            .collect::<Result<_, _>>()
+            .unwrap();
         assert!(!raw_logs.is_empty(), "Should extract raw log lines");
 
         // Count actual non-empty lines in source file for comparison
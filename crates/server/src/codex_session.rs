@@ -0,0 +1,114 @@
+use std::path::Path;
+
+use serde::Deserialize;
+
+use crate::claude_session::{
+    ClaudeSessionError, ExtractedTask, SessionParser, read_lines, truncate_title,
+};
+
+/// One line of a Codex rollout file: `{"timestamp", "type", "payload"}`
+#[derive(Debug, Deserialize)]
+struct RolloutLine {
+    timestamp: Option<String>,
+    #[serde(rename = "type")]
+    line_type: String,
+    #[serde(default)]
+    payload: serde_json::Value,
+}
+
+#[derive(Debug, Default, Deserialize)]
+struct SessionMeta {
+    id: Option<String>,
+    git: Option<GitInfo>,
+}
+
+#[derive(Debug, Deserialize)]
+struct GitInfo {
+    branch: Option<String>,
+}
+
+/// Codex CLI sessions. A rollout is one conversation, so its first prompt is the
+/// work item; later prompts steer the same work and are not offered as tasks.
+pub struct CodexSessionParser;
+
+impl SessionParser for CodexSessionParser {
+    fn parse_session_file(&self, path: &Path) -> Result<Vec<ExtractedTask>, ClaudeSessionError> {
+        let mut meta = SessionMeta::default();
+
+        for (line_num, line) in read_lines(path)?.enumerate() {
+            let line = line?;
+            if line.trim().is_empty() {
+                continue;
+            }
+
+            let rollout: RolloutLine =
+                serde_json::from_str(&line).map_err(|e| ClaudeSessionError::JsonParse {
+                    line: line_num + 1,
+                    error: e.to_string(),
+                })?;
+
+            match rollout.line_type.as_str() {
+                "session_meta" => {
+                    meta = serde_json::from_value(rollout.payload).unwrap_or_default();
+                }
+                // The typed prompt; user `response_item`s also carry injected
+                // instructions and environment context
+                "event_msg" if rollout.payload["type"] == "user_message" => {
+                    let text = rollout.payload["message"].as_str().unwrap_or_default();
+                    if text.trim().is_empty() {
+                        continue;
+                    }
+
+                    return Ok(vec![ExtractedTask {
+                        id: format!("task-{}", line_num),
+                        title: truncate_title(text, 100),
+                        description: Some(text.to_string()),
+                        timestamp: rollout.timestamp.unwrap_or_default(),
+                        branch: meta.git.and_then(|git| git.branch),
+                        session_id: meta.id,
+                    }]);
+                }
+                _ => {}
+            }
+        }
+
+        Ok(Vec::new())
+    }
+
+    fn get_session_summary(&self, _path: &Path) -> Result<Option<String>, ClaudeSessionError> {
+        Ok(None)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_codex_rollout() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("rollout.jsonl");
+        std::fs::write(
+            &path,
+            concat!(
+                r#"{"timestamp":"2025-10-01T10:00:00Z","type":"session_meta","payload":{"id":"abc","cwd":"/repo","git":{"branch":"fix-login"}}}"#,
+                "\n",
+                r#"{"timestamp":"2025-10-01T10:00:01Z","type":"response_item","payload":{"type":"message","role":"user","content":[{"type":"input_text","text":"<environment_context>"}]}}"#,
+                "\n\n",
+                r#"{"timestamp":"2025-10-01T10:00:02Z","type":"event_msg","payload":{"type":"user_message","message":"Fix the login redirect\nIt loops"}}"#,
+                "\n",
+                r#"{"timestamp":"2025-10-01T10:05:00Z","type":"event_msg","payload":{"type":"user_message","message":"Also add a test"}}"#,
+                "\n",
+            ),
+        )
+        .unwrap();
+
+        let tasks = CodexSessionParser.parse_session_file(&path).unwrap();
+        assert_eq!(tasks.len(), 1);
+        assert_eq!(tasks[0].title, "Fix the login redirect");
+        assert_eq!(tasks[0].timestamp, "2025-10-01T10:00:02Z");
+        assert_eq!(tasks[0].branch.as_deref(), Some("fix-login"));
+        assert_eq!(tasks[0].session_id.as_deref(), Some("abc"));
+        assert_eq!(CodexSessionParser.get_session_summary(&path).unwrap(), None);
+    }
+}
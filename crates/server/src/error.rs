@@ -7,7 +7,7 @@ use axum::{
 use db::models::{
     commander_session::CommanderSessionError, execution_process::ExecutionProcessError,
     project::ProjectError, project_repo::ProjectRepoError, repo::RepoError, scratch::ScratchError,
-    session::SessionError, workspace::WorkspaceError,
+    session::SessionError, task::TaskBatchError, workspace::WorkspaceError,
 };
 use deployment::{DeploymentError, RemoteClientNotConfigured};
 use executors::executors::ExecutorError;
@@ -76,6 +76,8 @@ pub enum ApiError {
     Conflict(String),
     #[error("Forbidden: {0}")]
     Forbidden(String),
+    #[error("Not found: {0}")]
+    NotFound(String),
 }
 
 impl From<&'static str> for ApiError {
@@ -177,6 +179,7 @@ impl IntoResponse for ApiError {
             ApiError::BadRequest(_) => (StatusCode::BAD_REQUEST, "BadRequest"),
             ApiError::Conflict(_) => (StatusCode::CONFLICT, "ConflictError"),
             ApiError::Forbidden(_) => (StatusCode::FORBIDDEN, "ForbiddenError"),
+            ApiError::NotFound(_) => (StatusCode::NOT_FOUND, "NotFound"),
         };
 
         let error_message = match &self {
@@ -253,6 +256,7 @@ impl IntoResponse for ApiError {
             ApiError::BadRequest(msg) => msg.clone(),
             ApiError::Conflict(msg) => msg.clone(),
             ApiError::Forbidden(msg) => msg.clone(),
+            ApiError::NotFound(msg) => msg.clone(),
             _ => format!("{}: {}", error_type, self),
         };
         let response = ApiResponse::<()>::error(&error_message);
@@ -390,6 +394,15 @@ impl From<ProjectRepoError> for ApiError {
     }
 }
 
+impl From<TaskBatchError> for ApiError {
+    fn from(err: TaskBatchError) -> Self {
+        match err {
+            TaskBatchError::Database(db_err) => ApiError::Database(db_err),
+            TaskBatchError::TaskFailed { .. } => ApiError::BadRequest(err.to_string()),
+        }
+    }
+}
+
 impl From<CommanderSessionError> for ApiError {
     fn from(err: CommanderSessionError) -> Self {
         match err {
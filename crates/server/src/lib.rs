@@ -1,4 +1,5 @@
 pub mod claude_session;
+pub mod codex_session;
 pub mod error;
 pub mod mcp;
 pub mod middleware;
@@ -11,7 +12,11 @@ use sqlx::Error as SqlxError;
 use strip_ansi_escapes::strip;
 use thiserror::Error;
 use tokio::sync::oneshot;
-use utils::{assets::asset_dir, browser::open_browser, port_file::write_port_file};
+use utils::{
+    assets::asset_dir,
+    browser::open_browser,
+    port_file::{remove_port_file, write_port_file},
+};
 
 pub type DeploymentImpl = local_deployment::LocalDeployment;
 
@@ -62,6 +67,44 @@ pub struct ServerConfig {
     pub skip_browser_open: bool,
 }
 
+/// Port from `BACKEND_PORT` or `PORT`, if either is set to a valid port
+fn port_from_env() -> Option<u16> {
+    std::env::var("BACKEND_PORT")
+        .or_else(|_| std::env::var("PORT"))
+        .ok()
+        .and_then(|s| {
+            let cleaned =
+                String::from_utf8(strip(s.as_bytes())).expect("UTF-8 after stripping ANSI");
+            cleaned.trim().parse::<u16>().ok()
+        })
+}
+
+/// Bind the port from the environment if set. Otherwise try the configured
+/// preferred port so the address stays the same across restarts, and let the OS
+/// assign one when it is taken or no preferred port is configured.
+async fn bind_listener(
+    host: &str,
+    preferred_port: Option<u16>,
+) -> std::io::Result<tokio::net::TcpListener> {
+    if let Some(port) = port_from_env() {
+        return tokio::net::TcpListener::bind(format!("{host}:{port}")).await;
+    }
+
+    if let Some(port) = preferred_port.filter(|port| *port != 0) {
+        match tokio::net::TcpListener::bind(format!("{host}:{port}")).await {
+            Ok(listener) => return Ok(listener),
+            Err(e) => tracing::info!(
+                "Preferred port {} is unavailable ({}), using port 0 for auto-assignment",
+                port,
+                e
+            ),
+        }
+    } else {
+        tracing::info!("No PORT environment variable set, using port 0 for auto-assignment");
+    }
+    tokio::net::TcpListener::bind(format!("{host}:0")).await
+}
+
 /// Run the server with the given configuration.
 /// Returns the actual port the server bound to.
 pub async fn run(config: ServerConfig) -> Result<u16, VibeKanbanError> {
@@ -86,6 +129,7 @@ pub async fn run(config: ServerConfig) -> Result<u16, VibeKanbanError> {
         .await
         .map_err(DeploymentError::from)?;
     deployment.spawn_pr_monitor_service().await;
+    deployment.spawn_linear_sync_service().await;
 
     // Pre-warm file search cache for most active projects
     let deployment_for_cache = deployment.clone();
@@ -111,21 +155,9 @@ pub async fn run(config: ServerConfig) -> Result<u16, VibeKanbanError> {
 
     let app_router = routes::router(deployment.clone());
 
-    let port = std::env::var("BACKEND_PORT")
-        .or_else(|_| std::env::var("PORT"))
-        .ok()
-        .and_then(|s| {
-            let cleaned =
-                String::from_utf8(strip(s.as_bytes())).expect("UTF-8 after stripping ANSI");
-            cleaned.trim().parse::<u16>().ok()
-        })
-        .unwrap_or_else(|| {
-            tracing::info!("No PORT environment variable set, using port 0 for auto-assignment");
-            0
-        });
-
+    let preferred_port = deployment.config().read().await.preferred_port;
     let host = std::env::var("HOST").unwrap_or_else(|_| "127.0.0.1".to_string());
-    let listener = tokio::net::TcpListener::bind(format!("{host}:{port}")).await?;
+    let listener = bind_listener(&host, preferred_port).await?;
     let actual_port = listener.local_addr()?.port();
 
     if let Err(e) = write_port_file(actual_port).await {
@@ -136,14 +168,18 @@ pub async fn run(config: ServerConfig) -> Result<u16, VibeKanbanError> {
 
     let should_open_browser = !config.skip_browser_open && !cfg!(debug_assertions);
     if should_open_browser {
-        let port_for_browser = actual_port;
+        // With token auth on, the first page load hands the browser its cookie
+        let browser_url = match middleware::api_token_from_env() {
+            Some(token) => format!("http://127.0.0.1:{actual_port}/?token={token}"),
+            None => format!("http://127.0.0.1:{actual_port}"),
+        };
         tracing::info!("Opening browser...");
         tokio::spawn(async move {
-            if let Err(e) = open_browser(&format!("http://127.0.0.1:{port_for_browser}")).await {
+            if let Err(e) = open_browser(&browser_url).await {
                 tracing::warn!(
-                    "Failed to open browser automatically: {}. Please open http://127.0.0.1:{} manually.",
+                    "Failed to open browser automatically: {}. Please open {} manually.",
                     e,
-                    port_for_browser
+                    browser_url
                 );
             }
         });
@@ -196,6 +232,11 @@ pub async fn perform_cleanup_actions(deployment: &DeploymentImpl) {
         .kill_all_running_processes()
         .await
         .expect("Failed to cleanly kill running execution processes");
+
+    // Don't leave a port file pointing at a server that is gone
+    if let Err(e) = remove_port_file().await {
+        tracing::warn!("Failed to remove port file: {}", e);
+    }
 }
 
 /// Run the server in embedded mode (non-blocking).
@@ -223,6 +264,7 @@ pub async fn run_embedded() -> Result<EmbeddedServerHandle, VibeKanbanError> {
         .await
         .map_err(DeploymentError::from)?;
     deployment.spawn_pr_monitor_service().await;
+    deployment.spawn_linear_sync_service().await;
 
     let deployment_for_cache = deployment.clone();
     tokio::spawn(async move {
@@ -246,21 +288,9 @@ pub async fn run_embedded() -> Result<EmbeddedServerHandle, VibeKanbanError> {
 
     let app_router = routes::router(deployment.clone());
 
-    let port = std::env::var("BACKEND_PORT")
-        .or_else(|_| std::env::var("PORT"))
-        .ok()
-        .and_then(|s| {
-            let cleaned =
-                String::from_utf8(strip(s.as_bytes())).expect("UTF-8 after stripping ANSI");
-            cleaned.trim().parse::<u16>().ok()
-        })
-        .unwrap_or_else(|| {
-            tracing::info!("No PORT environment variable set, using port 0 for auto-assignment");
-            0
-        });
-
+    let preferred_port = deployment.config().read().await.preferred_port;
     let host = std::env::var("HOST").unwrap_or_else(|_| "127.0.0.1".to_string());
-    let listener = tokio::net::TcpListener::bind(format!("{host}:{port}")).await?;
+    let listener = bind_listener(&host, preferred_port).await?;
     let actual_port = listener.local_addr()?.port();
 
     if let Err(e) = write_port_file(actual_port).await {
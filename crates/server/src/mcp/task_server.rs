@@ -1,7 +1,7 @@
 use std::{future::Future, str::FromStr};
 
 use db::models::{
-    project::Project,
+    project::ProjectWithHealth,
     repo::Repo,
     tag::Tag,
     task::{CreateTask, Task, TaskStatus, TaskWithAttemptStatus, UpdateTask},
@@ -21,9 +21,12 @@ use serde::{Deserialize, Serialize, de::DeserializeOwned};
 use serde_json;
 use uuid::Uuid;
 
-use crate::routes::{
-    containers::ContainerQuery,
-    task_attempts::{CreateTaskAttemptBody, WorkspaceRepoInput},
+use crate::{
+    middleware::api_token_from_env,
+    routes::{
+        containers::ContainerQuery,
+        task_attempts::{CreateTaskAttemptBody, WorkspaceRepoInput},
+    },
 };
 
 #[derive(Debug, Deserialize, schemars::JsonSchema)]
@@ -51,15 +54,18 @@ pub struct ProjectSummary {
     pub created_at: String,
     #[schemars(description = "When the project was last updated")]
     pub updated_at: String,
+    #[schemars(description = "False if the project's repository was moved or deleted on disk")]
+    pub valid: bool,
 }
 
 impl ProjectSummary {
-    fn from_project(project: Project) -> Self {
+    fn from_project(project: ProjectWithHealth) -> Self {
         Self {
-            id: project.id.to_string(),
-            name: project.name,
-            created_at: project.created_at.to_rfc3339(),
-            updated_at: project.updated_at.to_rfc3339(),
+            id: project.project.id.to_string(),
+            name: project.project.name,
+            created_at: project.project.created_at.to_rfc3339(),
+            updated_at: project.project.updated_at.to_rfc3339(),
+            valid: project.valid,
         }
     }
 }
@@ -284,8 +290,20 @@ pub struct McpContext {
 
 impl TaskServer {
     pub fn new(base_url: &str) -> Self {
+        // Authenticate against a server started with VIBE_API_TOKEN
+        let mut headers = reqwest::header::HeaderMap::new();
+        if let Some(token) = api_token_from_env()
+            && let Ok(value) = reqwest::header::HeaderValue::from_str(&format!("Bearer {}", token))
+        {
+            headers.insert(reqwest::header::AUTHORIZATION, value);
+        }
+        let client = reqwest::Client::builder()
+            .default_headers(headers)
+            .build()
+            .unwrap_or_default();
+
         Self {
-            client: reqwest::Client::new(),
+            client,
             base_url: base_url.to_string(),
             tool_router: Self::tool_router(),
             context: None,
@@ -534,7 +552,7 @@ impl TaskServer {
     #[tool(description = "List all the available projects")]
     async fn list_projects(&self) -> Result<CallToolResult, ErrorData> {
         let url = self.url("/api/projects");
-        let projects: Vec<Project> = match self.send_json(self.client.get(&url)).await {
+        let projects: Vec<ProjectWithHealth> = match self.send_json(self.client.get(&url)).await {
             Ok(ps) => ps,
             Err(e) => return Ok(e),
         };
@@ -605,14 +623,19 @@ impl TaskServer {
             None
         };
 
-        let url = self.url(&format!("/api/tasks?project_id={}", project_id));
+        let task_limit = limit.unwrap_or(50).max(0) as usize;
+
+        // Without a status filter the server can apply the limit itself
+        let mut url = self.url(&format!("/api/tasks?project_id={}", project_id));
+        if status_filter.is_none() {
+            url.push_str(&format!("&limit={}", task_limit));
+        }
         let all_tasks: Vec<TaskWithAttemptStatus> =
             match self.send_json(self.client.get(&url)).await {
                 Ok(t) => t,
                 Err(e) => return Ok(e),
             };
 
-        let task_limit = limit.unwrap_or(50).max(0) as usize;
         let filtered = all_tasks.into_iter().filter(|t| {
             if let Some(ref want) = status_filter {
                 &t.status == want
@@ -757,6 +780,7 @@ impl TaskServer {
             parent_workspace_id: None,
             image_ids: None,
             sync_to_linear: false, // MCP doesn't trigger Linear sync
+            due_date: None,
         };
         let url = self.url(&format!("/api/tasks/{}", task_id));
         let updated_task: Task = match self.send_json(self.client.put(&url).json(&payload)).await {
@@ -0,0 +1,116 @@
+use std::sync::Arc;
+
+use axum::{
+    extract::{Request, State},
+    http::{HeaderValue, StatusCode, header},
+    middleware::Next,
+    response::Response,
+};
+use subtle::ConstantTimeEq;
+
+/// When set, every `/api` request must send `Authorization: Bearer <token>`
+/// or the token cookie set when the frontend is opened with `?token=<token>`
+pub const API_TOKEN_ENV: &str = "VIBE_API_TOKEN";
+
+/// Cookie carrying the token for the bundled frontend, whose WebSocket streams
+/// cannot send an `Authorization` header
+pub const API_TOKEN_COOKIE: &str = "vibe_api_token";
+
+/// API token from the environment, if auth is enabled (empty values disable it)
+pub fn api_token_from_env() -> Option<String> {
+    std::env::var(API_TOKEN_ENV)
+        .ok()
+        .map(|token| token.trim().to_string())
+        .filter(|token| !token.is_empty())
+}
+
+fn token_matches(provided: Option<&str>, token: &str) -> bool {
+    provided.is_some_and(|provided| bool::from(provided.as_bytes().ct_eq(token.as_bytes())))
+}
+
+fn bearer_token(request: &Request) -> Option<&str> {
+    request
+        .headers()
+        .get(header::AUTHORIZATION)
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.strip_prefix("Bearer "))
+}
+
+fn cookie_token(request: &Request) -> Option<&str> {
+    request
+        .headers()
+        .get_all(header::COOKIE)
+        .iter()
+        .filter_map(|value| value.to_str().ok())
+        .flat_map(|value| value.split(';'))
+        .filter_map(|pair| pair.trim().split_once('='))
+        .find(|(name, _)| *name == API_TOKEN_COOKIE)
+        .map(|(_, value)| value)
+}
+
+pub async fn require_api_token(
+    State(token): State<Arc<String>>,
+    request: Request,
+    next: Next,
+) -> Result<Response, StatusCode> {
+    let authorized = token_matches(bearer_token(&request), &token)
+        || token_matches(cookie_token(&request), &token);
+
+    if !authorized {
+        tracing::warn!(
+            "Rejected {} {}: missing or invalid API token",
+            request.method(),
+            request.uri().path()
+        );
+        return Err(StatusCode::UNAUTHORIZED);
+    }
+
+    Ok(next.run(request).await)
+}
+
+/// Frontend page loads with a valid `?token=` get the token cookie, so the
+/// browser authenticates its later `/api` and WebSocket requests
+pub async fn set_api_token_cookie(
+    State(token): State<Arc<String>>,
+    request: Request,
+    next: Next,
+) -> Response {
+    let provided = request.uri().query().and_then(|query| {
+        url::form_urlencoded::parse(query.as_bytes())
+            .find(|(name, _)| name == "token")
+            .map(|(_, value)| value.into_owned())
+    });
+    let authorized = token_matches(provided.as_deref(), &token);
+
+    let mut response = next.run(request).await;
+    if authorized
+        && let Ok(cookie) = HeaderValue::from_str(&format!(
+            "{API_TOKEN_COOKIE}={token}; Path=/; HttpOnly; SameSite=Lax"
+        ))
+    {
+        response.headers_mut().insert(header::SET_COOKIE, cookie);
+    }
+    response
+}
+
+#[cfg(test)]
+mod tests {
+    use axum::body::Body;
+
+    use super::*;
+
+    #[test]
+    fn test_token_from_bearer_or_cookie() {
+        let request = Request::builder()
+            .header(header::AUTHORIZATION, "Bearer secret")
+            .header(header::COOKIE, "theme=dark; vibe_api_token=cookie-secret")
+            .body(Body::empty())
+            .unwrap();
+
+        assert_eq!(bearer_token(&request), Some("secret"));
+        assert_eq!(cookie_token(&request), Some("cookie-secret"));
+        assert!(token_matches(Some("secret"), "secret"));
+        assert!(!token_matches(Some("secre"), "secret"));
+        assert!(!token_matches(None, "secret"));
+    }
+}
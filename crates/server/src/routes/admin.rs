@@ -0,0 +1,56 @@
+use axum::{Router, extract::State, response::Json as ResponseJson, routing::post};
+use deployment::Deployment;
+use services::services::{container::ContainerService, pr_monitor::PrMonitorStatus};
+use utils::response::ApiResponse;
+
+use crate::{DeploymentImpl, error::ApiError};
+
+/// Re-run the repo name backfill that normally only runs at startup.
+pub async fn backfill_repo_names(
+    State(deployment): State<DeploymentImpl>,
+) -> Result<ResponseJson<ApiResponse<()>>, ApiError> {
+    deployment.container().backfill_repo_names().await?;
+    Ok(ResponseJson(ApiResponse::success(())))
+}
+
+/// Re-run the before_head_commit backfill that normally only runs at startup.
+pub async fn backfill_before_head_commits(
+    State(deployment): State<DeploymentImpl>,
+) -> Result<ResponseJson<ApiResponse<()>>, ApiError> {
+    deployment
+        .container()
+        .backfill_before_head_commits()
+        .await?;
+    Ok(ResponseJson(ApiResponse::success(())))
+}
+
+/// Stop PR polling, e.g. to avoid GitHub rate limits while debugging.
+pub async fn pause_pr_monitor(
+    State(deployment): State<DeploymentImpl>,
+) -> ResponseJson<ApiResponse<PrMonitorStatus>> {
+    let status = deployment.pr_monitor_status();
+    status.set_paused(true);
+    ResponseJson(ApiResponse::success(status.snapshot()))
+}
+
+/// Restart PR polling from the next tick.
+pub async fn resume_pr_monitor(
+    State(deployment): State<DeploymentImpl>,
+) -> ResponseJson<ApiResponse<PrMonitorStatus>> {
+    let status = deployment.pr_monitor_status();
+    status.set_paused(false);
+    ResponseJson(ApiResponse::success(status.snapshot()))
+}
+
+pub fn router(_deployment: &DeploymentImpl) -> Router<DeploymentImpl> {
+    let inner = Router::new()
+        .route("/backfill/repo-names", post(backfill_repo_names))
+        .route(
+            "/backfill/before-head-commits",
+            post(backfill_before_head_commits),
+        )
+        .route("/pr-monitor/pause", post(pause_pr_monitor))
+        .route("/pr-monitor/resume", post(resume_pr_monitor));
+
+    Router::new().nest("/admin", inner)
+}
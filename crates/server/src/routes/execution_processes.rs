@@ -1,9 +1,11 @@
+use std::time::Duration;
+
 use anyhow;
 use axum::{
     Extension, Router,
     extract::{
         Path, Query, State,
-        ws::{WebSocket, WebSocketUpgrade},
+        ws::{Message, WebSocket, WebSocketUpgrade},
     },
     middleware::from_fn_with_state,
     response::{IntoResponse, Json as ResponseJson},
@@ -11,12 +13,15 @@ use axum::{
 };
 use db::models::{
     execution_process::{ExecutionProcess, ExecutionProcessError, ExecutionProcessStatus},
+    execution_process_logs::ExecutionProcessLogs,
     execution_process_repo_state::ExecutionProcessRepoState,
 };
 use deployment::Deployment;
-use futures_util::{SinkExt, StreamExt, TryStreamExt};
+use futures_util::{SinkExt, StreamExt, TryStreamExt, stream::SplitSink};
 use serde::Deserialize;
 use services::services::container::ContainerService;
+use sqlx::SqlitePool;
+use tokio::sync::broadcast::error::RecvError;
 use utils::{log_msg::LogMsg, response::ApiResponse};
 use uuid::Uuid;
 
@@ -30,6 +35,14 @@ pub struct ExecutionProcessQuery {
     pub show_soft_deleted: Option<bool>,
 }
 
+#[derive(Debug, Deserialize)]
+pub struct RawLogsQuery {
+    /// If true, send stdout lines verbatim instead of wrapping them in JSON patches
+    /// (e.g. to get the original Claude JSONL back out of an imported session)
+    #[serde(default)]
+    pub raw: bool,
+}
+
 pub async fn get_execution_process_by_id(
     Extension(execution_process): Extension<ExecutionProcess>,
     State(_deployment): State<DeploymentImpl>,
@@ -41,6 +54,7 @@ pub async fn stream_raw_logs_ws(
     ws: WebSocketUpgrade,
     State(deployment): State<DeploymentImpl>,
     Path(exec_id): Path<Uuid>,
+    Query(query): Query<RawLogsQuery>,
 ) -> Result<impl IntoResponse, ApiError> {
     // Check if the stream exists before upgrading the WebSocket
     let _stream = deployment
@@ -52,7 +66,7 @@ pub async fn stream_raw_logs_ws(
         })?;
 
     Ok(ws.on_upgrade(move |socket| async move {
-        if let Err(e) = handle_raw_logs_ws(socket, deployment, exec_id).await {
+        if let Err(e) = handle_raw_logs_ws(socket, deployment, exec_id, query.raw).await {
             tracing::warn!("raw logs WS closed: {}", e);
         }
     }))
@@ -62,6 +76,7 @@ async fn handle_raw_logs_ws(
     socket: WebSocket,
     deployment: DeploymentImpl,
     exec_id: Uuid,
+    raw: bool,
 ) -> anyhow::Result<()> {
     use std::sync::{
         Arc,
@@ -71,31 +86,43 @@ async fn handle_raw_logs_ws(
     use executors::logs::utils::patch::ConversationPatch;
     use utils::log_msg::LogMsg;
 
-    // Get the raw stream and convert to JSON patches on-the-fly
+    // Get the raw stream and convert to JSON patches on-the-fly (unless raw mode is requested)
     let raw_stream = deployment
         .container()
         .stream_raw_logs(&exec_id)
         .await
         .ok_or_else(|| anyhow::anyhow!("Execution process not found"))?;
 
-    let counter = Arc::new(AtomicUsize::new(0));
-    let mut stream = raw_stream.map_ok({
-        let counter = counter.clone();
-        move |m| match m {
-            LogMsg::Stdout(content) => {
-                let index = counter.fetch_add(1, Ordering::SeqCst);
-                let patch = ConversationPatch::add_stdout(index, content);
-                LogMsg::JsonPatch(patch).to_ws_message_unchecked()
-            }
-            LogMsg::Stderr(content) => {
-                let index = counter.fetch_add(1, Ordering::SeqCst);
-                let patch = ConversationPatch::add_stderr(index, content);
-                LogMsg::JsonPatch(patch).to_ws_message_unchecked()
-            }
-            LogMsg::Finished => LogMsg::Finished.to_ws_message_unchecked(),
-            _ => unreachable!("Raw stream should only have Stdout/Stderr/Finished"),
-        }
-    });
+    let mut stream = if raw {
+        // One text message per stdout line, exactly as stored; close once the logs are done
+        raw_stream
+            .try_filter_map(|m| async move {
+                Ok(match m {
+                    LogMsg::Stdout(line) => Some(Message::Text(line.into())),
+                    LogMsg::Finished => Some(Message::Close(None)),
+                    _ => None,
+                })
+            })
+            .boxed()
+    } else {
+        let counter = Arc::new(AtomicUsize::new(0));
+        raw_stream
+            .map_ok(move |m| match m {
+                LogMsg::Stdout(content) => {
+                    let index = counter.fetch_add(1, Ordering::SeqCst);
+                    let patch = ConversationPatch::add_stdout(index, content);
+                    LogMsg::JsonPatch(patch).to_ws_message_unchecked()
+                }
+                LogMsg::Stderr(content) => {
+                    let index = counter.fetch_add(1, Ordering::SeqCst);
+                    let patch = ConversationPatch::add_stderr(index, content);
+                    LogMsg::JsonPatch(patch).to_ws_message_unchecked()
+                }
+                LogMsg::Finished => LogMsg::Finished.to_ws_message_unchecked(),
+                _ => unreachable!("Raw stream should only have Stdout/Stderr/Finished"),
+            })
+            .boxed()
+    };
 
     // Split socket into sender and receiver
     let (mut sender, mut receiver) = socket.split();
@@ -166,10 +193,121 @@ async fn handle_normalized_logs_ws(
     Ok(())
 }
 
+/// How often a log tail checks whether its process is still running
+const LOG_TAIL_STATUS_INTERVAL: Duration = Duration::from_secs(1);
+
+/// Tail the stored logs of an execution process: every row so far, then rows as
+/// they are appended, then `{"finished":true}` once the process is done
+pub async fn stream_logs_ws(
+    ws: WebSocketUpgrade,
+    Extension(execution_process): Extension<ExecutionProcess>,
+    State(deployment): State<DeploymentImpl>,
+) -> impl IntoResponse {
+    ws.on_upgrade(move |socket| async move {
+        if let Err(e) = handle_logs_stream_ws(socket, deployment, execution_process.id).await {
+            tracing::warn!("logs stream WS closed: {}", e);
+        }
+    })
+}
+
+async fn handle_logs_stream_ws(
+    socket: WebSocket,
+    deployment: DeploymentImpl,
+    exec_id: Uuid,
+) -> anyhow::Result<()> {
+    let pool = &deployment.db().pool;
+    // Subscribe before reading stored rows so nothing appended in between is missed
+    let mut appended = ExecutionProcessLogs::subscribe_appended();
+
+    let (mut sender, mut receiver) = socket.split();
+    // Drain (and ignore) any client->server messages so pings/pongs work
+    tokio::spawn(async move { while let Some(Ok(_)) = receiver.next().await {} });
+
+    let mut last_rowid = 0;
+    send_stored_logs(pool, exec_id, &mut last_rowid, &mut sender).await?;
+
+    let mut status_check = tokio::time::interval(LOG_TAIL_STATUS_INTERVAL);
+    // The last lines are written shortly after the status changes, so keep
+    // forwarding for one more interval before finishing
+    let mut finishing = false;
+    loop {
+        tokio::select! {
+            received = appended.recv() => match received {
+                Ok(row) if row.execution_id == exec_id && row.rowid > last_rowid => {
+                    last_rowid = row.rowid;
+                    send_log_lines(&row.logs, &mut sender).await?;
+                }
+                Ok(_) => {}
+                // Fell behind the channel: catch up from the database
+                Err(RecvError::Lagged(_)) => {
+                    send_stored_logs(pool, exec_id, &mut last_rowid, &mut sender).await?;
+                }
+                Err(RecvError::Closed) => break,
+            },
+            _ = status_check.tick() => {
+                let running = ExecutionProcess::find_by_id(pool, exec_id)
+                    .await?
+                    .is_some_and(|process| process.status == ExecutionProcessStatus::Running);
+                if running {
+                    continue;
+                }
+                if finishing {
+                    send_stored_logs(pool, exec_id, &mut last_rowid, &mut sender).await?;
+                    sender.send(LogMsg::Finished.to_ws_message_unchecked()).await?;
+                    break;
+                }
+                finishing = true;
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Send the stored rows after `last_rowid` and advance it past them
+async fn send_stored_logs(
+    pool: &SqlitePool,
+    exec_id: Uuid,
+    last_rowid: &mut i64,
+    sender: &mut SplitSink<WebSocket, Message>,
+) -> anyhow::Result<()> {
+    let rows = ExecutionProcessLogs::find_by_execution_id_with_rowids(pool, exec_id).await?;
+    for (rowid, record) in rows {
+        if rowid > *last_rowid {
+            send_log_lines(&record.logs, sender).await?;
+            *last_rowid = rowid;
+        }
+    }
+    Ok(())
+}
+
+/// Forward each JSONL `LogMsg` line of a stored row as its own message
+async fn send_log_lines(
+    jsonl: &str,
+    sender: &mut SplitSink<WebSocket, Message>,
+) -> anyhow::Result<()> {
+    for line in jsonl.lines().filter(|line| !line.trim().is_empty()) {
+        match serde_json::from_str::<LogMsg>(line) {
+            Ok(msg) => sender.send(msg.to_ws_message_unchecked()).await?,
+            Err(e) => tracing::warn!("Skipping unparseable log line: {}", e),
+        }
+    }
+    Ok(())
+}
+
+/// Kill one running execution process and mark it `Killed`. Killed processes are
+/// left alone by the exit monitor and by the orphan cleanup at startup, which only
+/// touches processes still marked running.
 pub async fn stop_execution_process(
     Extension(execution_process): Extension<ExecutionProcess>,
     State(deployment): State<DeploymentImpl>,
 ) -> Result<ResponseJson<ApiResponse<()>>, ApiError> {
+    if execution_process.status != ExecutionProcessStatus::Running {
+        return Err(ApiError::Conflict(format!(
+            "Execution process {} is not running",
+            execution_process.id
+        )));
+    }
+
     deployment
         .container()
         .stop_execution(&execution_process, ExecutionProcessStatus::Killed)
@@ -250,6 +388,7 @@ pub fn router(deployment: &DeploymentImpl) -> Router<DeploymentImpl> {
         .route("/repo-states", get(get_execution_process_repo_states))
         .route("/raw-logs/ws", get(stream_raw_logs_ws))
         .route("/normalized-logs/ws", get(stream_normalized_logs_ws))
+        .route("/logs/stream/ws", get(stream_logs_ws))
         .layer(from_fn_with_state(
             deployment.clone(),
             load_execution_process_middleware,
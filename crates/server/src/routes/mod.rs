@@ -1,10 +1,17 @@
+use std::sync::Arc;
+
 use axum::{
     Router,
+    middleware::from_fn_with_state,
     routing::{IntoMakeService, get},
 };
 
-use crate::DeploymentImpl;
+use crate::{
+    DeploymentImpl,
+    middleware::{api_token_from_env, require_api_token, set_api_token_cookie},
+};
 
+pub mod admin;
 pub mod approvals;
 pub mod commander;
 pub mod config;
@@ -18,6 +25,7 @@ pub mod health;
 pub mod images;
 pub mod oauth;
 pub mod organizations;
+pub mod pr_monitor;
 pub mod projects;
 pub mod repo;
 pub mod scratch;
@@ -30,9 +38,10 @@ pub mod tasks;
 
 pub fn router(deployment: DeploymentImpl) -> IntoMakeService<Router> {
     // Create routers with different middleware layers
-    let base_routes = Router::new()
+    let mut base_routes = Router::new()
         .route("/health", get(health::health_check))
         .merge(config::router())
+        .merge(admin::router(&deployment))
         .merge(containers::router(&deployment))
         .merge(projects::router(&deployment))
         .merge(tasks::router(&deployment))
@@ -40,6 +49,7 @@ pub fn router(deployment: DeploymentImpl) -> IntoMakeService<Router> {
         .merge(task_attempts::router(&deployment))
         .merge(execution_processes::router(&deployment))
         .merge(tags::router(&deployment))
+        .merge(pr_monitor::router(&deployment))
         .merge(oauth::router())
         .merge(organizations::router())
         .merge(filesystem::router())
@@ -53,9 +63,19 @@ pub fn router(deployment: DeploymentImpl) -> IntoMakeService<Router> {
         .nest("/images", images::routes())
         .with_state(deployment);
 
-    Router::new()
+    let mut frontend_routes = Router::new()
         .route("/", get(frontend::serve_frontend_root))
-        .route("/{*path}", get(frontend::serve_frontend))
+        .route("/{*path}", get(frontend::serve_frontend));
+
+    // Optional bearer token guard for running on a shared machine
+    if let Some(token) = api_token_from_env() {
+        tracing::info!("API token auth enabled for /api routes");
+        let token = Arc::new(token);
+        base_routes = base_routes.layer(from_fn_with_state(token.clone(), require_api_token));
+        frontend_routes = frontend_routes.layer(from_fn_with_state(token, set_api_token_cookie));
+    }
+
+    frontend_routes
         .nest("/api", base_routes)
         .into_make_service()
 }
@@ -0,0 +1,37 @@
+use axum::{
+    Router,
+    extract::State,
+    response::Json as ResponseJson,
+    routing::{get, post},
+};
+use deployment::Deployment;
+use services::services::pr_monitor::PrMonitorStatus;
+use utils::response::ApiResponse;
+
+use crate::DeploymentImpl;
+
+/// Last run time and last error of the background PR monitor.
+pub async fn get_pr_monitor_status(
+    State(deployment): State<DeploymentImpl>,
+) -> ResponseJson<ApiResponse<PrMonitorStatus>> {
+    ResponseJson(ApiResponse::success(
+        deployment.pr_monitor_status().snapshot(),
+    ))
+}
+
+/// Clear the recorded error once the underlying problem has been fixed.
+pub async fn clear_pr_monitor_error(
+    State(deployment): State<DeploymentImpl>,
+) -> ResponseJson<ApiResponse<PrMonitorStatus>> {
+    let status = deployment.pr_monitor_status();
+    status.clear_error();
+    ResponseJson(ApiResponse::success(status.snapshot()))
+}
+
+pub fn router(_deployment: &DeploymentImpl) -> Router<DeploymentImpl> {
+    let inner = Router::new()
+        .route("/status", get(get_pr_monitor_status))
+        .route("/status/clear-error", post(clear_pr_monitor_error));
+
+    Router::new().nest("/pr-monitor", inner)
+}
@@ -1,4 +1,4 @@
-use std::path::PathBuf;
+use std::{collections::HashSet, path::PathBuf};
 
 use anyhow;
 use axum::{
@@ -13,18 +13,24 @@ use axum::{
     routing::{get, post},
 };
 use db::models::{
-    project::{CreateProject, Project, ProjectError, SearchResult, UpdateProject},
+    project::{
+        CreateProject, Project, ProjectError, ProjectWithHealth, SearchResult, UpdateProject,
+    },
     project_repo::{CreateProjectRepo, ProjectRepo, UpdateProjectRepo},
     repo::Repo,
-    task::{CreateTask, Task},
+    task::{CreateTask, Task, TaskStatus},
 };
 use deployment::Deployment;
 use futures_util::{SinkExt, StreamExt, TryStreamExt};
 use serde::Deserialize;
 use serde::Serialize;
+use sqlx::SqlitePool;
 use services::services::{
-    file_search_cache::SearchQuery, linear::LinearClient, project::ProjectServiceError,
+    file_search_cache::SearchQuery,
+    linear::{LinearClient, LinearIssue, LinearStateMap, linear_api_key_from_env},
+    project::ProjectServiceError,
     remote_client::CreateRemoteProjectPayload,
+    vibe_tasks::{VibeTaskFile, read_vibe_tasks, vibe_tasks_dir},
 };
 use ts_rs::TS;
 use utils::{
@@ -48,9 +54,24 @@ pub struct CreateRemoteProjectRequest {
 
 pub async fn get_projects(
     State(deployment): State<DeploymentImpl>,
-) -> Result<ResponseJson<ApiResponse<Vec<Project>>>, ApiError> {
-    let projects = Project::find_all(&deployment.db().pool).await?;
-    Ok(ResponseJson(ApiResponse::success(projects)))
+) -> Result<ResponseJson<ApiResponse<Vec<ProjectWithHealth>>>, ApiError> {
+    let pool = &deployment.db().pool;
+    let projects = Project::find_all(pool).await?;
+
+    // Flag projects whose repositories were moved or deleted on disk
+    let mut projects_with_health = Vec::with_capacity(projects.len());
+    for project in projects {
+        let repos = ProjectRepo::find_repos_for_project(pool, project.id).await?;
+        let valid = repos.iter().all(|repo| {
+            deployment
+                .repo()
+                .validate_git_repo_path(&repo.path)
+                .is_ok()
+        });
+        projects_with_health.push(ProjectWithHealth { project, valid });
+    }
+
+    Ok(ResponseJson(ApiResponse::success(projects_with_health)))
 }
 
 pub async fn stream_projects_ws(
@@ -245,6 +266,32 @@ pub async fn update_project(
     State(deployment): State<DeploymentImpl>,
     Json(payload): Json<UpdateProject>,
 ) -> Result<ResponseJson<ApiResponse<Project>>, StatusCode> {
+    if payload.name.as_deref().is_some_and(|name| name.trim().is_empty()) {
+        tracing::warn!("Rejecting empty project name");
+        return Err(StatusCode::BAD_REQUEST);
+    }
+    if let Some(state_map) = &payload.linear_state_map
+        && let Err(e) = LinearStateMap::parse(state_map)
+    {
+        tracing::warn!("Rejecting invalid Linear state map: {}", e);
+        return Err(StatusCode::BAD_REQUEST);
+    }
+    if let Some(setup_profiles) = &payload.setup_profiles
+        && let Err(e) = Project::parse_setup_profiles(setup_profiles)
+    {
+        tracing::warn!("Rejecting invalid setup profiles: {}", e);
+        return Err(StatusCode::BAD_REQUEST);
+    }
+    if let Some(webhook_url) = &payload.webhook_url
+        && !matches!(
+            url::Url::parse(webhook_url).map(|u| u.scheme().to_string()).as_deref(),
+            Ok("http" | "https")
+        )
+    {
+        tracing::warn!("Rejecting invalid webhook URL: {}", webhook_url);
+        return Err(StatusCode::BAD_REQUEST);
+    }
+
     match deployment
         .project()
         .update_project(&deployment.db().pool, &existing_project, payload)
@@ -379,6 +426,34 @@ pub async fn search_project_files(
     }
 }
 
+#[derive(Debug, Serialize, TS)]
+pub struct WarmSearchCacheResponse {
+    pub repo_count: usize,
+}
+
+/// Re-warm the file search cache for a project's repositories. Startup only
+/// warms the most active projects, so a newly opened project starts cold.
+pub async fn warm_project_search_cache(
+    Extension(project): Extension<Project>,
+    State(deployment): State<DeploymentImpl>,
+) -> Result<ResponseJson<ApiResponse<WarmSearchCacheResponse>>, ApiError> {
+    let repos = ProjectRepo::find_repos_for_project(&deployment.db().pool, project.id).await?;
+    let repo_paths: Vec<PathBuf> = repos.into_iter().map(|repo| repo.path).collect();
+    let repo_count = repo_paths.len();
+
+    if let Err(e) = deployment
+        .file_search_cache()
+        .warm_and_watch(repo_paths)
+        .await
+    {
+        tracing::error!("Failed to warm search cache for project {}: {}", project.id, e);
+    }
+
+    Ok(ResponseJson(ApiResponse::success(WarmSearchCacheResponse {
+        repo_count,
+    })))
+}
+
 pub async fn get_project_repositories(
     Extension(project): Extension<Project>,
     State(deployment): State<DeploymentImpl>,
@@ -524,6 +599,26 @@ pub struct LinearSyncResponse {
     pub updated_count: usize,
 }
 
+#[derive(Debug, Serialize, TS)]
+pub struct VibeImportResponse {
+    pub created_count: usize,
+    pub updated_count: usize,
+    /// Files whose id belongs to a task in another project
+    pub skipped_count: usize,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(default)]
+pub struct ArchiveCompletedRequest {
+    /// Only archive tasks last updated at least this many days ago
+    pub older_than_days: Option<u32>,
+}
+
+#[derive(Debug, Serialize, TS)]
+pub struct ArchiveCompletedResponse {
+    pub archived_count: usize,
+}
+
 #[derive(Debug, Deserialize)]
 pub struct ValidateLinearAssigneeRequest {
     pub assignee_id: String,
@@ -535,23 +630,6 @@ pub struct ValidateLinearAssigneeResponse {
     pub name: Option<String>,
 }
 
-/// Derive the env var name for the Linear API key from a project name.
-/// E.g., "reflex" -> "REFLEX_LINEAR_API_KEY"
-fn get_linear_api_key_from_env(project_name: &str) -> Option<String> {
-    let normalized: String = project_name
-        .chars()
-        .map(|c| {
-            if c.is_alphanumeric() {
-                c.to_ascii_uppercase()
-            } else {
-                '_'
-            }
-        })
-        .collect();
-    let env_var = format!("{}_LINEAR_API_KEY", normalized);
-    std::env::var(&env_var).ok()
-}
-
 /// Validate that a Linear user ID exists
 pub async fn validate_linear_assignee(
     Extension(project): Extension<Project>,
@@ -561,7 +639,7 @@ pub async fn validate_linear_assignee(
     let api_key = project
         .linear_api_key
         .clone()
-        .or_else(|| get_linear_api_key_from_env(&project.name))
+        .or_else(|| linear_api_key_from_env(&project.name))
         .ok_or_else(|| {
             tracing::error!(
                 "Linear assignee validation failed for project {}: API key not configured",
@@ -594,6 +672,76 @@ pub async fn validate_linear_assignee(
     }
 }
 
+/// Create or update the local task for a Linear issue. Existing tasks are matched by
+/// issue id, then by URL (tasks linked before the id was stored), so repeated syncs
+/// never insert duplicates. Returns true if a new task was created.
+async fn upsert_linear_issue_task(
+    pool: &SqlitePool,
+    project_id: Uuid,
+    issue: &LinearIssue,
+) -> Result<bool, sqlx::Error> {
+    // Serialize labels to JSON
+    let labels_json = if issue.labels.is_empty() {
+        None
+    } else {
+        Some(serde_json::to_string(&issue.labels).unwrap_or_default())
+    };
+
+    let existing = match Task::find_by_linear_issue_id(pool, project_id, &issue.id).await? {
+        Some(task) => Some(task),
+        None => Task::find_by_linear_url(pool, project_id, &issue.url).await?,
+    };
+
+    let existing = match existing {
+        Some(task) => task,
+        None => {
+            // Create new task from Linear issue
+            let create_task = CreateTask::from_linear_issue(
+                project_id,
+                issue.title.clone(),
+                issue.description.clone(),
+                issue.id.clone(),
+                issue.url.clone(),
+            );
+            match Task::create(pool, &create_task, Uuid::new_v4()).await {
+                Ok(task) => {
+                    // Update labels for new task
+                    Task::update_linear_labels(pool, task.id, labels_json.as_deref()).await?;
+                    return Ok(true);
+                }
+                // A concurrent sync inserted the task first; update it instead
+                Err(sqlx::Error::Database(e)) if e.is_unique_violation() => {
+                    Task::find_by_linear_issue_id(pool, project_id, &issue.id)
+                        .await?
+                        .ok_or(sqlx::Error::RowNotFound)?
+                }
+                Err(e) => return Err(e),
+            }
+        }
+    };
+
+    // Update existing task title/description/url if changed
+    Task::update(
+        pool,
+        existing.id,
+        existing.project_id,
+        issue.title.clone(),
+        issue.description.clone(),
+        existing.status, // Keep current status (might have moved from backlog)
+        existing.parent_workspace_id,
+    )
+    .await?;
+    if existing.linear_issue_id.as_deref() != Some(issue.id.as_str()) {
+        Task::update_linear_issue_id(pool, existing.id, &issue.id).await?;
+    }
+    // Also update the linear_url in case it was missing
+    Task::update_linear_url(pool, existing.id, &issue.url).await?;
+    // Update labels
+    Task::update_linear_labels(pool, existing.id, labels_json.as_deref()).await?;
+
+    Ok(false)
+}
+
 /// Sync backlog issues from Linear into the project's Backlog column
 pub async fn sync_linear_backlog(
     Extension(project): Extension<Project>,
@@ -603,7 +751,7 @@ pub async fn sync_linear_backlog(
     let api_key = project
         .linear_api_key
         .clone()
-        .or_else(|| get_linear_api_key_from_env(&project.name))
+        .or_else(|| linear_api_key_from_env(&project.name))
         .ok_or_else(|| {
             tracing::error!(
                 "Linear sync failed for project {}: API key not configured",
@@ -630,63 +778,177 @@ pub async fn sync_linear_backlog(
     let pool = &deployment.db().pool;
     let mut created = 0;
     let mut updated = 0;
+    let mut seen = HashSet::new();
 
     for issue in &issues {
-        // Serialize labels to JSON
-        let labels_json = if issue.labels.is_empty() {
-            None
+        // The same issue can show up twice across result pages
+        if !seen.insert(issue.id.as_str()) {
+            continue;
+        }
+
+        if upsert_linear_issue_task(pool, project.id, issue).await? {
+            created += 1;
         } else {
-            Some(serde_json::to_string(&issue.labels).unwrap_or_default())
-        };
+            updated += 1;
+        }
+    }
 
-        if let Some(existing) = Task::find_by_linear_issue_id(pool, project.id, &issue.id).await? {
-            // Update existing task title/description/url if changed
+    tracing::info!(
+        "Linear sync complete for project {}: {} synced, {} created, {} updated",
+        project.id,
+        issues.len(),
+        created,
+        updated
+    );
+
+    Ok(ResponseJson(ApiResponse::success(LinearSyncResponse {
+        synced_count: issues.len(),
+        created_count: created,
+        updated_count: updated,
+    })))
+}
+
+/// Create or update a task from a TUI task file, keeping the file's id.
+/// Returns `None` when the id is already used by a task in another project.
+async fn upsert_vibe_task(
+    pool: &SqlitePool,
+    project_id: Uuid,
+    file: &VibeTaskFile,
+) -> Result<Option<bool>, sqlx::Error> {
+    let existing = Task::find_by_id(pool, file.id).await?;
+    let created = match &existing {
+        Some(existing) if existing.project_id != project_id => return Ok(None),
+        Some(existing) => {
+            // Statuses not set by hand in the TUI are derived there, so keep ours
+            let status = file.status.clone().unwrap_or_else(|| existing.status.clone());
             Task::update(
                 pool,
                 existing.id,
                 existing.project_id,
-                issue.title.clone(),
-                issue.description.clone(),
-                existing.status, // Keep current status (might have moved from backlog)
+                file.title.clone(),
+                file.description.clone(),
+                status,
                 existing.parent_workspace_id,
             )
             .await?;
-            // Also update the linear_url in case it was missing
-            Task::update_linear_url(pool, existing.id, &issue.url).await?;
-            // Update labels
-            Task::update_linear_labels(pool, existing.id, labels_json.as_deref()).await?;
-            updated += 1;
-        } else {
-            // Create new task from Linear issue
-            let create_task = CreateTask::from_linear_issue(
-                project.id,
-                issue.title.clone(),
-                issue.description.clone(),
-                issue.id.clone(),
-                issue.url.clone(),
-            );
-            let task = Task::create(pool, &create_task, Uuid::new_v4()).await?;
-            // Update labels for new task
-            Task::update_linear_labels(pool, task.id, labels_json.as_deref()).await?;
-            created += 1;
+            false
+        }
+        None => {
+            let create_task = CreateTask {
+                status: Some(file.status.clone().unwrap_or(TaskStatus::Backlog)),
+                linear_url: file.linear_url.clone(),
+                ..CreateTask::from_title_description(
+                    project_id,
+                    file.title.clone(),
+                    file.description.clone(),
+                )
+            };
+            Task::create(pool, &create_task, file.id).await?;
+            true
+        }
+    };
+
+    let tags_json = if file.tags.is_empty() {
+        None
+    } else {
+        Some(serde_json::to_string(&file.tags).unwrap_or_default())
+    };
+    Task::update_tags(pool, file.id, tags_json.as_deref()).await?;
+    Task::update_due_date(pool, file.id, file.due_date).await?;
+
+    // Linear sync owns the link once it is set; only fill in a missing one
+    if let Some(existing) = &existing
+        && existing.linear_url.is_none()
+        && let Some(linear_url) = &file.linear_url
+    {
+        Task::update_linear_url(pool, file.id, linear_url).await?;
+    }
+
+    Ok(Some(created))
+}
+
+/// Import the TUI's markdown tasks from `~/.vibe/projects/{name}/tasks/`,
+/// matching existing tasks by the id in each file's frontmatter
+pub async fn import_vibe_tasks(
+    Extension(project): Extension<Project>,
+    State(deployment): State<DeploymentImpl>,
+) -> Result<ResponseJson<ApiResponse<VibeImportResponse>>, ApiError> {
+    let dir = vibe_tasks_dir(&project.name).ok_or_else(|| {
+        ApiError::BadRequest(format!(
+            "No task files found in ~/.vibe/projects/{}/tasks",
+            project.name
+        ))
+    })?;
+    let files = read_vibe_tasks(&dir)?;
+
+    let pool = &deployment.db().pool;
+    let mut created = 0;
+    let mut updated = 0;
+    let mut skipped = 0;
+
+    for file in &files {
+        match upsert_vibe_task(pool, project.id, file).await? {
+            Some(true) => created += 1,
+            Some(false) => updated += 1,
+            None => {
+                tracing::warn!(
+                    "Skipping task file {} ({}): id belongs to another project",
+                    file.id,
+                    file.title
+                );
+                skipped += 1;
+            }
         }
     }
 
     tracing::info!(
-        "Linear sync complete for project {}: {} synced, {} created, {} updated",
+        "Imported .vibe tasks for project {}: {} created, {} updated, {} skipped",
         project.id,
-        issues.len(),
         created,
-        updated
+        updated,
+        skipped
     );
 
-    Ok(ResponseJson(ApiResponse::success(LinearSyncResponse {
-        synced_count: issues.len(),
+    Ok(ResponseJson(ApiResponse::success(VibeImportResponse {
         created_count: created,
         updated_count: updated,
+        skipped_count: skipped,
     })))
 }
 
+/// Archive the project's Done and Cancelled tasks so they leave the board
+pub async fn archive_completed_tasks(
+    Extension(project): Extension<Project>,
+    State(deployment): State<DeploymentImpl>,
+    Json(payload): Json<ArchiveCompletedRequest>,
+) -> Result<ResponseJson<ApiResponse<ArchiveCompletedResponse>>, ApiError> {
+    let before = payload
+        .older_than_days
+        .map(|days| chrono::Utc::now() - chrono::Duration::days(days.into()));
+
+    let archived = Task::archive_completed(&deployment.db().pool, project.id, before).await?;
+
+    tracing::info!("Archived {} completed tasks in project {}", archived, project.id);
+
+    Ok(ResponseJson(ApiResponse::success(ArchiveCompletedResponse {
+        archived_count: archived as usize,
+    })))
+}
+
+/// Star or unstar the project; starred projects are listed first
+pub async fn toggle_project_favorite(
+    Extension(project): Extension<Project>,
+    State(deployment): State<DeploymentImpl>,
+) -> Result<ResponseJson<ApiResponse<Project>>, ApiError> {
+    let pool = &deployment.db().pool;
+    Project::set_favorite(pool, project.id, !project.favorite).await?;
+    let project = Project::find_by_id(pool, project.id)
+        .await?
+        .ok_or(ProjectError::ProjectNotFound)?;
+
+    Ok(ResponseJson(ApiResponse::success(project)))
+}
+
 pub fn router(deployment: &DeploymentImpl) -> Router<DeploymentImpl> {
     let project_id_router = Router::new()
         .route(
@@ -695,6 +957,7 @@ pub fn router(deployment: &DeploymentImpl) -> Router<DeploymentImpl> {
         )
         .route("/remote/members", get(get_project_remote_members))
         .route("/search", get(search_project_files))
+        .route("/search/warm", post(warm_project_search_cache))
         .route("/open-editor", post(open_project_in_editor))
         .route(
             "/link",
@@ -707,6 +970,9 @@ pub fn router(deployment: &DeploymentImpl) -> Router<DeploymentImpl> {
         )
         .route("/linear/sync", post(sync_linear_backlog))
         .route("/linear/validate-assignee", post(validate_linear_assignee))
+        .route("/favorite", post(toggle_project_favorite))
+        .route("/archive-completed", post(archive_completed_tasks))
+        .route("/vibe/import", post(import_vibe_tasks))
         .layer(from_fn_with_state(
             deployment.clone(),
             load_project_middleware,
@@ -1,4 +1,8 @@
-use axum::{Json, Router, routing::get};
+use axum::{
+    Json, Router,
+    extract::{Path, Query},
+    routing::get,
+};
 use serde::{Deserialize, Serialize};
 use std::path::PathBuf;
 use ts_rs::TS;
@@ -21,10 +25,22 @@ pub struct SlashCommand {
     pub plugin_name: Option<String>,
     /// Where the command comes from
     pub source: SlashCommandSource,
+    /// Markdown file the command is read from; builtins have none
+    #[serde(skip)]
+    #[ts(skip)]
+    path: Option<PathBuf>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, TS)]
 #[ts(export)]
+pub struct SlashCommandDetail {
+    pub command: SlashCommand,
+    /// Markdown the command injects, without its frontmatter; a builtin's is its description
+    pub body: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, TS)]
+#[ts(export)]
 #[serde(rename_all = "lowercase")]
 pub enum SlashCommandSource {
     /// Built-in commands (compiled into Claude Code)
@@ -35,6 +51,35 @@ pub enum SlashCommandSource {
     Plugin,
 }
 
+#[derive(Debug, Deserialize, TS)]
+pub struct SlashCommandsQuery {
+    /// Only commands from this source
+    pub source: Option<SlashCommandSource>,
+    /// Case-insensitive match against the qualified name and description
+    pub q: Option<String>,
+}
+
+impl SlashCommandsQuery {
+    fn matches(&self, command: &SlashCommand) -> bool {
+        if let Some(source) = &self.source
+            && command.source != *source
+        {
+            return false;
+        }
+        match self.q.as_deref().map(str::trim) {
+            Some(q) if !q.is_empty() => {
+                let q = q.to_lowercase();
+                command.qualified_name.to_lowercase().contains(&q)
+                    || command
+                        .description
+                        .as_deref()
+                        .is_some_and(|d| d.to_lowercase().contains(&q))
+            }
+            _ => true,
+        }
+    }
+}
+
 #[derive(Debug, Deserialize)]
 struct PluginJson {
     name: String,
@@ -75,6 +120,17 @@ fn parse_frontmatter(content: &str) -> CommandFrontmatter {
     frontmatter
 }
 
+/// The markdown after the frontmatter, as `parse_frontmatter` delimits it
+fn strip_frontmatter(content: &str) -> &str {
+    if !content.starts_with("---") {
+        return content;
+    }
+    match content[3..].find("---") {
+        Some(end_idx) => content[3 + end_idx + 3..].trim_start_matches(['\r', '\n']),
+        None => content,
+    }
+}
+
 /// Get command name from file path (without .md extension)
 fn get_command_name(path: &std::path::Path) -> Option<String> {
     path.file_stem()
@@ -82,22 +138,45 @@ fn get_command_name(path: &std::path::Path) -> Option<String> {
         .map(|s| s.to_string())
 }
 
-/// Scan a directory for .md command files
+/// Scan a directory for .md command files, including those in subdirectories
 fn scan_command_dir(
     dir: &std::path::Path,
     plugin_name: Option<&str>,
     source: SlashCommandSource,
 ) -> Vec<SlashCommand> {
     let mut commands = Vec::new();
+    scan_command_subdir(dir, &[], plugin_name, &source, &mut commands);
+    commands
+}
 
+/// Commands in `dir`, which is `groups` deep below the command directory. Nested
+/// commands are namespaced by their path: `git/commit.md` of plugin `tools` is
+/// `tools:git:commit`.
+fn scan_command_subdir(
+    dir: &std::path::Path,
+    groups: &[String],
+    plugin_name: Option<&str>,
+    source: &SlashCommandSource,
+    commands: &mut Vec<SlashCommand>,
+) {
     let entries = match std::fs::read_dir(dir) {
         Ok(e) => e,
-        Err(_) => return commands,
+        Err(_) => return,
     };
 
     for entry in entries.flatten() {
         let path = entry.path();
 
+        // Symlinked directories are not followed, so a link cycle can't recurse forever
+        if entry.file_type().is_ok_and(|t| t.is_dir()) {
+            if let Some(group) = path.file_name().and_then(|s| s.to_str()) {
+                let mut nested = groups.to_vec();
+                nested.push(group.to_string());
+                scan_command_subdir(&path, &nested, plugin_name, source, commands);
+            }
+            continue;
+        }
+
         // Only process .md files
         if path.extension().and_then(|s| s.to_str()) != Some("md") {
             continue;
@@ -115,10 +194,12 @@ fn scan_command_dir(
 
         let frontmatter = parse_frontmatter(&content);
 
-        let qualified_name = match plugin_name {
-            Some(pn) => format!("{}:{}", pn, name),
-            None => name.clone(),
-        };
+        let qualified_name = plugin_name
+            .into_iter()
+            .chain(groups.iter().map(String::as_str))
+            .chain([name.as_str()])
+            .collect::<Vec<_>>()
+            .join(":");
 
         commands.push(SlashCommand {
             name,
@@ -127,10 +208,9 @@ fn scan_command_dir(
             argument_hint: frontmatter.argument_hint,
             plugin_name: plugin_name.map(|s| s.to_string()),
             source: source.clone(),
+            path: Some(path),
         });
     }
-
-    commands
 }
 
 /// Get built-in Claude Code commands (compiled into the binary)
@@ -172,30 +252,37 @@ fn get_builtin_commands() -> Vec<SlashCommand> {
             argument_hint: None,
             plugin_name: None,
             source: SlashCommandSource::Builtin,
+            path: None,
         })
         .collect()
 }
 
 /// Discover all available slash commands
 fn discover_commands() -> Vec<SlashCommand> {
+    let user_commands_dir = dirs::home_dir().map(|home| home.join(".claude").join("commands"));
+    collect_commands(user_commands_dir.as_deref(), get_plugin_directories())
+}
+
+/// Builtins plus the commands found in the user's command directory and in plugins
+fn collect_commands(
+    user_commands_dir: Option<&std::path::Path>,
+    plugin_dirs: Vec<PathBuf>,
+) -> Vec<SlashCommand> {
     let mut commands = Vec::new();
 
     // 1. Built-in commands first
     commands.extend(get_builtin_commands());
 
     // 2. User commands from ~/.claude/commands/
-    if let Some(home) = dirs::home_dir() {
-        let user_commands_dir = home.join(".claude").join("commands");
+    if let Some(user_commands_dir) = user_commands_dir {
         commands.extend(scan_command_dir(
-            &user_commands_dir,
+            user_commands_dir,
             None,
             SlashCommandSource::User,
         ));
     }
 
     // 3. Plugin commands - scan known plugin locations
-    let plugin_dirs = get_plugin_directories();
-
     for plugin_dir in plugin_dirs {
         // Read plugin.json to get plugin name
         let plugin_json_path = plugin_dir.join(".claude-plugin").join("plugin.json");
@@ -238,6 +325,7 @@ fn discover_commands() -> Vec<SlashCommand> {
                                         argument_hint: frontmatter.argument_hint,
                                         plugin_name: Some(plugin_name.clone()),
                                         source: SlashCommandSource::Plugin,
+                                        path: Some(skill_md),
                                     });
                                 }
                             }
@@ -298,11 +386,120 @@ fn get_plugin_directories() -> Vec<PathBuf> {
     dirs
 }
 
-pub async fn list_slash_commands() -> Result<Json<ApiResponse<Vec<SlashCommand>>>, ApiError> {
-    let commands = discover_commands();
+pub async fn list_slash_commands(
+    Query(query): Query<SlashCommandsQuery>,
+) -> Result<Json<ApiResponse<Vec<SlashCommand>>>, ApiError> {
+    let commands = discover_commands()
+        .into_iter()
+        .filter(|command| query.matches(command))
+        .collect();
     Ok(Json(ApiResponse::success(commands)))
 }
 
+/// The command with this qualified name; one read from a file wins over a builtin
+fn find_command(commands: Vec<SlashCommand>, qualified_name: &str) -> Option<SlashCommand> {
+    commands
+        .into_iter()
+        .filter(|command| command.qualified_name == qualified_name)
+        .max_by_key(|command| command.path.is_some())
+}
+
+fn command_detail(command: SlashCommand) -> Result<SlashCommandDetail, ApiError> {
+    let body = match &command.path {
+        Some(path) => strip_frontmatter(&std::fs::read_to_string(path)?).to_string(),
+        None => command.description.clone().unwrap_or_default(),
+    };
+    Ok(SlashCommandDetail { command, body })
+}
+
+/// What a command injects, so a client can preview it before running it
+pub async fn get_slash_command(
+    Path(qualified_name): Path<String>,
+) -> Result<Json<ApiResponse<SlashCommandDetail>>, ApiError> {
+    let command = find_command(discover_commands(), &qualified_name)
+        .ok_or_else(|| ApiError::NotFound(format!("Unknown slash command: {}", qualified_name)))?;
+    Ok(Json(ApiResponse::success(command_detail(command)?)))
+}
+
 pub fn router(_deployment: &DeploymentImpl) -> Router<DeploymentImpl> {
-    Router::new().route("/slash-commands", get(list_slash_commands))
+    Router::new()
+        .route("/slash-commands", get(list_slash_commands))
+        .route("/slash-commands/{qualified_name}", get(get_slash_command))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_plugin_command_resolves_by_qualified_name() {
+        let dir = tempfile::tempdir().unwrap();
+        let plugin = dir.path().join("commit-commands");
+        std::fs::create_dir_all(plugin.join(".claude-plugin")).unwrap();
+        std::fs::create_dir_all(plugin.join("commands")).unwrap();
+        std::fs::write(
+            plugin.join(".claude-plugin").join("plugin.json"),
+            r#"{"name": "commit-commands"}"#,
+        )
+        .unwrap();
+        std::fs::write(
+            plugin.join("commands").join("commit.md"),
+            "---\ndescription: Commit changes\nargument-hint: [message]\n---\n\nCommit the staged changes.\n",
+        )
+        .unwrap();
+
+        let commands = collect_commands(None, vec![plugin.clone()]);
+        assert!(find_command(commands.clone(), "commit").is_none());
+        let command = find_command(commands.clone(), "commit-commands:commit").unwrap();
+        let detail = command_detail(command).unwrap();
+        assert_eq!(
+            detail.command.plugin_name.as_deref(),
+            Some("commit-commands")
+        );
+        assert_eq!(detail.command.argument_hint.as_deref(), Some("[message]"));
+        assert_eq!(detail.body, "Commit the staged changes.\n");
+
+        let builtin = find_command(commands, "compact").unwrap();
+        assert_eq!(
+            command_detail(builtin).unwrap().body,
+            "Compact conversation to save context"
+        );
+    }
+
+    #[test]
+    fn test_nested_commands_are_namespaced_by_path() {
+        let tmp = tempfile::tempdir().unwrap();
+        let dir = tmp.path();
+        std::fs::create_dir_all(dir.join("git").join("pr")).unwrap();
+        std::fs::write(dir.join("review.md"), "Review the diff.\n").unwrap();
+        std::fs::write(dir.join("git").join("commit.md"), "Commit.\n").unwrap();
+        std::fs::write(dir.join("git").join("pr").join("open.md"), "Open a PR.\n").unwrap();
+        std::fs::write(dir.join("git").join("notes.txt"), "not a command").unwrap();
+
+        let qualified_names = |plugin_name| {
+            let mut names: Vec<_> = scan_command_dir(dir, plugin_name, SlashCommandSource::Plugin)
+                .into_iter()
+                .map(|command| (command.qualified_name, command.name))
+                .collect();
+            names.sort();
+            names
+        };
+
+        assert_eq!(
+            qualified_names(Some("tools")),
+            [
+                ("tools:git:commit".to_string(), "commit".to_string()),
+                ("tools:git:pr:open".to_string(), "open".to_string()),
+                ("tools:review".to_string(), "review".to_string()),
+            ]
+        );
+        assert_eq!(
+            qualified_names(None),
+            [
+                ("git:commit".to_string(), "commit".to_string()),
+                ("git:pr:open".to_string(), "open".to_string()),
+                ("review".to_string(), "review".to_string()),
+            ]
+        );
+    }
 }
@@ -2,6 +2,7 @@ pub mod codex_setup;
 pub mod cursor_setup;
 pub mod gh_cli_setup;
 pub mod images;
+pub mod mergeability;
 pub mod pr;
 pub mod util;
 
@@ -44,7 +45,7 @@ use git2::BranchType;
 use serde::{Deserialize, Serialize};
 use services::services::{
     container::ContainerService,
-    git::{ConflictOp, GitCliError, GitServiceError},
+    git::{ConflictOp, GitCliError, GitCommitInfo, GitServiceError},
     github::GitHubService,
 };
 use sqlx::Error as SqlxError;
@@ -59,7 +60,8 @@ use crate::{
 
 #[derive(Debug, Deserialize, Serialize, TS)]
 pub struct RebaseTaskAttemptRequest {
-    pub repo_id: Uuid,
+    /// Repository to rebase; every repository of the attempt onto its target branch when omitted
+    pub repo_id: Option<Uuid>,
     pub old_base_branch: Option<String>,
     pub new_base_branch: Option<String>,
 }
@@ -73,7 +75,12 @@ pub struct AbortConflictsRequest {
 #[serde(tag = "type", rename_all = "snake_case")]
 #[ts(tag = "type", rename_all = "snake_case")]
 pub enum GitOperationError {
-    MergeConflicts { message: String, op: ConflictOp },
+    MergeConflicts {
+        message: String,
+        op: ConflictOp,
+        /// Files left unmerged in the worktree
+        conflicted_files: Vec<String>,
+    },
     RebaseInProgress,
 }
 
@@ -131,12 +138,6 @@ pub async fn create_task_attempt(
 ) -> Result<ResponseJson<ApiResponse<Workspace>>, ApiError> {
     let executor_profile_id = payload.executor_profile_id.clone();
 
-    if payload.repos.is_empty() {
-        return Err(ApiError::BadRequest(
-            "At least one repository is required".to_string(),
-        ));
-    }
-
     let pool = &deployment.db().pool;
     let task = Task::find_by_id(&deployment.db().pool, payload.task_id)
         .await?
@@ -147,10 +148,17 @@ pub async fn create_task_attempt(
         .await?
         .ok_or(SqlxError::RowNotFound)?;
 
+    if payload.repos.is_empty() && !project.allow_repoless_tasks {
+        return Err(ApiError::BadRequest(
+            "At least one repository is required".to_string(),
+        ));
+    }
+
+    // Scratch workspaces have no repo directory to default into
     let agent_working_dir = project
         .default_agent_working_dir
         .as_ref()
-        .filter(|dir| !dir.is_empty())
+        .filter(|dir| !dir.is_empty() && !payload.repos.is_empty())
         .cloned();
 
     let attempt_id = Uuid::new_v4();
@@ -694,6 +702,51 @@ pub async fn get_task_attempt_branch_status(
     Ok(ResponseJson(ApiResponse::success(results)))
 }
 
+#[derive(Debug, Clone, Serialize, TS)]
+pub struct RepoCommits {
+    pub repo_id: Uuid,
+    pub repo_name: String,
+    pub target_branch_name: String,
+    /// Commits on the workspace branch since it left the target branch, newest first
+    pub commits: Vec<GitCommitInfo>,
+}
+
+pub async fn get_task_attempt_commits(
+    Extension(workspace): Extension<Workspace>,
+    State(deployment): State<DeploymentImpl>,
+) -> Result<ResponseJson<ApiResponse<Vec<RepoCommits>>>, ApiError> {
+    let pool = &deployment.db().pool;
+
+    let repositories = WorkspaceRepo::find_repos_for_workspace(pool, workspace.id).await?;
+    let workspace_repos = WorkspaceRepo::find_by_workspace_id(pool, workspace.id).await?;
+    let target_branches: HashMap<_, _> = workspace_repos
+        .iter()
+        .map(|wr| (wr.repo_id, wr.target_branch.clone()))
+        .collect();
+
+    let mut results = Vec::with_capacity(repositories.len());
+
+    for repo in repositories {
+        let Some(target_branch) = target_branches.get(&repo.id).cloned() else {
+            continue;
+        };
+
+        let commits =
+            deployment
+                .git()
+                .get_branch_commits(&repo.path, &workspace.branch, &target_branch)?;
+
+        results.push(RepoCommits {
+            repo_id: repo.id,
+            repo_name: repo.name,
+            target_branch_name: target_branch,
+            commits,
+        });
+    }
+
+    Ok(ResponseJson(ApiResponse::success(results)))
+}
+
 #[derive(serde::Deserialize, Debug, TS)]
 pub struct ChangeTargetBranchRequest {
     pub repo_id: Uuid,
@@ -911,80 +964,98 @@ pub async fn rebase_task_attempt(
 ) -> Result<ResponseJson<ApiResponse<(), GitOperationError>>, ApiError> {
     let pool = &deployment.db().pool;
 
-    let workspace_repo =
-        WorkspaceRepo::find_by_workspace_and_repo_id(pool, workspace.id, payload.repo_id)
-            .await?
-            .ok_or(RepoError::NotFound)?;
-
-    let repo = Repo::find_by_id(pool, workspace_repo.repo_id)
-        .await?
-        .ok_or(RepoError::NotFound)?;
-
-    let old_base_branch = payload
-        .old_base_branch
-        .unwrap_or_else(|| workspace_repo.target_branch.clone());
-    let new_base_branch = payload
-        .new_base_branch
-        .unwrap_or_else(|| workspace_repo.target_branch.clone());
-
-    match deployment
-        .git()
-        .check_branch_exists(&repo.path, &new_base_branch)?
-    {
-        true => {
-            WorkspaceRepo::update_target_branch(
-                pool,
-                workspace.id,
-                payload.repo_id,
-                &new_base_branch,
-            )
-            .await?;
-        }
-        false => {
+    let workspace_repos = match payload.repo_id {
+        Some(repo_id) => vec![
+            WorkspaceRepo::find_by_workspace_and_repo_id(pool, workspace.id, repo_id)
+                .await?
+                .ok_or(RepoError::NotFound)?,
+        ],
+        None if payload.old_base_branch.is_some() || payload.new_base_branch.is_some() => {
             return Ok(ResponseJson(ApiResponse::error(
-                format!(
-                    "Branch '{}' does not exist in the repository",
-                    new_base_branch
-                )
-                .as_str(),
+                "Base branches can only be given together with a repo_id",
             )));
         }
-    }
+        None => WorkspaceRepo::find_by_workspace_id(pool, workspace.id).await?,
+    };
 
     let container_ref = deployment
         .container()
         .ensure_container_exists(&workspace)
         .await?;
     let workspace_path = Path::new(&container_ref);
-    let worktree_path = workspace_path.join(&repo.name);
 
-    let result = deployment.git().rebase_branch(
-        &repo.path,
-        &worktree_path,
-        &new_base_branch,
-        &old_base_branch,
-        &workspace.branch.clone(),
-    );
-    if let Err(e) = result {
-        use services::services::git::GitServiceError;
-        return match e {
-            GitServiceError::MergeConflicts(msg) => Ok(ResponseJson(ApiResponse::<
-                (),
-                GitOperationError,
-            >::error_with_data(
-                GitOperationError::MergeConflicts {
-                    message: msg,
-                    op: ConflictOp::Rebase,
-                },
-            ))),
-            GitServiceError::RebaseInProgress => Ok(ResponseJson(ApiResponse::<
-                (),
-                GitOperationError,
-            >::error_with_data(
-                GitOperationError::RebaseInProgress,
-            ))),
-            other => Err(ApiError::GitService(other)),
-        };
+    for workspace_repo in workspace_repos {
+        let repo = Repo::find_by_id(pool, workspace_repo.repo_id)
+            .await?
+            .ok_or(RepoError::NotFound)?;
+
+        let old_base_branch = payload
+            .old_base_branch
+            .clone()
+            .unwrap_or_else(|| workspace_repo.target_branch.clone());
+        let new_base_branch = payload
+            .new_base_branch
+            .clone()
+            .unwrap_or_else(|| workspace_repo.target_branch.clone());
+
+        match deployment
+            .git()
+            .check_branch_exists(&repo.path, &new_base_branch)?
+        {
+            true => {
+                WorkspaceRepo::update_target_branch(
+                    pool,
+                    workspace.id,
+                    workspace_repo.repo_id,
+                    &new_base_branch,
+                )
+                .await?;
+            }
+            false => {
+                return Ok(ResponseJson(ApiResponse::error(
+                    format!(
+                        "Branch '{}' does not exist in the repository",
+                        new_base_branch
+                    )
+                    .as_str(),
+                )));
+            }
+        }
+
+        let worktree_path = workspace_path.join(&repo.name);
+
+        let result = deployment.git().rebase_branch(
+            &repo.path,
+            &worktree_path,
+            &new_base_branch,
+            &old_base_branch,
+            &workspace.branch.clone(),
+        );
+        if let Err(e) = result {
+            use services::services::git::GitServiceError;
+            return match e {
+                GitServiceError::MergeConflicts(msg) => Ok(ResponseJson(ApiResponse::<
+                    (),
+                    GitOperationError,
+                >::error_with_data(
+                    GitOperationError::MergeConflicts {
+                        message: msg,
+                        op: ConflictOp::Rebase,
+                        conflicted_files: deployment
+                            .git()
+                            .get_conflicted_files(&worktree_path)
+                            .unwrap_or_default(),
+                    },
+                ))),
+                GitServiceError::RebaseInProgress => Ok(ResponseJson(ApiResponse::<
+                    (),
+                    GitOperationError,
+                >::error_with_data(
+                    GitOperationError::RebaseInProgress,
+                ))),
+                other => Err(ApiError::GitService(other)),
+            };
+        }
     }
 
     Ok(ResponseJson(ApiResponse::success(())))
@@ -1347,6 +1418,11 @@ pub fn router(deployment: &DeploymentImpl) -> Router<DeploymentImpl> {
         .route("/run-setup-script", post(run_setup_script))
         .route("/run-cleanup-script", post(run_cleanup_script))
         .route("/branch-status", get(get_task_attempt_branch_status))
+        .route(
+            "/mergeability",
+            get(mergeability::get_task_attempt_mergeability),
+        )
+        .route("/commits", get(get_task_attempt_commits))
         .route("/diff/ws", get(stream_task_attempt_diff_ws))
         .route("/merge", post(merge_task_attempt))
         .route("/push", post(push_task_attempt_branch))
@@ -0,0 +1,191 @@
+use std::{
+    collections::HashMap,
+    path::{Path, PathBuf},
+};
+
+use axum::{Extension, extract::State, response::Json as ResponseJson};
+use db::models::{
+    merge::{ChecksStatus, Merge, MergeStatus, PrMerge, PullRequestInfo},
+    workspace::Workspace,
+    workspace_repo::WorkspaceRepo,
+};
+use deployment::Deployment;
+use git2::BranchType;
+use serde::Serialize;
+use services::services::{container::ContainerService, github::GitHubService};
+use ts_rs::TS;
+use utils::response::ApiResponse;
+use uuid::Uuid;
+
+use crate::{DeploymentImpl, error::ApiError};
+
+/// Why a workspace repo isn't ready to merge
+#[derive(Debug, Clone, PartialEq, Serialize, TS)]
+#[serde(tag = "type", rename_all = "snake_case")]
+#[ts(tag = "type", rename_all = "snake_case")]
+pub enum MergeBlocker {
+    /// The branch has nothing the target branch doesn't already have
+    NoCommits,
+    /// The target branch moved on since the branch was last rebased
+    BehindTarget {
+        commits: usize,
+    },
+    UncommittedChanges,
+    /// The worktree is mid-rebase or has unmerged files
+    UnresolvedConflicts,
+    /// GitHub reports the open PR conflicts with its base branch
+    PrHasConflicts,
+    ChecksFailing,
+    ChecksPending,
+}
+
+#[derive(Debug, Clone, Serialize, TS)]
+pub struct RepoMergeability {
+    pub repo_id: Uuid,
+    pub repo_name: String,
+    pub target_branch_name: String,
+    pub commits_ahead: usize,
+    pub commits_behind: usize,
+    /// Latest open PR from this repo, refreshed from GitHub when reachable
+    pub pr: Option<PullRequestInfo>,
+    /// Empty when the repo can be merged as-is
+    pub blockers: Vec<MergeBlocker>,
+}
+
+/// Pre-flight check combining git state and the open PR's GitHub status
+#[derive(Debug, Clone, Serialize, TS)]
+pub struct WorkspaceMergeability {
+    /// True when no repo has blockers
+    pub mergeable: bool,
+    pub repos: Vec<RepoMergeability>,
+}
+
+pub async fn get_task_attempt_mergeability(
+    Extension(workspace): Extension<Workspace>,
+    State(deployment): State<DeploymentImpl>,
+) -> Result<ResponseJson<ApiResponse<WorkspaceMergeability>>, ApiError> {
+    let pool = &deployment.db().pool;
+
+    let repositories = WorkspaceRepo::find_repos_for_workspace(pool, workspace.id).await?;
+    let target_branches: HashMap<_, _> = WorkspaceRepo::find_by_workspace_id(pool, workspace.id)
+        .await?
+        .into_iter()
+        .map(|wr| (wr.repo_id, wr.target_branch))
+        .collect();
+
+    let container_ref = deployment
+        .container()
+        .ensure_container_exists(&workspace)
+        .await?;
+    let workspace_dir = PathBuf::from(&container_ref);
+
+    let mut repos = Vec::with_capacity(repositories.len());
+
+    for repo in repositories {
+        let Some(target_branch) = target_branches.get(&repo.id).cloned() else {
+            continue;
+        };
+        let worktree_path = workspace_dir.join(&repo.name);
+        let git = deployment.git();
+
+        let target_branch_type = git.find_branch_type(&repo.path, &target_branch)?;
+        let (commits_ahead, commits_behind) = match target_branch_type {
+            BranchType::Local => {
+                git.get_branch_status(&repo.path, &workspace.branch, &target_branch)?
+            }
+            BranchType::Remote => {
+                git.get_remote_branch_status(&repo.path, &workspace.branch, Some(&target_branch))?
+            }
+        };
+        let has_uncommitted_changes = git
+            .get_worktree_change_counts(&worktree_path)
+            .is_ok_and(|(uncommitted, _)| uncommitted > 0);
+        let has_worktree_conflicts = git.is_rebase_in_progress(&worktree_path).unwrap_or(false)
+            || !git
+                .get_conflicted_files(&worktree_path)
+                .unwrap_or_default()
+                .is_empty();
+
+        let pr = match Merge::find_by_workspace_and_repo_id(pool, workspace.id, repo.id)
+            .await?
+            .into_iter()
+            .next()
+        {
+            Some(Merge::Pr(PrMerge { pr_info, .. })) if pr_info.status == MergeStatus::Open => {
+                Some(refresh_pr_info(&deployment, &repo.path, pr_info).await)
+            }
+            _ => None,
+        };
+
+        let mut blockers = Vec::new();
+        if commits_ahead == 0 {
+            blockers.push(MergeBlocker::NoCommits);
+        }
+        if commits_behind > 0 {
+            blockers.push(MergeBlocker::BehindTarget {
+                commits: commits_behind,
+            });
+        }
+        if has_uncommitted_changes {
+            blockers.push(MergeBlocker::UncommittedChanges);
+        }
+        if has_worktree_conflicts {
+            blockers.push(MergeBlocker::UnresolvedConflicts);
+        }
+        if let Some(pr) = &pr {
+            if pr.has_conflicts {
+                blockers.push(MergeBlocker::PrHasConflicts);
+            }
+            match pr.checks_status {
+                ChecksStatus::Failure => blockers.push(MergeBlocker::ChecksFailing),
+                ChecksStatus::Pending => blockers.push(MergeBlocker::ChecksPending),
+                ChecksStatus::Success => {}
+            }
+        }
+
+        repos.push(RepoMergeability {
+            repo_id: repo.id,
+            repo_name: repo.name,
+            target_branch_name: target_branch,
+            commits_ahead,
+            commits_behind,
+            pr,
+            blockers,
+        });
+    }
+
+    Ok(ResponseJson(ApiResponse::success(WorkspaceMergeability {
+        mergeable: repos.iter().all(|repo| repo.blockers.is_empty()),
+        repos,
+    })))
+}
+
+/// Current PR status from GitHub, or the status last stored by the PR monitor
+/// when gh isn't available
+async fn refresh_pr_info(
+    deployment: &DeploymentImpl,
+    repo_path: &Path,
+    stored: PullRequestInfo,
+) -> PullRequestInfo {
+    let fetched = async {
+        let repo_info = deployment.git().get_github_repo_info(repo_path)?;
+        let github_service = GitHubService::new()?;
+        github_service
+            .update_pr_status(&repo_info, stored.number)
+            .await
+            .map_err(ApiError::from)
+    }
+    .await;
+
+    match fetched {
+        Ok(pr_info) => pr_info,
+        Err(e) => {
+            tracing::warn!(
+                "Using stored status for PR #{}, GitHub lookup failed: {}",
+                stored.number,
+                e
+            );
+            stored
+        }
+    }
+}
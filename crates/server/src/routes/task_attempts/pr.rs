@@ -8,6 +8,7 @@ use axum::{
 use db::models::{
     execution_process::{ExecutionProcess, ExecutionProcessRunReason},
     merge::{Merge, MergeStatus},
+    project::Project,
     repo::{Repo, RepoError},
     session::{CreateSession, Session},
     task::{Task, TaskStatus},
@@ -415,7 +416,9 @@ pub async fn attach_existing_pr(
         .await?;
 
         // If PR is merged, mark task as done
-        if matches!(pr_info.status, MergeStatus::Merged) {
+        if matches!(pr_info.status, MergeStatus::Merged)
+            && Project::auto_done_on_merge_for_task(pool, task.id).await?
+        {
             Task::update_status(pool, task.id, TaskStatus::Done).await?;
 
             // Try broadcast update to other users in organization
@@ -567,6 +570,9 @@ pub async fn bind_pr_by_number(
         }
     };
 
+    let mark_done = matches!(pr_info.status, MergeStatus::Merged)
+        && Project::auto_done_on_merge_for_task(pool, task.id).await?;
+
     // Use a transaction to ensure atomicity of delete + create
     let mut tx = pool.begin().await?;
 
@@ -600,7 +606,7 @@ pub async fn bind_pr_by_number(
     }
 
     // If PR is merged, mark task as done
-    if matches!(pr_info.status, MergeStatus::Merged) {
+    if mark_done {
         Task::update_status(&mut *tx, task.id, TaskStatus::Done).await?;
     }
 
@@ -608,7 +614,7 @@ pub async fn bind_pr_by_number(
     tx.commit().await?;
 
     // Broadcast update outside of transaction (non-critical)
-    if matches!(pr_info.status, MergeStatus::Merged) {
+    if mark_done {
         if let Ok(publisher) = deployment.share_publisher() {
             if let Err(err) = publisher.update_shared_task_by_id(task.id).await {
                 tracing::warn!(
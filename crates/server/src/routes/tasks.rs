@@ -1,4 +1,8 @@
-use std::path::{Path, PathBuf};
+use std::{
+    collections::HashMap,
+    path::{Path, PathBuf},
+    sync::{Arc, LazyLock, Mutex},
+};
 
 use anyhow;
 use axum::{
@@ -7,11 +11,12 @@ use axum::{
         Query, State,
         ws::{WebSocket, WebSocketUpgrade},
     },
-    http::StatusCode,
+    http::{HeaderName, StatusCode},
     middleware::from_fn_with_state,
     response::{IntoResponse, Json as ResponseJson},
-    routing::{delete, get, post, put},
+    routing::{delete, get, patch, post, put},
 };
+use chrono::{DateTime, NaiveDate, Utc};
 use db::models::{
     execution_process::{
         CreateExecutionProcess, ExecutionProcess, ExecutionProcessRunReason,
@@ -23,7 +28,8 @@ use db::models::{
     project_repo::ProjectRepo,
     repo::Repo,
     session::{CreateSession, Session},
-    task::{CreateTask, Task, TaskStatus, TaskWithAttemptStatus, UpdateTask},
+    task::{CreateTask, Task, TaskBatchError, TaskStatus, TaskWithAttemptStatus, UpdateTask},
+    task_timeline::TaskTimelineEvent,
     workspace::{CreateWorkspace, Workspace},
     workspace_repo::{CreateWorkspaceRepo, WorkspaceRepo},
 };
@@ -32,13 +38,13 @@ use executors::{
     actions::{coding_agent_initial::CodingAgentInitialRequest, ExecutorAction, ExecutorActionType},
     executors::BaseCodingAgent,
     logs::{ActionType, NormalizedEntry, NormalizedEntryType, ToolStatus},
-    profile::ExecutorProfileId,
+    profile::{ExecutorConfigs, ExecutorProfileId},
 };
 use futures_util::{SinkExt, StreamExt, TryStreamExt};
 use serde::{Deserialize, Serialize};
 use services::services::{
     container::ContainerService,
-    linear::{LinearClient, LinearIssueWithState, linear_state_type_to_task_status},
+    linear::{LinearClient, LinearIssueWithState, LinearStateMap},
     share::ShareError,
     workspace_manager::WorkspaceManager,
 };
@@ -48,9 +54,9 @@ use utils::{api::oauth::LoginStatus, log_msg::LogMsg, response::ApiResponse};
 use uuid::Uuid;
 
 use crate::claude_session::{
-    self, ImportFromClaudeSessionRequest, ImportFromClaudeSessionResponse,
-    ImportWithHistoryRequest, ImportWithHistoryResponse, ListClaudeSessionsResponse,
-    PreviewClaudeSessionRequest, PreviewClaudeSessionResponse,
+    self, ImportAllSessionsRequest, ImportAllSessionsResponse, ImportFromClaudeSessionRequest,
+    ImportFromClaudeSessionResponse, ImportWithHistoryRequest, ImportWithHistoryResponse,
+    ListClaudeSessionsResponse, PreviewClaudeSessionRequest, PreviewClaudeSessionResponse,
 };
 
 use crate::{
@@ -58,20 +64,86 @@ use crate::{
     routes::task_attempts::WorkspaceRepoInput,
 };
 
+// Serializes imports of the same Claude session so concurrent requests can't both create it
+static SESSION_IMPORT_LOCKS: LazyLock<Mutex<HashMap<String, Arc<tokio::sync::Mutex<()>>>>> =
+    LazyLock::new(|| Mutex::new(HashMap::new()));
+
+/// A session's entry in `SESSION_IMPORT_LOCKS`, removed again once no import uses it
+struct SessionImportLock {
+    key: String,
+    lock: Arc<tokio::sync::Mutex<()>>,
+}
+
+impl SessionImportLock {
+    fn new(key: String) -> Self {
+        let lock = SESSION_IMPORT_LOCKS
+            .lock()
+            .unwrap()
+            .entry(key.clone())
+            .or_insert_with(|| Arc::new(tokio::sync::Mutex::new(())))
+            .clone();
+        Self { key, lock }
+    }
+}
+
+impl Drop for SessionImportLock {
+    fn drop(&mut self) {
+        let mut locks = SESSION_IMPORT_LOCKS.lock().unwrap();
+        // Only the map and this handle are left, so no other import is waiting
+        if Arc::strong_count(&self.lock) == 2 {
+            locks.remove(&self.key);
+        }
+    }
+}
+
+// Imported session logs are written in rows of about this size instead of one row per session
+const IMPORT_LOG_BATCH_BYTES: usize = 1024 * 1024;
+
+/// Response header of `GET /api/tasks` with the number of tasks matching the
+/// filters, regardless of `limit` and `offset`
+pub const TOTAL_COUNT_HEADER: HeaderName = HeaderName::from_static("x-total-count");
+
 #[derive(Debug, Serialize, Deserialize)]
 pub struct TaskQuery {
     pub project_id: Uuid,
+    /// Page size for `GET /api/tasks`; every task when omitted
+    pub limit: Option<i64>,
+    pub offset: Option<i64>,
+    /// Only list tasks updated at or after this time
+    pub updated_since: Option<DateTime<Utc>>,
 }
 
 pub async fn get_tasks(
     State(deployment): State<DeploymentImpl>,
     Query(query): Query<TaskQuery>,
-) -> Result<ResponseJson<ApiResponse<Vec<TaskWithAttemptStatus>>>, ApiError> {
-    let tasks =
-        Task::find_by_project_id_with_attempt_status(&deployment.db().pool, query.project_id)
-            .await?;
+) -> Result<
+    (
+        [(HeaderName, String); 1],
+        ResponseJson<ApiResponse<Vec<TaskWithAttemptStatus>>>,
+    ),
+    ApiError,
+> {
+    if query.limit.is_some_and(|limit| limit < 0) || query.offset.is_some_and(|offset| offset < 0) {
+        return Err(ApiError::BadRequest(
+            "limit and offset must not be negative".to_string(),
+        ));
+    }
 
-    Ok(ResponseJson(ApiResponse::success(tasks)))
+    let pool = &deployment.db().pool;
+    let tasks = Task::find_by_project_id_with_attempt_status_paged(
+        pool,
+        query.project_id,
+        query.updated_since,
+        query.limit,
+        query.offset.unwrap_or(0),
+    )
+    .await?;
+    let total = Task::count_by_project_id(pool, query.project_id, query.updated_since).await?;
+
+    Ok((
+        [(TOTAL_COUNT_HEADER, total.to_string())],
+        ResponseJson(ApiResponse::success(tasks)),
+    ))
 }
 
 pub async fn stream_tasks_ws(
@@ -172,6 +244,16 @@ pub async fn get_task(
     Ok(ResponseJson(ApiResponse::success(task)))
 }
 
+/// Chronological activity of a task: creation, status changes, attempts, their
+/// execution processes and merges
+pub async fn get_task_timeline(
+    Extension(task): Extension<Task>,
+    State(deployment): State<DeploymentImpl>,
+) -> Result<ResponseJson<ApiResponse<Vec<TaskTimelineEvent>>>, ApiError> {
+    let events = TaskTimelineEvent::find_for_task(&deployment.db().pool, &task).await?;
+    Ok(ResponseJson(ApiResponse::success(events)))
+}
+
 pub async fn create_task(
     State(deployment): State<DeploymentImpl>,
     Json(payload): Json<CreateTask>,
@@ -193,24 +275,67 @@ pub async fn create_task(
     Ok(ResponseJson(ApiResponse::success(task)))
 }
 
+#[derive(Debug, Deserialize, TS)]
+pub struct CreateTasksBatchRequest {
+    pub tasks: Vec<CreateTask>,
+}
+
+/// Create several tasks in one transaction; a failing task rolls back all of them
+pub async fn create_tasks_batch(
+    State(deployment): State<DeploymentImpl>,
+    Json(payload): Json<CreateTasksBatchRequest>,
+) -> Result<ResponseJson<ApiResponse<Vec<Task>>>, ApiError> {
+    tracing::debug!("Creating {} tasks in one batch", payload.tasks.len());
+
+    let tasks = Task::create_many(&deployment.db().pool, &payload.tasks).await?;
+    Ok(ResponseJson(ApiResponse::success(tasks)))
+}
+
+/// A coding agent that runs after the previous one finishes, e.g. implementing
+/// what an earlier agent planned
+#[derive(Debug, Clone, Deserialize, TS)]
+pub struct AgentPipelineStep {
+    pub executor_profile_id: ExecutorProfileId,
+    /// Instructions for this step, added after the task description
+    pub prompt: Option<String>,
+}
+
 #[derive(Debug, Deserialize, TS)]
 pub struct CreateAndStartTaskRequest {
     pub task: CreateTask,
     pub executor_profile_id: ExecutorProfileId,
     pub repos: Vec<WorkspaceRepoInput>,
+    /// Agents that run in order after `executor_profile_id`, in the same workspace
+    #[serde(default)]
+    #[ts(optional)]
+    pub pipeline: Option<Vec<AgentPipelineStep>>,
 }
 
 pub async fn create_task_and_start(
     State(deployment): State<DeploymentImpl>,
     Json(payload): Json<CreateAndStartTaskRequest>,
 ) -> Result<ResponseJson<ApiResponse<TaskWithAttemptStatus>>, ApiError> {
-    if payload.repos.is_empty() {
+    let pool = &deployment.db().pool;
+
+    let project = Project::find_by_id(pool, payload.task.project_id)
+        .await?
+        .ok_or(ProjectError::ProjectNotFound)?;
+
+    if payload.repos.is_empty() && !project.allow_repoless_tasks {
         return Err(ApiError::BadRequest(
             "At least one repository is required".to_string(),
         ));
     }
 
-    let pool = &deployment.db().pool;
+    // A typo'd variant would otherwise only fail at spawn, after the task and workspace exist
+    let profiles = ExecutorConfigs::get_cached();
+    let mut executor_profile_ids = vec![&payload.executor_profile_id];
+    executor_profile_ids.extend(payload.pipeline.iter().flatten().map(|s| &s.executor_profile_id));
+    for executor_profile_id in executor_profile_ids {
+        profiles
+            .validate_profile(executor_profile_id)
+            .map_err(|e| ApiError::BadRequest(e.to_string()))?;
+    }
 
     let task_id = Uuid::new_v4();
     let task = Task::create(pool, &payload.task, task_id).await?;
@@ -219,20 +344,17 @@ pub async fn create_task_and_start(
         TaskImage::associate_many_dedup(pool, task.id, image_ids).await?;
     }
 
-    let project = Project::find_by_id(pool, task.project_id)
-        .await?
-        .ok_or(ProjectError::ProjectNotFound)?;
-
     let attempt_id = Uuid::new_v4();
     let git_branch_name = deployment
         .container()
         .git_branch_from_workspace(&attempt_id, &task.title)
         .await;
 
+    // The default working dir names a repo, which a repo-less scratch dir doesn't have
     let agent_working_dir = project
         .default_agent_working_dir
         .as_ref()
-        .filter(|dir: &&String| !dir.is_empty())
+        .filter(|dir: &&String| !dir.is_empty() && !payload.repos.is_empty())
         .cloned();
 
     let workspace = Workspace::create(
@@ -256,9 +378,31 @@ pub async fn create_task_and_start(
         .collect();
     WorkspaceRepo::create_many(&deployment.db().pool, workspace.id, &workspace_repos).await?;
 
+    let task_prompt = task.to_prompt();
+    let later_agents: Vec<CodingAgentInitialRequest> = payload
+        .pipeline
+        .unwrap_or_default()
+        .into_iter()
+        .map(|step| CodingAgentInitialRequest {
+            prompt: match step.prompt.as_deref().map(str::trim) {
+                Some(prompt) if !prompt.is_empty() => format!("{}\n\n{}", task_prompt, prompt),
+                _ => task_prompt.clone(),
+            },
+            executor_profile_id: step.executor_profile_id,
+            working_dir: workspace
+                .agent_working_dir
+                .clone()
+                .filter(|dir| !dir.is_empty()),
+        })
+        .collect();
+
     let is_attempt_running = deployment
         .container()
-        .start_workspace(&workspace, payload.executor_profile_id.clone())
+        .start_workspace_pipeline(
+            &workspace,
+            payload.executor_profile_id.clone(),
+            later_agents,
+        )
         .await
         .inspect_err(|err| tracing::error!("Failed to start task attempt: {}", err))
         .is_ok();
@@ -288,6 +432,11 @@ pub struct ImportTaskFromPrRequest {
     pub repo_id: Uuid,
     pub pr_number: i64,
     pub executor_profile_id: ExecutorProfileId,
+    /// Branch the attempt targets; defaults to the PR's base branch
+    pub target_branch: Option<String>,
+    /// Add the PR's conversation and review comments to the task description
+    #[serde(default)]
+    pub include_review_comments: bool,
 }
 
 #[derive(Debug, Serialize, Deserialize, TS)]
@@ -305,7 +454,7 @@ pub async fn import_task_from_pr(
 ) -> Result<ResponseJson<ApiResponse<TaskWithAttemptStatus, ImportTaskFromPrError>>, ApiError> {
     use db::models::merge::{Merge, MergeStatus};
     use db::models::task::TaskStatus;
-    use services::services::github::{GitHubService, GitHubServiceError};
+    use services::services::github::{GitHubService, GitHubServiceError, UnifiedPrComment};
 
     let pool = &deployment.db().pool;
 
@@ -375,13 +524,43 @@ pub async fn import_task_from_pr(
         }
     };
 
-    // Create task from PR info
-    let task_id = Uuid::new_v4();
-    let description = if pr_import_info.body.is_empty() {
-        None
+    // The review discussion goes into the description so the agent gets it in its prompt
+    let review_comments = if payload.include_review_comments {
+        match github_service
+            .get_pr_comments(&repo_info, payload.pr_number)
+            .await
+        {
+            Ok(comments) => comments,
+            Err(e) => {
+                tracing::warn!(
+                    "Importing PR #{} without its review comments: {}",
+                    payload.pr_number,
+                    e
+                );
+                Vec::new()
+            }
+        }
     } else {
-        Some(pr_import_info.body.clone())
+        Vec::new()
     };
+
+    // Create task from PR info
+    let task_id = Uuid::new_v4();
+    let mut description = pr_import_info.body.clone();
+    if !review_comments.is_empty() {
+        if !description.is_empty() {
+            description.push_str("\n\n");
+        }
+        description.push_str("## Review comments\n\n");
+        description.push_str(
+            &review_comments
+                .iter()
+                .map(UnifiedPrComment::to_markdown)
+                .collect::<Vec<_>>()
+                .join("\n\n"),
+        );
+    }
+    let description = (!description.is_empty()).then_some(description);
     let task = Task::create(
         pool,
         &CreateTask {
@@ -422,20 +601,28 @@ pub async fn import_task_from_pr(
     )
     .await?;
 
-    // Create workspace repo with main as target branch
+    let target_branch = payload
+        .target_branch
+        .clone()
+        .filter(|branch| !branch.trim().is_empty())
+        .unwrap_or_else(|| pr_import_info.base_ref_name.clone());
+
     let workspace_repos = vec![CreateWorkspaceRepo {
         repo_id: payload.repo_id,
-        target_branch: "main".to_string(),
+        target_branch: target_branch.clone(),
     }];
     WorkspaceRepo::create_many(pool, workspace.id, &workspace_repos).await?;
 
+    let mark_done = matches!(pr_status_info.status, MergeStatus::Merged)
+        && Project::auto_done_on_merge_for_task(pool, task.id).await?;
+
     // Bind PR to workspace
     let mut tx = pool.begin().await?;
     let merge = Merge::create_pr_tx(
         &mut *tx,
         workspace.id,
         payload.repo_id,
-        "main",
+        &target_branch,
         pr_status_info.number,
         &pr_status_info.url,
     )
@@ -453,7 +640,7 @@ pub async fn import_task_from_pr(
     }
 
     // If PR is merged, mark task as done
-    if matches!(pr_status_info.status, MergeStatus::Merged) {
+    if mark_done {
         Task::update_status(&mut *tx, task.id, TaskStatus::Done).await?;
     }
 
@@ -514,8 +701,18 @@ pub async fn update_task(
     let parent_workspace_id = payload
         .parent_workspace_id
         .or(existing_task.parent_workspace_id);
+    let due_date = match payload.due_date.as_deref().map(str::trim) {
+        Some("") => Some(None), // Empty string = clear due date
+        Some(s) => {
+            let date = NaiveDate::parse_from_str(s, "%Y-%m-%d").map_err(|_| {
+                ApiError::BadRequest(format!("Invalid due date '{}', expected YYYY-MM-DD", s))
+            })?;
+            Some(Some(date))
+        }
+        None => None, // Field omitted = keep existing
+    };
 
-    let task = Task::update(
+    let mut task = Task::update(
         &deployment.db().pool,
         existing_task.id,
         existing_task.project_id,
@@ -526,6 +723,11 @@ pub async fn update_task(
     )
     .await?;
 
+    if let Some(due_date) = due_date {
+        Task::update_due_date(&deployment.db().pool, task.id, due_date).await?;
+        task.due_date = due_date;
+    }
+
     if let Some(image_ids) = &payload.image_ids {
         TaskImage::delete_by_task_id(&deployment.db().pool, task.id).await?;
         TaskImage::associate_many_dedup(&deployment.db().pool, task.id, image_ids).await?;
@@ -552,8 +754,10 @@ pub async fn update_task(
             {
                 if let Some(api_key) = project.linear_api_key {
                     let client = LinearClient::new(api_key);
+                    let state_map =
+                        LinearStateMap::from_project_json(project.linear_state_map.as_deref());
                     if let Err(e) = client
-                        .sync_task_status_to_linear(linear_issue_id, &new_status)
+                        .sync_task_status_to_linear(linear_issue_id, &new_status, &state_map)
                         .await
                     {
                         // Log warning but don't fail the local update
@@ -721,6 +925,165 @@ pub async fn share_task(
     })))
 }
 
+#[derive(Debug, Deserialize, TS)]
+pub struct SetTaskTagsRequest {
+    pub tags: Vec<String>,
+}
+
+#[derive(Debug, Deserialize, TS)]
+pub struct UpdateTaskStatusRequest {
+    pub status: TaskStatus,
+}
+
+/// Move a task to another status. A task whose PR has been merged stays Done,
+/// matching how the board derives its column from the PR, unless the project
+/// turned off auto-moving merged tasks to Done.
+pub async fn update_task_status(
+    Extension(existing_task): Extension<Task>,
+    State(deployment): State<DeploymentImpl>,
+    Json(payload): Json<UpdateTaskStatusRequest>,
+) -> Result<ResponseJson<ApiResponse<Task>>, ApiError> {
+    ensure_shared_task_auth(&existing_task, &deployment).await?;
+    let pool = &deployment.db().pool;
+
+    if payload.status != TaskStatus::Done
+        && Project::auto_done_on_merge_for_task(pool, existing_task.id).await?
+        && db::models::merge::Merge::task_has_merged_pr(pool, existing_task.id).await?
+    {
+        return Err(ApiError::Conflict(
+            "Task has a merged pull request and must stay done".to_string(),
+        ));
+    }
+
+    Task::update_status(pool, existing_task.id, payload.status).await?;
+    let task = Task::find_by_id(pool, existing_task.id)
+        .await?
+        .ok_or(SqlxError::RowNotFound)?;
+
+    // If task has been shared, broadcast update
+    if task.shared_task_id.is_some() {
+        let Ok(publisher) = deployment.share_publisher() else {
+            return Err(ShareError::MissingConfig("share publisher unavailable").into());
+        };
+        publisher.update_shared_task(&task).await?;
+    }
+
+    Ok(ResponseJson(ApiResponse::success(task)))
+}
+
+/// Replace the local tags on a task. Tags are trimmed, lowercased and deduplicated.
+pub async fn set_task_tags(
+    Extension(mut task): Extension<Task>,
+    State(deployment): State<DeploymentImpl>,
+    Json(payload): Json<SetTaskTagsRequest>,
+) -> Result<ResponseJson<ApiResponse<Task>>, ApiError> {
+    let mut tags: Vec<String> = payload
+        .tags
+        .iter()
+        .map(|t| t.trim().to_lowercase())
+        .filter(|t| !t.is_empty())
+        .collect();
+    tags.sort();
+    tags.dedup();
+
+    let tags_json = if tags.is_empty() {
+        None
+    } else {
+        Some(serde_json::to_string(&tags).unwrap_or_default())
+    };
+    Task::update_tags(&deployment.db().pool, task.id, tags_json.as_deref()).await?;
+    task.tags = tags_json;
+
+    Ok(ResponseJson(ApiResponse::success(task)))
+}
+
+#[derive(Debug, Deserialize, TS)]
+pub struct MoveTaskRequest {
+    pub project_id: Uuid,
+}
+
+/// Move a task to a different project. Tasks with workspaces are refused since their
+/// worktrees and repos belong to the current project.
+pub async fn move_task(
+    Extension(task): Extension<Task>,
+    State(deployment): State<DeploymentImpl>,
+    Json(payload): Json<MoveTaskRequest>,
+) -> Result<ResponseJson<ApiResponse<Task>>, ApiError> {
+    if payload.project_id == task.project_id {
+        return Ok(ResponseJson(ApiResponse::success(task)));
+    }
+
+    let pool = &deployment.db().pool;
+
+    Project::find_by_id(pool, payload.project_id)
+        .await?
+        .ok_or(ProjectError::ProjectNotFound)?;
+
+    if task.shared_task_id.is_some() {
+        return Err(ApiError::Conflict(
+            "Shared tasks cannot be moved. Unshare the task first.".to_string(),
+        ));
+    }
+
+    let workspaces = Workspace::fetch_all(pool, Some(task.id)).await?;
+    if !workspaces.is_empty() {
+        return Err(ApiError::Conflict(format!(
+            "Task has {} workspace(s) in its current project. Delete them before moving the task.",
+            workspaces.len()
+        )));
+    }
+
+    Task::move_to_project(pool, task.id, payload.project_id).await?;
+    let moved = Task::find_by_id(pool, task.id)
+        .await?
+        .ok_or(ApiError::Database(SqlxError::RowNotFound))?;
+
+    tracing::info!(
+        "Moved task {} from project {} to project {}",
+        task.id,
+        task.project_id,
+        payload.project_id
+    );
+
+    Ok(ResponseJson(ApiResponse::success(moved)))
+}
+
+#[derive(Debug, Deserialize, TS)]
+pub struct SetTaskSetupProfileRequest {
+    /// Name of a setup profile defined on the project; None clears the selection
+    pub setup_profile: Option<String>,
+}
+
+/// Pin a task to one of its project's setup profiles, run before the coding agent on start.
+pub async fn set_task_setup_profile(
+    Extension(mut task): Extension<Task>,
+    State(deployment): State<DeploymentImpl>,
+    Json(payload): Json<SetTaskSetupProfileRequest>,
+) -> Result<ResponseJson<ApiResponse<Task>>, ApiError> {
+    let setup_profile = payload
+        .setup_profile
+        .map(|name| name.trim().to_string())
+        .filter(|name| !name.is_empty());
+
+    if let Some(name) = &setup_profile {
+        let project = task
+            .parent_project(&deployment.db().pool)
+            .await?
+            .ok_or(ProjectError::ProjectNotFound)?;
+        if project.setup_profile_script(name).is_none() {
+            return Err(ApiError::BadRequest(format!(
+                "Setup profile '{}' is not defined for this project",
+                name
+            )));
+        }
+    }
+
+    Task::update_setup_profile(&deployment.db().pool, task.id, setup_profile.as_deref()).await?;
+    task.setup_profile = setup_profile;
+
+    Ok(ResponseJson(ApiResponse::success(task)))
+}
+
 /// Response type for Linear issue state fetch
 #[derive(Debug, Serialize, Deserialize, TS)]
 pub struct LinearIssueStateResponse {
@@ -753,7 +1116,8 @@ pub async fn get_linear_issue_state(
         .map_err(|e| ApiError::BadRequest(format!("Failed to fetch Linear issue: {}", e)))?
         .ok_or_else(|| ApiError::BadRequest("Linear issue not found".to_string()))?;
 
-    let mapped_status = linear_state_type_to_task_status(&issue.state.state_type);
+    let mapped_status = LinearStateMap::from_project_json(project.linear_state_map.as_deref())
+        .task_status(&issue.state);
 
     Ok(ResponseJson(ApiResponse::success(
         LinearIssueStateResponse {
@@ -821,7 +1185,8 @@ pub async fn pull_from_linear(
             ApiError::BadRequest("Linear issue not found".to_string())
         })?;
 
-    let new_status = linear_state_type_to_task_status(&issue.state.state_type);
+    let new_status = LinearStateMap::from_project_json(project.linear_state_map.as_deref())
+        .task_status(&issue.state);
 
     // Update local task with Linear data
     let mut task = Task::update(
@@ -882,9 +1247,10 @@ pub async fn push_to_linear(
         ApiError::BadRequest("Project does not have a Linear API key configured".to_string())
     })?;
 
+    let state_map = LinearStateMap::from_project_json(project.linear_state_map.as_deref());
     let client = LinearClient::new(api_key);
     client
-        .sync_task_status_to_linear(linear_issue_id, &task.status)
+        .sync_task_status_to_linear(linear_issue_id, &task.status, &state_map)
         .await
         .map_err(|e| ApiError::BadRequest(format!("Failed to push to Linear: {}", e)))?;
 
@@ -927,10 +1293,13 @@ pub async fn preview_claude_session(
         )));
     }
 
-    let items = claude_session::parse_session_file(path)
+    let parser = payload.format.unwrap_or_default().parser();
+    let items = parser
+        .parse_session_file(path)
         .map_err(|e| ApiError::BadRequest(format!("Failed to parse session: {}", e)))?;
 
-    let session_summary = claude_session::get_session_summary(path)
+    let session_summary = parser
+        .get_session_summary(path)
         .map_err(|e| ApiError::BadRequest(format!("Failed to get session summary: {}", e)))?;
 
     Ok(ResponseJson(ApiResponse::success(
@@ -954,7 +1323,10 @@ pub async fn import_from_claude_session(
         )));
     }
 
-    let items = claude_session::parse_session_file(path)
+    let format = payload.format.unwrap_or_default();
+    let items = format
+        .parser()
+        .parse_session_file(path)
         .map_err(|e| ApiError::BadRequest(format!("Failed to parse session: {}", e)))?;
 
     let default_status = payload
@@ -976,34 +1348,40 @@ pub async fn import_from_claude_session(
         .filter(|item| selected_ids.contains(&item.id))
         .collect();
 
-    let mut imported_count = 0;
-    let mut errors = Vec::new();
-
-    for item in items_to_import {
-        let task_id = Uuid::new_v4();
-        let create_task = CreateTask {
+    let tasks: Vec<CreateTask> = items_to_import
+        .iter()
+        .map(|item| CreateTask {
             project_id: query.project_id,
-            title: item.title,
-            description: item.description,
+            title: item.title.clone(),
+            description: item.description.clone(),
             status: Some(default_status.clone()),
             parent_workspace_id: None,
             image_ids: None,
             shared_task_id: None,
             linear_issue_id: None,
             linear_url: None,
-        };
+        })
+        .collect();
 
-        match Task::create(&deployment.db().pool, &create_task, task_id).await {
-            Ok(_) => {
-                imported_count += 1;
-                tracing::info!("Imported task {} from Claude session", task_id);
-            }
-            Err(e) => {
-                errors.push(format!("Failed to import task '{}': {}", item.id, e));
-                tracing::error!("Failed to import task from Claude session: {}", e);
-            }
+    // All selected items are imported together or not at all
+    let result = Task::create_many(&deployment.db().pool, &tasks).await;
+    let (imported_count, errors) = match result {
+        Ok(created) => {
+            tracing::info!("Imported {} tasks from {:?} session", created.len(), format);
+            (created.len(), Vec::new())
         }
-    }
+        Err(TaskBatchError::TaskFailed { index, source }) => {
+            tracing::error!("Failed to import task from {:?} session: {}", format, source);
+            (
+                0,
+                vec![format!(
+                    "Failed to import task '{}': {}; no tasks were imported",
+                    items_to_import[index].id, source
+                )],
+            )
+        }
+        Err(TaskBatchError::Database(e)) => return Err(ApiError::Database(e)),
+    };
 
     Ok(ResponseJson(ApiResponse::success(
         ImportFromClaudeSessionResponse {
@@ -1020,6 +1398,78 @@ pub async fn import_with_history(
     Query(query): Query<TaskQuery>,
     Json(payload): Json<ImportWithHistoryRequest>,
 ) -> Result<ResponseJson<ApiResponse<ImportWithHistoryResponse>>, ApiError> {
+    let response = import_session_with_history(&deployment, query.project_id, &payload).await?;
+    Ok(ResponseJson(ApiResponse::success(response)))
+}
+
+/// Import every Claude Code session of a project that hasn't been imported into it yet.
+/// Sessions are matched by their Claude session id and imported oldest first.
+pub async fn import_all_sessions(
+    State(deployment): State<DeploymentImpl>,
+    Query(query): Query<TaskQuery>,
+    Json(payload): Json<ImportAllSessionsRequest>,
+) -> Result<ResponseJson<ApiResponse<ImportAllSessionsResponse>>, ApiError> {
+    let sessions = claude_session::list_project_sessions(&payload.project_path)
+        .map_err(|e| ApiError::BadRequest(format!("Failed to list sessions: {}", e)))?;
+    let already_imported: std::collections::HashSet<String> =
+        Session::find_imported_claude_session_ids(&deployment.db().pool, query.project_id)
+            .await?
+            .into_iter()
+            .collect();
+
+    let mut imported_count = 0;
+    let mut skipped_count = 0;
+    let mut errors = Vec::new();
+
+    for session in sessions {
+        if already_imported.contains(&session.session_id) {
+            skipped_count += 1;
+            continue;
+        }
+
+        let request = ImportWithHistoryRequest {
+            session_path: session.path,
+            task_title: None,
+            default_status: payload.default_status.clone(),
+            from_uuid: None,
+            to_uuid: None,
+        };
+        match import_session_with_history(&deployment, query.project_id, &request).await {
+            // Imported by a concurrent request since the list above was read
+            Ok(response) if response.already_imported => skipped_count += 1,
+            Ok(_) => imported_count += 1,
+            Err(e) => {
+                tracing::error!("Failed to import Claude session {}: {}", session.session_id, e);
+                errors.push(format!(
+                    "Failed to import session '{}': {}",
+                    session.session_id, e
+                ));
+            }
+        }
+    }
+
+    tracing::info!(
+        "Batch import for project {}: {} imported, {} already imported, {} failed",
+        query.project_id,
+        imported_count,
+        skipped_count,
+        errors.len()
+    );
+
+    Ok(ResponseJson(ApiResponse::success(
+        ImportAllSessionsResponse {
+            imported_count,
+            skipped_count,
+            errors,
+        },
+    )))
+}
+
+async fn import_session_with_history(
+    deployment: &DeploymentImpl,
+    project_id: Uuid,
+    payload: &ImportWithHistoryRequest,
+) -> Result<ImportWithHistoryResponse, ApiError> {
     let path = Path::new(&payload.session_path);
     if !path.exists() {
         return Err(ApiError::BadRequest(format!(
@@ -1034,6 +1484,30 @@ pub async fn import_with_history(
     let metadata = claude_session::parse_session_metadata(path)
         .map_err(|e| ApiError::BadRequest(format!("Failed to parse session metadata: {}", e)))?;
 
+    // Hold the session's lock until its ids are written, so a repeated request
+    // (e.g. a double-clicked import) finds this import instead of duplicating it
+    let lock = SessionImportLock::new(
+        metadata
+            .session_id
+            .clone()
+            .unwrap_or_else(|| payload.session_path.clone()),
+    );
+    let _guard = lock.lock.lock().await;
+
+    // Ranged imports intentionally create a task per slice of the same session
+    if payload.from_uuid.is_none()
+        && payload.to_uuid.is_none()
+        && let Some(claude_session_id) = &metadata.session_id
+        && let Some(existing) = Session::find_imported(pool, project_id, claude_session_id).await?
+    {
+        tracing::info!(
+            "Claude session '{}' is already imported as session {}",
+            claude_session_id,
+            existing.id
+        );
+        return existing_import(pool, existing).await;
+    }
+
     // Get task title from the request or use slug/session_id
     let (title, description) = if let Some(custom_title) = &payload.task_title {
         (custom_title.clone(), None)
@@ -1061,9 +1535,20 @@ pub async fn import_with_history(
         })
         .unwrap_or(TaskStatus::Todo);
 
-    // Extract raw session logs (1:1 parity with Claude Code JSONL)
-    let log_lines = claude_session::extract_raw_session_logs(path)
-        .map_err(|e| ApiError::BadRequest(format!("Failed to extract logs: {}", e)))?;
+    // Stream raw session logs (1:1 parity with Claude Code JSONL)
+    let session_logs = || {
+        let lines = claude_session::extract_raw_session_logs(path)
+            .map_err(|e| ApiError::BadRequest(format!("Failed to extract logs: {}", e)))?;
+        Ok::<_, ApiError>(claude_session::slice_session_logs_by_uuid(
+            lines,
+            payload.from_uuid.as_deref(),
+            payload.to_uuid.as_deref(),
+        ))
+    };
+    // Check the range before creating anything; the import reads the file again
+    for line in session_logs()? {
+        line.map_err(|e| ApiError::BadRequest(e.to_string()))?;
+    }
 
     let branch = metadata
         .git_branch
@@ -1080,7 +1565,7 @@ pub async fn import_with_history(
     let task = Task::create(
         pool,
         &CreateTask {
-            project_id: query.project_id,
+            project_id,
             title,
             description,
             status: Some(status),
@@ -1130,7 +1615,7 @@ pub async fn import_with_history(
         // Skip workspace repo creation - we're using existing worktree as-is
     } else {
         // Case 2: Not a worktree - add repos so the system creates one
-        let project_repos = ProjectRepo::find_by_project_id(pool, query.project_id).await?;
+        let project_repos = ProjectRepo::find_by_project_id(pool, project_id).await?;
         if !project_repos.is_empty() {
             let workspace_repos: Vec<CreateWorkspaceRepo> = project_repos
                 .iter()
@@ -1155,6 +1640,9 @@ pub async fn import_with_history(
     )
     .await
     .map_err(|e| ApiError::BadRequest(format!("Failed to create session: {}", e)))?;
+    if let Some(claude_session_id) = &metadata.session_id {
+        Session::set_claude_session_id(pool, session.id, claude_session_id).await?;
+    }
 
     // 4. Create ExecutionProcess (marked as Completed)
     let execution_process_id = Uuid::new_v4();
@@ -1181,6 +1669,13 @@ pub async fn import_with_history(
         &[], // No repo states for imported sessions
     )
     .await?;
+    ExecutionProcess::set_import_source(
+        pool,
+        execution_process.id,
+        &payload.session_path,
+        metadata.slug.as_deref(),
+    )
+    .await?;
 
     // Mark the execution process as completed
     ExecutionProcess::update_completion(
@@ -1191,18 +1686,26 @@ pub async fn import_with_history(
     )
     .await?;
 
-    // 5. Import log lines as a single batch (one row in the database)
-    let log_lines_count = log_lines.len();
-    let jsonl_lines: Vec<String> = log_lines
-        .into_iter()
-        .map(|line| {
-            let log_msg = LogMsg::Stdout(line);
-            serde_json::to_string(&log_msg)
-        })
-        .collect::<Result<Vec<_>, _>>()
-        .map_err(|e| ApiError::BadRequest(format!("Failed to serialize log: {}", e)))?;
-
-    ExecutionProcessLogs::append_log_lines_batch(pool, execution_process.id, &jsonl_lines).await?;
+    // 5. Import log lines in bounded batches (one row in the database per batch)
+    let mut log_lines_count = 0;
+    let mut batch = Vec::new();
+    let mut batch_bytes = 0;
+    for line in session_logs()? {
+        let line = line.map_err(|e| ApiError::BadRequest(e.to_string()))?;
+        let log_msg = serde_json::to_string(&LogMsg::Stdout(line))
+            .map_err(|e| ApiError::BadRequest(format!("Failed to serialize log: {}", e)))?;
+        log_lines_count += 1;
+        batch_bytes += log_msg.len();
+        batch.push(log_msg);
+
+        if batch_bytes >= IMPORT_LOG_BATCH_BYTES {
+            ExecutionProcessLogs::append_log_lines_batch(pool, execution_process.id, &batch)
+                .await?;
+            batch.clear();
+            batch_bytes = 0;
+        }
+    }
+    ExecutionProcessLogs::append_log_lines_batch(pool, execution_process.id, &batch).await?;
 
     // 6. Import plan file if it exists
     if let Ok(Some(plan_path)) = claude_session::get_plan_path(path) {
@@ -1242,31 +1745,64 @@ pub async fn import_with_history(
         log_lines_count
     );
 
-    Ok(ResponseJson(ApiResponse::success(ImportWithHistoryResponse {
+    Ok(ImportWithHistoryResponse {
         task_id: task.id.to_string(),
         workspace_id: workspace.id.to_string(),
         session_id: session.id.to_string(),
         execution_process_id: execution_process.id.to_string(),
         log_lines_imported: log_lines_count,
-    })))
+        already_imported: false,
+    })
+}
+
+/// Response for a session that an earlier import already created
+async fn existing_import(
+    pool: &sqlx::SqlitePool,
+    session: Session,
+) -> Result<ImportWithHistoryResponse, ApiError> {
+    let workspace = Workspace::find_by_id(pool, session.workspace_id)
+        .await?
+        .ok_or(SqlxError::RowNotFound)?;
+    let execution_process = ExecutionProcess::find_latest_by_session_and_run_reason(
+        pool,
+        session.id,
+        &ExecutionProcessRunReason::ImportedSession,
+    )
+    .await?
+    .ok_or(SqlxError::RowNotFound)?;
+
+    Ok(ImportWithHistoryResponse {
+        task_id: workspace.task_id.to_string(),
+        workspace_id: workspace.id.to_string(),
+        session_id: session.id.to_string(),
+        execution_process_id: execution_process.id.to_string(),
+        log_lines_imported: 0,
+        already_imported: true,
+    })
 }
 
 pub fn router(deployment: &DeploymentImpl) -> Router<DeploymentImpl> {
     let task_actions_router = Router::new()
         .route("/", put(update_task))
         .route("/", delete(delete_task))
+        .route("/status", patch(update_task_status))
         .route("/share", post(share_task))
+        .route("/tags", put(set_task_tags))
+        .route("/setup-profile", put(set_task_setup_profile))
+        .route("/move", post(move_task))
         .route("/linear", get(get_linear_issue_state))
         .route("/linear/pull", post(pull_from_linear))
         .route("/linear/push", post(push_to_linear));
 
     let task_id_router = Router::new()
         .route("/", get(get_task))
+        .route("/timeline", get(get_task_timeline))
         .merge(task_actions_router)
         .layer(from_fn_with_state(deployment.clone(), load_task_middleware));
 
     let inner = Router::new()
         .route("/", get(get_tasks).post(create_task))
+        .route("/batch", post(create_tasks_batch))
         .route("/stream/ws", get(stream_tasks_ws))
         .route("/create-and-start", post(create_task_and_start))
         .route("/import-from-pr", post(import_task_from_pr))
@@ -1277,6 +1813,7 @@ pub fn router(deployment: &DeploymentImpl) -> Router<DeploymentImpl> {
             post(import_from_claude_session),
         )
         .route("/import-with-history", post(import_with_history))
+        .route("/import-all-sessions", post(import_all_sessions))
         .nest("/{task_id}", task_id_router);
 
     // Top-level tasks routes (not scoped to a project)
@@ -1287,3 +1824,28 @@ pub fn router(deployment: &DeploymentImpl) -> Router<DeploymentImpl> {
     Router::new()
         .nest("/tasks", inner.merge(all_tasks_router))
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn has_import_lock(key: &str) -> bool {
+        SESSION_IMPORT_LOCKS.lock().unwrap().contains_key(key)
+    }
+
+    #[tokio::test]
+    async fn test_session_import_lock_is_dropped_after_last_import() {
+        let key = "session-import-lock-test";
+        let first = SessionImportLock::new(key.to_string());
+        let guard = first.lock.lock().await;
+
+        let second = SessionImportLock::new(key.to_string());
+        drop(guard);
+        drop(first);
+        // The waiting import still needs the entry
+        assert!(has_import_lock(key));
+
+        drop(second);
+        assert!(!has_import_lock(key));
+    }
+}
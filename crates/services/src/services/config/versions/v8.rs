@@ -17,6 +17,13 @@ fn default_pr_auto_description_enabled() -> bool {
     true
 }
 
+/// Port the server binds when `PORT` isn't set and the port is free
+const DEFAULT_PREFERRED_PORT: u16 = 6770;
+
+fn default_preferred_port() -> Option<u16> {
+    Some(DEFAULT_PREFERRED_PORT)
+}
+
 #[derive(Clone, Debug, Serialize, Deserialize, TS)]
 pub struct Config {
     pub config_version: String,
@@ -40,6 +47,10 @@ pub struct Config {
     pub pr_auto_description_enabled: bool,
     #[serde(default)]
     pub pr_auto_description_prompt: Option<String>,
+    /// Port the server tries first when `PORT` isn't set, falling back to a random
+    /// free port when it's taken. None always uses a random port.
+    #[serde(default = "default_preferred_port")]
+    pub preferred_port: Option<u16>,
 }
 
 impl Config {
@@ -61,6 +72,7 @@ impl Config {
             showcases: old_config.showcases,
             pr_auto_description_enabled: true,
             pr_auto_description_prompt: None,
+            preferred_port: default_preferred_port(),
         }
     }
 
@@ -110,6 +122,7 @@ impl Default for Config {
             showcases: ShowcaseState::default(),
             pr_auto_description_enabled: true,
             pr_auto_description_prompt: None,
+            preferred_port: default_preferred_port(),
         }
     }
 }
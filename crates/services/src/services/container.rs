@@ -413,6 +413,16 @@ pub trait ContainerService {
                                 },
                                 linear_api_key: None,
                                 linear_assignee_id: None,
+                                git_author_name: project.git_author_name.clone(),
+                                git_author_email: project.git_author_email.clone(),
+                                commit_message_template: project.commit_message_template.clone(),
+                                linear_auto_sync: None,
+                                linear_state_map: project.linear_state_map.clone(),
+                                setup_profiles: project.setup_profiles.clone(),
+                                webhook_url: project.webhook_url.clone(),
+                                webhook_secret: None,
+                                allow_repoless_tasks: None,
+                                auto_done_on_merge: None,
                             },
                         )
                         .await?;
@@ -530,6 +540,46 @@ pub trait ContainerService {
         chained
     }
 
+    /// Script of the task's selected setup profile, if it has one that the
+    /// project defines
+    fn setup_profile_script(project: &Project, task: &Task) -> Option<String> {
+        let profile = task.setup_profile.as_deref()?;
+        let script = project.setup_profile_script(profile);
+        if script.is_none() {
+            tracing::warn!(
+                "Setup profile '{}' for task {} not found in project {}",
+                profile,
+                task.id,
+                project.id
+            );
+        }
+        script
+    }
+
+    /// Prepend the task's selected setup profile (e.g. a toolchain setup) to the chain
+    fn prepend_setup_profile(
+        project: &Project,
+        task: &Task,
+        next_action: ExecutorAction,
+    ) -> ExecutorAction {
+        match Self::setup_profile_script(project, task) {
+            Some(script) => Self::setup_profile_action(script, next_action),
+            None => next_action,
+        }
+    }
+
+    fn setup_profile_action(script: String, next_action: ExecutorAction) -> ExecutorAction {
+        ExecutorAction::new(
+            ExecutorActionType::ScriptRequest(ScriptRequest {
+                script,
+                language: ScriptRequestLanguage::Bash,
+                context: ScriptContext::SetupScript,
+                working_dir: None,
+            }),
+            Some(Box::new(next_action)),
+        )
+    }
+
     async fn try_stop(&self, workspace: &Workspace, include_dev_server: bool) {
         // stop execution processes for this workspace's sessions
         let sessions = match Session::find_by_workspace_id(&self.db().pool, workspace.id).await {
@@ -907,6 +957,18 @@ pub trait ContainerService {
         &self,
         workspace: &Workspace,
         executor_profile_id: ExecutorProfileId,
+    ) -> Result<ExecutionProcess, ContainerError> {
+        self.start_workspace_pipeline(workspace, executor_profile_id, Vec::new())
+            .await
+    }
+
+    /// Start the workspace's first coding agent, then run `later_agents` one after
+    /// another in the same workspace before the cleanup scripts
+    async fn start_workspace_pipeline(
+        &self,
+        workspace: &Workspace,
+        executor_profile_id: ExecutorProfileId,
+        later_agents: Vec<CodingAgentInitialRequest>,
     ) -> Result<ExecutionProcess, ContainerError> {
         // Create container
         self.create(workspace).await?;
@@ -923,8 +985,15 @@ pub trait ContainerService {
             .await?
             .ok_or(SqlxError::RowNotFound)?;
 
-        let project_repos =
-            ProjectRepo::find_by_project_id_with_names(&self.db().pool, project.id).await?;
+        // Repo setup and cleanup scripts have nothing to run against in a scratch dir
+        let project_repos = if WorkspaceRepo::find_by_workspace_id(&self.db().pool, workspace.id)
+            .await?
+            .is_empty()
+        {
+            Vec::new()
+        } else {
+            ProjectRepo::find_by_project_id_with_names(&self.db().pool, project.id).await?
+        };
 
         let workspace = Workspace::find_by_id(&self.db().pool, workspace.id)
             .await?
@@ -958,17 +1027,27 @@ pub trait ContainerService {
             .filter(|dir| !dir.is_empty())
             .cloned();
 
-        let coding_action = ExecutorAction::new(
+        let mut coding_action = ExecutorAction::new(
             ExecutorActionType::CodingAgentInitialRequest(CodingAgentInitialRequest {
                 prompt,
                 executor_profile_id: executor_profile_id.clone(),
                 working_dir,
             }),
-            cleanup_action.map(Box::new),
+            None,
         );
+        for agent in later_agents {
+            coding_action = coding_action.append_action(ExecutorAction::new(
+                ExecutorActionType::CodingAgentInitialRequest(agent),
+                None,
+            ));
+        }
+        if let Some(cleanup_action) = cleanup_action {
+            coding_action = coding_action.append_action(cleanup_action);
+        }
 
         let execution_process = if all_parallel {
             // All parallel: start each setup independently, then start coding agent
+            // (preceded by the task's setup profile, if any)
             for repo in &repos_with_setup {
                 if let Some(action) = Self::setup_action_for_repo(repo)
                     && let Err(e) = self
@@ -983,16 +1062,22 @@ pub trait ContainerService {
                     tracing::warn!(?e, "Failed to start setup script in parallel mode");
                 }
             }
-            self.start_execution(
-                &workspace,
-                &session,
-                &coding_action,
-                &ExecutionProcessRunReason::CodingAgent,
-            )
-            .await?
+            let (main_action, run_reason) = match Self::setup_profile_script(&project, &task) {
+                Some(script) => (
+                    Self::setup_profile_action(script, coding_action),
+                    ExecutionProcessRunReason::SetupScript,
+                ),
+                None => (coding_action, ExecutionProcessRunReason::CodingAgent),
+            };
+            self.start_execution(&workspace, &session, &main_action, &run_reason)
+                .await?
         } else {
-            // Any sequential: chain ALL setups → coding agent via next_action
-            let main_action = Self::build_sequential_setup_chain(&repos_with_setup, coding_action);
+            // Any sequential: setup profile → ALL setups → coding agent via next_action
+            let main_action = Self::prepend_setup_profile(
+                &project,
+                &task,
+                Self::build_sequential_setup_chain(&repos_with_setup, coding_action),
+            );
             self.start_execution(
                 &workspace,
                 &session,
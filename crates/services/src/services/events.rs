@@ -3,8 +3,13 @@ use std::{str::FromStr, sync::Arc};
 use db::{
     DBService,
     models::{
-        execution_process::ExecutionProcess, merge::Merge, project::Project, scratch::Scratch,
-        session::Session, task::Task, workspace::Workspace,
+        execution_process::ExecutionProcess,
+        merge::Merge,
+        project::Project,
+        scratch::Scratch,
+        session::Session,
+        task::{Task, TaskStatus},
+        workspace::Workspace,
     },
 };
 use serde_json::json;
@@ -13,6 +18,8 @@ use tokio::sync::RwLock;
 use utils::msg_store::MsgStore;
 use uuid::Uuid;
 
+use crate::services::task_webhook;
+
 #[path = "events/patches.rs"]
 pub mod patches;
 #[path = "events/streams.rs"]
@@ -109,7 +116,32 @@ impl EventService {
                 let runtime_handle = tokio::runtime::Handle::current();
                 handle.set_preupdate_hook({
                     let msg_store_for_preupdate = msg_store_for_hook.clone();
+                    let db_for_preupdate = db_for_hook.clone();
+                    let runtime_handle = runtime_handle.clone();
                     move |preupdate: sqlx::sqlite::PreupdateHookResult<'_>| {
+                        if preupdate.operation == SqliteOperation::Update {
+                            // Only the preupdate hook sees the old status, which the
+                            // project's task status webhook reports
+                            if preupdate.table == "tasks"
+                                && let Ok(id_val) = preupdate.get_old_column_value(0)
+                                && let Ok(task_id) = <Uuid as Decode<Sqlite>>::decode(id_val)
+                                && let Ok(old_val) = preupdate.get_old_column_value(4)
+                                && let Ok(old_status) =
+                                    <TaskStatus as Decode<Sqlite>>::decode(old_val)
+                                && let Ok(new_val) = preupdate.get_new_column_value(4)
+                                && let Ok(new_status) =
+                                    <TaskStatus as Decode<Sqlite>>::decode(new_val)
+                                && old_status != new_status
+                            {
+                                runtime_handle.spawn(task_webhook::notify_status_change(
+                                    db_for_preupdate.clone(),
+                                    task_id,
+                                    old_status,
+                                    new_status,
+                                ));
+                            }
+                            return;
+                        }
                         if preupdate.operation != SqliteOperation::Delete {
                             return;
                         }
@@ -336,17 +368,20 @@ impl EventService {
                                             task.project_id,
                                         )
                                         .await
-                                        && let Some(task_with_status) =
-                                            task_list.into_iter().find(|t| t.id == task.id)
                                     {
-                                        let patch = match hook.operation {
-                                            SqliteOperation::Insert => {
-                                                task_patch::add(&task_with_status)
-                                            }
-                                            SqliteOperation::Update => {
-                                                task_patch::replace(&task_with_status)
-                                            }
-                                            _ => task_patch::replace(&task_with_status), // fallback
+                                        let patch = match task_list
+                                            .into_iter()
+                                            .find(|t| t.id == task.id)
+                                        {
+                                            Some(task_with_status) => match hook.operation {
+                                                SqliteOperation::Insert => {
+                                                    task_patch::add(&task_with_status)
+                                                }
+                                                // Updates and anything else replace the entry
+                                                _ => task_patch::replace(&task_with_status),
+                                            },
+                                            // Archived tasks drop out of the board list
+                                            None => task_patch::remove(task.id),
                                         };
                                         msg_store_for_hook.push_patch(patch);
                                         return;
@@ -205,6 +205,15 @@ impl FileSearchCache {
             return Ok(());
         }
 
+        self.warm_and_watch(repo_paths).await?;
+
+        info!("File search cache warming completed");
+        Ok(())
+    }
+
+    /// Queue a rebuild for the given repositories and keep them fresh with watchers,
+    /// e.g. after a project is opened that wasn't among the most active at startup
+    pub async fn warm_and_watch(&self, repo_paths: Vec<PathBuf>) -> Result<(), String> {
         info!(
             "Warming cache for {} repositories: {:?}",
             repo_paths.len(),
@@ -216,14 +225,12 @@ impl FileSearchCache {
             .await
             .map_err(|e| format!("Failed to warm cache: {e}"))?;
 
-        // Setup watchers for active projects
+        // Setup watchers so later changes keep the cache fresh
         for repo_path in &repo_paths {
             if let Err(e) = self.setup_watcher(repo_path).await {
                 warn!("Failed to setup watcher for {:?}: {}", repo_path, e);
             }
         }
-
-        info!("File search cache warming completed");
         Ok(())
     }
 
@@ -46,6 +46,8 @@ pub struct GitService {}
 // Max inline diff size for UI (in bytes). Files larger than this will have
 // their contents omitted from the diff stream to avoid UI crashes.
 const MAX_INLINE_DIFF_BYTES: usize = 2 * 1024 * 1024; // ~2MB
+/// Repo git config naming the remote PRs go to, for fork workflows
+pub const PR_REMOTE_CONFIG_KEY: &str = "vibe-kanban.prRemote";
 
 #[derive(Debug, Clone, Serialize, Deserialize, TS, PartialEq, Eq)]
 #[serde(rename_all = "snake_case")]
@@ -66,6 +68,17 @@ pub struct GitBranch {
     pub last_commit_date: DateTime<Utc>,
 }
 
+/// A commit as listed in a branch log
+#[derive(Debug, Clone, Serialize, TS)]
+pub struct GitCommitInfo {
+    pub sha: String,
+    pub message: String,
+    pub author_name: String,
+    pub author_email: String,
+    #[ts(type = "Date")]
+    pub time: DateTime<Utc>,
+}
+
 #[derive(Debug, Clone)]
 pub struct HeadInfo {
     pub branch: String,
@@ -916,6 +929,44 @@ impl GitService {
         )
     }
 
+    /// Commits reachable from `branch_name` but not from `base_branch_name`, newest first
+    pub fn get_branch_commits(
+        &self,
+        repo_path: &Path,
+        branch_name: &str,
+        base_branch_name: &str,
+    ) -> Result<Vec<GitCommitInfo>, GitServiceError> {
+        let repo = self.open_repo(repo_path)?;
+        let branch_oid = Self::find_branch(&repo, branch_name)?
+            .get()
+            .peel_to_commit()?
+            .id();
+        let base_oid = Self::find_branch(&repo, base_branch_name)?
+            .get()
+            .peel_to_commit()?
+            .id();
+
+        let mut revwalk = repo.revwalk()?;
+        revwalk.set_sorting(Sort::TOPOLOGICAL | Sort::TIME)?;
+        revwalk.push(branch_oid)?;
+        revwalk.hide(base_oid)?;
+
+        revwalk
+            .map(|oid| -> Result<GitCommitInfo, GitServiceError> {
+                let commit = repo.find_commit(oid?)?;
+                let author = commit.author();
+                Ok(GitCommitInfo {
+                    sha: commit.id().to_string(),
+                    message: commit.message().unwrap_or_default().trim_end().to_string(),
+                    author_name: author.name().unwrap_or_default().to_string(),
+                    author_email: author.email().unwrap_or_default().to_string(),
+                    time: DateTime::from_timestamp(commit.time().seconds(), 0)
+                        .unwrap_or_else(Utc::now),
+                })
+            })
+            .collect()
+    }
+
     pub fn get_base_commit(
         &self,
         repo_path: &Path,
@@ -1600,9 +1651,43 @@ impl GitService {
         &self,
         repo_path: &Path,
     ) -> Result<GitHubRepoInfo, GitServiceError> {
+        if !repo_path.exists() {
+            // Project directory was moved or deleted; libgit2's error here is opaque
+            return Err(GitServiceError::InvalidRepository(format!(
+                "Repository path no longer exists: {}",
+                repo_path.display()
+            )));
+        }
         let repo = self.open_repo(repo_path)?;
-        let remote_name = self.default_remote_name(&repo);
-        let remote = repo.find_remote(&remote_name).map_err(|_| {
+        let pr_remote = self.pr_remote_name(&repo);
+        let mut info = Self::github_repo_info_for_remote(&repo, &pr_remote)?;
+
+        // Branches are pushed to the default remote; in a fork that's not where PRs go
+        let push_remote = self.default_remote_name(&repo);
+        if push_remote != pr_remote
+            && let Ok(push_info) = Self::github_repo_info_for_remote(&repo, &push_remote)
+        {
+            info.head_owner = push_info.owner;
+        }
+        Ok(info)
+    }
+
+    /// Remote PRs are opened on and looked up in: `vibe-kanban.prRemote` from the
+    /// repo's git config (e.g. `upstream` in a fork), otherwise the default remote
+    pub fn pr_remote_name(&self, repo: &Repository) -> String {
+        repo.config()
+            .and_then(|cfg| cfg.get_string(PR_REMOTE_CONFIG_KEY))
+            .ok()
+            .map(|name| name.trim().to_string())
+            .filter(|name| !name.is_empty())
+            .unwrap_or_else(|| self.default_remote_name(repo))
+    }
+
+    fn github_repo_info_for_remote(
+        repo: &Repository,
+        remote_name: &str,
+    ) -> Result<GitHubRepoInfo, GitServiceError> {
+        let remote = repo.find_remote(remote_name).map_err(|_| {
             GitServiceError::InvalidRepository(format!("No '{remote_name}' remote found"))
         })?;
 
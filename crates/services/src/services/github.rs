@@ -50,6 +50,39 @@ impl UnifiedPrComment {
             UnifiedPrComment::Review { created_at, .. } => *created_at,
         }
     }
+
+    /// The comment as a quoted markdown block, headed by its author and, for
+    /// review comments, the file and line it was left on
+    pub fn to_markdown(&self) -> String {
+        let (author, location, body) = match self {
+            UnifiedPrComment::General { author, body, .. } => (author, None, body),
+            UnifiedPrComment::Review {
+                author,
+                path,
+                line,
+                body,
+                ..
+            } => {
+                let location = match line {
+                    Some(line) => format!("{}:{}", path, line),
+                    None => path.clone(),
+                };
+                (author, Some(location), body)
+            }
+        };
+
+        let heading = match location {
+            Some(location) => format!("**@{}** on `{}`:", author, location),
+            None => format!("**@{}**:", author),
+        };
+        let quoted = body
+            .trim()
+            .lines()
+            .map(|line| format!("> {}", line).trim_end().to_string())
+            .collect::<Vec<_>>()
+            .join("\n");
+        format!("{}\n{}", heading, quoted)
+    }
 }
 
 #[derive(Debug, Error)]
@@ -106,6 +139,9 @@ impl GitHubServiceError {
 pub struct GitHubRepoInfo {
     pub owner: String,
     pub repo_name: String,
+    /// Owner of the repo that branches are pushed to. In a fork workflow it differs
+    /// from `owner`, which is where PRs are opened.
+    pub head_owner: String,
 }
 impl GitHubRepoInfo {
     pub fn from_remote_url(remote_url: &str) -> Result<Self, GitHubServiceError> {
@@ -139,7 +175,21 @@ impl GitHubRepoInfo {
             .as_str()
             .to_string();
 
-        Ok(Self { owner, repo_name })
+        Ok(Self {
+            head_owner: owner.clone(),
+            owner,
+            repo_name,
+        })
+    }
+
+    /// `branch` as gh expects it for `--head`: prefixed with the fork owner when
+    /// PRs are opened on another repo
+    pub fn head_ref(&self, branch: &str) -> String {
+        if self.head_owner == self.owner {
+            branch.to_string()
+        } else {
+            format!("{}:{}", self.head_owner, branch)
+        }
     }
 }
 
@@ -287,13 +337,14 @@ impl GitHubService {
         (|| async {
             let owner = repo_info.owner.clone();
             let repo = repo_info.repo_name.clone();
+            let head_owner = repo_info.head_owner.clone();
             let branch = branch_name.to_string();
             let cli = self.gh_cli.clone();
             let prs = task::spawn_blocking({
                 let owner = owner.clone();
                 let repo = repo.clone();
                 let branch = branch.clone();
-                move || cli.list_prs_for_branch(&owner, &repo, &branch)
+                move || cli.list_prs_for_branch(&owner, &repo, &head_owner, &branch)
             })
             .await
             .map_err(|err| {
@@ -495,3 +546,41 @@ impl GitHubService {
             .map_err(GitHubServiceError::from)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn formats_comments_as_quoted_markdown() {
+        let created_at = DateTime::<Utc>::default();
+        let general = UnifiedPrComment::General {
+            id: "IC_1".to_string(),
+            author: "alice".to_string(),
+            author_association: "MEMBER".to_string(),
+            body: "Looks good overall.\n\nOne nit below.".to_string(),
+            created_at,
+            url: String::new(),
+        };
+        assert_eq!(
+            general.to_markdown(),
+            "**@alice**:\n> Looks good overall.\n>\n> One nit below."
+        );
+
+        let review = UnifiedPrComment::Review {
+            id: 2,
+            author: "bob".to_string(),
+            author_association: "CONTRIBUTOR".to_string(),
+            body: "Rename this".to_string(),
+            created_at,
+            url: String::new(),
+            path: "src/lib.rs".to_string(),
+            line: Some(12),
+            diff_hunk: String::new(),
+        };
+        assert_eq!(
+            review.to_markdown(),
+            "**@bob** on `src/lib.rs:12`:\n> Rename this"
+        );
+    }
+}
@@ -76,6 +76,8 @@ pub struct PrListItem {
     pub author: PrListAuthor,
     pub created_at: DateTime<Utc>,
     pub head_ref_name: String,
+    /// Branch the PR merges into
+    pub base_ref_name: String,
 }
 
 /// PR details for importing as a task
@@ -87,6 +89,7 @@ pub struct PrImportInfo {
     pub title: String,
     pub body: String,
     pub head_ref_name: String,
+    pub base_ref_name: String,
 }
 
 /// High-level errors originating from the GitHub CLI.
@@ -176,7 +179,7 @@ impl GhCli {
             repo_info.owner, repo_info.repo_name
         )));
         args.push(OsString::from("--head"));
-        args.push(OsString::from(&request.head_branch));
+        args.push(OsString::from(repo_info.head_ref(&request.head_branch)));
         args.push(OsString::from("--base"));
         args.push(OsString::from(&request.base_branch));
         args.push(OsString::from("--title"));
@@ -236,18 +239,20 @@ impl GhCli {
             "--repo",
             &format!("{owner}/{repo}"),
             "--json",
-            "number,url,title,body,headRefName",
+            "number,url,title,body,headRefName,baseRefName",
         ])?;
         serde_json::from_str(&raw).map_err(|e| {
             GhCliError::UnexpectedOutput(format!("Failed to parse PR import info: {e}"))
         })
     }
 
-    /// List pull requests for a branch (includes closed/merged).
+    /// List pull requests for a branch (includes closed/merged). `head_owner` owns
+    /// the branch, which is a fork's owner when PRs go to an upstream repo.
     pub fn list_prs_for_branch(
         &self,
         owner: &str,
         repo: &str,
+        head_owner: &str,
         branch: &str,
     ) -> Result<Vec<PullRequestInfo>, GhCliError> {
         let raw = self.run([
@@ -258,7 +263,7 @@ impl GhCli {
             "--state",
             "all",
             "--head",
-            &format!("{owner}:{branch}"),
+            &format!("{head_owner}:{branch}"),
             "--json",
             "number,url,state,mergedAt,mergeCommit,isDraft,reviewDecision,statusCheckRollup",
         ])?;
@@ -316,7 +321,7 @@ impl GhCli {
             "--limit".to_string(),
             limit.to_string(),
             "--json".to_string(),
-            "number,url,state,title,body,author,createdAt,headRefName".to_string(),
+            "number,url,state,title,body,author,createdAt,headRefName,baseRefName".to_string(),
         ];
 
         if let Some(query) = search {
@@ -0,0 +1,70 @@
+use std::{path::Path, sync::LazyLock, time::Duration};
+
+use reqwest::{Certificate, Client};
+
+/// Path to a PEM bundle of extra root certificates, e.g. a corporate proxy's CA
+pub const CA_CERT_ENV: &str = "VIBE_CA_CERT";
+
+const CONNECT_TIMEOUT: Duration = Duration::from_secs(15);
+
+static HTTP_CLIENT: LazyLock<Client> = LazyLock::new(build_client);
+
+/// Client for outbound API calls (Linear, ...), built once and shared.
+///
+/// Proxies come from `HTTPS_PROXY`/`HTTP_PROXY`/`ALL_PROXY` and `NO_PROXY`, which
+/// reqwest reads from the environment; extra roots come from [`CA_CERT_ENV`].
+pub fn shared_http_client() -> Client {
+    HTTP_CLIENT.clone()
+}
+
+fn build_client() -> Client {
+    let mut builder = Client::builder().connect_timeout(CONNECT_TIMEOUT);
+
+    if let Some(path) = std::env::var_os(CA_CERT_ENV) {
+        match load_root_certificates(Path::new(&path)) {
+            Ok(certs) => {
+                tracing::info!(
+                    "Trusting {} extra root certificate(s) from {:?}",
+                    certs.len(),
+                    path
+                );
+                for cert in certs {
+                    builder = builder.add_root_certificate(cert);
+                }
+            }
+            Err(e) => tracing::warn!("Ignoring {}={:?}: {}", CA_CERT_ENV, path, e),
+        }
+    }
+
+    builder.build().unwrap_or_else(|e| {
+        tracing::warn!(
+            "Failed to build configured HTTP client, using defaults: {}",
+            e
+        );
+        Client::new()
+    })
+}
+
+fn load_root_certificates(path: &Path) -> Result<Vec<Certificate>, String> {
+    let pem = std::fs::read(path).map_err(|e| e.to_string())?;
+    let certs = Certificate::from_pem_bundle(&pem).map_err(|e| e.to_string())?;
+    if certs.is_empty() {
+        return Err("no certificates found".to_string());
+    }
+    Ok(certs)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rejects_missing_or_empty_bundles() {
+        let dir = tempfile::tempdir().unwrap();
+        assert!(load_root_certificates(&dir.path().join("missing.pem")).is_err());
+
+        let empty = dir.path().join("empty.pem");
+        std::fs::write(&empty, "not a certificate\n").unwrap();
+        assert!(load_root_certificates(&empty).is_err());
+    }
+}
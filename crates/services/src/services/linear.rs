@@ -1,21 +1,49 @@
+use std::{collections::HashMap, time::Duration};
+
+use backon::{ExponentialBuilder, Retryable};
 use db::models::task::TaskStatus;
-use reqwest::Client;
+use reqwest::{Client, StatusCode, header::RETRY_AFTER};
 use serde::{Deserialize, Serialize};
 use thiserror::Error;
 use ts_rs::TS;
 
+use crate::services::http_client::shared_http_client;
+
 #[derive(Debug, Error)]
 pub enum LinearError {
     #[error("network error: {0}")]
     Transport(#[from] reqwest::Error),
     #[error("Linear API error: {0}")]
     Api(String),
+    /// Rate limited or a server-side failure, worth trying again
+    #[error("Linear API error: HTTP {status} - {body}")]
+    Unavailable {
+        status: u16,
+        body: String,
+        retry_after: Option<Duration>,
+    },
     #[error("missing API key")]
     MissingApiKey,
     #[error("state not found: {0}")]
     StateNotFound(String),
 }
 
+impl LinearError {
+    fn is_retryable(&self) -> bool {
+        matches!(self, LinearError::Unavailable { .. })
+    }
+
+    /// Delay the server asked for, capped so a sync never stalls for long
+    fn retry_after(&self) -> Option<Duration> {
+        match self {
+            LinearError::Unavailable { retry_after, .. } => {
+                retry_after.map(|d| d.min(LinearClient::MAX_RETRY_DELAY))
+            }
+            _ => None,
+        }
+    }
+}
+
 /// A label attached to a Linear issue
 #[derive(Debug, Clone, Serialize, Deserialize, TS)]
 pub struct LinearLabel {
@@ -146,6 +174,16 @@ struct IssuesData {
     issues: IssueConnection,
 }
 
+#[derive(Debug, Deserialize)]
+struct IssuesWithStateData {
+    issues: IssueWithStateConnection,
+}
+
+#[derive(Debug, Deserialize)]
+struct IssueWithStateConnection {
+    nodes: Vec<LinearIssueWithState>,
+}
+
 #[derive(Debug, Deserialize)]
 struct IssueConnection {
     nodes: Vec<LinearIssue>,
@@ -212,18 +250,108 @@ pub fn linear_state_type_to_task_status(state_type: &str) -> TaskStatus {
     }
 }
 
+/// Per-project overrides keyed by Linear state name (e.g. "In Review") or state type
+/// (e.g. "started"). Names take precedence over types; keys match case-insensitively.
+#[derive(Debug, Clone, Default)]
+pub struct LinearStateMap(HashMap<String, TaskStatus>);
+
+impl LinearStateMap {
+    /// Parse the JSON stored on the project. Invalid JSON yields an empty map.
+    pub fn from_project_json(json: Option<&str>) -> Self {
+        json.and_then(|j| {
+            Self::parse(j)
+                .map_err(|e| tracing::warn!("Ignoring invalid Linear state map: {}", e))
+                .ok()
+        })
+        .unwrap_or_default()
+    }
+
+    pub fn parse(json: &str) -> Result<Self, serde_json::Error> {
+        let raw: HashMap<String, TaskStatus> = serde_json::from_str(json)?;
+        Ok(Self(
+            raw.into_iter()
+                .map(|(k, v)| (k.trim().to_lowercase(), v))
+                .collect(),
+        ))
+    }
+
+    /// Override for this state, by name first, then by type
+    pub fn lookup(&self, state: &WorkflowState) -> Option<TaskStatus> {
+        self.0
+            .get(&state.name.to_lowercase())
+            .or_else(|| self.0.get(&state.state_type.to_lowercase()))
+            .cloned()
+    }
+
+    /// Map a Linear state to a local status, consulting overrides before the built-in mapping
+    pub fn task_status(&self, state: &WorkflowState) -> TaskStatus {
+        self.lookup(state)
+            .unwrap_or_else(|| linear_state_type_to_task_status(&state.state_type))
+    }
+
+    /// Pick the workflow state to push for a local status: a state whose name is mapped
+    /// to this status wins, otherwise the first state of the default type that isn't
+    /// mapped by name to another status. Without that check InProgress could land in
+    /// a custom "In Review" state just because Linear lists it first among `started`.
+    pub fn workflow_state_for<'a>(
+        &self,
+        states: &'a [WorkflowState],
+        status: &TaskStatus,
+    ) -> Option<&'a WorkflowState> {
+        let mapped_status = |s: &WorkflowState| self.0.get(&s.name.to_lowercase());
+        let target_type = task_status_to_linear_state_type(status);
+        let of_type = || states.iter().filter(move |s| s.state_type == target_type);
+
+        states
+            .iter()
+            .find(|s| mapped_status(*s) == Some(status))
+            .or_else(|| of_type().find(|s| mapped_status(*s).is_none()))
+            .or_else(|| of_type().next())
+    }
+}
+
+/// Env var holding the Linear API key for a project without a stored key.
+/// E.g., "reflex" -> "REFLEX_LINEAR_API_KEY"
+pub fn linear_api_key_env_var(project_name: &str) -> String {
+    let normalized: String = project_name
+        .chars()
+        .map(|c| {
+            if c.is_alphanumeric() {
+                c.to_ascii_uppercase()
+            } else {
+                '_'
+            }
+        })
+        .collect();
+    format!("{}_LINEAR_API_KEY", normalized)
+}
+
+/// Read the Linear API key from the env var derived from a project name
+pub fn linear_api_key_from_env(project_name: &str) -> Option<String> {
+    std::env::var(linear_api_key_env_var(project_name)).ok()
+}
+
 pub struct LinearClient {
     http: Client,
     api_key: String,
+    api_url: String,
+    /// Retries for rate limiting and 5xx responses; 0 fails on the first one
+    max_retries: usize,
 }
 
 impl LinearClient {
     const API_URL: &'static str = "https://api.linear.app/graphql";
+    /// Linear caps connection page size, so id batches are chunked to this size
+    const ISSUE_BATCH_SIZE: usize = 50;
+    const DEFAULT_MAX_RETRIES: usize = 3;
+    const MAX_RETRY_DELAY: Duration = Duration::from_secs(30);
 
     pub fn new(api_key: String) -> Self {
         Self {
-            http: Client::new(),
+            http: shared_http_client(),
             api_key,
+            api_url: Self::API_URL.to_string(),
+            max_retries: Self::DEFAULT_MAX_RETRIES,
         }
     }
 
@@ -238,24 +366,25 @@ impl LinearClient {
             None => serde_json::json!({ "query": query }),
         };
 
-        let response = self
-            .http
-            .post(Self::API_URL)
-            .header("Authorization", &self.api_key)
-            .json(&body)
-            .send()
+        let response = (|| self.send_query(&body))
+            .retry(
+                ExponentialBuilder::default()
+                    .with_min_delay(Duration::from_secs(1))
+                    .with_max_delay(Self::MAX_RETRY_DELAY)
+                    .with_max_times(self.max_retries)
+                    .with_jitter(),
+            )
+            .when(LinearError::is_retryable)
+            .adjust(|e: &LinearError, backoff| backoff.map(|d| e.retry_after().unwrap_or(d)))
+            .notify(|e: &LinearError, dur: Duration| {
+                tracing::warn!(
+                    "Linear request failed, retrying after {:.2}s: {}",
+                    dur.as_secs_f64(),
+                    e
+                );
+            })
             .await?;
 
-        let status = response.status();
-        if !status.is_success() {
-            let text = response.text().await.unwrap_or_default();
-            return Err(LinearError::Api(format!(
-                "HTTP {} - {}",
-                status.as_u16(),
-                text.chars().take(200).collect::<String>()
-            )));
-        }
-
         let result: GraphQLResponse<T> = response.json().await?;
 
         if let Some(errors) = result.errors {
@@ -272,6 +401,46 @@ impl LinearClient {
             .ok_or_else(|| LinearError::Api("No data in response".to_string()))
     }
 
+    /// POST the query once; 429 and 500/502/503 come back as `LinearError::Unavailable`
+    async fn send_query(&self, body: &serde_json::Value) -> Result<reqwest::Response, LinearError> {
+        let response = self
+            .http
+            .post(&self.api_url)
+            .header("Authorization", &self.api_key)
+            .json(body)
+            .send()
+            .await?;
+
+        let status = response.status();
+        if status.is_success() {
+            return Ok(response);
+        }
+
+        let retry_after = response
+            .headers()
+            .get(RETRY_AFTER)
+            .and_then(|v| v.to_str().ok())
+            .and_then(|v| v.trim().parse::<u64>().ok())
+            .map(Duration::from_secs);
+        let text = response.text().await.unwrap_or_default();
+        let body = text.chars().take(200).collect::<String>();
+        match status {
+            StatusCode::TOO_MANY_REQUESTS
+            | StatusCode::INTERNAL_SERVER_ERROR
+            | StatusCode::BAD_GATEWAY
+            | StatusCode::SERVICE_UNAVAILABLE => Err(LinearError::Unavailable {
+                status: status.as_u16(),
+                body,
+                retry_after,
+            }),
+            _ => Err(LinearError::Api(format!(
+                "HTTP {} - {}",
+                status.as_u16(),
+                body
+            ))),
+        }
+    }
+
     /// Fetch all issues assigned to the current user (viewer) that are in "backlog" state
     pub async fn fetch_backlog_issues(&self) -> Result<Vec<LinearIssue>, LinearError> {
         let query = r#"
@@ -413,14 +582,15 @@ impl LinearClient {
         &self,
         issue_id: &str,
         status: &TaskStatus,
+        state_map: &LinearStateMap,
     ) -> Result<(), LinearError> {
         let states = self.fetch_workflow_states().await?;
-        let target_type = task_status_to_linear_state_type(status);
 
-        let state = states
-            .iter()
-            .find(|s| s.state_type == target_type)
-            .ok_or_else(|| LinearError::StateNotFound(target_type.to_string()))?;
+        let state = state_map
+            .workflow_state_for(&states, status)
+            .ok_or_else(|| {
+                LinearError::StateNotFound(task_status_to_linear_state_type(status).to_string())
+            })?;
 
         self.update_issue_state(issue_id, &state.id).await
     }
@@ -461,12 +631,72 @@ impl LinearClient {
         let data: IssueData = self.execute_query(query, Some(variables)).await?;
         Ok(data.issue)
     }
+
+    /// Fetch many issues by ID with their current state, batched into as few queries as possible.
+    /// Issues that no longer exist are simply missing from the result.
+    pub async fn fetch_issues_by_ids(
+        &self,
+        issue_ids: &[String],
+    ) -> Result<Vec<LinearIssueWithState>, LinearError> {
+        let query = r#"
+            query($ids: [ID!], $first: Int) {
+                issues(filter: { id: { in: $ids } }, first: $first) {
+                    nodes {
+                        id
+                        title
+                        description
+                        url
+                        state {
+                            id
+                            name
+                            type
+                        }
+                        labels {
+                            nodes {
+                                id
+                                name
+                                color
+                            }
+                        }
+                        assignee {
+                            id
+                            name
+                        }
+                    }
+                }
+            }
+        "#;
+
+        let mut issues = Vec::with_capacity(issue_ids.len());
+        for chunk in issue_ids.chunks(Self::ISSUE_BATCH_SIZE) {
+            let variables = serde_json::json!({ "ids": chunk, "first": chunk.len() });
+            let data: IssuesWithStateData = self.execute_query(query, Some(variables)).await?;
+            issues.extend(data.issues.nodes);
+        }
+        Ok(issues)
+    }
 }
 
 #[cfg(test)]
 mod tests {
+    use std::sync::{
+        Arc,
+        atomic::{AtomicUsize, Ordering},
+    };
+
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
     use super::*;
 
+    #[test]
+    fn test_linear_api_key_env_var() {
+        assert_eq!(linear_api_key_env_var("reflex"), "REFLEX_LINEAR_API_KEY");
+        assert_eq!(
+            linear_api_key_env_var("my-app v2"),
+            "MY_APP_V2_LINEAR_API_KEY"
+        );
+    }
+
     #[test]
     fn test_deserialize_viewer_response() {
         let json = r#"{
@@ -516,6 +746,117 @@ mod tests {
         assert_eq!(states[3].state_type, "completed");
     }
 
+    #[test]
+    fn test_deserialize_issues_with_state_response() {
+        let json = r#"{
+            "data": {
+                "issues": {
+                    "nodes": [
+                        {
+                            "id": "abc123",
+                            "title": "Test Issue",
+                            "description": null,
+                            "url": "https://linear.app/team/issue/ABC-123",
+                            "state": { "id": "state3", "name": "In Progress", "type": "started" },
+                            "labels": { "nodes": [] },
+                            "assignee": null
+                        }
+                    ]
+                }
+            }
+        }"#;
+
+        let response: GraphQLResponse<IssuesWithStateData> = serde_json::from_str(json).unwrap();
+        let issues = response.data.unwrap().issues.nodes;
+        assert_eq!(issues.len(), 1);
+        assert_eq!(issues[0].id, "abc123");
+        assert_eq!(issues[0].state.state_type, "started");
+        assert!(issues[0].labels.is_empty());
+    }
+
+    fn state(name: &str, state_type: &str) -> WorkflowState {
+        WorkflowState {
+            id: name.to_lowercase().replace(' ', "-"),
+            name: name.to_string(),
+            state_type: state_type.to_string(),
+        }
+    }
+
+    #[test]
+    fn test_linear_state_map_overrides() {
+        let map = LinearStateMap::parse(r#"{"In Review": "inreview", "Started": "todo"}"#).unwrap();
+
+        // Name match beats type match
+        assert_eq!(
+            map.task_status(&state("In Review", "started")),
+            TaskStatus::InReview
+        );
+        // Type match (case-insensitive) when the name isn't mapped
+        assert_eq!(
+            map.task_status(&state("In Progress", "started")),
+            TaskStatus::Todo
+        );
+        // Built-in fallback
+        assert_eq!(
+            map.task_status(&state("Done", "completed")),
+            TaskStatus::Done
+        );
+
+        let states = vec![
+            state("In Progress", "started"),
+            state("In Review", "started"),
+            state("Done", "completed"),
+        ];
+        assert_eq!(
+            map.workflow_state_for(&states, &TaskStatus::InReview)
+                .map(|s| s.name.as_str()),
+            Some("In Review")
+        );
+        assert_eq!(
+            map.workflow_state_for(&states, &TaskStatus::InProgress)
+                .map(|s| s.name.as_str()),
+            Some("In Progress")
+        );
+    }
+
+    #[test]
+    fn test_workflow_state_for_skips_states_mapped_elsewhere() {
+        let map = LinearStateMap::parse(r#"{"In Review": "inreview"}"#).unwrap();
+        // Linear lists the custom review state before the default started state
+        let states = vec![
+            state("In Review", "started"),
+            state("In Progress", "started"),
+        ];
+        assert_eq!(
+            map.workflow_state_for(&states, &TaskStatus::InProgress)
+                .map(|s| s.name.as_str()),
+            Some("In Progress")
+        );
+        assert_eq!(
+            map.workflow_state_for(&states, &TaskStatus::InReview)
+                .map(|s| s.name.as_str()),
+            Some("In Review")
+        );
+
+        // With nothing else of the type left, the mapped state is still better than none
+        let states = vec![state("In Review", "started")];
+        assert_eq!(
+            map.workflow_state_for(&states, &TaskStatus::InProgress)
+                .map(|s| s.name.as_str()),
+            Some("In Review")
+        );
+    }
+
+    #[test]
+    fn test_linear_state_map_invalid_json() {
+        assert!(LinearStateMap::parse(r#"{"In Review": "nope"}"#).is_err());
+        assert!(
+            LinearStateMap::from_project_json(Some("not json"))
+                .lookup(&state("In Review", "started"))
+                .is_none()
+        );
+    }
+
     #[test]
     fn test_status_mapping() {
         assert_eq!(
@@ -552,4 +893,101 @@ mod tests {
             TaskStatus::Done
         ));
     }
+
+    /// Serve the canned responses on a local port, one per connection, counting requests
+    async fn mock_linear(responses: Vec<String>) -> (String, Arc<AtomicUsize>) {
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!("http://{}/graphql", listener.local_addr().unwrap());
+        let served = Arc::new(AtomicUsize::new(0));
+        let counter = served.clone();
+        tokio::spawn(async move {
+            for response in responses {
+                let (mut stream, _) = listener.accept().await.unwrap();
+                // Read the headers and the JSON body before answering
+                let mut request = Vec::new();
+                let mut chunk = [0u8; 4096];
+                loop {
+                    let n = stream.read(&mut chunk).await.unwrap();
+                    if n == 0 {
+                        break;
+                    }
+                    request.extend_from_slice(&chunk[..n]);
+                    let text = String::from_utf8_lossy(&request);
+                    let Some(end) = text.find("\r\n\r\n") else {
+                        continue;
+                    };
+                    let content_length = text[..end]
+                        .lines()
+                        .filter_map(|line| line.split_once(':'))
+                        .find(|(name, _)| name.eq_ignore_ascii_case("content-length"))
+                        .and_then(|(_, value)| value.trim().parse::<usize>().ok())
+                        .unwrap_or(0);
+                    if request.len() >= end + 4 + content_length {
+                        break;
+                    }
+                }
+                counter.fetch_add(1, Ordering::SeqCst);
+                stream.write_all(response.as_bytes()).await.unwrap();
+                stream.shutdown().await.unwrap();
+            }
+        });
+        (url, served)
+    }
+
+    fn http_response(status: &str, extra_headers: &str, body: &str) -> String {
+        format!(
+            "HTTP/1.1 {status}\r\nContent-Type: application/json\r\nContent-Length: {}\r\n\
+             Connection: close\r\n{extra_headers}\r\n{body}",
+            body.len()
+        )
+    }
+
+    fn test_client(api_url: String, max_retries: usize) -> LinearClient {
+        LinearClient {
+            http: Client::builder().no_proxy().build().unwrap(),
+            api_key: "test-key".to_string(),
+            api_url,
+            max_retries,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_execute_query_retries_rate_limit() {
+        let (url, served) = mock_linear(vec![
+            http_response("429 Too Many Requests", "Retry-After: 0\r\n", "{}"),
+            http_response("200 OK", "", r#"{"data":{"ok":true}}"#),
+        ])
+        .await;
+
+        let data: serde_json::Value = test_client(url, 3)
+            .execute_query("query { ok }", None)
+            .await
+            .unwrap();
+        assert_eq!(data, serde_json::json!({ "ok": true }));
+        assert_eq!(served.load(Ordering::SeqCst), 2);
+    }
+
+    #[tokio::test]
+    async fn test_execute_query_fails_fast_without_retries_or_on_client_errors() {
+        let (url, served) =
+            mock_linear(vec![http_response("503 Service Unavailable", "", "down")]).await;
+        let err = test_client(url, 0)
+            .execute_query::<serde_json::Value>("query { ok }", None)
+            .await
+            .unwrap_err();
+        assert!(matches!(err, LinearError::Unavailable { status: 503, .. }));
+        assert_eq!(served.load(Ordering::SeqCst), 1);
+
+        let (url, served) = mock_linear(vec![
+            http_response("401 Unauthorized", "", "bad key"),
+            http_response("200 OK", "", r#"{"data":{"ok":true}}"#),
+        ])
+        .await;
+        let err = test_client(url, 3)
+            .execute_query::<serde_json::Value>("query { ok }", None)
+            .await
+            .unwrap_err();
+        assert_eq!(err.to_string(), "Linear API error: HTTP 401 - bad key");
+        assert_eq!(served.load(Ordering::SeqCst), 1);
+    }
 }
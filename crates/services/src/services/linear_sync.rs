@@ -0,0 +1,165 @@
+use std::{collections::HashMap, time::Duration};
+
+use db::{
+    DBService,
+    models::{project::Project, task::Task},
+};
+use sqlx::error::Error as SqlxError;
+use thiserror::Error;
+use tokio::time::interval;
+use tracing::{debug, error, info};
+
+use crate::services::{
+    linear::{
+        LinearClient, LinearError, LinearStateMap, WorkflowState, linear_api_key_from_env,
+        task_status_to_linear_state_type,
+    },
+    share::SharePublisher,
+};
+
+#[derive(Debug, Error)]
+enum LinearSyncError {
+    #[error(transparent)]
+    Linear(#[from] LinearError),
+    #[error(transparent)]
+    Sqlx(#[from] SqlxError),
+}
+
+/// Service to periodically refresh the status of Linear-linked tasks for projects that opt in
+pub struct LinearSyncService {
+    db: DBService,
+    poll_interval: Duration,
+    publisher: Option<SharePublisher>,
+}
+
+impl LinearSyncService {
+    pub async fn spawn(
+        db: DBService,
+        publisher: Option<SharePublisher>,
+    ) -> tokio::task::JoinHandle<()> {
+        let service = Self {
+            db,
+            poll_interval: Duration::from_secs(300), // Check every 5 minutes
+            publisher,
+        };
+        tokio::spawn(async move {
+            service.start().await;
+        })
+    }
+
+    async fn start(&self) {
+        info!(
+            "Starting Linear sync service with interval {:?}",
+            self.poll_interval
+        );
+
+        let mut interval = interval(self.poll_interval);
+
+        loop {
+            interval.tick().await;
+            if let Err(e) = self.sync_all_projects().await {
+                error!("Error syncing Linear issue states: {}", e);
+            }
+        }
+    }
+
+    /// Refresh linked tasks for every project with auto sync enabled
+    async fn sync_all_projects(&self) -> Result<(), LinearSyncError> {
+        let projects = Project::find_linear_auto_sync(&self.db.pool).await?;
+
+        if projects.is_empty() {
+            debug!("No projects with Linear auto sync enabled");
+            return Ok(());
+        }
+
+        for project in projects {
+            if let Err(e) = self.sync_project(&project).await {
+                error!(
+                    "Error syncing Linear issue states for project {}: {}",
+                    project.id, e
+                );
+            }
+        }
+        Ok(())
+    }
+
+    /// Fetch the current state of all linked issues in one batched query and update local status
+    async fn sync_project(&self, project: &Project) -> Result<(), LinearSyncError> {
+        let Some(api_key) = project
+            .linear_api_key
+            .clone()
+            .or_else(|| linear_api_key_from_env(&project.name))
+        else {
+            debug!(
+                "Skipping Linear sync for project {}: no API key configured",
+                project.id
+            );
+            return Ok(());
+        };
+
+        let tasks = Task::find_linear_linked_by_project_id(&self.db.pool, project.id).await?;
+        if tasks.is_empty() {
+            return Ok(());
+        }
+
+        let issue_ids: Vec<String> = tasks
+            .iter()
+            .filter_map(|t| t.linear_issue_id.clone())
+            .collect();
+
+        let client = LinearClient::new(api_key);
+        let issues = client.fetch_issues_by_ids(&issue_ids).await?;
+        let state_by_issue: HashMap<String, WorkflowState> = issues
+            .into_iter()
+            .map(|issue| (issue.id, issue.state))
+            .collect();
+        let state_map = LinearStateMap::from_project_json(project.linear_state_map.as_deref());
+
+        debug!(
+            "Fetched {} Linear issue states for project {}",
+            state_by_issue.len(),
+            project.id
+        );
+
+        for task in tasks {
+            let Some(state) = task
+                .linear_issue_id
+                .as_ref()
+                .and_then(|id| state_by_issue.get(id))
+            else {
+                continue;
+            };
+
+            let new_status = match state_map.lookup(state) {
+                Some(status) => status,
+                // Several local statuses share a Linear state type (e.g. in review vs started),
+                // so without an override only move the task when the type actually differs
+                None if task_status_to_linear_state_type(&task.status) == state.state_type => {
+                    continue;
+                }
+                None => state_map.task_status(state),
+            };
+            if new_status == task.status {
+                continue;
+            }
+
+            info!(
+                "Linear issue for task {} moved to '{}', updating status {:?} -> {:?}",
+                task.id, state.name, task.status, new_status
+            );
+            Task::update_status(&self.db.pool, task.id, new_status).await?;
+
+            if let Some(publisher) = &self.publisher
+                && let Err(err) = publisher.update_shared_task_by_id(task.id).await
+            {
+                tracing::warn!(
+                    ?err,
+                    "Failed to propagate shared task update for {}",
+                    task.id
+                );
+            }
+        }
+
+        Ok(())
+    }
+}
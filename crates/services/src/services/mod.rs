@@ -10,8 +10,10 @@ pub mod filesystem;
 pub mod filesystem_watcher;
 pub mod git;
 pub mod github;
+pub mod http_client;
 pub mod image;
 pub mod linear;
+pub mod linear_sync;
 pub mod notification;
 pub mod oauth_credentials;
 pub mod pr_monitor;
@@ -20,5 +22,7 @@ pub mod queued_message;
 pub mod remote_client;
 pub mod repo;
 pub mod share;
+pub mod task_webhook;
+pub mod vibe_tasks;
 pub mod workspace_manager;
 pub mod worktree_manager;
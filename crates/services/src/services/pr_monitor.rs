@@ -1,17 +1,24 @@
-use std::time::Duration;
+use std::{
+    sync::{Arc, RwLock},
+    time::Duration,
+};
 
+use chrono::{DateTime, Utc};
 use db::{
     DBService,
     models::{
         merge::{Merge, MergeStatus, PrMerge},
+        project::Project,
         task::{Task, TaskStatus},
         workspace::{Workspace, WorkspaceError},
     },
 };
+use serde::Serialize;
 use sqlx::error::Error as SqlxError;
 use thiserror::Error;
 use tokio::time::interval;
 use tracing::{debug, error, info};
+use ts_rs::TS;
 
 use crate::services::{
     github::{GitHubRepoInfo, GitHubService, GitHubServiceError},
@@ -28,22 +35,93 @@ enum PrMonitorError {
     Sqlx(#[from] SqlxError),
 }
 
+const POLL_INTERVAL: Duration = Duration::from_secs(60); // Check every minute
+
+/// Outcome of the PR monitor's most recent runs, so clients can tell when PR status goes stale
+#[derive(Debug, Clone, Serialize, TS)]
+pub struct PrMonitorStatus {
+    pub poll_interval_secs: u64,
+    /// When the monitor last finished a pass over open PRs
+    pub last_run_at: Option<DateTime<Utc>>,
+    /// When a pass last completed without any errors
+    pub last_success_at: Option<DateTime<Utc>>,
+    pub last_error: Option<String>,
+    pub last_error_at: Option<DateTime<Utc>>,
+    /// Set by the admin pause endpoint; polling is skipped until resumed
+    pub paused: bool,
+}
+
+impl Default for PrMonitorStatus {
+    fn default() -> Self {
+        Self {
+            poll_interval_secs: POLL_INTERVAL.as_secs(),
+            last_run_at: None,
+            last_success_at: None,
+            last_error: None,
+            last_error_at: None,
+            paused: false,
+        }
+    }
+}
+
+/// Shared handle to the PR monitor status, updated by the service and read by the API
+#[derive(Debug, Clone, Default)]
+pub struct PrMonitorStatusHandle(Arc<RwLock<PrMonitorStatus>>);
+
+impl PrMonitorStatusHandle {
+    pub fn snapshot(&self) -> PrMonitorStatus {
+        self.0.read().unwrap().clone()
+    }
+
+    /// Forget the last error, e.g. after fixing `gh` auth
+    pub fn clear_error(&self) {
+        let mut status = self.0.write().unwrap();
+        status.last_error = None;
+        status.last_error_at = None;
+    }
+
+    /// Stop or restart polling without restarting the server
+    pub fn set_paused(&self, paused: bool) {
+        self.0.write().unwrap().paused = paused;
+    }
+
+    fn is_paused(&self) -> bool {
+        self.0.read().unwrap().paused
+    }
+
+    fn record_run(&self, error: Option<String>) {
+        let now = Utc::now();
+        let mut status = self.0.write().unwrap();
+        status.last_run_at = Some(now);
+        match error {
+            Some(e) => {
+                status.last_error = Some(e);
+                status.last_error_at = Some(now);
+            }
+            None => status.last_success_at = Some(now),
+        }
+    }
+}
+
 /// Service to monitor GitHub PRs and update task status when they are merged
 pub struct PrMonitorService {
     db: DBService,
     poll_interval: Duration,
     publisher: Option<SharePublisher>,
+    status: PrMonitorStatusHandle,
 }
 
 impl PrMonitorService {
     pub async fn spawn(
         db: DBService,
         publisher: Option<SharePublisher>,
+        status: PrMonitorStatusHandle,
     ) -> tokio::task::JoinHandle<()> {
         let service = Self {
             db,
-            poll_interval: Duration::from_secs(60), // Check every minute
+            poll_interval: POLL_INTERVAL,
             publisher,
+            status,
         };
         tokio::spawn(async move {
             service.start().await;
@@ -60,32 +138,44 @@ impl PrMonitorService {
 
         loop {
             interval.tick().await;
-            if let Err(e) = self.check_all_open_prs().await {
-                error!("Error checking open PRs: {}", e);
+            if self.status.is_paused() {
+                debug!("PR monitor paused, skipping poll");
+                continue;
             }
+            let error = match self.check_all_open_prs().await {
+                Ok(last_pr_error) => last_pr_error,
+                Err(e) => {
+                    error!("Error checking open PRs: {}", e);
+                    Some(e.to_string())
+                }
+            };
+            self.status.record_run(error);
         }
     }
 
-    /// Check all open PRs for updates with the provided GitHub token
-    async fn check_all_open_prs(&self) -> Result<(), PrMonitorError> {
+    /// Check all open PRs for updates with the provided GitHub token.
+    /// Returns the last per-PR error, if any PR failed to update.
+    async fn check_all_open_prs(&self) -> Result<Option<String>, PrMonitorError> {
         let open_prs = Merge::get_open_prs(&self.db.pool).await?;
 
         if open_prs.is_empty() {
             debug!("No open PRs to check");
-            return Ok(());
+            return Ok(None);
         }
 
         info!("Checking {} open PRs", open_prs.len());
 
+        let mut last_error = None;
         for pr_merge in open_prs {
             if let Err(e) = self.check_pr_status(&pr_merge).await {
                 error!(
                     "Error checking PR #{} for workspace {}: {}",
                     pr_merge.pr_info.number, pr_merge.workspace_id, e
                 );
+                last_error = Some(format!("PR #{}: {}", pr_merge.pr_info.number, e));
             }
         }
-        Ok(())
+        Ok(last_error)
     }
 
     /// Check the status of a specific PR
@@ -116,10 +206,12 @@ impl PrMonitorService {
             // Update merge status with the latest information from GitHub
             Merge::update_status(&self.db.pool, pr_merge.id, &pr_status).await?;
 
-            // If the PR was merged, update the task status to done
+            // If the PR was merged, update the task status to done unless the
+            // project tracks a step after merge
             if matches!(&pr_status.status, MergeStatus::Merged)
                 && let Some(workspace) =
                     Workspace::find_by_id(&self.db.pool, pr_merge.workspace_id).await?
+                && Project::auto_done_on_merge_for_task(&self.db.pool, workspace.task_id).await?
             {
                 info!(
                     "PR #{} was merged, updating task {} to done",
@@ -18,8 +18,10 @@ use uuid::Uuid;
 use super::{
     file_ranker::FileRanker,
     file_search_cache::{CacheError, FileSearchCache, SearchMode, SearchQuery},
+    linear::linear_api_key_env_var,
     repo::{RepoError, RepoService},
     share::ShareError,
+    vibe_tasks::vibe_tasks_dir,
 };
 
 #[derive(Debug, Error)]
@@ -134,6 +136,16 @@ impl ProjectService {
                     default_agent_working_dir: Some(repo.name),
                     linear_api_key: None,
                     linear_assignee_id: None,
+                    git_author_name: None,
+                    git_author_email: None,
+                    commit_message_template: None,
+                    linear_auto_sync: None,
+                    linear_state_map: None,
+                    setup_profiles: None,
+                    webhook_url: None,
+                    webhook_secret: None,
+                    allow_repoless_tasks: None,
+                    auto_done_on_merge: None,
                 },
             )
             .await?;
@@ -150,6 +162,10 @@ impl ProjectService {
     ) -> Result<Project> {
         let project = Project::update(pool, existing.id, &payload).await?;
 
+        if project.name != existing.name {
+            log_rename_notes(existing, &project.name);
+        }
+
         Ok(project)
     }
 
@@ -488,3 +504,35 @@ impl ProjectService {
         Ok(results)
     }
 }
+
+/// Values derived from the project name stay on the old name after a rename;
+/// warn when something still depends on it so it can be moved by hand.
+fn log_rename_notes(existing: &Project, new_name: &str) {
+    let old_env_var = linear_api_key_env_var(&existing.name);
+    let new_env_var = linear_api_key_env_var(new_name);
+    if existing.linear_api_key.is_none()
+        && old_env_var != new_env_var
+        && std::env::var_os(&old_env_var).is_some()
+        && std::env::var_os(&new_env_var).is_none()
+    {
+        tracing::warn!(
+            "Project '{}' renamed to '{}': its Linear API key was read from {}; set {} or store the key in project settings",
+            existing.name,
+            new_name,
+            old_env_var,
+            new_env_var
+        );
+    }
+
+    if let Some(old_dir) = vibe_tasks_dir(&existing.name)
+        && vibe_tasks_dir(new_name).is_none()
+    {
+        tracing::warn!(
+            "Project '{}' renamed to '{}': TUI task files in {:?} are only imported from ~/.vibe/projects/{}/tasks now",
+            existing.name,
+            new_name,
+            old_dir,
+            new_name
+        );
+    }
+}
@@ -0,0 +1,174 @@
+use std::time::Duration;
+
+use backon::{ExponentialBuilder, Retryable};
+use chrono::{DateTime, Utc};
+use db::{
+    DBService,
+    models::{
+        merge::Merge,
+        project::Project,
+        task::{Task, TaskStatus},
+    },
+};
+use hmac::{Hmac, Mac};
+use reqwest::StatusCode;
+use serde::Serialize;
+use sha2::Sha256;
+use thiserror::Error;
+use uuid::Uuid;
+
+use crate::services::http_client::shared_http_client;
+
+/// Header carrying `sha256=<hex HMAC-SHA256 of the body>` when the project has a webhook secret
+pub const SIGNATURE_HEADER: &str = "X-Vibe-Signature";
+
+const EVENT_TASK_STATUS_CHANGED: &str = "task.status_changed";
+const DELIVERY_TIMEOUT: Duration = Duration::from_secs(10);
+
+#[derive(Debug, Error)]
+enum WebhookError {
+    #[error(transparent)]
+    Sqlx(#[from] sqlx::Error),
+    #[error(transparent)]
+    Request(#[from] reqwest::Error),
+    #[error("webhook responded with {0}")]
+    Status(StatusCode),
+}
+
+impl WebhookError {
+    /// Other client errors mean the receiver rejected the payload; resending won't help
+    fn should_retry(&self) -> bool {
+        match self {
+            Self::Sqlx(_) => false,
+            Self::Request(_) => true,
+            Self::Status(status) => {
+                status.is_server_error()
+                    || *status == StatusCode::TOO_MANY_REQUESTS
+                    || *status == StatusCode::REQUEST_TIMEOUT
+            }
+        }
+    }
+}
+
+/// Body POSTed to a project's webhook when one of its tasks changes status
+#[derive(Debug, Serialize)]
+struct TaskStatusChanged {
+    event: &'static str,
+    task_id: Uuid,
+    project_id: Uuid,
+    title: String,
+    old_status: TaskStatus,
+    new_status: TaskStatus,
+    pr_url: Option<String>,
+    changed_at: DateTime<Utc>,
+}
+
+/// Tell the task's project webhook, if one is configured, that the task moved from
+/// `old_status` to `new_status`. Delivery is retried with backoff; failures are only logged.
+pub async fn notify_status_change(
+    db: DBService,
+    task_id: Uuid,
+    old_status: TaskStatus,
+    new_status: TaskStatus,
+) {
+    if let Err(e) = deliver_status_change(&db, task_id, old_status, new_status).await {
+        tracing::warn!("Task status webhook for task {} failed: {}", task_id, e);
+    }
+}
+
+async fn deliver_status_change(
+    db: &DBService,
+    task_id: Uuid,
+    old_status: TaskStatus,
+    new_status: TaskStatus,
+) -> Result<(), WebhookError> {
+    let Some(task) = Task::find_by_id(&db.pool, task_id).await? else {
+        return Ok(());
+    };
+    let Some(project) = Project::find_by_id(&db.pool, task.project_id).await? else {
+        return Ok(());
+    };
+    let Some(url) = project.webhook_url.filter(|url| !url.trim().is_empty()) else {
+        return Ok(());
+    };
+
+    let payload = TaskStatusChanged {
+        event: EVENT_TASK_STATUS_CHANGED,
+        task_id: task.id,
+        project_id: task.project_id,
+        title: task.title,
+        old_status,
+        new_status,
+        pr_url: Merge::find_latest_pr_url_for_task(&db.pool, task.id).await?,
+        changed_at: Utc::now(),
+    };
+    // Serialize once so the signature covers exactly the bytes that are sent
+    let body = serde_json::to_vec(&payload).unwrap_or_default();
+    let signature = project
+        .webhook_secret
+        .as_deref()
+        .filter(|secret| !secret.is_empty())
+        .map(|secret| sign(secret, &body));
+
+    (|| async {
+        let mut request = shared_http_client()
+            .post(&url)
+            .timeout(DELIVERY_TIMEOUT)
+            .header(reqwest::header::CONTENT_TYPE, "application/json")
+            .body(body.clone());
+        if let Some(signature) = &signature {
+            request = request.header(SIGNATURE_HEADER, signature);
+        }
+
+        let response = request.send().await?;
+        if response.status().is_success() {
+            Ok(())
+        } else {
+            Err(WebhookError::Status(response.status()))
+        }
+    })
+    .retry(
+        &ExponentialBuilder::default()
+            .with_min_delay(Duration::from_secs(1))
+            .with_max_delay(Duration::from_secs(30))
+            .with_max_times(3)
+            .with_jitter(),
+    )
+    .when(|e: &WebhookError| e.should_retry())
+    .notify(|e: &WebhookError, dur: Duration| {
+        tracing::warn!(
+            "Task status webhook failed, retrying after {:.2}s: {}",
+            dur.as_secs_f64(),
+            e
+        );
+    })
+    .await
+}
+
+/// `sha256=` followed by the hex HMAC-SHA256 of `body`, keyed with the project's secret
+fn sign(secret: &str, body: &[u8]) -> String {
+    let mut mac =
+        Hmac::<Sha256>::new_from_slice(secret.as_bytes()).expect("HMAC accepts keys of any size");
+    mac.update(body);
+    format!("sha256={}", hex::encode(mac.finalize().into_bytes()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn signs_body_with_hmac_sha256() {
+        assert_eq!(
+            sign("key", b"The quick brown fox jumps over the lazy dog"),
+            "sha256=f7bc83f430538424b13298e6aa6fb143ef4d59a14946175997479dbc2d1a3cd8"
+        );
+    }
+
+    #[test]
+    fn retries_only_transient_statuses() {
+        assert!(WebhookError::Status(StatusCode::BAD_GATEWAY).should_retry());
+        assert!(WebhookError::Status(StatusCode::TOO_MANY_REQUESTS).should_retry());
+        assert!(!WebhookError::Status(StatusCode::NOT_FOUND).should_retry());
+    }
+}
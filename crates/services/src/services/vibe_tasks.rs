@@ -0,0 +1,161 @@
+use std::path::{Path, PathBuf};
+
+use chrono::NaiveDate;
+use db::models::task::TaskStatus;
+use serde::Deserialize;
+use uuid::Uuid;
+
+/// A task written by the TUI as a markdown file in `~/.vibe/projects/{project}/tasks/`
+#[derive(Debug, Clone, PartialEq)]
+pub struct VibeTaskFile {
+    /// Frontmatter id; used as the database task id so re-imports update in place
+    pub id: Uuid,
+    pub title: String,
+    pub description: Option<String>,
+    /// Only set when the status was picked by hand in the TUI
+    pub status: Option<TaskStatus>,
+    pub tags: Vec<String>,
+    pub due_date: Option<NaiveDate>,
+    /// Lets Linear sync recognise the issue; the TUI only stores its identifier
+    pub linear_url: Option<String>,
+}
+
+/// Frontmatter written by the TUI's task storage
+#[derive(Debug, Deserialize)]
+struct VibeFrontmatter {
+    id: String,
+    linear_url: Option<String>,
+    #[serde(default)]
+    tags: Vec<String>,
+    due: Option<String>,
+    status: Option<TaskStatus>,
+}
+
+/// Directory the TUI keeps a project's task files in
+pub fn vibe_tasks_dir(project_name: &str) -> Option<PathBuf> {
+    let dir = dirs::home_dir()?
+        .join(".vibe")
+        .join("projects")
+        .join(project_name)
+        .join("tasks");
+    dir.is_dir().then_some(dir)
+}
+
+/// Read every task file in `dir`, skipping files without a valid frontmatter id
+pub fn read_vibe_tasks(dir: &Path) -> std::io::Result<Vec<VibeTaskFile>> {
+    let mut tasks = Vec::new();
+    for entry in std::fs::read_dir(dir)? {
+        let path = entry?.path();
+        if path.extension().is_none_or(|ext| ext != "md") {
+            continue;
+        }
+
+        let content = match std::fs::read_to_string(&path) {
+            Ok(content) => content,
+            Err(e) => {
+                tracing::warn!("Failed to read task file {:?}: {}", path, e);
+                continue;
+            }
+        };
+        let fallback_title = path
+            .file_stem()
+            .and_then(|s| s.to_str())
+            .unwrap_or("Untitled");
+
+        match parse_vibe_task(&content, fallback_title) {
+            Some(task) => tasks.push(task),
+            None => tracing::warn!("Skipping task file without a valid id: {:?}", path),
+        }
+    }
+    Ok(tasks)
+}
+
+/// Parse a task file the same way the TUI does: YAML frontmatter, the first
+/// heading as the title and everything after it as the description.
+pub fn parse_vibe_task(content: &str, fallback_title: &str) -> Option<VibeTaskFile> {
+    let parts: Vec<&str> = content.splitn(3, "---").collect();
+    if !content.starts_with("---") || parts.len() < 3 {
+        return None;
+    }
+
+    let frontmatter: VibeFrontmatter = serde_yaml::from_str(parts[1].trim()).ok()?;
+    let id = Uuid::parse_str(&frontmatter.id).ok()?;
+
+    let mut lines = parts[2].trim().lines();
+    let title = lines
+        .find(|line| line.starts_with('#'))
+        .map(|line| line.trim_start_matches('#').trim().to_string())
+        .unwrap_or_else(|| fallback_title.to_string());
+    let description = lines.collect::<Vec<_>>().join("\n").trim().to_string();
+
+    Some(VibeTaskFile {
+        id,
+        title,
+        description: (!description.is_empty()).then_some(description),
+        status: frontmatter.status,
+        tags: frontmatter.tags,
+        due_date: frontmatter
+            .due
+            .and_then(|d| NaiveDate::parse_from_str(d.trim(), "%Y-%m-%d").ok()),
+        linear_url: frontmatter.linear_url,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_tui_task_file() {
+        let content = concat!(
+            "---\n",
+            "id: 4b1c2a4e-7a0e-4f55-9d62-1f0f3c9b8a11\n",
+            "linear_id: VIB-6\n",
+            "linear_url: https://linear.app/acme/issue/VIB-6\n",
+            "tags:\n- backend\n",
+            "due: 2026-03-01\n",
+            "status: inprogress\n",
+            "created: 2026-01-20\n",
+            "---\n\n# Fix login\n\nSessions expire too early.\n"
+        );
+
+        let task = parse_vibe_task(content, "fix-login").unwrap();
+        assert_eq!(
+            task.id,
+            Uuid::parse_str("4b1c2a4e-7a0e-4f55-9d62-1f0f3c9b8a11").unwrap()
+        );
+        assert_eq!(task.title, "Fix login");
+        assert_eq!(
+            task.description.as_deref(),
+            Some("Sessions expire too early.")
+        );
+        assert_eq!(task.status, Some(TaskStatus::InProgress));
+        assert_eq!(task.tags, vec!["backend".to_string()]);
+        assert_eq!(task.due_date, NaiveDate::from_ymd_opt(2026, 3, 1));
+        assert_eq!(
+            task.linear_url.as_deref(),
+            Some("https://linear.app/acme/issue/VIB-6")
+        );
+    }
+
+    #[test]
+    fn skips_files_without_valid_id() {
+        assert_eq!(parse_vibe_task("# No frontmatter", "x"), None);
+        assert_eq!(
+            parse_vibe_task(
+                "---\nid: not-a-uuid\ncreated: 2026-01-20\n---\n# Title\n",
+                "x"
+            ),
+            None
+        );
+    }
+
+    #[test]
+    fn falls_back_to_file_name_for_title() {
+        let content =
+            "---\nid: 4b1c2a4e-7a0e-4f55-9d62-1f0f3c9b8a11\ncreated: 2026-01-20\n---\nno heading\n";
+        let task = parse_vibe_task(content, "my-task").unwrap();
+        assert_eq!(task.title, "my-task");
+        assert_eq!(task.status, None);
+    }
+}
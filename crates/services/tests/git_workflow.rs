@@ -6,7 +6,7 @@ use std::{
 
 use git2::{Repository, build::CheckoutBuilder};
 use services::services::{
-    git::{DiffTarget, GitCli, GitService},
+    git::{DiffTarget, GitCli, GitService, PR_REMOTE_CONFIG_KEY},
     github::{GitHubRepoInfo, GitHubServiceError},
 };
 use tempfile::TempDir;
@@ -311,6 +311,44 @@ fn branch_status_ahead_and_behind() {
     assert_eq!((ahead2, behind2), (2, 1));
 }
 
+#[test]
+fn branch_commits_lists_only_commits_since_base() {
+    let td = TempDir::new().unwrap();
+    let repo_path = init_repo_main(&td);
+    let s = GitService::new();
+
+    write_file(&repo_path, "base.txt", "base\n");
+    let _ = s.commit(&repo_path, "base").unwrap();
+
+    create_branch(&repo_path, "feature");
+    checkout_branch(&repo_path, "feature");
+    write_file(&repo_path, "feature.txt", "f1\n");
+    let _ = s.commit(&repo_path, "f1").unwrap();
+    write_file(&repo_path, "feature2.txt", "f2\n");
+    let _ = s.commit(&repo_path, "f2\n\nlonger body").unwrap();
+
+    // commits on main after branching are not part of the feature log
+    checkout_branch(&repo_path, "main");
+    write_file(&repo_path, "main.txt", "m1\n");
+    let _ = s.commit(&repo_path, "m1").unwrap();
+
+    let commits = s.get_branch_commits(&repo_path, "feature", "main").unwrap();
+    let messages: Vec<_> = commits.iter().map(|c| c.message.as_str()).collect();
+    assert_eq!(messages, vec!["f2\n\nlonger body", "f1"]);
+    assert_eq!(
+        commits[0].sha,
+        s.get_branch_oid(&repo_path, "feature").unwrap()
+    );
+    assert_eq!(commits[0].author_name, "Test User");
+    assert_eq!(commits[0].author_email, "test@example.com");
+
+    assert!(
+        s.get_branch_commits(&repo_path, "main", "main")
+            .unwrap()
+            .is_empty()
+    );
+}
+
 #[test]
 fn get_all_branches_lists_current_and_others() {
     let td = TempDir::new().unwrap();
@@ -487,6 +525,32 @@ fn github_repo_info_parses_https_and_ssh_urls() {
     }
 }
 
+#[test]
+fn github_repo_info_follows_configured_pr_remote() {
+    let td = TempDir::new().unwrap();
+    let repo_path = init_repo_main(&td);
+    let repo = Repository::open(&repo_path).unwrap();
+    repo.remote("origin", "git@github.com:me/project.git")
+        .unwrap();
+    repo.remote("upstream", "https://github.com/acme/project.git")
+        .unwrap();
+
+    let s = GitService::new();
+    let info = s.get_github_repo_info(&repo_path).unwrap();
+    assert_eq!(info.owner, "me");
+    assert_eq!(info.head_ref("feature"), "feature");
+
+    repo.config()
+        .unwrap()
+        .set_str(PR_REMOTE_CONFIG_KEY, "upstream")
+        .unwrap();
+    let info = s.get_github_repo_info(&repo_path).unwrap();
+    assert_eq!(info.owner, "acme");
+    assert_eq!(info.repo_name, "project");
+    assert_eq!(info.head_owner, "me");
+    assert_eq!(info.head_ref("feature"), "me:feature");
+}
+
 #[test]
 fn squash_merge_libgit2_sets_author_without_user() {
     // Verify merge_changes (libgit2 path) uses fallback author when no config exists
@@ -4,17 +4,30 @@ use ratatui::layout::{Constraint, Direction, Layout};
 use tokio::sync::mpsc;
 
 use crate::external::{
-    attach_zellij_foreground, edit_markdown, get_pr_for_branch, launch_zellij_claude_in_worktree,
-    launch_zellij_claude_in_worktree_with_context, list_sessions_with_status, list_worktrees,
-    BranchPrInfo, ClaudeActivityTracker, ClaudePlanReader, LinearClient, LinearIssue,
-    WorktreeInfo, ZellijSession,
+    attach_zellij_foreground, check_session_needs_attention, copy_to_clipboard, dump_session_screen,
+    edit_markdown, get_pr_by_number, get_pr_for_branch, launch_zellij_claude_in_worktree,
+    launch_zellij_claude_in_worktree_with_context, list_claude_sessions,
+    list_sessions_with_status, list_worktrees, open_file, retry_zellij_claude_in_worktree,
+    session_name_for_branch,
+    BranchPrInfo, ClaudeActivityState, ClaudeActivityTracker, ClaudePlanReader, LinearClient,
+    LinearIssue, ProcessLogEvent, ServerClient, SessionLaunchMode, TimelineEvent, WorktreeInfo,
+    ZellijSession,
 };
-use crate::input::{extract_key_event, key_to_action, Action, EventStream};
-use crate::state::{check_linear_api_key, linear_env_var_name, AppState, Modal, View};
-use crate::storage::TaskStorage;
+use crate::config::{Config, ProjectConfig};
+use crate::input::{
+    confirm_delete_bindings, extract_key_event, extract_paste_event, key_to_action,
+    paste_to_action, Action, EventStream,
+};
+use crate::state::{
+    bound_pr_key, check_linear_api_key, is_valid_project_dir, linear_env_var_name, AppState,
+    FocusState, KnownProject, Modal, ProjectSwitcher, TaskStatus, View, WorktreesState,
+};
+use crate::storage::{TaskStorage, TuiState};
 use crate::terminal::Terminal;
 use crate::ui::{
-    render_footer, render_header, render_help_modal, render_kanban_board, render_logs,
+    render_ci_failures, render_confirm_modal, render_focus, render_footer, render_header,
+    render_help_modal, render_kanban_board, render_lock_screen, render_project_switcher_modal,
+    render_resume_session_modal, render_status_picker_modal, render_logs,
     render_logs_overlay, render_search, render_sessions, render_task_detail_with_actions,
     render_worktrees,
 };
@@ -23,6 +36,33 @@ type WorktreeResult = Result<Vec<WorktreeInfo>, String>;
 type SessionResult = Result<Vec<ZellijSession>, String>;
 type BranchPrResult = (String, Option<BranchPrInfo>);
 type LinearResult = Result<Vec<LinearIssue>, String>;
+type FocusResult = (String, Option<String>);
+type TimelineResult = (String, Vec<TimelineEvent>);
+
+/// Time since the last key press or paste, for locking an idle board
+struct IdleTimer {
+    /// Lock the board after this long without input, if configured
+    timeout: Option<std::time::Duration>,
+    last_input: std::time::Instant,
+}
+
+impl IdleTimer {
+    fn new(timeout: Option<std::time::Duration>) -> Self {
+        Self {
+            timeout,
+            last_input: std::time::Instant::now(),
+        }
+    }
+
+    fn touch(&mut self) {
+        self.last_input = std::time::Instant::now();
+    }
+
+    fn expired(&self) -> bool {
+        self.timeout
+            .is_some_and(|timeout| self.last_input.elapsed() >= timeout)
+    }
+}
 
 pub struct App {
     state: AppState,
@@ -32,8 +72,17 @@ pub struct App {
     last_animation_tick: std::time::Instant,
     last_pr_poll: std::time::Instant,
     last_activity_poll: std::time::Instant,
+    last_focus_poll: std::time::Instant,
     claude_activity_tracker: ClaudeActivityTracker,
     plan_reader: ClaudePlanReader,
+    session_launch_mode: SessionLaunchMode,
+    /// Store Done (and update Linear) when a task's PR is seen merged
+    done_on_merge: bool,
+    /// How long to wait on a request to the vibe server
+    server_timeout: std::time::Duration,
+    idle: IdleTimer,
+    /// When each project's board was last left, saved with the TUI state
+    last_seen: std::collections::BTreeMap<String, String>,
     // Background loading channels
     worktree_receiver: mpsc::Receiver<WorktreeResult>,
     worktree_sender: mpsc::Sender<WorktreeResult>,
@@ -44,6 +93,18 @@ pub struct App {
     // Linear sync channels
     linear_receiver: mpsc::Receiver<LinearResult>,
     linear_sender: mpsc::Sender<LinearResult>,
+    // Focus mode session screen channel
+    focus_receiver: mpsc::Receiver<FocusResult>,
+    focus_sender: mpsc::Sender<FocusResult>,
+    // Task detail activity timeline channel
+    timeline_receiver: mpsc::Receiver<TimelineResult>,
+    timeline_sender: mpsc::Sender<TimelineResult>,
+    // Repository directories of the server's favorite projects, for the switcher
+    favorites_receiver: mpsc::Receiver<Vec<std::path::PathBuf>>,
+    favorites_sender: mpsc::Sender<Vec<std::path::PathBuf>>,
+    // Live execution process output, replaced for every new tail
+    log_tail_receiver: Option<mpsc::Receiver<ProcessLogEvent>>,
+    log_tail_task: Option<tokio::task::JoinHandle<()>>,
 }
 
 impl App {
@@ -52,7 +113,11 @@ impl App {
         let storage = TaskStorage::from_cwd()?;
         let project_name = storage.project_name().to_string();
 
+        let config = Config::load();
         let mut state = AppState::new();
+        state.set_spinner(&config.spinner);
+        state.tasks.set_wip_limits(&config.wip);
+        state.tasks.merged_is_done = ProjectConfig::load(&project_name).merged_is_done();
 
         // Check if Linear API key env var is available
         state.linear_api_key_available = check_linear_api_key(&project_name);
@@ -63,14 +128,20 @@ impl App {
 
         // No project selection - we're already in the project
         state.selected_project_id = Some(project_name.clone());
+        state.project_valid = std::env::current_dir().is_ok_and(|dir| is_valid_project_dir(&dir));
         state.view = View::Kanban;
         state.backend_connected = true; // File-based, always "connected"
+        let tui_state = TuiState::load();
+        tui_state.restore(&mut state);
 
         // Create background loading channels
         let (worktree_sender, worktree_receiver) = mpsc::channel(4);
         let (session_sender, session_receiver) = mpsc::channel(4);
         let (pr_info_sender, pr_info_receiver) = mpsc::channel(32);
         let (linear_sender, linear_receiver) = mpsc::channel(4);
+        let (focus_sender, focus_receiver) = mpsc::channel(4);
+        let (timeline_sender, timeline_receiver) = mpsc::channel(4);
+        let (favorites_sender, favorites_receiver) = mpsc::channel(4);
 
         // Mark as loading immediately so UI shows loading state
         state.worktrees.loading = true;
@@ -126,8 +197,14 @@ impl App {
             last_animation_tick: std::time::Instant::now(),
             last_pr_poll: std::time::Instant::now(),
             last_activity_poll: std::time::Instant::now(),
+            last_focus_poll: std::time::Instant::now(),
             claude_activity_tracker: ClaudeActivityTracker::new(),
             plan_reader: ClaudePlanReader::new(),
+            session_launch_mode: config.session.launch_mode(),
+            done_on_merge: config.status.done_on_merge,
+            server_timeout: config.server.timeout(),
+            idle: IdleTimer::new(config.lock.idle_timeout()),
+            last_seen: tui_state.last_seen,
             worktree_receiver,
             worktree_sender,
             session_receiver,
@@ -136,6 +213,14 @@ impl App {
             pr_info_sender,
             linear_receiver,
             linear_sender,
+            focus_receiver,
+            focus_sender,
+            timeline_receiver,
+            timeline_sender,
+            favorites_receiver,
+            favorites_sender,
+            log_tail_receiver: None,
+            log_tail_task: None,
         })
     }
 
@@ -148,11 +233,18 @@ impl App {
         const ANIMATION_TICK_INTERVAL: std::time::Duration = std::time::Duration::from_millis(250);
         // Poll Claude activity every 500ms for responsive status updates
         const ACTIVITY_POLL_INTERVAL: std::time::Duration = std::time::Duration::from_millis(500);
+        // Capture the focused task's session screen every second
+        const FOCUS_POLL_INTERVAL: std::time::Duration = std::time::Duration::from_secs(1);
 
         loop {
             // Check for background load results (worktrees, sessions, PRs)
             self.check_background_loads();
 
+            // Hide the board once nobody has touched it for the configured time
+            if !self.state.locked && self.idle.expired() {
+                self.state.locked = true;
+            }
+
             // Poll session status periodically (non-blocking background refresh)
             if self.last_session_poll.elapsed() >= SESSION_POLL_INTERVAL {
                 self.poll_sessions_async();
@@ -171,6 +263,14 @@ impl App {
                 self.last_activity_poll = std::time::Instant::now();
             }
 
+            // Refresh the live session output while in focus mode
+            if self.state.view == View::Focus
+                && self.last_focus_poll.elapsed() >= FOCUS_POLL_INTERVAL
+            {
+                self.poll_focus_screen_async();
+                self.last_focus_poll = std::time::Instant::now();
+            }
+
             // Tick animation for spinners
             if self.last_animation_tick.elapsed() >= ANIMATION_TICK_INTERVAL {
                 self.state.tick_animation();
@@ -182,7 +282,13 @@ impl App {
 
             // Handle events
             if let Some(event) = self.events.next().await? {
+                let input = matches!(event, Event::Key(_) | Event::Paste(_));
                 self.handle_event(event, terminal).await?;
+                // Handling can suspend the TUI for a foreground session or $EDITOR
+                // longer than the idle timeout; that time is not idle
+                if input {
+                    self.idle.touch();
+                }
             }
 
             if self.state.should_quit {
@@ -190,6 +296,13 @@ impl App {
             }
         }
 
+        self.record_last_seen();
+        let mut tui_state = TuiState::capture(&self.state);
+        tui_state.last_seen = std::mem::take(&mut self.last_seen);
+        if let Err(e) = tui_state.save() {
+            tracing::warn!("Failed to save TUI state: {}", e);
+        }
+
         Ok(())
     }
 
@@ -200,6 +313,7 @@ impl App {
                 Ok(worktrees) => {
                     // Spawn PR info fetch for each branch
                     self.fetch_pr_info_for_branches(&worktrees);
+                    self.fetch_bound_prs();
                     self.state.worktrees.set_worktrees(worktrees);
                     self.state.worktrees.loading = false;
                     self.state.worktrees.error = None;
@@ -229,12 +343,58 @@ impl App {
         // Non-blocking check for PR info results
         while let Ok((branch, pr_info)) = self.pr_info_receiver.try_recv() {
             if let Some(info) = pr_info {
+                if !branch.starts_with('#') {
+                    self.bind_task_pr(&branch, info.number);
+                }
+                if self.done_on_merge && self.state.tasks.merged_is_done && info.state == "MERGED" {
+                    self.mark_merged_task_done(&branch);
+                }
                 self.state.worktrees.set_branch_pr(branch, info);
             } else {
                 self.state.worktrees.clear_branch_pr(&branch);
             }
         }
 
+        // Non-blocking check for focus mode screen captures
+        while let Ok((task_id, screen)) = self.focus_receiver.try_recv() {
+            if self.state.focus.task_id.as_deref() == Some(task_id.as_str()) {
+                self.state.focus.set_screen(screen.as_deref());
+            }
+        }
+
+        // Non-blocking check for task timelines from the server
+        while let Ok((task_id, events)) = self.timeline_receiver.try_recv() {
+            self.state.task_detail.set_server_activity(&task_id, &events);
+        }
+
+        // Server favorites arrive after the project switcher opened
+        while let Ok(dirs) = self.favorites_receiver.try_recv() {
+            if let Some(Modal::SwitchProject(switcher)) = &mut self.state.modal {
+                switcher.pin_favorites(dirs);
+            }
+        }
+
+        // Non-blocking check for tailed execution process output
+        if let Some(receiver) = &mut self.log_tail_receiver {
+            let logs = &mut self.state.logs;
+            while let Ok(event) = receiver.try_recv() {
+                match event {
+                    ProcessLogEvent::Connected => logs.tail_connected(),
+                    ProcessLogEvent::Output(output) => logs.push_output(&output, false),
+                    ProcessLogEvent::Stderr(output) => logs.push_output(&output, true),
+                    ProcessLogEvent::Finished => logs.tail_status = "finished".to_string(),
+                    ProcessLogEvent::Disconnected(reason, retry_in) => {
+                        tracing::warn!("Process log tail disconnected: {}", reason);
+                        logs.tail_status = format!("reconnecting in {}s", retry_in.as_secs());
+                    }
+                    ProcessLogEvent::Failed(reason) => {
+                        tracing::error!("Failed to tail process logs: {}", reason);
+                        logs.tail_status = format!("failed: {}", reason);
+                    }
+                }
+            }
+        }
+
         // Non-blocking check for Linear results
         while let Ok(result) = self.linear_receiver.try_recv() {
             match result {
@@ -263,6 +423,29 @@ impl App {
                 }
             }
         }
+
+        if self.state.tasks.active_work_only {
+            self.refresh_live_session_tasks();
+        }
+    }
+
+    /// Record which tasks have a live session, for the active work filter
+    fn refresh_live_session_tasks(&mut self) {
+        let sessions = &self.state.sessions;
+        let live = self
+            .state
+            .tasks
+            .tasks
+            .iter()
+            .filter(|task| {
+                let branch = task_branch(task);
+                sessions
+                    .session_for_branch(&branch)
+                    .is_some_and(|session| !session.is_dead)
+            })
+            .map(|task| task.id.clone())
+            .collect();
+        self.state.tasks.live_session_task_ids = live;
     }
 
     fn fetch_pr_info_for_branches(&self, worktrees: &[WorktreeInfo]) {
@@ -283,8 +466,82 @@ impl App {
         }
     }
 
+    /// Fetch bound PRs by number so they're found even after their branch was renamed
+    fn fetch_bound_prs(&self) {
+        for task in &self.state.tasks.tasks {
+            let Some(number) = task.pr_number else {
+                continue;
+            };
+            let branch = task_branch(task);
+            let sender = self.pr_info_sender.clone();
+
+            tokio::task::spawn_blocking(move || {
+                let pr_info = get_pr_by_number(number).ok().flatten();
+                if let Some(info) = &pr_info
+                    && info.head_ref_name != branch
+                {
+                    tracing::debug!(
+                        "PR #{} is on branch {}, task branch is {}",
+                        number,
+                        info.head_ref_name,
+                        branch
+                    );
+                }
+                let _ = sender.blocking_send((bound_pr_key(number), pr_info));
+            });
+        }
+    }
+
+    /// Remember the first PR detected for a task's branch in its frontmatter
+    fn bind_task_pr(&mut self, branch: &str, number: u64) {
+        let Some(task) = self
+            .state
+            .tasks
+            .tasks
+            .iter()
+            .find(|t| t.pr_number.is_none() && task_branch(t) == branch)
+        else {
+            return;
+        };
+        let task_id = task.id.clone();
+
+        if let Err(e) = self.storage.bind_task_pr(&task_id, number) {
+            tracing::error!("Failed to bind PR #{} to task {}: {}", number, task_id, e);
+            return;
+        }
+        tracing::info!("Bound PR #{} to task {}", number, task_id);
+        self.reload_tasks();
+    }
+
+    /// Reload tasks after a background update without losing the selected card
+    fn reload_tasks(&mut self) {
+        if let Ok(tasks) = self.storage.list_tasks() {
+            self.state.tasks.reload_tasks_with_prs(
+                tasks,
+                &self.state.worktrees.branch_prs,
+                &self.state.worktrees.worktrees,
+            );
+        }
+    }
+
+    /// Locally detected PR for a task, preferring the PR bound to it
+    fn local_task_pr(&self, task: &crate::state::Task) -> Option<&BranchPrInfo> {
+        let branch_prs = &self.state.worktrees.branch_prs;
+        task.pr_number
+            .and_then(|number| branch_prs.get(&bound_pr_key(number)))
+            .or_else(|| {
+                let branch = task_branch(task);
+                branch_prs.get(&branch)
+            })
+    }
+
     fn render(&mut self, terminal: &mut Terminal) -> Result<()> {
         terminal.draw(|frame| {
+            if self.state.locked {
+                render_lock_screen(frame, frame.area(), &self.state);
+                return;
+            }
+
             let chunks = Layout::default()
                 .direction(Direction::Vertical)
                 .constraints([
@@ -308,7 +565,7 @@ impl App {
                         &self.state.tasks,
                         &self.state.worktrees,
                         &self.state.sessions,
-                        self.state.spinner_char(),
+                        self.state.spinner(),
                         self.state.linear_pending_issues.len(),
                     );
                 }
@@ -322,12 +579,19 @@ impl App {
                                 chunks[1],
                                 task,
                                 self.state.selected_task_plan.as_deref(),
+                                &mut self.state.task_detail,
                             );
                         }
                     }
                 }
                 View::Worktrees => {
-                    render_worktrees(frame, chunks[1], &self.state.worktrees);
+                    render_worktrees(
+                        frame,
+                        chunks[1],
+                        &self.state.worktrees,
+                        self.state.spinner_char(),
+                        self.state.spinner_color,
+                    );
                 }
                 View::Sessions => {
                     render_sessions(
@@ -335,6 +599,7 @@ impl App {
                         chunks[1],
                         &self.state.sessions,
                         self.state.spinner_char(),
+                        self.state.spinner_color,
                     );
                 }
                 View::Logs => {
@@ -343,6 +608,30 @@ impl App {
                 View::Search => {
                     render_search(frame, chunks[1], &self.state.search);
                 }
+                View::CiFailures => {
+                    render_ci_failures(
+                        frame,
+                        chunks[1],
+                        &self.state.tasks,
+                        &self.state.worktrees,
+                        self.state.spinner_char(),
+                        self.state.spinner_color,
+                    );
+                }
+                View::Focus => {
+                    if let Some(task) = self.focused_task() {
+                        let branch = task_branch(task);
+                        render_focus(
+                            frame,
+                            chunks[1],
+                            task,
+                            self.state.sessions.session_for_branch(&branch),
+                            &self.state.focus,
+                            self.state.spinner_char(),
+                            self.state.spinner_color,
+                        );
+                    }
+                }
             }
 
             render_footer(frame, chunks[2], &self.state);
@@ -353,8 +642,43 @@ impl App {
             }
 
             // Render modal if present
-            if let Some(Modal::Help) = &self.state.modal {
-                render_help_modal(frame, frame.area());
+            match &self.state.modal {
+                Some(Modal::Help) => render_help_modal(frame, frame.area()),
+                Some(Modal::ConfirmKillSession { session_name }) => render_confirm_modal(
+                    frame,
+                    frame.area(),
+                    " Kill Session ",
+                    &format!("{} looks busy - the agent may be mid-edit.", session_name),
+                    "y/Enter",
+                ),
+                Some(Modal::ConfirmStopProcess { process_id }) => render_confirm_modal(
+                    frame,
+                    frame.area(),
+                    " Stop Process ",
+                    &format!("Stop execution process {}?", process_id),
+                    "y/Enter",
+                ),
+                Some(Modal::ConfirmDelete { task_title, .. }) => render_confirm_modal(
+                    frame,
+                    frame.area(),
+                    " Delete Task ",
+                    &format!("Delete \"{}\"?", task_title),
+                    "y",
+                ),
+                Some(Modal::PickStatus {
+                    task_title,
+                    selected,
+                    ..
+                }) => render_status_picker_modal(frame, frame.area(), task_title, *selected),
+                Some(Modal::ResumeSession {
+                    branch,
+                    sessions,
+                    selected,
+                }) => render_resume_session_modal(frame, frame.area(), branch, sessions, *selected),
+                Some(Modal::SwitchProject(switcher)) => {
+                    render_project_switcher_modal(frame, frame.area(), switcher)
+                }
+                None => {}
             }
         })?;
 
@@ -362,27 +686,125 @@ impl App {
     }
 
     async fn handle_event(&mut self, event: Event, terminal: &mut Terminal) -> Result<()> {
-        let Some(key) = extract_key_event(event) else {
-            return Ok(());
-        };
+        if matches!(event, Event::Key(_) | Event::Paste(_)) {
+            self.idle.touch();
+            // The key that wakes a locked board only unlocks it
+            if self.state.locked {
+                self.state.locked = false;
+                return Ok(());
+            }
+        }
 
         let in_modal = self.state.modal.is_some();
+        let modal_input = matches!(self.state.modal, Some(Modal::SwitchProject(_)));
         let command_active = self.state.command_input.is_some();
-        let Some(action) = key_to_action(
-            key,
-            self.state.view,
-            in_modal,
-            self.state.search_active,
-            self.state.logs_overlay_visible,
-            command_active,
-        ) else {
-            return Ok(());
+
+        // Bracketed paste arrives as one event; insert it into the text field in one go
+        let action = if let Some(text) = extract_paste_event(&event) {
+            let Some(action) = paste_to_action(
+                text,
+                in_modal,
+                modal_input,
+                self.state.search_active,
+                command_active,
+            ) else {
+                return Ok(());
+            };
+            action
+        } else {
+            let Some(key) = extract_key_event(event) else {
+                return Ok(());
+            };
+
+            let action = if matches!(self.state.modal, Some(Modal::ConfirmDelete { .. })) {
+                confirm_delete_bindings(key)
+            } else {
+                key_to_action(
+                    key,
+                    self.state.view,
+                    in_modal,
+                    modal_input,
+                    self.state.search_active,
+                    self.state.logs_overlay_visible,
+                    command_active,
+                )
+            };
+            let Some(action) = action else {
+                return Ok(());
+            };
+            action
         };
 
         // Handle modal-specific actions
+        if let Some(Modal::SwitchProject(switcher)) = &mut self.state.modal {
+            match action {
+                Action::SearchType(c) => switcher.type_char(c),
+                Action::SearchPaste(text) => switcher.type_str(&text),
+                Action::SearchBackspace => switcher.backspace(),
+                Action::SearchDeleteWord | Action::ClearSearch => switcher.clear_query(),
+                Action::Up => switcher.select_prev(),
+                Action::Down => switcher.select_next(),
+                Action::Select => {
+                    if let Some(project) = switcher.selected_project().cloned() {
+                        self.state.modal = None;
+                        self.switch_project(&project);
+                    }
+                }
+                Action::Back => self.state.modal = None,
+                _ => {}
+            }
+            return Ok(());
+        }
         if in_modal {
-            if let Action::Back = action {
-                self.state.modal = None;
+            match action {
+                Action::Back => {
+                    self.state.modal = None;
+                }
+                Action::Select => match self.state.modal.take() {
+                    Some(Modal::ConfirmKillSession { session_name }) => {
+                        self.kill_session(&session_name);
+                    }
+                    Some(Modal::ConfirmStopProcess { process_id }) => {
+                        self.stop_process(&process_id);
+                    }
+                    Some(Modal::ConfirmDelete { task_id, .. }) => {
+                        self.delete_task(&task_id)?;
+                    }
+                    Some(Modal::PickStatus {
+                        task_id, selected, ..
+                    }) => {
+                        self.set_task_status(&task_id, TaskStatus::ALL[selected])?;
+                    }
+                    Some(Modal::ResumeSession {
+                        branch,
+                        sessions,
+                        selected,
+                    }) => {
+                        if let Some(session) = sessions.get(selected) {
+                            self.resume_claude_session(terminal, &branch, &session.id)?;
+                        }
+                    }
+                    modal => self.state.modal = modal,
+                },
+                Action::Up | Action::Down => {
+                    let (selected, len) = match &mut self.state.modal {
+                        Some(Modal::ResumeSession {
+                            sessions, selected, ..
+                        }) => (selected, sessions.len()),
+                        Some(Modal::PickStatus { selected, .. }) => {
+                            (selected, TaskStatus::ALL.len())
+                        }
+                        _ => return Ok(()),
+                    };
+                    if len > 0 {
+                        *selected = if action == Action::Down {
+                            (*selected + 1) % len
+                        } else {
+                            (*selected + len - 1) % len
+                        };
+                    }
+                }
+                _ => {}
             }
             return Ok(());
         }
@@ -445,12 +867,21 @@ impl App {
             Action::ShowSessions => {
                 self.handle_show_sessions()?;
             }
+            Action::ShowCiFailures => {
+                self.handle_show_ci_failures();
+            }
             Action::LaunchSession => {
                 self.handle_launch_session(terminal, false)?;
             }
             Action::LaunchSessionPlan => {
                 self.handle_launch_session(terminal, true)?;
             }
+            Action::RetrySession => {
+                self.handle_retry_session(terminal)?;
+            }
+            Action::ResumeSession => {
+                self.handle_resume_session();
+            }
             Action::ViewPR => {
                 self.handle_view_pr()?;
             }
@@ -458,6 +889,48 @@ impl App {
                 // PR binding not available in standalone mode
                 tracing::info!("PR binding requires server mode");
             }
+            Action::CopyTaskMarkdown => {
+                self.handle_copy_task_markdown();
+            }
+            Action::CopyTaskPrompt => {
+                self.handle_copy_task_prompt();
+            }
+            Action::PickStatus => {
+                self.handle_pick_status();
+            }
+            Action::MoveTaskRight => {
+                self.handle_move_task(true)?;
+            }
+            Action::MoveTaskLeft => {
+                self.handle_move_task(false)?;
+            }
+            Action::ToggleFocus => {
+                self.handle_toggle_focus();
+            }
+            Action::JumpToActive => {
+                self.handle_jump_to_active();
+            }
+            Action::ToggleTaskIds => {
+                if self.state.view == View::Kanban {
+                    self.state.tasks.show_ids = !self.state.tasks.show_ids;
+                }
+            }
+            Action::ToggleActiveWork => {
+                if self.state.view == View::Kanban {
+                    self.refresh_live_session_tasks();
+                    if self.state.tasks.toggle_active_work_only() {
+                        tracing::info!("Showing only tasks with a worktree or live session");
+                    } else {
+                        tracing::info!("Showing all tasks");
+                    }
+                }
+            }
+            Action::CycleSort => {
+                if self.state.view == View::Kanban {
+                    let sort = self.state.tasks.cycle_column_sort();
+                    tracing::info!("Sorting column by {}", sort.label());
+                }
+            }
             Action::AttachSession => {
                 self.handle_attach_session(terminal)?;
             }
@@ -467,16 +940,31 @@ impl App {
 
             // Search actions
             Action::StartSearch => {
-                // Populate search with current tasks and switch to search view
-                self.state.search.set_tasks(self.state.tasks.tasks.clone());
-                self.state.view = View::Search;
-                self.state.search_active = true;
+                if self.state.view == View::Sessions {
+                    // Filter the session list in place
+                    self.state.search_query = self.state.sessions.search_filter.clone();
+                    self.state.search_active = true;
+                } else {
+                    // Populate search with current tasks and switch to search view
+                    self.state.search.set_tasks(self.state.tasks.tasks.clone());
+                    self.state.view = View::Search;
+                    self.state.search_active = true;
+                }
             }
             Action::SearchType(c) => {
                 if self.state.view == View::Search {
                     self.state.search.type_char(c);
                 } else {
                     self.state.search_query.push(c);
+                    self.sync_session_filter();
+                }
+            }
+            Action::SearchPaste(text) => {
+                if self.state.view == View::Search {
+                    self.state.search.type_str(&text);
+                } else {
+                    self.state.search_query.push_str(&text);
+                    self.sync_session_filter();
                 }
             }
             Action::SearchBackspace => {
@@ -484,11 +972,17 @@ impl App {
                     self.state.search.backspace();
                 } else {
                     self.state.search_query.pop();
+                    self.sync_session_filter();
                 }
             }
             Action::SearchDeleteWord => {
                 if self.state.view == View::Search {
                     self.state.search.delete_word();
+                } else if self.state.view == View::Sessions {
+                    let query = self.state.search_query.trim_end();
+                    let keep = query.rfind(char::is_whitespace).map_or(0, |i| i + 1);
+                    self.state.search_query.truncate(keep);
+                    self.sync_session_filter();
                 }
             }
             Action::ClearSearch => {
@@ -497,6 +991,7 @@ impl App {
                 } else {
                     self.state.search_query.clear();
                     self.state.tasks.search_filter.clear();
+                    self.sync_session_filter();
                 }
             }
 
@@ -504,9 +999,45 @@ impl App {
                 self.handle_sync_linear()?;
             }
 
+            Action::WarmSearchCache => {
+                self.handle_warm_search_cache();
+            }
+            Action::RebaseBranch => {
+                self.handle_rebase_branch();
+            }
+            Action::OpenProjectDir => {
+                self.handle_open_project_dir();
+            }
+            Action::SwitchProject => {
+                let projects = self.storage.known_projects();
+                self.state.modal = Some(Modal::SwitchProject(ProjectSwitcher::new(projects)));
+                self.load_favorite_projects();
+            }
+
             Action::ShowLogs => {
                 self.handle_show_logs();
             }
+            Action::ToggleLogSelection => {
+                self.state.logs.toggle_selection();
+            }
+            Action::StopProcess => match &self.state.logs.tailing {
+                Some(process_id) => {
+                    self.state.modal = Some(Modal::ConfirmStopProcess {
+                        process_id: process_id.clone(),
+                    });
+                }
+                None => tracing::info!("Tail a process with ;t <id> to stop it"),
+            },
+            Action::ToggleStderrOnly => {
+                if self.state.logs.tailing.is_none() {
+                    tracing::info!("Only a process tail (;t <id>) has stderr to show");
+                } else {
+                    self.state.logs.toggle_stderr_only();
+                }
+            }
+            Action::YankLogs => {
+                self.handle_yank_logs();
+            }
 
             // Command mode actions (vim-like ;f)
             Action::StartCommand => {
@@ -517,6 +1048,11 @@ impl App {
                     cmd.push(c);
                 }
             }
+            Action::CommandPaste(text) => {
+                if let Some(ref mut cmd) = self.state.command_input {
+                    cmd.push_str(&text);
+                }
+            }
             Action::CommandBackspace => {
                 if let Some(ref mut cmd) = self.state.command_input {
                     cmd.pop();
@@ -541,6 +1077,124 @@ impl App {
         }
     }
 
+    fn handle_yank_logs(&mut self) {
+        let Some(text) = self.state.logs.selected_text() else {
+            tracing::warn!("No log lines selected - press v to start a selection");
+            return;
+        };
+        let count = text.lines().count();
+
+        match copy_to_clipboard(&text) {
+            Ok(()) => tracing::info!("Copied {} log line(s) to clipboard", count),
+            Err(e) => tracing::error!("Failed to copy log lines to clipboard: {}", e),
+        }
+        self.state.logs.selection_anchor = None;
+    }
+
+    /// Ask the running server to re-warm its file search cache for this project
+    fn handle_warm_search_cache(&self) {
+        let Some(project_dir) = self.get_project_dir() else {
+            return;
+        };
+        let timeout = self.server_timeout;
+        tokio::spawn(async move {
+            let result = match ServerClient::discover(timeout) {
+                Ok(client) => client.warm_search_cache(&project_dir).await,
+                Err(e) => Err(e),
+            };
+            match result {
+                Ok(count) => tracing::info!("Warming server search cache for {} repos", count),
+                Err(e) => tracing::error!("Failed to warm server search cache: {}", e),
+            }
+        });
+    }
+
+    fn handle_rebase_branch(&self) {
+        let Some(task) = self.selected_task() else {
+            return;
+        };
+        let branch = task_branch(task);
+        let title = task.title.clone();
+        let timeout = self.server_timeout;
+        tokio::spawn(async move {
+            let result = match ServerClient::discover(timeout) {
+                Ok(client) => client.rebase_task_branch(&branch).await,
+                Err(e) => Err(e),
+            };
+            match result {
+                Ok(()) => tracing::info!("Rebased the branch of \"{}\" onto its target", title),
+                Err(e) => tracing::error!("Failed to rebase the branch of \"{}\": {}", title, e),
+            }
+        });
+    }
+
+    fn handle_open_project_dir(&self) {
+        let Some(project_dir) = self.get_project_dir() else {
+            return;
+        };
+        match open_file(&project_dir.to_string_lossy()) {
+            Ok(()) => tracing::info!("Opened {} in the file manager", project_dir.display()),
+            Err(e) => tracing::error!("Failed to open project directory: {}", e),
+        }
+    }
+
+    /// Make `project` the current one. Everything else is derived from the working
+    /// directory, so change into it and reload tasks, worktrees and Linear issues.
+    fn switch_project(&mut self, project: &KnownProject) {
+        let storage = std::env::set_current_dir(&project.dir)
+            .map_err(anyhow::Error::from)
+            .and_then(|()| TaskStorage::from_cwd());
+        let storage = match storage {
+            Ok(storage) => storage,
+            Err(e) => {
+                tracing::error!("Failed to switch to project {}: {}", project.name, e);
+                return;
+            }
+        };
+        let tasks = match storage.list_tasks() {
+            Ok(tasks) => tasks,
+            Err(e) => {
+                tracing::error!("Failed to load tasks of {}: {}", project.name, e);
+                Vec::new()
+            }
+        };
+        self.storage = storage;
+        tracing::info!("Switched to project {}", project.name);
+
+        self.record_last_seen();
+        self.state.tasks.set_tasks(tasks);
+        self.state.tasks.merged_is_done = ProjectConfig::load(&project.name).merged_is_done();
+        let last_seen = self.last_seen.get(&project.name).cloned();
+        self.state.tasks.set_last_seen(last_seen, TuiState::now());
+        self.state.tasks.search_filter.clear();
+        self.state.search_query.clear();
+        self.state.search.clear();
+        self.state.search_active = false;
+        self.state.selected_project_id = Some(project.name.clone());
+        self.state.selected_task_id = None;
+        self.state.selected_task_plan = None;
+        self.state.project_valid = is_valid_project_dir(&project.dir);
+        self.state.focus = FocusState::new();
+        self.state.view = View::Kanban;
+
+        // Drop the old project's worktrees and PRs; a load still in flight for
+        // it is replaced by this one
+        self.state.worktrees = WorktreesState::new();
+        self.load_worktrees();
+
+        self.state.linear_pending_issues.clear();
+        self.state.linear_error = None;
+        self.state.linear_api_key_available = check_linear_api_key(&project.name);
+        self.refresh_linear();
+    }
+
+    /// Remember that the current project's board was seen until now
+    fn record_last_seen(&mut self) {
+        if let Some(project) = &self.state.selected_project_id {
+            self.last_seen.insert(project.clone(), TuiState::now());
+        }
+    }
+
     fn handle_sync_linear(&mut self) -> Result<()> {
         if self.state.linear_pending_issues.is_empty() {
             tracing::info!("No pending Linear issues to import");
@@ -550,9 +1204,36 @@ impl App {
         // Import all pending issues
         let pending = std::mem::take(&mut self.state.linear_pending_issues);
         let mut imported = 0;
+        let mut updated = 0;
         let mut errors = 0;
 
+        // Re-read task files so issues imported since the last fetch are updated, not duplicated
+        let existing: std::collections::HashMap<String, String> = self
+            .storage
+            .list_tasks()?
+            .into_iter()
+            .filter_map(|t| t.linear_issue_id.map(|linear_id| (linear_id, t.id)))
+            .collect();
+
         for issue in &pending {
+            if let Some(task_id) = existing.get(&issue.identifier) {
+                match self
+                    .storage
+                    .update_task(task_id, &issue.title, issue.description.as_deref())
+                {
+                    Ok(_) => updated += 1,
+                    Err(e) => {
+                        tracing::error!(
+                            "Failed to update task for Linear issue {}: {}",
+                            issue.title,
+                            e
+                        );
+                        errors += 1;
+                    }
+                }
+                continue;
+            }
+
             match self.storage.create_task_from_linear(issue) {
                 Ok(_) => {
                     tracing::info!("Imported Linear issue: {}", issue.title);
@@ -565,7 +1246,12 @@ impl App {
             }
         }
 
-        tracing::info!("Linear sync: imported {}, errors {}", imported, errors);
+        tracing::info!(
+            "Linear sync: imported {}, updated {}, errors {}",
+            imported,
+            updated,
+            errors
+        );
 
         // Refresh tasks to show newly imported ones
         let tasks = self.storage.list_tasks()?;
@@ -574,15 +1260,28 @@ impl App {
         Ok(())
     }
 
+    /// Apply the search line to the session list while searching in the Sessions view
+    fn sync_session_filter(&mut self) {
+        if self.state.view == View::Sessions {
+            self.state
+                .sessions
+                .set_search_filter(&self.state.search_query);
+        }
+    }
+
     fn execute_command(&mut self) {
         let cmd = self.state.command_input.take().unwrap_or_default();
-        match cmd.as_str() {
+        let (name, arg) = cmd.split_once(' ').unwrap_or((cmd.as_str(), ""));
+        match name {
             "f" | "find" => {
                 // Start search mode
                 self.state.search.set_tasks(self.state.tasks.tasks.clone());
                 self.state.view = View::Search;
                 self.state.search_active = true;
             }
+            "t" | "tail" => {
+                self.start_log_tail(arg.trim());
+            }
             _ => {
                 // Unknown command - just clear
                 tracing::debug!("Unknown command: {}", cmd);
@@ -590,7 +1289,53 @@ impl App {
         }
     }
 
+    /// Follow an execution process's output from the running server in the logs view
+    fn start_log_tail(&mut self, process_id: &str) {
+        if process_id.is_empty() {
+            tracing::warn!("Usage: ;tail <execution process id>");
+            return;
+        }
+        let client = match ServerClient::discover(self.server_timeout) {
+            Ok(client) => client,
+            Err(e) => {
+                tracing::error!("Failed to tail process logs: {}", e);
+                return;
+            }
+        };
+
+        self.stop_log_tail();
+        let (sender, receiver) = mpsc::channel(64);
+        let id = process_id.to_string();
+        self.log_tail_task = Some(tokio::spawn(async move {
+            client.tail_process_logs(&id, sender).await;
+        }));
+        self.log_tail_receiver = Some(receiver);
+        self.state.logs.start_tail(process_id);
+        self.state.logs_overlay_visible = false;
+        self.state.view = View::Logs;
+    }
+
+    fn stop_log_tail(&mut self) {
+        if let Some(task) = self.log_tail_task.take() {
+            task.abort();
+        }
+        self.log_tail_receiver = None;
+        if self.state.logs.tailing.is_some() {
+            self.state.logs.stop_tail();
+        }
+    }
+
     fn handle_back(&mut self) {
+        let logs_shown = self.state.logs_overlay_visible || self.state.view == View::Logs;
+        if logs_shown && self.state.logs.selection_anchor.is_some() {
+            self.state.logs.selection_anchor = None;
+            return;
+        }
+        if self.state.logs_overlay_visible {
+            self.state.logs_overlay_visible = false;
+            return;
+        }
+
         match self.state.view {
             View::Projects | View::Kanban => {
                 // In standalone mode, quit from kanban
@@ -601,7 +1346,19 @@ impl App {
                 self.state.selected_task_plan = None;
                 self.state.view = View::Kanban;
             }
-            View::Worktrees | View::Sessions | View::Logs => {
+            View::Logs => {
+                self.stop_log_tail();
+                self.state.view = View::Kanban;
+            }
+            View::Sessions
+                if self.state.search_active || !self.state.sessions.search_filter.is_empty() =>
+            {
+                // First Esc drops the session filter, the next one leaves the view
+                self.state.search_active = false;
+                self.state.search_query.clear();
+                self.state.sessions.clear_search_filter();
+            }
+            View::Worktrees | View::Sessions | View::CiFailures => {
                 self.state.view = View::Kanban;
             }
             View::Search => {
@@ -609,6 +1366,9 @@ impl App {
                 self.state.search_active = false;
                 self.state.view = View::Kanban;
             }
+            View::Focus => {
+                self.state.view = self.state.focus.exit();
+            }
         }
     }
 
@@ -628,7 +1388,9 @@ impl App {
                     .tasks
                     .select_prev_card_with_prs(&branch_prs, &worktrees);
             }
-            View::TaskDetail => {}
+            View::TaskDetail => {
+                self.state.task_detail.scroll_up();
+            }
             View::Worktrees => {
                 self.state.worktrees.select_prev();
             }
@@ -641,6 +1403,10 @@ impl App {
             View::Search => {
                 self.state.search.select_prev();
             }
+            View::CiFailures => {
+                self.state.tasks.select_prev_failure();
+            }
+            View::Focus => {}
         }
     }
 
@@ -660,7 +1426,9 @@ impl App {
                     .tasks
                     .select_next_card_with_prs(&branch_prs, &worktrees);
             }
-            View::TaskDetail => {}
+            View::TaskDetail => {
+                self.state.task_detail.scroll_down();
+            }
             View::Worktrees => {
                 self.state.worktrees.select_next();
             }
@@ -673,6 +1441,11 @@ impl App {
             View::Search => {
                 self.state.search.select_next();
             }
+            View::CiFailures => {
+                let count = self.ci_failures().len();
+                self.state.tasks.select_next_failure(count);
+            }
+            View::Focus => {}
         }
     }
 
@@ -699,16 +1472,70 @@ impl App {
     fn handle_open_task(&mut self) {
         if self.state.view == View::Kanban {
             if let Some(task) = self.selected_task().cloned() {
-                self.state.selected_task_id = Some(task.id.clone());
-                self.load_plan_for_task(&task);
-                self.state.view = View::TaskDetail;
+                self.open_task_detail(&task);
             }
         }
     }
 
+    fn open_task_detail(&mut self, task: &crate::state::Task) {
+        self.state.selected_task_id = Some(task.id.clone());
+        self.state.tasks.mark_seen(&task.id);
+        self.load_plan_for_task(task);
+        self.state.task_detail.reset();
+        self.load_activity_for_task(task);
+        self.state.view = View::TaskDetail;
+    }
+
+    /// Ask a running server which projects are starred, to list them first in
+    /// the project switcher
+    fn load_favorite_projects(&self) {
+        let client = match ServerClient::discover(self.server_timeout) {
+            Ok(client) => client,
+            Err(e) => {
+                tracing::debug!("No server for favorite projects: {}", e);
+                return;
+            }
+        };
+        let sender = self.favorites_sender.clone();
+        tokio::spawn(async move {
+            match client.favorite_project_dirs().await {
+                Ok(dirs) => {
+                    let _ = sender.send(dirs).await;
+                }
+                Err(e) => tracing::debug!("Failed to load favorite projects: {}", e),
+            }
+        });
+    }
+
+    /// Show what is known locally about the task right away, and ask a running
+    /// server for its full activity timeline in the background. The server task
+    /// is the one with an attempt on the task's branch.
+    fn load_activity_for_task(&mut self, task: &crate::state::Task) {
+        self.state.task_detail.set_local_activity(task);
+
+        let client = match ServerClient::discover(self.server_timeout) {
+            Ok(client) => client,
+            Err(e) => {
+                tracing::debug!("No server for the task timeline: {}", e);
+                return;
+            }
+        };
+        let sender = self.timeline_sender.clone();
+        let task_id = task.id.clone();
+        let branch = task_branch(task);
+        tokio::spawn(async move {
+            match client.task_timeline(&branch).await {
+                Ok(events) => {
+                    let _ = sender.send((task_id, events)).await;
+                }
+                Err(e) => tracing::debug!("No server timeline for task {}: {}", task_id, e),
+            }
+        });
+    }
+
     /// Load the Claude Code plan for a task based on its branch.
     fn load_plan_for_task(&mut self, task: &crate::state::Task) {
-        let branch = task_title_to_branch(&task.title, task.linear_issue_id.as_deref());
+        let branch = task_branch(task);
         if let Some(project_dir) = self.get_project_dir() {
             let project_path = project_dir.to_string_lossy().to_string();
             self.state.selected_task_plan = self
@@ -726,9 +1553,7 @@ impl App {
             }
             View::Kanban => {
                 if let Some(task) = self.selected_task().cloned() {
-                    self.state.selected_task_id = Some(task.id.clone());
-                    self.load_plan_for_task(&task);
-                    self.state.view = View::TaskDetail;
+                    self.open_task_detail(&task);
                 }
             }
             View::TaskDetail => {
@@ -740,7 +1565,8 @@ impl App {
                 self.handle_launch_session(terminal, false)?;
             }
             View::Sessions => {
-                // Attach to selected session
+                // Attach to selected session, keeping any filter for when we come back
+                self.state.search_active = false;
                 self.handle_attach_session(terminal)?;
             }
             View::Logs => {
@@ -750,13 +1576,24 @@ impl App {
             View::Search => {
                 // Select task from search results and go to detail view
                 if let Some(task) = self.state.search.selected_task().cloned() {
-                    self.state.selected_task_id = Some(task.id.clone());
-                    self.load_plan_for_task(&task);
                     self.state.search.clear();
                     self.state.search_active = false;
-                    self.state.view = View::TaskDetail;
+                    self.open_task_detail(&task);
+                }
+            }
+            View::CiFailures => {
+                let task = self
+                    .ci_failures()
+                    .get(self.state.tasks.selected_failure)
+                    .map(|entry| entry.task.clone());
+                if let Some(task) = task {
+                    self.open_task_detail(&task);
                 }
             }
+            View::Focus => {
+                // Launch or attach the focused task's session
+                self.handle_launch_session(terminal, false)?;
+            }
         }
 
         Ok(())
@@ -785,11 +1622,74 @@ impl App {
                 self.state.tasks.set_tasks(tasks.clone());
                 self.state.search.set_tasks(tasks);
             }
+            View::CiFailures => {
+                let tasks = self.storage.list_tasks()?;
+                self.state.tasks.set_tasks(tasks);
+                self.load_worktrees();
+            }
+            View::Focus => {
+                let tasks = self.storage.list_tasks()?;
+                self.state.tasks.set_tasks(tasks);
+                self.load_sessions();
+                self.poll_focus_screen_async();
+            }
         }
 
         Ok(())
     }
 
+    fn record_task_branch(&mut self, task_id: &str, branch: &str) {
+        if let Err(e) = self.storage.set_task_branch(task_id, branch) {
+            tracing::error!(
+                "Failed to record branch {} for task {}: {}",
+                branch,
+                task_id,
+                e
+            );
+            return;
+        }
+        self.reload_tasks();
+    }
+
+    /// Persist Done for the task whose branch merged, unless its status was set by hand
+    fn mark_merged_task_done(&mut self, branch: &str) {
+        let Some(task) = self.state.tasks.tasks.iter().find(|t| {
+            !t.status_pinned
+                && (t.pr_number.is_some_and(|n| bound_pr_key(n) == branch)
+                    || task_branch(t) == branch)
+        }) else {
+            return;
+        };
+        let task_id = task.id.clone();
+        let linear_issue_id = task.linear_issue_id.clone();
+
+        if let Err(e) = self.storage.set_status(&task_id, TaskStatus::Done) {
+            tracing::error!("Failed to mark merged task {} done: {}", task_id, e);
+            return;
+        }
+        tracing::info!("PR for {} merged, marked task {} done", branch, task_id);
+        self.reload_tasks();
+
+        let Some(identifier) = linear_issue_id else {
+            return;
+        };
+        if !self.state.linear_api_key_available {
+            return;
+        }
+        let env_var = linear_env_var_name(self.storage.project_name());
+        tokio::spawn(async move {
+            if let Ok(api_key) = std::env::var(&env_var) {
+                let client = LinearClient::new(api_key);
+                match client.mark_issue_done(&identifier).await {
+                    Ok(()) => tracing::info!("Marked Linear issue {} done", identifier),
+                    Err(e) => {
+                        tracing::error!("Failed to mark Linear issue {} done: {}", identifier, e)
+                    }
+                }
+            }
+        });
+    }
+
     fn refresh_linear(&self) {
         if !self.state.linear_api_key_available {
             return;
@@ -876,15 +1776,20 @@ impl App {
         // Suspend terminal for editor
         terminal.suspend()?;
 
-        // Edit new task in editor
-        let content = "# New Task\n\nDescription here...";
-        let edited = edit_markdown(content);
+        // Edit new task in editor, starting from the user's template
+        let template = self.storage.new_task_template();
+        let edited = edit_markdown(&template);
 
         // Resume terminal
         terminal.resume()?;
 
         // Process the edit
         if let Ok(Some(new_content)) = edited {
+            // Saving the template untouched cancels
+            if new_content.trim() == template.trim() {
+                return Ok(());
+            }
+
             // Parse the edited content
             let mut lines = new_content.lines();
             let title_line = lines.next().unwrap_or("New Task");
@@ -910,20 +1815,32 @@ impl App {
         Ok(())
     }
 
+    /// Ask for confirmation before deleting the selected task
     fn handle_delete_task(&mut self) -> Result<()> {
-        // Get the selected task
-        let task_id = match self.state.view {
-            View::TaskDetail => self.state.selected_task_id.clone(),
-            View::Kanban => self.selected_task().map(|t| t.id.clone()),
+        let task = match self.state.view {
+            View::TaskDetail => self
+                .state
+                .selected_task_id
+                .as_ref()
+                .and_then(|id| self.state.tasks.tasks.iter().find(|t| &t.id == id)),
+            View::Kanban => self.selected_task(),
             _ => None,
         };
 
-        let Some(task_id) = task_id else {
+        let Some(task) = task else {
             return Ok(());
         };
 
-        // Delete the task
-        self.storage.delete_task(&task_id)?;
+        self.state.modal = Some(Modal::ConfirmDelete {
+            task_id: task.id.clone(),
+            task_title: task.title.clone(),
+        });
+
+        Ok(())
+    }
+
+    fn delete_task(&mut self, task_id: &str) -> Result<()> {
+        self.storage.delete_task(task_id)?;
 
         // Go back if we were in task detail view
         if self.state.view == View::TaskDetail {
@@ -962,6 +1879,21 @@ impl App {
         });
     }
 
+    /// Open the list of tasks whose PRs have failing checks. Worktrees are reloaded
+    /// so locally detected PRs are up to date.
+    fn handle_show_ci_failures(&mut self) {
+        self.load_worktrees();
+        self.state.tasks.selected_failure = 0;
+        self.state.view = View::CiFailures;
+    }
+
+    fn ci_failures(&self) -> Vec<crate::state::FailingChecksEntry<'_>> {
+        self.state.tasks.tasks_with_failing_checks(
+            &self.state.worktrees.branch_prs,
+            &self.state.worktrees.worktrees,
+        )
+    }
+
     fn handle_show_sessions(&mut self) -> Result<()> {
         self.load_sessions();
         self.state.view = View::Sessions;
@@ -1001,6 +1933,7 @@ impl App {
         // Re-fetch PR info for all known worktree branches
         let worktrees = self.state.worktrees.worktrees.clone();
         self.fetch_pr_info_for_branches(&worktrees);
+        self.fetch_bound_prs();
     }
 
     fn poll_claude_activity(&mut self) {
@@ -1036,8 +1969,13 @@ impl App {
                 // If in worktrees view, use selected worktree directly
                 if let Some(wt) = self.state.worktrees.selected() {
                     terminal.suspend()?;
-                    let result =
-                        launch_zellij_claude_in_worktree(&wt.branch, plan_mode, &project_dir);
+                    let result = launch_zellij_claude_in_worktree(
+                        &wt.branch,
+                        plan_mode,
+                        &project_dir,
+                        self.session_launch_mode,
+                        None,
+                    );
                     terminal.resume()?;
                     if let Err(e) = result {
                         tracing::error!("Failed to launch session: {}", e);
@@ -1054,6 +1992,7 @@ impl App {
                     .and_then(|id| self.state.tasks.tasks.iter().find(|t| &t.id == id))
             }
             View::Kanban => self.selected_task(),
+            View::Focus => self.focused_task(),
             _ => None,
         };
 
@@ -1063,18 +2002,10 @@ impl App {
         };
 
         // Create branch slug from task title (with Linear ID prefix if available)
-        let branch = task_title_to_branch(&task.title, task.linear_issue_id.as_deref());
+        let branch = task_branch(task);
 
         // Build task context for fresh sessions
-        let task_context = {
-            let mut context = format!("Task: {}", task.title);
-            if let Some(desc) = &task.description {
-                if !desc.is_empty() {
-                    context.push_str(&format!("\n\nDescription:\n{}", desc));
-                }
-            }
-            context
-        };
+        let task_context = task_launch_context(task);
 
         // Suspend TUI, create worktree if needed, launch claude
         terminal.suspend()?;
@@ -1084,12 +2015,17 @@ impl App {
             &task_context,
             plan_mode,
             &project_dir,
+            self.session_launch_mode,
         );
 
         terminal.resume()?;
 
         if let Err(e) = result {
             tracing::error!("Failed to launch session: {}", e);
+        } else if task.branch.is_none() {
+            // Match the worktree by this exact branch from now on
+            let task_id = task.id.clone();
+            self.record_task_branch(&task_id, &branch);
         }
 
         // After returning from session, go back to kanban board
@@ -1102,7 +2038,258 @@ impl App {
         Ok(())
     }
 
+    /// Start the task over in a fresh session once its previous one has exited,
+    /// then follow its progress in focus mode
+    fn handle_retry_session(&mut self, terminal: &mut Terminal) -> Result<()> {
+        let Some(project_dir) = self.get_project_dir().filter(|dir| dir.exists()) else {
+            tracing::error!("Project directory not found");
+            return Ok(());
+        };
+
+        let task = match self.state.view {
+            View::TaskDetail => self
+                .state
+                .selected_task_id
+                .as_ref()
+                .and_then(|id| self.state.tasks.tasks.iter().find(|t| &t.id == id)),
+            View::Focus => self.focused_task(),
+            _ => None,
+        };
+        let Some(task) = task else {
+            tracing::warn!("No task selected for retry");
+            return Ok(());
+        };
+
+        let task_id = task.id.clone();
+        let branch = task_branch(task);
+        let task_context = task_launch_context(task);
+
+        terminal.suspend()?;
+        let result = retry_zellij_claude_in_worktree(
+            &branch,
+            &task_context,
+            false,
+            &project_dir,
+            self.session_launch_mode,
+        );
+        terminal.resume()?;
+
+        if let Err(e) = result {
+            tracing::error!("Failed to retry session: {}", e);
+            return Ok(());
+        }
+        tracing::info!("Retrying task {} in a fresh session", task_id);
+
+        if self.state.view != View::Focus {
+            self.state.focus.enter(task_id, self.state.view);
+            self.state.view = View::Focus;
+        }
+        self.load_sessions();
+        self.poll_focus_screen_async();
+        self.last_focus_poll = std::time::Instant::now();
+
+        Ok(())
+    }
+
+    /// Select the most recently updated task that has an agent running
+    fn handle_jump_to_active(&mut self) {
+        if self.state.view != View::Kanban {
+            return;
+        }
+
+        let sessions = &self.state.sessions;
+        let newest = self.state.tasks.newest_active_task(|task| {
+            let branch = task_branch(task);
+            sessions
+                .session_for_branch(&branch)
+                .is_some_and(|session| !session.is_dead)
+        });
+        let Some(task_id) = newest.map(|task| task.id.clone()) else {
+            tracing::info!("No task has an agent running");
+            return;
+        };
+
+        if !self.state.tasks.select_task_with_prs(
+            &task_id,
+            &self.state.worktrees.branch_prs,
+            &self.state.worktrees.worktrees,
+        ) {
+            tracing::info!("The newest active task is hidden by the search filter");
+        }
+    }
+
+    /// Enter focus mode for the selected task, or leave it
+    fn handle_toggle_focus(&mut self) {
+        if self.state.view == View::Focus {
+            self.state.view = self.state.focus.exit();
+            return;
+        }
+
+        let task_id = match self.state.view {
+            View::TaskDetail => self.state.selected_task_id.clone(),
+            View::Kanban => self.selected_task().map(|t| t.id.clone()),
+            _ => None,
+        };
+        let Some(task_id) = task_id else {
+            tracing::warn!("No task selected");
+            return;
+        };
+
+        self.state.tasks.mark_seen(&task_id);
+        self.state.focus.enter(task_id, self.state.view);
+        self.state.view = View::Focus;
+        self.load_sessions();
+        self.poll_focus_screen_async();
+        self.last_focus_poll = std::time::Instant::now();
+    }
+
+    fn focused_task(&self) -> Option<&crate::state::Task> {
+        let task_id = self.state.focus.task_id.as_ref()?;
+        self.state.tasks.tasks.iter().find(|t| &t.id == task_id)
+    }
+
+    /// Capture the focused task's session screen in the background
+    fn poll_focus_screen_async(&self) {
+        let Some(task) = self.focused_task() else {
+            return;
+        };
+        let task_id = task.id.clone();
+        let branch = task_branch(task);
+        let session_name = session_name_for_branch(&branch);
+        let sender = self.focus_sender.clone();
+
+        tokio::task::spawn_blocking(move || {
+            let screen = dump_session_screen(&session_name);
+            let _ = sender.blocking_send((task_id, screen));
+        });
+    }
+
+    /// Open the status picker for the selected task
+    fn handle_pick_status(&mut self) {
+        let task = match self.state.view {
+            View::TaskDetail => self
+                .state
+                .selected_task_id
+                .as_ref()
+                .and_then(|id| self.state.tasks.tasks.iter().find(|t| &t.id == id)),
+            View::Kanban => self.selected_task(),
+            View::Focus => self.focused_task(),
+            _ => None,
+        };
+        let Some(task) = task else {
+            tracing::warn!("No task selected");
+            return;
+        };
+
+        let selected = TaskStatus::ALL
+            .iter()
+            .position(|s| *s == task.status)
+            .unwrap_or(0);
+        self.state.modal = Some(Modal::PickStatus {
+            task_id: task.id.clone(),
+            task_title: task.title.clone(),
+            selected,
+        });
+    }
+
+    /// Move the selected card to the neighbouring column and keep it selected.
+    /// Tasks with a merged PR stay in Done, unless the project opts out.
+    fn handle_move_task(&mut self, forward: bool) -> Result<()> {
+        if self.state.view != View::Kanban {
+            return Ok(());
+        }
+        let Some(task) = self.selected_task() else {
+            tracing::warn!("No task selected");
+            return Ok(());
+        };
+        if self.state.tasks.merged_is_done
+            && task.has_merged_pr_with_prs(
+                &self.state.worktrees.branch_prs,
+                &self.state.worktrees.worktrees,
+            )
+        {
+            tracing::warn!("{} has a merged PR and stays in Done", task.title);
+            return Ok(());
+        }
+        let Some(status) = self.state.tasks.move_target(forward) else {
+            return Ok(());
+        };
+
+        let task_id = task.id.clone();
+        self.set_task_status(&task_id, status)?;
+        self.state.tasks.select_task_with_prs(
+            &task_id,
+            &self.state.worktrees.branch_prs,
+            &self.state.worktrees.worktrees,
+        );
+        Ok(())
+    }
+
+    fn set_task_status(&mut self, task_id: &str, status: TaskStatus) -> Result<()> {
+        self.storage.set_status(task_id, status)?;
+        tracing::info!("Set task status to {}", status.label());
+        self.refresh()
+    }
+
+    /// Open the picker of past Claude conversations for the selected worktree
+    fn handle_resume_session(&mut self) {
+        if self.state.view != View::Worktrees {
+            return;
+        }
+        let Some(wt) = self.state.worktrees.selected() else {
+            tracing::warn!("No worktree selected");
+            return;
+        };
+
+        let sessions = list_claude_sessions(std::path::Path::new(&wt.path));
+        self.state.modal = Some(Modal::ResumeSession {
+            branch: wt.branch.clone(),
+            sessions,
+            selected: 0,
+        });
+    }
+
+    /// Relaunch the worktree's session with `claude --resume <id>`
+    fn resume_claude_session(
+        &mut self,
+        terminal: &mut Terminal,
+        branch: &str,
+        session_id: &str,
+    ) -> Result<()> {
+        let Some(project_dir) = self.get_project_dir().filter(|dir| dir.exists()) else {
+            tracing::error!("Project directory does not exist");
+            return Ok(());
+        };
+
+        terminal.suspend()?;
+        let result = launch_zellij_claude_in_worktree(
+            branch,
+            false,
+            &project_dir,
+            self.session_launch_mode,
+            Some(session_id),
+        );
+        terminal.resume()?;
+
+        if let Err(e) = result {
+            tracing::error!("Failed to resume session: {}", e);
+        }
+        Ok(())
+    }
+
     fn handle_view_pr(&self) -> Result<()> {
+        if self.state.view == View::CiFailures {
+            let failures = self.ci_failures();
+            if let Some(url) = failures
+                .get(self.state.tasks.selected_failure)
+                .and_then(|entry| entry.pr_url)
+                && let Err(e) = open::that(url)
+            {
+                tracing::error!("Failed to open PR URL: {}", e);
+            }
+            return Ok(());
+        }
+
         if let Some(task) = self.selected_task() {
             // Check task's PR URL first
             if let Some(pr_url) = &task.pr_url {
@@ -1113,8 +2300,7 @@ impl App {
             }
 
             // Check locally detected PR info
-            let branch = task_title_to_branch(&task.title, task.linear_issue_id.as_deref());
-            if let Some(pr_info) = self.state.worktrees.branch_prs.get(&branch) {
+            if let Some(pr_info) = self.local_task_pr(task) {
                 if let Err(e) = open::that(&pr_info.url) {
                     tracing::error!("Failed to open PR URL: {}", e);
                 }
@@ -1125,6 +2311,37 @@ impl App {
         Ok(())
     }
 
+    fn handle_copy_task_markdown(&self) {
+        let Some(task) = self.selected_task() else {
+            tracing::warn!("No task selected");
+            return;
+        };
+
+        // Prefer the backend PR URL, fall back to the locally detected one
+        let pr_url = task
+            .pr_url
+            .as_deref()
+            .or_else(|| self.local_task_pr(task).map(|pr| pr.url.as_str()));
+
+        match copy_to_clipboard(&task.to_markdown(pr_url, self.state.tasks.merged_is_done)) {
+            Ok(()) => tracing::info!("Copied task '{}' to clipboard as markdown", task.title),
+            Err(e) => tracing::error!("Failed to copy task to clipboard: {}", e),
+        }
+    }
+
+    /// Copy the context a fresh session is launched with, to paste into another tool
+    fn handle_copy_task_prompt(&self) {
+        let Some(task) = self.selected_task() else {
+            tracing::warn!("No task selected");
+            return;
+        };
+
+        match copy_to_clipboard(&task_launch_context(task)) {
+            Ok(()) => tracing::info!("Copied the launch prompt of '{}' to clipboard", task.title),
+            Err(e) => tracing::error!("Failed to copy task prompt to clipboard: {}", e),
+        }
+    }
+
     fn handle_attach_session(&mut self, terminal: &mut Terminal) -> Result<()> {
         let Some(session) = self.state.sessions.selected() else {
             tracing::warn!("No session selected");
@@ -1155,18 +2372,80 @@ impl App {
             return Ok(());
         };
 
-        if let Err(e) = crate::external::kill_session(&session.name) {
+        // Ask before killing an agent that is still working
+        if session_appears_busy(session) {
+            self.state.modal = Some(Modal::ConfirmKillSession {
+                session_name: session.name.clone(),
+            });
+            return Ok(());
+        }
+
+        let session_name = session.name.clone();
+        self.kill_session(&session_name);
+
+        Ok(())
+    }
+
+    fn stop_process(&self, process_id: &str) {
+        let timeout = self.server_timeout;
+        let process_id = process_id.to_string();
+        tokio::spawn(async move {
+            let result = match ServerClient::discover(timeout) {
+                Ok(client) => client.stop_process(&process_id).await,
+                Err(e) => Err(e),
+            };
+            match result {
+                // The tail sees the process finish and says so in its title
+                Ok(()) => tracing::info!("Stopped execution process {}", process_id),
+                Err(e) => tracing::error!("Failed to stop execution process {}: {}", process_id, e),
+            }
+        });
+    }
+
+    fn kill_session(&mut self, session_name: &str) {
+        if let Err(e) = crate::external::kill_session(session_name) {
             tracing::error!("Failed to kill session: {}", e);
         } else {
-            tracing::info!("Killed session {}", session.name);
+            tracing::info!("Killed session {}", session_name);
             // Refresh the sessions list
             self.load_sessions();
         }
+    }
+}
 
-        Ok(())
+/// A session looks busy when Claude is actively thinking. Without statusline
+/// data, fall back to the screen check - a session not waiting on input is
+/// assumed to be working.
+fn session_appears_busy(session: &ZellijSession) -> bool {
+    if session.is_dead {
+        return false;
+    }
+    match session.claude_activity {
+        ClaudeActivityState::Thinking => true,
+        ClaudeActivityState::WaitingForUser | ClaudeActivityState::Idle => false,
+        ClaudeActivityState::Unknown => !check_session_needs_attention(&session.name),
     }
 }
 
+/// Initial prompt for a fresh session: the task title and description
+fn task_launch_context(task: &crate::state::Task) -> String {
+    let mut context = format!("Task: {}", task.title);
+    if let Some(desc) = &task.description
+        && !desc.is_empty()
+    {
+        context.push_str(&format!("\n\nDescription:\n{}", desc));
+    }
+    context
+}
+
+/// Branch of the task's worktree: the one recorded when its session was first
+/// launched, otherwise derived from the title
+fn task_branch(task: &crate::state::Task) -> String {
+    task.branch
+        .clone()
+        .unwrap_or_else(|| task_title_to_branch(&task.title, task.linear_issue_id.as_deref()))
+}
+
 /// Convert task title to a branch name slug.
 /// If linear_id is provided, prefixes the branch name with it (e.g., "AMB-67/add-feature").
 fn task_title_to_branch(title: &str, linear_id: Option<&str>) -> String {
@@ -1190,6 +2469,21 @@ fn task_title_to_branch(title: &str, linear_id: Option<&str>) -> String {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_idle_timer_restarts_after_handled_input() {
+        let timeout = std::time::Duration::from_secs(60);
+        let mut idle = IdleTimer::new(Some(timeout));
+        assert!(!idle.expired());
+
+        // The key that opened a foreground session was handled two minutes ago
+        idle.last_input -= 2 * timeout;
+        assert!(idle.expired());
+        idle.touch();
+        assert!(!idle.expired());
+
+        assert!(!IdleTimer::new(None).expired());
+    }
+
     #[test]
     fn test_task_title_to_branch_without_linear_id() {
         assert_eq!(task_title_to_branch("Hello World", None), "hello-world");
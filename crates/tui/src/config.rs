@@ -0,0 +1,353 @@
+use std::{path::PathBuf, str::FromStr, time::Duration};
+
+use ratatui::style::Color;
+use serde::Deserialize;
+
+use crate::{external::SessionLaunchMode, state::TaskStatus};
+
+const ASCII_SPINNER: &[char] = &['|', '/', '-', '\\'];
+const BRAILLE_SPINNER: &[char] = &['⠋', '⠙', '⠹', '⠸', '⠼', '⠴', '⠦', '⠧', '⠇', '⠏'];
+const DOTS_SPINNER: &[char] = &['.', 'o', 'O', 'o'];
+const DEFAULT_SERVER_TIMEOUT_SECS: u64 = 10;
+
+/// User configuration loaded from `~/.vibe/config.toml`
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default)]
+pub struct Config {
+    pub spinner: SpinnerConfig,
+    pub session: SessionConfig,
+    pub status: StatusConfig,
+    pub wip: WipConfig,
+    pub server: ServerConfig,
+    pub lock: LockConfig,
+}
+
+/// `[spinner]` section:
+///
+/// ```toml
+/// [spinner]
+/// style = "braille"   # ascii (default), braille, dots
+/// frames = "-=*=-"    # custom frames, one char each; overrides style
+/// color = "cyan"      # color name or hex like "#ffaa00"
+/// ```
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default)]
+pub struct SpinnerConfig {
+    pub style: Option<String>,
+    pub frames: Option<String>,
+    pub color: Option<String>,
+}
+
+/// `[session]` section:
+///
+/// ```toml
+/// [session]
+/// launch_mode = "pane"   # foreground (default) replaces the TUI, pane opens alongside it
+/// ```
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default)]
+pub struct SessionConfig {
+    pub launch_mode: Option<String>,
+}
+
+/// `[status]` section:
+///
+/// ```toml
+/// [status]
+/// done_on_merge = true   # store Done (and update Linear) when a task's PR merges
+/// ```
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default)]
+pub struct StatusConfig {
+    pub done_on_merge: bool,
+}
+
+/// `[wip]` section, the most cards a column should hold before it is flagged:
+///
+/// ```toml
+/// [wip]
+/// inprogress = 3
+/// inreview = 5
+/// ```
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default)]
+pub struct WipConfig {
+    pub backlog: Option<usize>,
+    pub inprogress: Option<usize>,
+    pub inreview: Option<usize>,
+    pub done: Option<usize>,
+}
+
+/// `[server]` section, for requests to a running vibe-kanban server:
+///
+/// ```toml
+/// [server]
+/// timeout_secs = 30   # give up on a request after this long (default 10)
+/// ```
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default)]
+pub struct ServerConfig {
+    pub timeout_secs: Option<u64>,
+}
+
+/// `[lock]` section, for boards left open on shared terminals:
+///
+/// ```toml
+/// [lock]
+/// idle_minutes = 15   # hide the board until a key is pressed after this long without input
+/// ```
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default)]
+pub struct LockConfig {
+    pub idle_minutes: Option<u64>,
+}
+
+/// Per-project settings loaded from `~/.vibe/projects/<project>/config.toml`:
+///
+/// ```toml
+/// [status]
+/// merged_is_done = false   # keep merged tasks in their own column instead of forcing Done
+/// ```
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default)]
+pub struct ProjectConfig {
+    pub status: ProjectStatusConfig,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default)]
+pub struct ProjectStatusConfig {
+    pub merged_is_done: Option<bool>,
+}
+
+impl Config {
+    pub fn path() -> PathBuf {
+        dirs::home_dir()
+            .unwrap_or_else(|| PathBuf::from("."))
+            .join(".vibe")
+            .join("config.toml")
+    }
+
+    /// Load the config file, falling back to defaults if it is missing or invalid
+    pub fn load() -> Self {
+        let path = Self::path();
+        let Ok(content) = std::fs::read_to_string(&path) else {
+            return Self::default();
+        };
+
+        match Self::parse(&content) {
+            Ok(config) => config,
+            Err(e) => {
+                tracing::warn!("Ignoring invalid config {:?}: {}", path, e);
+                Self::default()
+            }
+        }
+    }
+
+    pub fn parse(content: &str) -> Result<Self, toml::de::Error> {
+        toml::from_str(content)
+    }
+}
+
+impl ProjectConfig {
+    pub fn path(project_name: &str) -> PathBuf {
+        dirs::home_dir()
+            .unwrap_or_else(|| PathBuf::from("."))
+            .join(".vibe")
+            .join("projects")
+            .join(project_name)
+            .join("config.toml")
+    }
+
+    /// Load the project's config file, falling back to defaults if it is missing or invalid
+    pub fn load(project_name: &str) -> Self {
+        let path = Self::path(project_name);
+        let Ok(content) = std::fs::read_to_string(&path) else {
+            return Self::default();
+        };
+
+        match Self::parse(&content) {
+            Ok(config) => config,
+            Err(e) => {
+                tracing::warn!("Ignoring invalid project config {:?}: {}", path, e);
+                Self::default()
+            }
+        }
+    }
+
+    pub fn parse(content: &str) -> Result<Self, toml::de::Error> {
+        toml::from_str(content)
+    }
+
+    /// Whether a merged PR puts the task in Done whatever its stored status (default on)
+    pub fn merged_is_done(&self) -> bool {
+        self.status.merged_is_done.unwrap_or(true)
+    }
+}
+
+impl SpinnerConfig {
+    pub fn frames(&self) -> Vec<char> {
+        if let Some(frames) = &self.frames {
+            let frames: Vec<char> = frames.chars().filter(|c| !c.is_whitespace()).collect();
+            if !frames.is_empty() {
+                return frames;
+            }
+        }
+
+        match self.style.as_deref() {
+            None | Some("ascii") => ASCII_SPINNER.to_vec(),
+            Some("braille") => BRAILLE_SPINNER.to_vec(),
+            Some("dots") => DOTS_SPINNER.to_vec(),
+            Some(other) => {
+                tracing::warn!("Unknown spinner style '{}', using ascii", other);
+                ASCII_SPINNER.to_vec()
+            }
+        }
+    }
+
+    pub fn color(&self) -> Color {
+        match self.color.as_deref() {
+            None => Color::Yellow,
+            Some(name) => Color::from_str(name).unwrap_or_else(|_| {
+                tracing::warn!("Unknown spinner color '{}', using yellow", name);
+                Color::Yellow
+            }),
+        }
+    }
+}
+
+impl SessionConfig {
+    pub fn launch_mode(&self) -> SessionLaunchMode {
+        match self.launch_mode.as_deref() {
+            None => SessionLaunchMode::default(),
+            Some(value) => SessionLaunchMode::parse(value).unwrap_or_else(|| {
+                tracing::warn!("Unknown session launch_mode '{}', using foreground", value);
+                SessionLaunchMode::default()
+            }),
+        }
+    }
+}
+
+impl ServerConfig {
+    pub fn timeout(&self) -> Duration {
+        Duration::from_secs(
+            self.timeout_secs
+                .filter(|secs| *secs > 0)
+                .unwrap_or(DEFAULT_SERVER_TIMEOUT_SECS),
+        )
+    }
+}
+
+impl LockConfig {
+    /// None when locking is off (unset or 0)
+    pub fn idle_timeout(&self) -> Option<Duration> {
+        self.idle_minutes
+            .filter(|minutes| *minutes > 0)
+            .map(|minutes| Duration::from_secs(minutes * 60))
+    }
+}
+
+impl WipConfig {
+    pub fn limit(&self, status: TaskStatus) -> Option<usize> {
+        match status {
+            TaskStatus::Backlog => self.backlog,
+            TaskStatus::Inprogress => self.inprogress,
+            TaskStatus::Inreview => self.inreview,
+            TaskStatus::Done => self.done,
+            TaskStatus::Todo | TaskStatus::Cancelled => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_default_spinner() {
+        let config = Config::parse("").unwrap();
+        assert_eq!(config.spinner.frames(), vec!['|', '/', '-', '\\']);
+        assert_eq!(config.spinner.color(), Color::Yellow);
+    }
+
+    #[test]
+    fn test_spinner_style_and_color() {
+        let config = Config::parse("[spinner]\nstyle = \"braille\"\ncolor = \"cyan\"\n").unwrap();
+        assert_eq!(config.spinner.frames().len(), 10);
+        assert_eq!(config.spinner.color(), Color::Cyan);
+    }
+
+    #[test]
+    fn test_spinner_custom_frames_override_style() {
+        let config = Config::parse("[spinner]\nstyle = \"braille\"\nframes = \"-+\"\n").unwrap();
+        assert_eq!(config.spinner.frames(), vec!['-', '+']);
+    }
+
+    #[test]
+    fn test_spinner_invalid_values_fall_back() {
+        let config = Config::parse("[spinner]\nstyle = \"nope\"\ncolor = \"nope\"\n").unwrap();
+        assert_eq!(config.spinner.frames(), vec!['|', '/', '-', '\\']);
+        assert_eq!(config.spinner.color(), Color::Yellow);
+    }
+
+    #[test]
+    fn test_session_launch_mode() {
+        let config = Config::parse("").unwrap();
+        assert_eq!(config.session.launch_mode(), SessionLaunchMode::Foreground);
+
+        let config = Config::parse("[session]\nlaunch_mode = \"pane\"\n").unwrap();
+        assert_eq!(config.session.launch_mode(), SessionLaunchMode::Pane);
+    }
+
+    #[test]
+    fn test_status_done_on_merge() {
+        let config = Config::parse("").unwrap();
+        assert!(!config.status.done_on_merge);
+
+        let config = Config::parse("[status]\ndone_on_merge = true\n").unwrap();
+        assert!(config.status.done_on_merge);
+    }
+
+    #[test]
+    fn test_project_merged_is_done() {
+        let config = ProjectConfig::parse("").unwrap();
+        assert!(config.merged_is_done());
+
+        let config = ProjectConfig::parse("[status]\nmerged_is_done = false\n").unwrap();
+        assert!(!config.merged_is_done());
+    }
+
+    #[test]
+    fn test_wip_limits() {
+        let config = Config::parse("").unwrap();
+        assert_eq!(config.wip.limit(TaskStatus::Inprogress), None);
+
+        let config = Config::parse("[wip]\ninprogress = 3\n").unwrap();
+        assert_eq!(config.wip.limit(TaskStatus::Inprogress), Some(3));
+        assert_eq!(config.wip.limit(TaskStatus::Inreview), None);
+    }
+
+    #[test]
+    fn test_server_timeout() {
+        let config = Config::parse("").unwrap();
+        assert_eq!(config.server.timeout(), Duration::from_secs(10));
+
+        let config = Config::parse("[server]\ntimeout_secs = 30\n").unwrap();
+        assert_eq!(config.server.timeout(), Duration::from_secs(30));
+
+        let config = Config::parse("[server]\ntimeout_secs = 0\n").unwrap();
+        assert_eq!(config.server.timeout(), Duration::from_secs(10));
+    }
+
+    #[test]
+    fn test_lock_idle_timeout() {
+        let config = Config::parse("").unwrap();
+        assert_eq!(config.lock.idle_timeout(), None);
+
+        let config = Config::parse("[lock]\nidle_minutes = 15\n").unwrap();
+        assert_eq!(config.lock.idle_timeout(), Some(Duration::from_secs(900)));
+
+        let config = Config::parse("[lock]\nidle_minutes = 0\n").unwrap();
+        assert_eq!(config.lock.idle_timeout(), None);
+    }
+}
@@ -0,0 +1,189 @@
+use std::{
+    fs,
+    io::{BufRead, BufReader},
+    path::{Path, PathBuf},
+};
+
+use serde::Deserialize;
+
+/// Longest preview shown for a conversation in the resume picker
+const PREVIEW_MAX_CHARS: usize = 80;
+
+/// A past Claude Code conversation recorded for a worktree
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ClaudeSessionInfo {
+    /// Session id accepted by `claude --resume`
+    pub id: String,
+    /// Last time the conversation was written to, local time
+    pub modified_at: String,
+    /// Summary or first prompt of the conversation
+    pub preview: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct SessionLine {
+    #[serde(rename = "type")]
+    kind: Option<String>,
+    summary: Option<String>,
+    #[serde(rename = "isMeta", default)]
+    is_meta: bool,
+    message: Option<SessionMessage>,
+}
+
+#[derive(Debug, Deserialize)]
+struct SessionMessage {
+    content: serde_json::Value,
+}
+
+/// List the Claude Code conversations recorded for a worktree, newest first.
+///
+/// Claude Code keeps one JSONL file per conversation in
+/// `~/.claude/projects/{sanitized-path}/{session-id}.jsonl`.
+pub fn list_claude_sessions(worktree_path: &Path) -> Vec<ClaudeSessionInfo> {
+    let Some(projects_dir) = dirs::home_dir().map(|h| h.join(".claude").join("projects")) else {
+        return Vec::new();
+    };
+    let session_dir = projects_dir.join(claude_project_dir_name(worktree_path));
+
+    let Ok(entries) = fs::read_dir(&session_dir) else {
+        return Vec::new();
+    };
+
+    let mut files: Vec<(PathBuf, std::time::SystemTime)> = entries
+        .flatten()
+        .map(|e| e.path())
+        .filter(|p| p.extension().map(|ext| ext == "jsonl").unwrap_or(false))
+        .filter_map(|p| {
+            let modified = fs::metadata(&p).ok()?.modified().ok()?;
+            Some((p, modified))
+        })
+        .collect();
+    files.sort_by(|a, b| b.1.cmp(&a.1));
+
+    files
+        .into_iter()
+        .filter_map(|(path, modified)| {
+            let id = path.file_stem()?.to_string_lossy().to_string();
+            let preview = read_preview(&path)?;
+            let modified: chrono::DateTime<chrono::Local> = modified.into();
+            Some(ClaudeSessionInfo {
+                id,
+                modified_at: modified.format("%Y-%m-%d %H:%M").to_string(),
+                preview,
+            })
+        })
+        .collect()
+}
+
+/// Summary line if Claude wrote one, otherwise the first user prompt.
+/// Files without either (e.g. only snapshots) are not resumable conversations.
+fn read_preview(path: &Path) -> Option<String> {
+    let file = fs::File::open(path).ok()?;
+    let reader = BufReader::new(file);
+
+    for line in reader.lines().map_while(Result::ok) {
+        let Ok(entry) = serde_json::from_str::<SessionLine>(&line) else {
+            continue;
+        };
+        match entry.kind.as_deref() {
+            Some("summary") => {
+                if let Some(summary) = entry.summary.filter(|s| !s.trim().is_empty()) {
+                    return Some(truncate_preview(&summary));
+                }
+            }
+            Some("user") if !entry.is_meta => {
+                if let Some(text) = entry.message.and_then(|m| message_text(&m.content)) {
+                    return Some(truncate_preview(&text));
+                }
+            }
+            _ => {}
+        }
+    }
+
+    None
+}
+
+/// Text of a user message; content is either a string or a list of blocks
+fn message_text(content: &serde_json::Value) -> Option<String> {
+    let text = match content {
+        serde_json::Value::String(s) => s.clone(),
+        serde_json::Value::Array(blocks) => blocks
+            .iter()
+            .filter(|b| b.get("type").and_then(|t| t.as_str()) == Some("text"))
+            .filter_map(|b| b.get("text").and_then(|t| t.as_str()))
+            .collect::<Vec<_>>()
+            .join(" "),
+        _ => return None,
+    };
+    let text = text.trim();
+    (!text.is_empty()).then(|| text.to_string())
+}
+
+fn truncate_preview(text: &str) -> String {
+    let line = text.split_whitespace().collect::<Vec<_>>().join(" ");
+    if line.chars().count() <= PREVIEW_MAX_CHARS {
+        return line;
+    }
+    let truncated: String = line.chars().take(PREVIEW_MAX_CHARS - 3).collect();
+    format!("{}...", truncated)
+}
+
+/// Claude Code names a project's directory after its path with every
+/// non-alphanumeric character replaced by a dash.
+fn claude_project_dir_name(path: &Path) -> String {
+    path.to_string_lossy()
+        .chars()
+        .map(|c| if c.is_ascii_alphanumeric() { c } else { '-' })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_claude_project_dir_name() {
+        assert_eq!(
+            claude_project_dir_name(Path::new("/Users/test/my-app.feature_x")),
+            "-Users-test-my-app-feature-x"
+        );
+    }
+
+    #[test]
+    fn test_read_preview_prefers_summary_then_first_prompt() {
+        let dir = tempfile::tempdir().unwrap();
+
+        let with_summary = dir.path().join("a.jsonl");
+        fs::write(
+            &with_summary,
+            concat!(
+                r#"{"type":"summary","summary":"Fix login flow"}"#,
+                "\n",
+                r#"{"type":"user","message":{"role":"user","content":"hello"}}"#,
+                "\n"
+            ),
+        )
+        .unwrap();
+        assert_eq!(
+            read_preview(&with_summary).as_deref(),
+            Some("Fix login flow")
+        );
+
+        let with_prompt = dir.path().join("b.jsonl");
+        fs::write(
+            &with_prompt,
+            concat!(
+                r#"{"type":"user","isMeta":true,"message":{"role":"user","content":"meta"}}"#,
+                "\n",
+                r#"{"type":"user","message":{"content":[{"type":"text","text":"add\n tests"}]}}"#,
+                "\n"
+            ),
+        )
+        .unwrap();
+        assert_eq!(read_preview(&with_prompt).as_deref(), Some("add tests"));
+
+        let empty = dir.path().join("c.jsonl");
+        fs::write(&empty, r#"{"type":"file-history-snapshot"}"#).unwrap();
+        assert_eq!(read_preview(&empty), None);
+    }
+}
@@ -0,0 +1,50 @@
+use std::{
+    io::Write,
+    process::{Command, Stdio},
+};
+
+use anyhow::{Context, Result};
+
+use super::command::run_spawn;
+
+/// Clipboard commands to try, in order of preference for the current platform
+#[cfg(target_os = "macos")]
+const CLIPBOARD_COMMANDS: &[(&str, &[&str])] = &[("pbcopy", &[])];
+
+#[cfg(target_os = "windows")]
+const CLIPBOARD_COMMANDS: &[(&str, &[&str])] = &[("clip", &[])];
+
+#[cfg(not(any(target_os = "macos", target_os = "windows")))]
+const CLIPBOARD_COMMANDS: &[(&str, &[&str])] = &[
+    ("wl-copy", &[]),
+    ("xclip", &["-selection", "clipboard"]),
+    ("xsel", &["--clipboard", "--input"]),
+];
+
+/// Copy text to the system clipboard using the platform clipboard tool
+pub fn copy_to_clipboard(text: &str) -> Result<()> {
+    for (program, args) in CLIPBOARD_COMMANDS {
+        let Ok(mut child) = run_spawn(
+            Command::new(program)
+                .args(*args)
+                .stdin(Stdio::piped())
+                .stdout(Stdio::null())
+                .stderr(Stdio::null()),
+        ) else {
+            continue;
+        };
+
+        child
+            .stdin
+            .take()
+            .context("Failed to open clipboard stdin")?
+            .write_all(text.as_bytes())?;
+
+        let status = child.wait()?;
+        if status.success() {
+            return Ok(());
+        }
+    }
+
+    anyhow::bail!("No clipboard tool available (tried pbcopy/wl-copy/xclip/xsel/clip)")
+}
@@ -0,0 +1,83 @@
+//! Runs the external tools (wt, gh, zellij, tmux, ...) so every invocation is
+//! logged the same way: argv, exit status and stderr at debug level.
+
+use std::process::{Child, Command, ExitStatus, Output};
+
+/// Run a command to completion, capturing stdout/stderr
+pub fn run_output(cmd: &mut Command) -> std::io::Result<Output> {
+    let argv = describe(cmd);
+    match cmd.output() {
+        Ok(output) => {
+            tracing::debug!(
+                command = %argv,
+                status = %output.status,
+                stderr = %String::from_utf8_lossy(&output.stderr).trim(),
+                "external command finished"
+            );
+            Ok(output)
+        }
+        Err(e) => {
+            tracing::debug!(command = %argv, error = %e, "external command failed to run");
+            Err(e)
+        }
+    }
+}
+
+/// Run a command to completion with inherited stdio. Needed when the child
+/// takes over our TTY (wt -x, zellij attach), so stderr is not captured.
+pub fn run_status(cmd: &mut Command) -> std::io::Result<ExitStatus> {
+    let argv = describe(cmd);
+    match cmd.status() {
+        Ok(status) => {
+            tracing::debug!(command = %argv, status = %status, "external command finished");
+            Ok(status)
+        }
+        Err(e) => {
+            tracing::debug!(command = %argv, error = %e, "external command failed to run");
+            Err(e)
+        }
+    }
+}
+
+/// Start a command without waiting for it
+pub fn run_spawn(cmd: &mut Command) -> std::io::Result<Child> {
+    let argv = describe(cmd);
+    match cmd.spawn() {
+        Ok(child) => {
+            tracing::debug!(command = %argv, pid = child.id(), "external command spawned");
+            Ok(child)
+        }
+        Err(e) => {
+            tracing::debug!(command = %argv, error = %e, "external command failed to spawn");
+            Err(e)
+        }
+    }
+}
+
+/// Program and arguments as a single shell-like line, quoting arguments with spaces
+fn describe(cmd: &Command) -> String {
+    std::iter::once(cmd.get_program())
+        .chain(cmd.get_args())
+        .map(|arg| {
+            let arg = arg.to_string_lossy();
+            if arg.is_empty() || arg.contains(char::is_whitespace) {
+                format!("{:?}", arg)
+            } else {
+                arg.into_owned()
+            }
+        })
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_describe_quotes_arguments_with_spaces() {
+        let mut cmd = Command::new("wt");
+        cmd.args(["switch", "--create", "my branch", ""]);
+        assert_eq!(describe(&cmd), r#"wt switch --create "my branch" """#);
+    }
+}
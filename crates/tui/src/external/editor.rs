@@ -3,6 +3,8 @@ use std::process::Command;
 use anyhow::Result;
 use tempfile::NamedTempFile;
 
+use super::command::run_status;
+
 pub fn edit_in_editor(initial_content: &str, file_extension: &str) -> Result<Option<String>> {
     let editor = std::env::var("EDITOR").unwrap_or_else(|_| "nvim".to_string());
 
@@ -12,7 +14,7 @@ pub fn edit_in_editor(initial_content: &str, file_extension: &str) -> Result<Opt
     let named_path = temp_path.with_extension(file_extension);
     std::fs::write(&named_path, initial_content)?;
 
-    let status = Command::new(&editor).arg(&named_path).status()?;
+    let status = run_status(Command::new(&editor).arg(&named_path))?;
 
     if !status.success() {
         std::fs::remove_file(&named_path).ok();
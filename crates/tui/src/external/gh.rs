@@ -2,11 +2,12 @@ use anyhow::Result;
 use serde::Deserialize;
 use std::process::Command;
 
+use super::command::run_output;
+
 /// PR info fetched from `gh pr view`
 #[derive(Debug, Clone, Deserialize)]
 pub struct BranchPrInfo {
-    #[serde(rename = "number")]
-    pub _number: i64,
+    pub number: u64,
     pub url: String,
     pub state: String, // OPEN, CLOSED, MERGED
     #[serde(rename = "isDraft")]
@@ -17,6 +18,13 @@ pub struct BranchPrInfo {
     pub status_check_rollup: Option<Vec<StatusCheck>>,
     #[serde(rename = "mergeable")]
     pub mergeable: Option<String>, // MERGEABLE, CONFLICTING, UNKNOWN
+    /// Branch the PR was opened from; stays the same if the local branch is renamed
+    #[serde(rename = "headRefName", default)]
+    pub head_ref_name: String,
+    #[serde(default)]
+    pub additions: u64,
+    #[serde(default)]
+    pub deletions: u64,
 }
 
 #[derive(Debug, Clone, Deserialize)]
@@ -68,15 +76,54 @@ impl BranchPrInfo {
 /// Get PR info for a specific branch using `gh pr view`
 /// Returns None if no PR exists for the branch
 pub fn get_pr_for_branch(branch: &str) -> Result<Option<BranchPrInfo>> {
-    let output = Command::new("gh")
-        .args([
-            "pr",
-            "view",
-            branch,
-            "--json",
-            "number,url,state,isDraft,reviewDecision,statusCheckRollup,mergeable",
-        ])
-        .output()?;
+    view_pr(branch)
+}
+
+/// Get PR info by number, regardless of what its branch is called locally
+pub fn get_pr_by_number(number: u64) -> Result<Option<BranchPrInfo>> {
+    view_pr(&number.to_string())
+}
+
+/// Git config naming the remote PRs live on, e.g. `upstream` in a fork
+const PR_REMOTE_CONFIG_KEY: &str = "vibe-kanban.prRemote";
+
+/// `owner/repo` of the configured PR remote; None leaves the choice to gh
+fn configured_pr_repo() -> Option<String> {
+    let git = |args: &[&str]| {
+        let output = run_output(Command::new("git").args(args)).ok()?;
+        let value = String::from_utf8(output.stdout).ok()?.trim().to_string();
+        (output.status.success() && !value.is_empty()).then_some(value)
+    };
+    let remote = git(&["config", "--get", PR_REMOTE_CONFIG_KEY])?;
+    let url = git(&["remote", "get-url", &remote])?;
+    github_repo_from_url(&url)
+}
+
+/// `owner/repo` from an SSH or HTTPS GitHub remote URL
+fn github_repo_from_url(url: &str) -> Option<String> {
+    let (_, path) = url.split_once("github.com")?;
+    let path = path.trim_start_matches([':', '/']);
+    let mut parts = path.split('/');
+    let owner = parts.next().filter(|s| !s.is_empty())?;
+    let repo = parts.next()?.trim_end_matches(".git");
+    (!repo.is_empty()).then(|| format!("{}/{}", owner, repo))
+}
+
+/// `gh pr view` accepts a branch name, PR number or URL
+fn view_pr(selector: &str) -> Result<Option<BranchPrInfo>> {
+    let mut cmd = Command::new("gh");
+    cmd.args([
+        "pr",
+        "view",
+        selector,
+        "--json",
+        "number,url,state,isDraft,reviewDecision,statusCheckRollup,mergeable,headRefName,\
+         additions,deletions",
+    ]);
+    if let Some(repo) = configured_pr_repo() {
+        cmd.args(["--repo", &repo]);
+    }
+    let output = run_output(&mut cmd)?;
 
     if !output.status.success() {
         let stderr = String::from_utf8_lossy(&output.stderr);
@@ -93,3 +140,24 @@ pub fn get_pr_for_branch(branch: &str) -> Result<Option<BranchPrInfo>> {
     let pr_info: BranchPrInfo = serde_json::from_str(&stdout)?;
     Ok(Some(pr_info))
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_github_repo_from_url() {
+        assert_eq!(
+            github_repo_from_url("git@github.com:acme/project.git").as_deref(),
+            Some("acme/project")
+        );
+        assert_eq!(
+            github_repo_from_url("https://github.com/acme/project").as_deref(),
+            Some("acme/project")
+        );
+        assert_eq!(
+            github_repo_from_url("https://gitlab.com/acme/project"),
+            None
+        );
+    }
+}
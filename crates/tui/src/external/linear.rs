@@ -1,5 +1,14 @@
-use reqwest::Client;
-use serde::Deserialize;
+use std::{path::Path, sync::LazyLock, time::Duration};
+
+use reqwest::{Certificate, Client};
+use serde::{Deserialize, de::DeserializeOwned};
+
+/// Path to a PEM bundle of extra root certificates, e.g. a corporate proxy's CA
+const CA_CERT_ENV: &str = "VIBE_CA_CERT";
+
+/// Shared by every Linear client so connections are reused across fetches.
+/// Proxies come from `HTTPS_PROXY`/`ALL_PROXY`/`NO_PROXY`, which reqwest reads itself.
+static HTTP_CLIENT: LazyLock<Client> = LazyLock::new(build_http_client);
 
 #[derive(Debug, Clone)]
 pub struct LinearIssue {
@@ -56,6 +65,44 @@ struct LabelNode {
     name: String,
 }
 
+#[derive(Debug, Deserialize)]
+struct IssueStatesData {
+    issue: IssueWithTeam,
+}
+
+#[derive(Debug, Deserialize)]
+struct IssueWithTeam {
+    id: String,
+    team: TeamNode,
+}
+
+#[derive(Debug, Deserialize)]
+struct TeamNode {
+    states: StateConnection,
+}
+
+#[derive(Debug, Deserialize)]
+struct StateConnection {
+    nodes: Vec<StateNode>,
+}
+
+#[derive(Debug, Deserialize)]
+struct StateNode {
+    id: String,
+    position: f64,
+}
+
+#[derive(Debug, Deserialize)]
+struct IssueUpdateData {
+    #[serde(rename = "issueUpdate")]
+    issue_update: MutationResult,
+}
+
+#[derive(Debug, Deserialize)]
+struct MutationResult {
+    success: bool,
+}
+
 pub struct LinearClient {
     http: Client,
     api_key: String,
@@ -66,7 +113,7 @@ impl LinearClient {
 
     pub fn new(api_key: String) -> Self {
         Self {
-            http: Client::new(),
+            http: HTTP_CLIENT.clone(),
             api_key,
         }
     }
@@ -93,8 +140,80 @@ impl LinearClient {
             }
         "#;
 
-        let body = serde_json::json!({ "query": query });
+        let data: ViewerData = self.query(serde_json::json!({ "query": query })).await?;
+        let issues = data
+            .viewer
+            .assigned_issues
+            .map(|c| c.nodes)
+            .unwrap_or_default();
 
+        Ok(issues
+            .into_iter()
+            .map(|node| LinearIssue {
+                identifier: node.identifier,
+                title: node.title,
+                description: node.description,
+                url: node.url,
+                labels: node
+                    .labels
+                    .map(|l| l.nodes.into_iter().map(|n| n.name).collect())
+                    .unwrap_or_default(),
+            })
+            .collect())
+    }
+
+    /// Move an issue (by identifier, e.g. "VIB-6") to its team's first completed state
+    pub async fn mark_issue_done(&self, identifier: &str) -> Result<(), String> {
+        let query = r#"
+            query($id: String!) {
+                issue(id: $id) {
+                    id
+                    team {
+                        states(filter: { type: { eq: "completed" } }) {
+                            nodes {
+                                id
+                                position
+                            }
+                        }
+                    }
+                }
+            }
+        "#;
+
+        let data: IssueStatesData = self
+            .query(serde_json::json!({ "query": query, "variables": { "id": identifier } }))
+            .await?;
+        let state = data
+            .issue
+            .team
+            .states
+            .nodes
+            .into_iter()
+            .min_by(|a, b| a.position.total_cmp(&b.position))
+            .ok_or_else(|| format!("No completed state for {}", identifier))?;
+
+        let mutation = r#"
+            mutation($id: String!, $stateId: String!) {
+                issueUpdate(id: $id, input: { stateId: $stateId }) {
+                    success
+                }
+            }
+        "#;
+
+        let data: IssueUpdateData = self
+            .query(serde_json::json!({
+                "query": mutation,
+                "variables": { "id": data.issue.id, "stateId": state.id },
+            }))
+            .await?;
+        if !data.issue_update.success {
+            return Err(format!("Linear refused to update {}", identifier));
+        }
+
+        Ok(())
+    }
+
+    async fn query<T: DeserializeOwned>(&self, body: serde_json::Value) -> Result<T, String> {
         let response = self
             .http
             .post(Self::API_URL)
@@ -114,7 +233,7 @@ impl LinearClient {
             ));
         }
 
-        let result: GraphQLResponse<ViewerData> = response
+        let result: GraphQLResponse<T> = response
             .json()
             .await
             .map_err(|e| format!("JSON parse error: {}", e))?;
@@ -128,27 +247,40 @@ impl LinearClient {
             return Err(format!("GraphQL error: {}", msg));
         }
 
-        let data = result.data.ok_or("No data in response")?;
-        let issues = data
-            .viewer
-            .assigned_issues
-            .map(|c| c.nodes)
-            .unwrap_or_default();
+        result.data.ok_or_else(|| "No data in response".to_string())
+    }
+}
 
-        Ok(issues
-            .into_iter()
-            .map(|node| LinearIssue {
-                identifier: node.identifier,
-                title: node.title,
-                description: node.description,
-                url: node.url,
-                labels: node
-                    .labels
-                    .map(|l| l.nodes.into_iter().map(|n| n.name).collect())
-                    .unwrap_or_default(),
-            })
-            .collect())
+fn build_http_client() -> Client {
+    let mut builder = Client::builder().connect_timeout(Duration::from_secs(15));
+
+    if let Some(path) = std::env::var_os(CA_CERT_ENV) {
+        match load_root_certificates(Path::new(&path)) {
+            Ok(certs) => {
+                for cert in certs {
+                    builder = builder.add_root_certificate(cert);
+                }
+            }
+            Err(e) => tracing::warn!("Ignoring {}={:?}: {}", CA_CERT_ENV, path, e),
+        }
     }
+
+    builder.build().unwrap_or_else(|e| {
+        tracing::warn!(
+            "Failed to build configured HTTP client, using defaults: {}",
+            e
+        );
+        Client::new()
+    })
+}
+
+fn load_root_certificates(path: &Path) -> Result<Vec<Certificate>, String> {
+    let pem = std::fs::read(path).map_err(|e| e.to_string())?;
+    let certs = Certificate::from_pem_bundle(&pem).map_err(|e| e.to_string())?;
+    if certs.is_empty() {
+        return Err("no certificates found".to_string());
+    }
+    Ok(certs)
 }
 
 #[cfg(test)]
@@ -181,4 +313,14 @@ mod tests {
             }
         }
     }
+
+    #[test]
+    fn test_rejects_missing_or_empty_ca_bundles() {
+        let dir = tempfile::tempdir().unwrap();
+        assert!(load_root_certificates(&dir.path().join("missing.pem")).is_err());
+
+        let empty = dir.path().join("empty.pem");
+        std::fs::write(&empty, "not a certificate\n").unwrap();
+        assert!(load_root_certificates(&empty).is_err());
+    }
 }
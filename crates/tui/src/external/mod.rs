@@ -1,5 +1,8 @@
 mod claude_activity;
 mod claude_plans;
+mod claude_sessions;
+mod clipboard;
+mod command;
 mod editor;
 mod gh;
 mod linear;
@@ -7,15 +10,21 @@ mod linear;
 mod notifications;
 #[allow(dead_code)]
 mod opener;
+mod server;
 mod terminal_spawn;
+mod tmux;
 mod worktrunk;
 mod zellij;
 
 pub use claude_activity::ClaudeActivityTracker;
 pub use claude_plans::ClaudePlanReader;
+pub use claude_sessions::{ClaudeSessionInfo, list_claude_sessions};
+pub use clipboard::copy_to_clipboard;
 pub use editor::edit_markdown;
 pub use gh::*;
 pub use linear::{LinearClient, LinearIssue};
+pub use opener::open_file;
+pub use server::{ProcessLogEvent, ServerClient, TimelineEvent};
 pub use terminal_spawn::*;
 pub use worktrunk::*;
 pub use zellij::*;
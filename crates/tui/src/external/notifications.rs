@@ -2,26 +2,22 @@ use std::process::Command;
 
 use anyhow::Result;
 
+use super::command::run_spawn;
+
 /// Send a system notification
 pub fn notify(title: &str, body: &str) -> Result<()> {
     #[cfg(target_os = "macos")]
     {
-        Command::new("osascript")
-            .arg("-e")
-            .arg(format!(
-                r#"display notification "{}" with title "{}""#,
-                body.replace('"', r#"\""#),
-                title.replace('"', r#"\""#)
-            ))
-            .spawn()?;
+        run_spawn(Command::new("osascript").arg("-e").arg(format!(
+            r#"display notification "{}" with title "{}""#,
+            body.replace('"', r#"\""#),
+            title.replace('"', r#"\""#)
+        )))?;
     }
 
     #[cfg(target_os = "linux")]
     {
-        Command::new("notify-send")
-            .arg(title)
-            .arg(body)
-            .spawn()?;
+        run_spawn(Command::new("notify-send").arg(title).arg(body))?;
     }
 
     #[cfg(target_os = "windows")]
@@ -2,21 +2,23 @@ use std::process::Command;
 
 use anyhow::Result;
 
+use super::command::run_spawn;
+
 /// Open a URL in the system default browser
 pub fn open_url(url: &str) -> Result<()> {
     #[cfg(target_os = "macos")]
     {
-        Command::new("open").arg(url).spawn()?;
+        run_spawn(Command::new("open").arg(url))?;
     }
 
     #[cfg(target_os = "linux")]
     {
-        Command::new("xdg-open").arg(url).spawn()?;
+        run_spawn(Command::new("xdg-open").arg(url))?;
     }
 
     #[cfg(target_os = "windows")]
     {
-        Command::new("cmd").args(["/C", "start", "", url]).spawn()?;
+        run_spawn(Command::new("cmd").args(["/C", "start", "", url]))?;
     }
 
     Ok(())
@@ -26,17 +28,17 @@ pub fn open_url(url: &str) -> Result<()> {
 pub fn open_file(path: &str) -> Result<()> {
     #[cfg(target_os = "macos")]
     {
-        Command::new("open").arg(path).spawn()?;
+        run_spawn(Command::new("open").arg(path))?;
     }
 
     #[cfg(target_os = "linux")]
     {
-        Command::new("xdg-open").arg(path).spawn()?;
+        run_spawn(Command::new("xdg-open").arg(path))?;
     }
 
     #[cfg(target_os = "windows")]
     {
-        Command::new("cmd").args(["/C", "start", "", path]).spawn()?;
+        run_spawn(Command::new("cmd").args(["/C", "start", "", path]))?;
     }
 
     Ok(())
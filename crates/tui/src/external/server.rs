@@ -0,0 +1,577 @@
+use std::{
+    fmt,
+    path::{Path, PathBuf},
+    time::Duration,
+};
+
+use futures_util::StreamExt;
+use reqwest::{
+    Client, RequestBuilder,
+    header::{AUTHORIZATION, HeaderMap, HeaderValue},
+};
+use serde::{Deserialize, de::DeserializeOwned};
+use tokio::sync::mpsc;
+use tokio_tungstenite::tungstenite::{self, Message, client::IntoClientRequest};
+
+const USER_AGENT: &str = concat!("vibe-tui/", env!("CARGO_PKG_VERSION"));
+
+/// Bearer token of a server started with `VIBE_API_TOKEN`
+const API_TOKEN_ENV: &str = "VIBE_API_TOKEN";
+
+/// Wait before reconnecting a dropped log tail, doubled on every failure in a row
+const TAIL_RECONNECT_BASE_DELAY: Duration = Duration::from_secs(2);
+const TAIL_RECONNECT_MAX_DELAY: Duration = Duration::from_secs(30);
+/// A connection that lasted this long resets the backoff
+const TAIL_STABLE_CONNECTION: Duration = Duration::from_secs(10);
+
+/// What a live tail of an execution process's logs reports
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ProcessLogEvent {
+    /// (Re)connected; the server replays the process's logs from the start
+    Connected,
+    /// Stdout output, possibly several lines
+    Output(String),
+    /// Raw stderr output, where spawn failures and executor errors show up
+    Stderr(String),
+    /// The process is done and the tail has ended
+    Finished,
+    /// The connection dropped; it is retried after the given delay
+    Disconnected(String, Duration),
+    /// The server refused the tail, e.g. for an unknown process; not retried
+    Failed(String),
+}
+
+/// Client for a locally running vibe-kanban server. The TUI works without one;
+/// this is only used for actions that act on the server's own state.
+pub struct ServerClient {
+    base_url: String,
+    client: Client,
+    timeout: Duration,
+    /// `Authorization` value for the WebSocket handshake; HTTP requests get it
+    /// as a default header of `client`
+    authorization: Option<HeaderValue>,
+}
+
+#[derive(Debug, Deserialize)]
+struct ApiResponse<T> {
+    success: bool,
+    data: Option<T>,
+    message: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct Project {
+    id: String,
+    #[serde(default)]
+    favorite: bool,
+}
+
+#[derive(Debug, Deserialize)]
+struct Repo {
+    path: PathBuf,
+}
+
+#[derive(Debug, Deserialize)]
+struct WarmSearchCacheResponse {
+    repo_count: usize,
+}
+
+/// A task attempt (workspace) on the server
+#[derive(Debug, Deserialize)]
+struct TaskAttempt {
+    id: String,
+    task_id: String,
+    branch: String,
+}
+
+/// Response of the rebase endpoint; failures carry a `GitOperationError`
+#[derive(Debug, Deserialize)]
+struct RebaseResponse {
+    success: bool,
+    error_data: Option<GitOperationError>,
+    message: Option<String>,
+}
+
+/// Why the server could not rebase a branch
+#[derive(Debug, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+enum GitOperationError {
+    MergeConflicts {
+        message: String,
+        #[serde(default)]
+        conflicted_files: Vec<String>,
+    },
+    RebaseInProgress,
+}
+
+impl fmt::Display for GitOperationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::MergeConflicts {
+                message,
+                conflicted_files,
+            } => {
+                write!(f, "{}", message)?;
+                if !conflicted_files.is_empty() {
+                    write!(f, " (conflicts in {})", conflicted_files.join(", "))?;
+                }
+                Ok(())
+            }
+            Self::RebaseInProgress => {
+                f.write_str("a rebase is already in progress; resolve or abort it first")
+            }
+        }
+    }
+}
+
+/// One entry of a task's activity timeline on the server
+#[derive(Debug, Clone, Deserialize)]
+pub struct TimelineEvent {
+    pub at: String,
+    pub summary: String,
+}
+
+impl ServerClient {
+    /// Find the server the same way the MCP server does: `BACKEND_PORT`/`PORT`,
+    /// otherwise the port file the server writes on startup. Requests that take
+    /// longer than `timeout` fail instead of hanging the action, and carry the
+    /// `VIBE_API_TOKEN` bearer token when one is set.
+    pub fn discover(timeout: Duration) -> Result<Self, String> {
+        let port = match std::env::var("BACKEND_PORT").or_else(|_| std::env::var("PORT")) {
+            Ok(port) => port
+                .parse()
+                .map_err(|e| format!("Invalid server port '{}': {}", port, e))?,
+            Err(_) => read_port_file()?,
+        };
+        let host = std::env::var("HOST").unwrap_or_else(|_| "127.0.0.1".to_string());
+
+        let authorization = std::env::var(API_TOKEN_ENV)
+            .ok()
+            .map(|token| token.trim().to_string())
+            .filter(|token| !token.is_empty())
+            .map(|token| {
+                let mut value = HeaderValue::from_str(&format!("Bearer {}", token))
+                    .map_err(|e| format!("Invalid {}: {}", API_TOKEN_ENV, e))?;
+                value.set_sensitive(true);
+                Ok::<_, String>(value)
+            })
+            .transpose()?;
+        let mut headers = HeaderMap::new();
+        if let Some(value) = &authorization {
+            headers.insert(AUTHORIZATION, value.clone());
+        }
+
+        Ok(Self {
+            base_url: format!("http://{}:{}", host, port),
+            client: Client::builder()
+                .no_proxy()
+                .user_agent(USER_AGENT)
+                .default_headers(headers)
+                .connect_timeout(timeout)
+                .timeout(timeout)
+                .build()
+                .map_err(|e| format!("Failed to build HTTP client: {}", e))?,
+            timeout,
+            authorization,
+        })
+    }
+
+    async fn send<T: DeserializeOwned>(&self, request: RequestBuilder) -> Result<T, String> {
+        let response = request.send().await.map_err(|e| self.request_error(e))?;
+        let body: ApiResponse<T> = response.json().await.map_err(|e| {
+            if e.is_timeout() {
+                self.request_error(e)
+            } else {
+                format!("Failed to parse server response: {}", e)
+            }
+        })?;
+
+        match body.data {
+            Some(data) if body.success => Ok(data),
+            _ => Err(body
+                .message
+                .unwrap_or_else(|| "Server returned an error".to_string())),
+        }
+    }
+
+    /// For endpoints whose success carries no data
+    async fn send_empty(&self, request: RequestBuilder) -> Result<(), String> {
+        let response = request.send().await.map_err(|e| self.request_error(e))?;
+        let body: ApiResponse<serde::de::IgnoredAny> = response
+            .json()
+            .await
+            .map_err(|e| format!("Failed to parse server response: {}", e))?;
+        if body.success {
+            Ok(())
+        } else {
+            Err(body
+                .message
+                .unwrap_or_else(|| "Server returned an error".to_string()))
+        }
+    }
+
+    fn request_error(&self, e: reqwest::Error) -> String {
+        if e.is_timeout() {
+            format!(
+                "vibe server at {} did not respond within {}s",
+                self.base_url,
+                self.timeout.as_secs()
+            )
+        } else {
+            format!("Server request failed: {}", e)
+        }
+    }
+
+    /// Server project that has `dir` as one of its repositories
+    async fn project_for_dir(&self, dir: &Path) -> Result<String, String> {
+        let dir = dir.canonicalize().unwrap_or_else(|_| dir.to_path_buf());
+        let projects: Vec<Project> = self
+            .send(self.client.get(format!("{}/api/projects", self.base_url)))
+            .await?;
+
+        for project in projects {
+            let repos = self.project_repos(&project.id).await?;
+            if repos.iter().any(|repo| {
+                repo.path
+                    .canonicalize()
+                    .unwrap_or_else(|_| repo.path.clone())
+                    == dir
+            }) {
+                return Ok(project.id);
+            }
+        }
+
+        Err(format!("No server project has {:?} as a repository", dir))
+    }
+
+    async fn project_repos(&self, project_id: &str) -> Result<Vec<Repo>, String> {
+        self.send(self.client.get(format!(
+            "{}/api/projects/{}/repositories",
+            self.base_url, project_id
+        )))
+        .await
+    }
+
+    /// Repository directories of the projects starred on the server
+    pub async fn favorite_project_dirs(&self) -> Result<Vec<PathBuf>, String> {
+        let projects: Vec<Project> = self
+            .send(self.client.get(format!("{}/api/projects", self.base_url)))
+            .await?;
+
+        let mut dirs = Vec::new();
+        for project in projects.iter().filter(|project| project.favorite) {
+            let repos = self.project_repos(&project.id).await?;
+            dirs.extend(
+                repos
+                    .into_iter()
+                    .map(|repo| repo.path.canonicalize().unwrap_or(repo.path)),
+            );
+        }
+        Ok(dirs)
+    }
+
+    /// Tail the logs of an execution process, reconnecting whenever the
+    /// connection drops. Returns once the process has finished, the server
+    /// refuses the tail, or `events` is closed.
+    pub async fn tail_process_logs(&self, process_id: &str, events: mpsc::Sender<ProcessLogEvent>) {
+        let url = format!(
+            "{}/api/execution-processes/{}/logs/stream/ws",
+            self.base_url.replacen("http", "ws", 1),
+            process_id
+        );
+
+        let mut failures = 0;
+        loop {
+            let mut request = match url.as_str().into_client_request() {
+                Ok(request) => request,
+                Err(e) => {
+                    let _ = events.send(ProcessLogEvent::Failed(e.to_string())).await;
+                    return;
+                }
+            };
+            if let Some(value) = &self.authorization {
+                request.headers_mut().insert(AUTHORIZATION, value.clone());
+            }
+
+            let reason = match tokio_tungstenite::connect_async(request).await {
+                Ok((mut socket, _)) => {
+                    if events.send(ProcessLogEvent::Connected).await.is_err() {
+                        return;
+                    }
+                    let connected_at = std::time::Instant::now();
+                    let reason = loop {
+                        let event = match socket.next().await {
+                            Some(Ok(Message::Text(text))) => parse_log_message(&text),
+                            Some(Ok(Message::Close(_))) | None => {
+                                break "connection closed".to_string();
+                            }
+                            Some(Ok(_)) => None,
+                            Some(Err(e)) => break e.to_string(),
+                        };
+                        let Some(event) = event else {
+                            continue;
+                        };
+                        let finished = event == ProcessLogEvent::Finished;
+                        if events.send(event).await.is_err() || finished {
+                            return;
+                        }
+                    };
+                    if connected_at.elapsed() >= TAIL_STABLE_CONNECTION {
+                        failures = 0;
+                    }
+                    reason
+                }
+                Err(tungstenite::Error::Http(response)) => {
+                    let reason = format!("server answered {}", response.status());
+                    let _ = events.send(ProcessLogEvent::Failed(reason)).await;
+                    return;
+                }
+                Err(e) => e.to_string(),
+            };
+
+            let delay = reconnect_delay(failures);
+            failures += 1;
+            if events
+                .send(ProcessLogEvent::Disconnected(reason, delay))
+                .await
+                .is_err()
+            {
+                return;
+            }
+            tokio::time::sleep(delay).await;
+        }
+    }
+
+    /// Kill a running execution process; the server marks it killed
+    pub async fn stop_process(&self, process_id: &str) -> Result<(), String> {
+        self.send_empty(self.client.post(format!(
+            "{}/api/execution-processes/{}/stop",
+            self.base_url, process_id
+        )))
+        .await
+    }
+
+    /// Activity timeline, oldest first, of the server task whose latest attempt
+    /// works on `branch`
+    pub async fn task_timeline(&self, branch: &str) -> Result<Vec<TimelineEvent>, String> {
+        let attempt = self.attempt_for_branch(branch).await?;
+        self.send(self.client.get(format!(
+            "{}/api/tasks/{}/timeline",
+            self.base_url, attempt.task_id
+        )))
+        .await
+    }
+
+    /// Latest server attempt working on `branch`. TUI task ids are local to the
+    /// task files, so the branch is what ties a task to the server's attempts.
+    async fn attempt_for_branch(&self, branch: &str) -> Result<TaskAttempt, String> {
+        let attempts: Vec<TaskAttempt> = self
+            .send(
+                self.client
+                    .get(format!("{}/api/task-attempts", self.base_url)),
+            )
+            .await?;
+        latest_attempt_on_branch(attempts, branch)
+            .ok_or_else(|| format!("No attempt on the server works on branch {}", branch))
+    }
+
+    /// Rebase `branch`, as checked out by its latest attempt, onto its target
+    /// branch in every repository of the attempt. A conflict stops the rebase
+    /// and is reported with the files left unmerged.
+    pub async fn rebase_task_branch(&self, branch: &str) -> Result<(), String> {
+        let attempt = self.attempt_for_branch(branch).await?;
+
+        let response = self
+            .client
+            .post(format!(
+                "{}/api/task-attempts/{}/rebase",
+                self.base_url, attempt.id
+            ))
+            .json(&serde_json::json!({}))
+            .send()
+            .await
+            .map_err(|e| self.request_error(e))?;
+        let body: RebaseResponse = response
+            .json()
+            .await
+            .map_err(|e| format!("Failed to parse server response: {}", e))?;
+        rebase_result(body)
+    }
+
+    /// Re-warm the server's file search cache for the project at `dir`.
+    /// Returns the number of repositories queued.
+    pub async fn warm_search_cache(&self, dir: &Path) -> Result<usize, String> {
+        let project_id = self.project_for_dir(dir).await?;
+        let response: WarmSearchCacheResponse = self
+            .send(self.client.post(format!(
+                "{}/api/projects/{}/search/warm",
+                self.base_url, project_id
+            )))
+            .await?;
+        Ok(response.repo_count)
+    }
+}
+
+/// Port of the running server from the `<port>\n<pid>` file it writes on
+/// startup, rejecting a file left behind by a server that is gone
+fn read_port_file() -> Result<u16, String> {
+    let path = std::env::temp_dir().join("vibe").join("vibe.port");
+    let content = std::fs::read_to_string(&path)
+        .map_err(|e| format!("vibe server not running ({:?}: {})", path, e))?;
+    let (port, pid) =
+        parse_port_file(&content).ok_or_else(|| format!("Invalid server port file {:?}", path))?;
+
+    if let Some(pid) = pid
+        && !process_is_alive(pid)
+    {
+        return Err(format!(
+            "vibe server not running (stale port file {:?}: process {} is gone)",
+            path, pid
+        ));
+    }
+    Ok(port)
+}
+
+/// Parse `<port>\n<pid>`; files from older servers have no PID line
+fn parse_port_file(content: &str) -> Option<(u16, Option<u32>)> {
+    let mut lines = content.lines().map(str::trim).filter(|l| !l.is_empty());
+    let port = lines.next()?.parse().ok()?;
+    let pid = match lines.next() {
+        Some(pid) => Some(pid.parse().ok()?),
+        None => None,
+    };
+    Some((port, pid))
+}
+
+#[cfg(unix)]
+fn process_is_alive(pid: u32) -> bool {
+    use nix::{errno::Errno, sys::signal::kill, unistd::Pid};
+
+    // Signal 0 only checks whether the process exists; EPERM means it exists
+    // but belongs to someone else
+    matches!(
+        kill(Pid::from_raw(pid as i32), None),
+        Ok(()) | Err(Errno::EPERM)
+    )
+}
+
+#[cfg(not(unix))]
+fn process_is_alive(_pid: u32) -> bool {
+    true
+}
+
+/// Backoff before the next tail reconnect after `failures` failures in a row
+fn reconnect_delay(failures: u32) -> Duration {
+    TAIL_RECONNECT_BASE_DELAY
+        .saturating_mul(2u32.saturating_pow(failures))
+        .min(TAIL_RECONNECT_MAX_DELAY)
+}
+
+/// The server lists attempts newest first
+fn latest_attempt_on_branch(attempts: Vec<TaskAttempt>, branch: &str) -> Option<TaskAttempt> {
+    attempts
+        .into_iter()
+        .find(|attempt| attempt.branch == branch)
+}
+
+fn rebase_result(body: RebaseResponse) -> Result<(), String> {
+    if body.success {
+        return Ok(());
+    }
+    Err(match (body.error_data, body.message) {
+        (Some(error), _) => error.to_string(),
+        (None, Some(message)) => message,
+        (None, None) => "Server returned an error".to_string(),
+    })
+}
+
+/// One message of the logs stream: `{"Stdout":".."}`, `{"Stderr":".."}` or
+/// `{"finished":true}`; other log messages are skipped
+fn parse_log_message(text: &str) -> Option<ProcessLogEvent> {
+    let value: serde_json::Value = serde_json::from_str(text).ok()?;
+    if value.get("finished").is_some() {
+        return Some(ProcessLogEvent::Finished);
+    }
+    if let Some(output) = value.get("Stderr").and_then(|v| v.as_str()) {
+        return Some(ProcessLogEvent::Stderr(output.to_string()));
+    }
+    value
+        .get("Stdout")?
+        .as_str()
+        .map(|output| ProcessLogEvent::Output(output.to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_log_message() {
+        assert_eq!(
+            parse_log_message(r#"{"Stdout":"Compiling vibe\n"}"#),
+            Some(ProcessLogEvent::Output("Compiling vibe\n".to_string()))
+        );
+        assert_eq!(
+            parse_log_message(r#"{"Stderr":"warning: unused"}"#),
+            Some(ProcessLogEvent::Stderr("warning: unused".to_string()))
+        );
+        assert_eq!(
+            parse_log_message(r#"{"finished":true}"#),
+            Some(ProcessLogEvent::Finished)
+        );
+        assert_eq!(parse_log_message(r#"{"SessionId":"abc"}"#), None);
+        assert_eq!(parse_log_message("not json"), None);
+    }
+
+    #[test]
+    fn test_rebase_result_reports_conflicts() {
+        let body: RebaseResponse = serde_json::from_str(
+            r#"{"success":false,"data":null,"message":null,"error_data":{
+                "type":"merge_conflicts","message":"Rebase failed","op":"rebase",
+                "conflicted_files":["src/main.rs","Cargo.toml"]}}"#,
+        )
+        .unwrap();
+        assert_eq!(
+            rebase_result(body),
+            Err("Rebase failed (conflicts in src/main.rs, Cargo.toml)".to_string())
+        );
+
+        let body: RebaseResponse =
+            serde_json::from_str(r#"{"success":true,"data":null,"message":null}"#).unwrap();
+        assert_eq!(rebase_result(body), Ok(()));
+    }
+
+    #[test]
+    fn test_latest_attempt_on_branch() {
+        let attempts: Vec<TaskAttempt> = serde_json::from_str(
+            r#"[
+                {"id":"a3","task_id":"t2","branch":"vk/other-task"},
+                {"id":"a2","task_id":"t1","branch":"vk/add-login"},
+                {"id":"a1","task_id":"t1","branch":"vk/add-login"}
+            ]"#,
+        )
+        .unwrap();
+
+        let attempt = latest_attempt_on_branch(attempts, "vk/add-login").unwrap();
+        assert_eq!(
+            (attempt.id.as_str(), attempt.task_id.as_str()),
+            ("a2", "t1")
+        );
+        assert!(latest_attempt_on_branch(Vec::new(), "vk/add-login").is_none());
+    }
+
+    #[test]
+    fn test_parse_port_file() {
+        assert_eq!(parse_port_file("3000\n4242\n"), Some((3000, Some(4242))));
+        assert_eq!(parse_port_file("3000"), Some((3000, None)));
+        assert_eq!(parse_port_file(""), None);
+        assert_eq!(parse_port_file("3000\nnot-a-pid"), None);
+    }
+
+    #[test]
+    fn test_reconnect_delay_backs_off_to_a_cap() {
+        let delays: Vec<u64> = (0..6).map(|n| reconnect_delay(n).as_secs()).collect();
+        assert_eq!(delays, [2, 4, 8, 16, 30, 30]);
+        assert_eq!(reconnect_delay(u32::MAX), TAIL_RECONNECT_MAX_DELAY);
+    }
+}
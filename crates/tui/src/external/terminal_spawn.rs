@@ -4,6 +4,8 @@ use anyhow::Result;
 use std::path::Path;
 use std::process::Command;
 
+use super::command::{run_spawn, run_status};
+
 /// Open a new tmux pane running zellij with claude
 /// This creates a vertical split in tmux and runs the zellij session there
 pub fn open_tmux_pane_with_zellij_claude(session_name: &str, cwd: &Path) -> Result<()> {
@@ -17,13 +19,14 @@ pub fn open_tmux_pane_with_zellij_claude(session_name: &str, cwd: &Path) -> Resu
     // Create a new tmux pane (vertical split) and run zellij in it
     // -h = horizontal split (creates pane on right)
     // -c = start directory
-    Command::new("tmux")
-        .arg("split-window")
-        .arg("-h")
-        .arg("-c")
-        .arg(cwd)
-        .arg(&zellij_cmd)
-        .spawn()?;
+    run_spawn(
+        Command::new("tmux")
+            .arg("split-window")
+            .arg("-h")
+            .arg("-c")
+            .arg(cwd)
+            .arg(&zellij_cmd),
+    )?;
 
     Ok(())
 }
@@ -32,11 +35,12 @@ pub fn open_tmux_pane_with_zellij_claude(session_name: &str, cwd: &Path) -> Resu
 pub fn open_tmux_pane_attach_zellij(session_name: &str) -> Result<()> {
     let zellij_cmd = format!("zellij attach {}", session_name);
 
-    Command::new("tmux")
-        .arg("split-window")
-        .arg("-h")
-        .arg(&zellij_cmd)
-        .spawn()?;
+    run_spawn(
+        Command::new("tmux")
+            .arg("split-window")
+            .arg("-h")
+            .arg(&zellij_cmd),
+    )?;
 
     Ok(())
 }
@@ -66,7 +70,7 @@ pub fn open_ghostty_with_zellij_claude(session_name: &str, cwd: &Path) -> Result
         zellij_cmd.replace('"', "\\\"")
     );
 
-    Command::new("osascript").arg("-e").arg(&script).spawn()?;
+    run_spawn(Command::new("osascript").arg("-e").arg(&script))?;
 
     Ok(())
 }
@@ -78,13 +82,14 @@ pub fn open_ghostty_attach_zellij(session_name: &str) -> Result<()> {
     // Wrap in /bin/zsh -c "..." as a single string for Ghostty's -e flag
     let full_cmd = format!("/bin/zsh -c \"{}\"", zellij_cmd);
 
-    Command::new("open")
-        .arg("-na")
-        .arg("Ghostty")
-        .arg("--args")
-        .arg("-e")
-        .arg(&full_cmd)
-        .spawn()?;
+    run_spawn(
+        Command::new("open")
+            .arg("-na")
+            .arg("Ghostty")
+            .arg("--args")
+            .arg("-e")
+            .arg(&full_cmd),
+    )?;
 
     Ok(())
 }
@@ -102,10 +107,57 @@ pub fn open_ghostty_with_command(command: &str, cwd: Option<&Path>) -> Result<()
         cmd.arg(command);
     }
 
-    cmd.spawn()?;
+    run_spawn(&mut cmd)?;
     Ok(())
 }
 
+/// Terminal multiplexer hosting agent sessions.
+/// Selected with `VIBE_MULTIPLEXER=tmux|zellij` (defaults to zellij).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Multiplexer {
+    Zellij,
+    Tmux,
+}
+
+impl Multiplexer {
+    pub fn from_env() -> Self {
+        std::env::var("VIBE_MULTIPLEXER")
+            .ok()
+            .and_then(|v| Self::parse(&v))
+            .unwrap_or(Multiplexer::Zellij)
+    }
+
+    fn parse(value: &str) -> Option<Self> {
+        match value.trim().to_lowercase().as_str() {
+            "zellij" => Some(Multiplexer::Zellij),
+            "tmux" => Some(Multiplexer::Tmux),
+            _ => None,
+        }
+    }
+}
+
+/// Where a launched session opens.
+/// Configured with `[session] launch_mode` in `~/.vibe/config.toml`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SessionLaunchMode {
+    /// Take over the current terminal; the TUI is suspended until the session detaches
+    #[default]
+    Foreground,
+    /// Open alongside the TUI in a new tmux/zellij pane, falling back to
+    /// foreground when not running inside a multiplexer
+    Pane,
+}
+
+impl SessionLaunchMode {
+    pub fn parse(value: &str) -> Option<Self> {
+        match value.trim().to_lowercase().as_str() {
+            "foreground" => Some(SessionLaunchMode::Foreground),
+            "pane" => Some(SessionLaunchMode::Pane),
+            _ => None,
+        }
+    }
+}
+
 /// Simple shell escape for command arguments
 fn shell_escape(s: &str) -> String {
     // If string contains no special chars, return as-is
@@ -155,6 +207,22 @@ fn create_launcher_script(
     // Track plan mode state in a marker file
     let plan_marker = script_dir.join(format!("{}-plan.marker", session_name));
 
+    if Multiplexer::from_env() == Multiplexer::Tmux {
+        let launcher_path = script_dir.join(format!("{}-launch.sh", session_name));
+        let launcher_script = tmux_launcher_script(
+            session_name,
+            &fresh_script_path,
+            &continue_script_path,
+            &plan_marker,
+            plan_mode,
+        );
+        let mut file = std::fs::File::create(&launcher_path)?;
+        file.write_all(launcher_script.as_bytes())?;
+        drop(file);
+        std::fs::set_permissions(&launcher_path, std::fs::Permissions::from_mode(0o755))?;
+        return Ok(launcher_path);
+    }
+
     // Launcher script that wt switch -x will execute
     // Check session state and handle accordingly:
     // - Running + plan mode requested but session not in plan mode: kill and restart
@@ -231,6 +299,108 @@ SHELL={fresh_script} exec zellij -s {session}
     Ok(launcher_path)
 }
 
+/// Wrap a launcher so it opens in a new pane of the surrounding multiplexer.
+/// The multiplexer env var is cleared in the pane so the launcher attaches
+/// to its own session instead of switching the outer client away from the TUI.
+fn create_pane_launcher_script(session_name: &str, launcher: &Path) -> Result<std::path::PathBuf> {
+    use std::io::Write;
+    use std::os::unix::fs::PermissionsExt;
+
+    let script_path = launcher.with_file_name(format!("{}-pane.sh", session_name));
+    let script = format!(
+        r#"#!/bin/zsh
+if [[ -n "$TMUX" ]]; then
+  exec tmux split-window -h -c "$PWD" env -u TMUX {launcher}
+elif [[ -n "$ZELLIJ" ]]; then
+  exec zellij action new-pane --cwd "$PWD" -- env -u ZELLIJ {launcher}
+fi
+# Not inside a multiplexer - take over this terminal instead
+exec {launcher}
+"#,
+        launcher = launcher.display(),
+    );
+
+    let mut file = std::fs::File::create(&script_path)?;
+    file.write_all(script.as_bytes())?;
+    drop(file);
+    std::fs::set_permissions(&script_path, std::fs::Permissions::from_mode(0o755))?;
+    Ok(script_path)
+}
+
+/// Launcher script to pass to `wt switch -x` for the given mode
+fn launcher_for_mode(
+    session_name: &str,
+    launcher: std::path::PathBuf,
+    mode: SessionLaunchMode,
+) -> Result<std::path::PathBuf> {
+    match mode {
+        SessionLaunchMode::Foreground => Ok(launcher),
+        SessionLaunchMode::Pane => create_pane_launcher_script(session_name, &launcher),
+    }
+}
+
+/// Launcher script equivalent of the zellij one for tmux.
+/// Sessions are started with `remain-on-exit` so the pane stays after claude
+/// exits; a dead pane plays the role of zellij's EXITED state and is replaced
+/// by a session that resumes the conversation with the continue script.
+/// Inside an existing tmux client we switch to the session instead of nesting.
+fn tmux_launcher_script(
+    session_name: &str,
+    fresh_script: &Path,
+    continue_script: &Path,
+    plan_marker: &Path,
+    plan_mode: bool,
+) -> String {
+    let restart_for_plan = if plan_mode {
+        format!(
+            r#"  if [[ ! -f {plan_marker} ]]; then
+    # Session running but not in plan mode - kill and restart in plan mode
+    tmux kill-session -t ={session} 2>/dev/null
+    sleep 0.2
+  else
+    open_session
+  fi
+"#,
+            session = session_name,
+            plan_marker = plan_marker.display(),
+        )
+    } else {
+        "  open_session\n".to_string()
+    };
+    let marker_cmd = if plan_mode { "touch" } else { "rm -f" };
+
+    format!(
+        r#"#!/bin/zsh
+open_session() {{
+  if [[ -n "$TMUX" ]]; then
+    exec tmux switch-client -t ={session}
+  fi
+  exec tmux attach-session -t ={session}
+}}
+# Keep the pane after the script exits so an exited session can be resumed
+start_session() {{
+  tmux new-session -d -s {session} -c "$PWD" "$1" \; set-option -w remain-on-exit on
+  open_session
+}}
+if tmux has-session -t ={session} 2>/dev/null; then
+  if [[ "$(tmux display-message -p -t ={session}: '#{{pane_dead}}')" == 1 ]]; then
+    tmux kill-session -t ={session} 2>/dev/null
+    {marker_cmd} {plan_marker}
+    start_session {continue_script}
+  fi
+{restart_for_plan}fi
+{marker_cmd} {plan_marker}
+start_session {fresh_script}
+"#,
+        session = session_name,
+        restart_for_plan = restart_for_plan,
+        marker_cmd = marker_cmd,
+        plan_marker = plan_marker.display(),
+        fresh_script = fresh_script.display(),
+        continue_script = continue_script.display(),
+    )
+}
+
 /// Get the wt binary path - check WORKTRUNK_BIN env or fall back to cargo bin
 fn wt_binary() -> String {
     std::env::var("WORKTRUNK_BIN").unwrap_or_else(|_| {
@@ -244,10 +414,13 @@ fn wt_binary() -> String {
 /// Uses `wt switch -x` to switch/create worktree AND launch zellij in one step
 /// The -x script inherits TTY from wt, which inherits from us (via .status())
 /// project_dir: The project's git repo root directory (wt must run from within repo)
+/// resume_session: Claude session id to resume instead of the most recent conversation
 pub fn launch_zellij_claude_in_worktree(
     branch: &str,
     plan_mode: bool,
     project_dir: &std::path::Path,
+    mode: SessionLaunchMode,
+    resume_session: Option<&str>,
 ) -> Result<()> {
     let session_name = super::session_name_for_branch(branch);
     let wt = wt_binary();
@@ -260,31 +433,46 @@ pub fn launch_zellij_claude_in_worktree(
         anyhow::bail!("project_dir does not exist: {:?}", project_dir);
     }
 
-    // Both fresh and continue use --continue since this is for existing worktrees
+    // A running session would just be attached, showing its current conversation
+    if resume_session.is_some() && session_is_running(&session_name) {
+        anyhow::bail!(
+            "session {} is still running - kill it before resuming another conversation",
+            session_name
+        );
+    }
+
+    // Both fresh and continue use the same command since this is for existing worktrees
+    let resume_arg = match resume_session {
+        Some(id) => format!("--resume {}", shell_escape(id)),
+        None => "--continue".to_string(),
+    };
     let claude_cmd = if plan_mode {
-        "claude --continue --dangerously-skip-permissions --plan"
+        format!("claude {} --dangerously-skip-permissions --plan", resume_arg)
     } else {
-        "claude --continue --dangerously-skip-permissions"
+        format!("claude {} --dangerously-skip-permissions", resume_arg)
     };
 
-    let launcher = create_launcher_script(&session_name, claude_cmd, claude_cmd, plan_mode)?;
+    let launcher = create_launcher_script(&session_name, &claude_cmd, &claude_cmd, plan_mode)?;
+    let launcher = launcher_for_mode(&session_name, launcher, mode)?;
     let launcher_path = launcher.to_str().unwrap();
 
     // Use .status() to inherit TTY - this is critical for zellij to work!
     // Try existing branch first, then --create if not found
-    let status = Command::new(&wt)
-        .current_dir(project_dir)
-        .args(["switch", branch, "-y", "-x", launcher_path])
-        .status();
+    let status = run_status(
+        Command::new(&wt)
+            .current_dir(project_dir)
+            .args(["switch", branch, "-y", "-x", launcher_path]),
+    );
 
     match status {
         Ok(s) if s.success() => Ok(()),
         Ok(_) => {
             // Try with --create for new branches
-            let status = Command::new(&wt)
-                .current_dir(project_dir)
-                .args(["switch", "--create", branch, "-y", "-x", launcher_path])
-                .status()?;
+            let status = run_status(
+                Command::new(&wt)
+                    .current_dir(project_dir)
+                    .args(["switch", "--create", branch, "-y", "-x", launcher_path]),
+            )?;
 
             if status.success() {
                 Ok(())
@@ -304,6 +492,7 @@ pub fn launch_zellij_claude_in_worktree_with_context(
     task_context: &str,
     plan_mode: bool,
     project_dir: &std::path::Path,
+    mode: SessionLaunchMode,
 ) -> Result<()> {
     let session_name = super::session_name_for_branch(branch);
     let wt = wt_binary();
@@ -346,22 +535,25 @@ pub fn launch_zellij_claude_in_worktree_with_context(
     };
 
     let launcher = create_launcher_script(&session_name, &fresh_cmd, &continue_cmd, plan_mode)?;
+    let launcher = launcher_for_mode(&session_name, launcher, mode)?;
     let launcher_path = launcher.to_str().unwrap();
 
     // Use .status() to inherit TTY - critical for zellij!
-    let status = Command::new(&wt)
-        .current_dir(project_dir)
-        .args(["switch", branch, "-y", "-x", launcher_path])
-        .status();
+    let status = run_status(
+        Command::new(&wt)
+            .current_dir(project_dir)
+            .args(["switch", branch, "-y", "-x", launcher_path]),
+    );
 
     match status {
         Ok(s) if s.success() => Ok(()),
         Ok(_) => {
             // Try with --create for new branches
-            let status = Command::new(&wt)
-                .current_dir(project_dir)
-                .args(["switch", "--create", branch, "-y", "-x", launcher_path])
-                .status()?;
+            let status = run_status(
+                Command::new(&wt)
+                    .current_dir(project_dir)
+                    .args(["switch", "--create", branch, "-y", "-x", launcher_path]),
+            )?;
 
             if status.success() {
                 Ok(())
@@ -373,11 +565,49 @@ pub fn launch_zellij_claude_in_worktree_with_context(
     }
 }
 
+/// Retry a task in its worktree: discard the exited session and start a fresh
+/// conversation with the task context instead of continuing the failed one.
+/// Refuses while the session is running so a working agent is never killed.
+pub fn retry_zellij_claude_in_worktree(
+    branch: &str,
+    task_context: &str,
+    plan_mode: bool,
+    project_dir: &std::path::Path,
+    mode: SessionLaunchMode,
+) -> Result<()> {
+    let session_name = super::session_name_for_branch(branch);
+
+    if session_is_running(&session_name) {
+        anyhow::bail!("session {} is still running - kill it before retrying", session_name);
+    }
+    if super::zellij::get_session_status(&session_name) == Some(true) {
+        super::zellij::delete_session(&session_name)?;
+    }
+
+    launch_zellij_claude_in_worktree_with_context(
+        branch,
+        task_context,
+        plan_mode,
+        project_dir,
+        mode,
+    )
+}
+
+/// Whether the multiplexer session exists and has not exited
+fn session_is_running(session_name: &str) -> bool {
+    super::zellij::get_session_status(session_name) == Some(false)
+}
+
 /// Attach to existing zellij session in current terminal (blocks)
 /// Handles dead sessions by force-resurrecting them
+/// With VIBE_MULTIPLEXER=tmux this attaches to the tmux session of the same name
 pub fn attach_zellij_foreground(session_name: &str) -> Result<()> {
     use super::zellij::get_session_status;
 
+    if Multiplexer::from_env() == Multiplexer::Tmux {
+        return attach_tmux_foreground(session_name);
+    }
+
     // Check if session is dead (None = doesn't exist, Some(is_dead) = exists)
     let is_dead = get_session_status(session_name).unwrap_or(false);
 
@@ -387,10 +617,69 @@ pub fn attach_zellij_foreground(session_name: &str) -> Result<()> {
     }
     args.push(session_name);
 
-    let status = Command::new("zellij").args(&args).status()?;
+    let status = run_status(Command::new("zellij").args(&args))?;
 
     if !status.success() {
         anyhow::bail!("zellij attach exited with error");
     }
     Ok(())
 }
+
+/// Attach to a tmux session in the current terminal (blocks).
+/// Switches the client instead when already running inside tmux.
+fn attach_tmux_foreground(session_name: &str) -> Result<()> {
+    let target = format!("={}", session_name);
+    let subcommand = if std::env::var_os("TMUX").is_some() {
+        "switch-client"
+    } else {
+        "attach-session"
+    };
+
+    let status = run_status(Command::new("tmux").args([subcommand, "-t", &target]))?;
+
+    if !status.success() {
+        anyhow::bail!("tmux {} exited with error", subcommand);
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_multiplexer_parse() {
+        assert_eq!(Multiplexer::parse("tmux"), Some(Multiplexer::Tmux));
+        assert_eq!(Multiplexer::parse(" TMUX "), Some(Multiplexer::Tmux));
+        assert_eq!(Multiplexer::parse("zellij"), Some(Multiplexer::Zellij));
+        assert_eq!(Multiplexer::parse("screen"), None);
+    }
+
+    #[test]
+    fn test_session_launch_mode_parse() {
+        assert_eq!(SessionLaunchMode::parse("pane"), Some(SessionLaunchMode::Pane));
+        assert_eq!(
+            SessionLaunchMode::parse(" Foreground "),
+            Some(SessionLaunchMode::Foreground)
+        );
+        assert_eq!(SessionLaunchMode::parse("window"), None);
+    }
+
+    #[test]
+    fn test_tmux_launcher_script_plan_mode() {
+        let script = tmux_launcher_script(
+            "vibe-my-task",
+            Path::new("/tmp/vibe-my-task-fresh.sh"),
+            Path::new("/tmp/vibe-my-task-continue.sh"),
+            Path::new("/tmp/vibe-my-task-plan.marker"),
+            true,
+        );
+        assert!(script.contains("tmux has-session -t =vibe-my-task"));
+        assert!(script.contains("tmux kill-session -t =vibe-my-task"));
+        assert!(script.contains("touch /tmp/vibe-my-task-plan.marker"));
+        assert!(script.contains("start_session /tmp/vibe-my-task-fresh.sh"));
+        // An exited session resumes the conversation
+        assert!(script.contains("'#{pane_dead}'"));
+        assert!(script.contains("start_session /tmp/vibe-my-task-continue.sh"));
+    }
+}
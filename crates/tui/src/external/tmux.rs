@@ -0,0 +1,110 @@
+//! Session listing, screen dumps and kills for `VIBE_MULTIPLEXER=tmux`.
+//! Sessions keep their pane after claude exits (`remain-on-exit`), which is
+//! the tmux equivalent of an EXITED zellij session.
+
+use anyhow::Result;
+use std::process::Command;
+
+use super::command::{run_output, run_status};
+use super::zellij::{ClaudeActivityState, ZellijSession};
+
+const LIST_FORMAT: &str = "#{session_name} #{session_attached} #{pane_dead}";
+
+/// `=name` targets exactly that session instead of a name prefix
+fn target(session_name: &str) -> String {
+    format!("={}", session_name)
+}
+
+pub fn list_sessions() -> Result<Vec<ZellijSession>> {
+    let output = run_output(Command::new("tmux").args(["list-sessions", "-F", LIST_FORMAT]))?;
+
+    if !output.status.success() {
+        // tmux fails when its server is not running, which just means no sessions
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        if is_no_server_message(&stderr) || stderr.trim().is_empty() {
+            return Ok(Vec::new());
+        }
+        anyhow::bail!("tmux list-sessions failed: {}", stderr);
+    }
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    Ok(parse_session_list(&stdout))
+}
+
+/// Parse `tmux list-sessions` output in `LIST_FORMAT`. A session counts as
+/// current while a client is attached to it, and as dead once its pane exited.
+fn parse_session_list(output: &str) -> Vec<ZellijSession> {
+    output
+        .lines()
+        .filter_map(|line| {
+            let mut fields = line.split_whitespace();
+            let name = fields.next()?.to_string();
+            let attached = fields.next().is_some_and(|n| n != "0");
+            let dead = fields.next() == Some("1");
+
+            Some(ZellijSession {
+                name,
+                is_current: attached,
+                is_dead: dead,
+                needs_attention: false,
+                claude_activity: ClaudeActivityState::Unknown,
+            })
+        })
+        .collect()
+}
+
+fn is_no_server_message(text: &str) -> bool {
+    text.contains("no server running") || text.contains("error connecting to")
+}
+
+/// Visible content of the session's active pane, None if it cannot be captured
+pub fn dump_session_screen(session_name: &str) -> Option<String> {
+    let pane = format!("{}:", target(session_name));
+    let output = run_output(Command::new("tmux").args(["capture-pane", "-p", "-t", &pane])).ok()?;
+
+    if !output.status.success() {
+        return None;
+    }
+
+    Some(String::from_utf8_lossy(&output.stdout).into_owned())
+}
+
+/// Kill a session, running or exited
+pub fn kill_session(name: &str) -> Result<()> {
+    let status = run_status(Command::new("tmux").args(["kill-session", "-t", &target(name)]))?;
+
+    if !status.success() {
+        anyhow::bail!("Failed to kill tmux session: {}", name);
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_session_list() {
+        let output = "vibe-feature-a 1 0\nvibe-feature-b 0 1\nvibe-feature-c 0 0\n";
+        let sessions = parse_session_list(output);
+        let names: Vec<&str> = sessions.iter().map(|s| s.name.as_str()).collect();
+        assert_eq!(
+            names,
+            vec!["vibe-feature-a", "vibe-feature-b", "vibe-feature-c"]
+        );
+        assert!(sessions[0].is_current && !sessions[0].is_dead);
+        assert!(!sessions[1].is_current && sessions[1].is_dead);
+        assert!(!sessions[2].is_current && !sessions[2].is_dead);
+    }
+
+    #[test]
+    fn test_no_server_message() {
+        assert!(is_no_server_message(
+            "no server running on /tmp/tmux-1000/default"
+        ));
+        assert!(is_no_server_message(
+            "error connecting to /tmp/tmux-1000/default (No such file or directory)"
+        ));
+        assert!(!is_no_server_message("can't find session: vibe-x"));
+    }
+}
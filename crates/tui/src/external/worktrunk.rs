@@ -4,6 +4,8 @@ use anyhow::Result;
 use serde::Deserialize;
 use std::process::Command;
 
+use super::command::{run_output, run_status};
+
 /// Get the wt binary path - check WORKTRUNK_BIN env or fall back to cargo bin
 fn wt_binary() -> String {
     std::env::var("WORKTRUNK_BIN").unwrap_or_else(|_| {
@@ -93,9 +95,7 @@ impl WorktreeInfo {
 }
 
 pub fn list_worktrees() -> Result<Vec<WorktreeInfo>> {
-    let output = Command::new(wt_binary())
-        .args(["list", "--format=json"])
-        .output()?;
+    let output = run_output(Command::new(wt_binary()).args(["list", "--format=json"]))?;
 
     if !output.status.success() {
         let stderr = String::from_utf8_lossy(&output.stderr);
@@ -108,9 +108,10 @@ pub fn list_worktrees() -> Result<Vec<WorktreeInfo>> {
 }
 
 pub fn create_worktree(branch: &str) -> Result<()> {
-    let status = Command::new(wt_binary())
-        .args(["switch", "--create", branch])
-        .status()?;
+    let status = run_status(
+        Command::new(wt_binary())
+            .args(["switch", "--create", branch]),
+    )?;
 
     if !status.success() {
         anyhow::bail!("wt switch --create {} failed", branch);
@@ -119,9 +120,7 @@ pub fn create_worktree(branch: &str) -> Result<()> {
 }
 
 pub fn switch_worktree(branch: &str) -> Result<()> {
-    let status = Command::new(wt_binary())
-        .args(["switch", branch])
-        .status()?;
+    let status = run_status(Command::new(wt_binary()).args(["switch", branch]))?;
 
     if !status.success() {
         anyhow::bail!("wt switch {} failed", branch);
@@ -130,7 +129,7 @@ pub fn switch_worktree(branch: &str) -> Result<()> {
 }
 
 pub fn remove_worktree() -> Result<()> {
-    let status = Command::new(wt_binary()).args(["remove"]).status()?;
+    let status = run_status(Command::new(wt_binary()).args(["remove"]))?;
 
     if !status.success() {
         anyhow::bail!("wt remove failed");
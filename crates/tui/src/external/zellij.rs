@@ -1,8 +1,95 @@
 #![allow(dead_code)]
 
 use anyhow::Result;
-use std::path::Path;
+use std::path::{Path, PathBuf};
 use std::process::Command;
+use std::sync::LazyLock;
+
+use super::command::{run_output, run_status};
+use super::terminal_spawn::Multiplexer;
+
+/// Patterns that indicate Claude is waiting for input, unless overridden by
+/// `~/.vibe/attention-patterns.txt`
+const DEFAULT_ATTENTION_PATTERNS: &[&str] = &[
+    "? ",             // Interactive prompt
+    "[y/n]",          // Yes/no prompt
+    "(y/N)",          // Yes/no with default
+    "(Y/n)",          // Yes/no with default
+    "Continue?",      // Confirmation
+    "Press Enter",    // Waiting for enter
+    "Proceed?",       // Confirmation
+    "Do you want to", // Confirmation question
+    ">",              // Generic prompt at end of line
+    "waiting for",    // Waiting state
+    "permission",     // Permission request
+];
+
+static ATTENTION_PATTERNS: LazyLock<AttentionPatterns> = LazyLock::new(AttentionPatterns::load);
+
+/// Case-insensitive substrings that mark a session screen line as waiting for input.
+/// A line matching an `exclude` pattern never counts, to silence false positives.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AttentionPatterns {
+    include: Vec<String>,
+    exclude: Vec<String>,
+}
+
+impl AttentionPatterns {
+    fn builtin() -> Self {
+        Self {
+            include: DEFAULT_ATTENTION_PATTERNS
+                .iter()
+                .map(|p| p.to_lowercase())
+                .collect(),
+            exclude: Vec::new(),
+        }
+    }
+
+    fn path() -> PathBuf {
+        dirs::home_dir()
+            .unwrap_or_else(|| PathBuf::from("."))
+            .join(".vibe")
+            .join("attention-patterns.txt")
+    }
+
+    /// The patterns file, or the built-in list when there is none
+    fn load() -> Self {
+        match std::fs::read_to_string(Self::path()) {
+            Ok(content) => Self::parse(&content),
+            Err(_) => Self::builtin(),
+        }
+    }
+
+    /// One pattern per line, matched verbatim (trailing spaces included). Blank lines
+    /// and lines starting with `#` are skipped, `!pattern` suppresses lines containing
+    /// it. A file with only `!` patterns keeps the built-in ones.
+    pub fn parse(content: &str) -> Self {
+        let mut include = Vec::new();
+        let mut exclude = Vec::new();
+        for line in content.lines() {
+            if line.trim().is_empty() || line.starts_with('#') {
+                continue;
+            }
+            match line.strip_prefix('!') {
+                Some(pattern) if !pattern.is_empty() => exclude.push(pattern.to_lowercase()),
+                Some(_) => {}
+                None => include.push(line.to_lowercase()),
+            }
+        }
+
+        if include.is_empty() {
+            include = Self::builtin().include;
+        }
+        Self { include, exclude }
+    }
+
+    pub fn matches(&self, screen: &str) -> bool {
+        screen.lines().map(str::to_lowercase).any(|line| {
+            self.include.iter().any(|p| line.contains(p.as_str()))
+                && !self.exclude.iter().any(|p| line.contains(p.as_str()))
+        })
+    }
+}
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
 pub enum ClaudeActivityState {
@@ -22,91 +109,137 @@ pub struct ZellijSession {
     pub claude_activity: ClaudeActivityState,
 }
 
+impl ZellijSession {
+    /// Claude stopped for input, or the screen looks like a prompt when there is no
+    /// statusline data
+    pub fn waiting_for_user(&self) -> bool {
+        !self.is_dead
+            && match self.claude_activity {
+                ClaudeActivityState::WaitingForUser => true,
+                ClaudeActivityState::Unknown => self.needs_attention,
+                ClaudeActivityState::Idle | ClaudeActivityState::Thinking => false,
+            }
+    }
+}
+
+/// Sessions of the configured multiplexer (tmux with `VIBE_MULTIPLEXER=tmux`)
 pub fn list_sessions() -> Result<Vec<ZellijSession>> {
-    let output = Command::new("zellij").args(["list-sessions"]).output()?;
+    if Multiplexer::from_env() == Multiplexer::Tmux {
+        return super::tmux::list_sessions();
+    }
+
+    // Plain output is only available since zellij 0.39; older versions reject the flag
+    let mut output =
+        run_output(Command::new("zellij").args(["list-sessions", "--no-formatting"]))?;
+    if !output.status.success() && is_unknown_argument_error(&output.stderr) {
+        output = run_output(Command::new("zellij").args(["list-sessions"]))?;
+    }
 
     if !output.status.success() {
         // zellij returns error if no sessions exist
         let stderr = String::from_utf8_lossy(&output.stderr);
-        if stderr.contains("No active sessions") || stderr.is_empty() {
+        if is_no_sessions_message(&stderr) || stderr.trim().is_empty() {
             return Ok(Vec::new());
         }
         anyhow::bail!("zellij list-sessions failed: {}", stderr);
     }
 
-    let stdout = String::from_utf8(output.stdout)?;
-    let sessions: Vec<ZellijSession> = stdout
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    Ok(parse_session_list(&stdout))
+}
+
+/// Parse `zellij list-sessions` output. Tolerates the formats of different
+/// zellij versions:
+/// - `session-name` (old versions, one name per line)
+/// - `session-name [Created 3m 5s ago] (current)`
+/// - `session-name [Created 3m 5s ago] (EXITED - attach to resurrect)`
+/// - any of the above wrapped in ANSI colors when `--no-formatting` is unsupported
+fn parse_session_list(output: &str) -> Vec<ZellijSession> {
+    output
         .lines()
-        .filter(|line| !line.is_empty())
-        .map(|line| {
-            // Format: "session-name [Created 3m 5s ago] (current)"
-            // Or dead: "session-name [Created 3m 5s ago] (EXITED -9attach to resurrect)"
-            let is_current = line.contains("(current)");
-            let is_dead = line.contains("EXITED");
-
-            // Extract session name: everything before first '[' or space with metadata
-            let name = line
-                .split(|c| c == '[')
-                .next()
-                .unwrap_or("")
-                .trim()
-                .to_string();
-
-            ZellijSession {
+        .map(strip_ansi)
+        .filter(|line| !line.trim().is_empty() && !is_no_sessions_message(line))
+        .filter_map(|line| {
+            // Session names cannot contain whitespace, metadata follows the name
+            let name = line.split_whitespace().next()?.to_string();
+            let metadata = line.trim_start()[name.len()..].to_lowercase();
+
+            Some(ZellijSession {
                 name,
-                is_current,
-                is_dead,
+                is_current: metadata.contains("(current)"),
+                is_dead: metadata.contains("exited"),
                 needs_attention: false,
                 claude_activity: ClaudeActivityState::Unknown,
-            }
+            })
         })
-        .collect();
+        .collect()
+}
 
-    Ok(sessions)
+fn is_no_sessions_message(text: &str) -> bool {
+    text.to_lowercase().contains("no active")
 }
 
-/// Check if a session is waiting for user input by dumping screen content
-pub fn check_session_needs_attention(session_name: &str) -> bool {
-    // Dump the last few lines of the session screen
-    let output = Command::new("zellij")
-        .args([
+/// clap's error for flags an older zellij does not know
+fn is_unknown_argument_error(stderr: &[u8]) -> bool {
+    let stderr = String::from_utf8_lossy(stderr).to_lowercase();
+    stderr.contains("unexpected argument") || stderr.contains("wasn't expected")
+}
+
+/// Remove ANSI escape sequences (colors, bold) from a line
+fn strip_ansi(line: &str) -> String {
+    let mut out = String::with_capacity(line.len());
+    let mut chars = line.chars();
+    while let Some(c) = chars.next() {
+        if c != '\x1b' {
+            out.push(c);
+            continue;
+        }
+        // CSI sequences end with a byte in the @..~ range
+        if chars.next() == Some('[') {
+            for c in chars.by_ref() {
+                if ('@'..='~').contains(&c) {
+                    break;
+                }
+            }
+        }
+    }
+    out
+}
+
+/// Current screen content of a session, None if it cannot be dumped
+pub fn dump_session_screen(session_name: &str) -> Option<String> {
+    if Multiplexer::from_env() == Multiplexer::Tmux {
+        return super::tmux::dump_session_screen(session_name);
+    }
+
+    let output = run_output(
+        Command::new("zellij").args([
             "action",
             "--session",
             session_name,
             "dump-screen",
             "/dev/stdout",
-        ])
-        .output();
-
-    let Ok(output) = output else {
-        return false;
-    };
+        ]),
+    )
+    .ok()?;
 
     if !output.status.success() {
-        return false;
+        return None;
     }
 
-    let screen = String::from_utf8_lossy(&output.stdout);
+    Some(String::from_utf8_lossy(&output.stdout).into_owned())
+}
+
+/// Check if a session is waiting for user input by dumping screen content
+pub fn check_session_needs_attention(session_name: &str) -> bool {
+    // Dump the last few lines of the session screen
+    let Some(screen) = dump_session_screen(session_name) else {
+        return false;
+    };
+
     let last_lines: String = screen.lines().rev().take(10).collect::<Vec<_>>().join("\n");
 
-    // Patterns that indicate Claude is waiting for input
-    let attention_patterns = [
-        "? ",                            // Interactive prompt
-        "[y/n]",                         // Yes/no prompt
-        "(y/N)",                         // Yes/no with default
-        "(Y/n)",                         // Yes/no with default
-        "Continue?",                     // Confirmation
-        "Press Enter",                   // Waiting for enter
-        "Proceed?",                      // Confirmation
-        "Do you want to",                // Confirmation question
-        ">",                             // Generic prompt at end of line
-        "waiting for",                   // Waiting state
-        "permission",                    // Permission request
-    ];
-
-    attention_patterns
-        .iter()
-        .any(|pattern| last_lines.to_lowercase().contains(&pattern.to_lowercase()))
+    ATTENTION_PATTERNS.matches(&last_lines)
 }
 
 /// List sessions with attention status (slower, checks each session)
@@ -135,14 +268,15 @@ pub fn get_session_status(name: &str) -> Option<bool> {
 pub fn create_session_with_command(name: &str, cwd: &Path, command: &str) -> Result<()> {
     // Create a zellij session that runs the specified command
     // We use `zellij -s <name> --cwd <path> -- <command>`
-    let status = Command::new("zellij")
-        .arg("-s")
-        .arg(name)
-        .arg("--cwd")
-        .arg(cwd)
-        .arg("--")
-        .arg(command)
-        .status()?;
+    let status = run_status(
+        Command::new("zellij")
+            .arg("-s")
+            .arg(name)
+            .arg("--cwd")
+            .arg(cwd)
+            .arg("--")
+            .arg(command),
+    )?;
 
     if !status.success() {
         anyhow::bail!("Failed to create zellij session: {}", name);
@@ -162,7 +296,7 @@ pub fn attach_session_with_resurrect(name: &str, force_resurrect: bool) -> Resul
     }
     args.push(name);
 
-    let status = Command::new("zellij").args(&args).status()?;
+    let status = run_status(Command::new("zellij").args(&args))?;
 
     if !status.success() {
         anyhow::bail!("Failed to attach to zellij session: {}", name);
@@ -171,9 +305,11 @@ pub fn attach_session_with_resurrect(name: &str, force_resurrect: bool) -> Resul
 }
 
 pub fn kill_session(name: &str) -> Result<()> {
-    let status = Command::new("zellij")
-        .args(["kill-session", name])
-        .status()?;
+    if Multiplexer::from_env() == Multiplexer::Tmux {
+        return super::tmux::kill_session(name);
+    }
+
+    let status = run_status(Command::new("zellij").args(["kill-session", name]))?;
 
     if !status.success() {
         anyhow::bail!("Failed to kill zellij session: {}", name);
@@ -181,6 +317,21 @@ pub fn kill_session(name: &str) -> Result<()> {
     Ok(())
 }
 
+/// Remove an exited session so the next launch starts from scratch
+pub fn delete_session(name: &str) -> Result<()> {
+    // An exited tmux session is only a dead pane kept around, killing removes it
+    if Multiplexer::from_env() == Multiplexer::Tmux {
+        return super::tmux::kill_session(name);
+    }
+
+    let status = run_status(Command::new("zellij").args(["delete-session", name]))?;
+
+    if !status.success() {
+        anyhow::bail!("Failed to delete zellij session: {}", name);
+    }
+    Ok(())
+}
+
 pub fn sanitize_session_name(branch: &str) -> String {
     // Convert branch name to valid zellij session name
     // Replace slashes and special chars with dashes
@@ -210,10 +361,87 @@ pub fn session_name_for_branch(branch: &str) -> String {
 }
 
 pub fn is_zellij_installed() -> bool {
-    Command::new("zellij")
-        .arg("--version")
-        .output()
+    run_output(Command::new("zellij").arg("--version"))
         .map(|o| o.status.success())
         .unwrap_or(false)
 }
 
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_attention_patterns_file() {
+        let patterns = AttentionPatterns::parse(
+            "# prompts from our tools\n\
+             Approve?\n\
+             \n\
+             [y/n]\n\
+             !$ git\n",
+        );
+        assert!(patterns.matches("Approve? (yes)"));
+        assert!(patterns.matches("Overwrite file [Y/N]"));
+        assert!(!patterns.matches("Do you want to proceed"));
+        assert!(!patterns.matches("$ git push [y/n]"));
+        assert!(!patterns.matches("# prompts from our tools"));
+    }
+
+    #[test]
+    fn test_attention_negations_keep_builtin_patterns() {
+        let patterns = AttentionPatterns::parse("!user@host\n");
+        assert!(patterns.matches("Do you want to proceed?"));
+        assert!(!patterns.matches("user@host ~/repo >"));
+        assert!(patterns.matches("~/repo >"));
+    }
+
+    fn names(sessions: &[ZellijSession]) -> Vec<&str> {
+        sessions.iter().map(|s| s.name.as_str()).collect()
+    }
+
+    #[test]
+    fn test_parse_session_list_plain_names() {
+        // zellij < 0.35 printed only the names
+        let sessions = parse_session_list("feature-a\nfeature-b (current)\n");
+        assert_eq!(names(&sessions), vec!["feature-a", "feature-b"]);
+        assert!(!sessions[0].is_current);
+        assert!(sessions[1].is_current);
+    }
+
+    #[test]
+    fn test_parse_session_list_no_formatting() {
+        let output = "feature-a [Created 3m 5s ago] (current)\n\
+                      feature-b [Created 1h 2m ago] (EXITED - attach to resurrect)\n\
+                      feature-c [Created 10s ago] \n";
+        let sessions = parse_session_list(output);
+        assert_eq!(names(&sessions), vec!["feature-a", "feature-b", "feature-c"]);
+        assert!(sessions[0].is_current && !sessions[0].is_dead);
+        assert!(!sessions[1].is_current && sessions[1].is_dead);
+        assert!(!sessions[2].is_current && !sessions[2].is_dead);
+    }
+
+    #[test]
+    fn test_parse_session_list_colored() {
+        let output = "\x1b[32;1mfeature-a\x1b[m [Created \x1b[35;1m3m 5s\x1b[m ago] \
+                      (\x1b[31;1mEXITED\x1b[m - attach to resurrect)\n";
+        let sessions = parse_session_list(output);
+        assert_eq!(names(&sessions), vec!["feature-a"]);
+        assert!(sessions[0].is_dead);
+    }
+
+    #[test]
+    fn test_parse_session_list_no_sessions() {
+        assert!(parse_session_list("No active zellij sessions found.\n").is_empty());
+        assert!(parse_session_list("").is_empty());
+    }
+
+    #[test]
+    fn test_is_unknown_argument_error() {
+        assert!(is_unknown_argument_error(
+            b"error: unexpected argument '--no-formatting' found"
+        ));
+        assert!(is_unknown_argument_error(
+            b"error: Found argument '--no-formatting' which wasn't expected"
+        ));
+        assert!(!is_unknown_argument_error(b"No active zellij sessions found."));
+    }
+}
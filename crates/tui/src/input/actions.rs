@@ -19,15 +19,33 @@ pub enum Action {
     SwitchWorktree,
 
     ShowSessions,
+    ShowCiFailures,
     LaunchSession,
     LaunchSessionPlan,
+    ResumeSession,
+    RetrySession,
     AttachSession,
     KillSession,
     ViewPR,
     BindPR,
+    CopyTaskMarkdown,
+    /// Copy the prompt a fresh session for the task would be launched with
+    CopyTaskPrompt,
+    CycleSort,
+    ToggleTaskIds,
+    /// Show only tasks with a matching worktree or a live session
+    ToggleActiveWork,
+    PickStatus,
+    /// Move the selected task to the next or previous kanban column
+    MoveTaskRight,
+    MoveTaskLeft,
+    ToggleFocus,
+    /// Select the most recently updated task with an agent running
+    JumpToActive,
 
     StartSearch,
     SearchType(char),
+    SearchPaste(String),
     SearchBackspace,
     SearchDeleteWord,
     ClearSearch,
@@ -35,6 +53,7 @@ pub enum Action {
     // Command mode (vim-like ;f)
     StartCommand,
     CommandType(char),
+    CommandPaste(String),
     CommandBackspace,
     ExecuteCommand,
     CancelCommand,
@@ -42,5 +61,20 @@ pub enum Action {
     ShowHelp,
     Refresh,
     SyncLinear,
+    WarmSearchCache,
+    /// Rebase the selected task's branch onto its target branch through the server
+    RebaseBranch,
+    /// Show the project's repository in the system file manager
+    OpenProjectDir,
+    /// Open the quick project switcher
+    SwitchProject,
     ShowLogs,
+    /// Start or cancel a visual line selection in the logs
+    ToggleLogSelection,
+    /// Copy the selected log lines to the clipboard
+    YankLogs,
+    /// Show only the raw stderr of a tailed process, or all of its output again
+    ToggleStderrOnly,
+    /// Stop the execution process tailed in the logs view
+    StopProcess,
 }
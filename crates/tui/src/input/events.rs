@@ -36,3 +36,16 @@ pub fn extract_key_event(event: Event) -> Option<KeyEvent> {
         _ => None,
     }
 }
+
+/// Text from a bracketed paste, flattened to a single line for the search input
+pub fn extract_paste_event(event: &Event) -> Option<String> {
+    match event {
+        Event::Paste(text) => Some(
+            text.split(|c: char| c.is_control())
+                .filter(|part| !part.is_empty())
+                .collect::<Vec<_>>()
+                .join(" "),
+        ),
+        _ => None,
+    }
+}
@@ -7,27 +7,37 @@ pub fn key_to_action(
     key: KeyEvent,
     view: View,
     in_modal: bool,
+    modal_input: bool,
     search_active: bool,
     logs_overlay_visible: bool,
     command_active: bool,
 ) -> Option<Action> {
-    // Modal-specific bindings
+    // Modal-specific bindings; a modal with a text field takes typing like search
     if in_modal {
+        if modal_input {
+            return search_bindings(key);
+        }
         return match key.code {
-            KeyCode::Esc => Some(Action::Back),
-            KeyCode::Enter => Some(Action::Select),
+            KeyCode::Esc | KeyCode::Char('n') => Some(Action::Back),
+            KeyCode::Enter | KeyCode::Char('y') => Some(Action::Select),
+            KeyCode::Char('j') | KeyCode::Down => Some(Action::Down),
+            KeyCode::Char('k') | KeyCode::Up => Some(Action::Up),
             _ => None,
         };
     }
 
-    // Logs overlay bindings - Shift+I toggles, j/k scroll, Esc closes
+    // Logs overlay bindings - Shift+I toggles, j/k scroll, v/y select and copy, Esc closes
     if logs_overlay_visible {
         return match (key.code, key.modifiers) {
             (KeyCode::Char('I'), KeyModifiers::SHIFT) => Some(Action::ShowLogs),
             (KeyCode::Char('j') | KeyCode::Down, _) => Some(Action::Down),
             (KeyCode::Char('k') | KeyCode::Up, _) => Some(Action::Up),
             (KeyCode::Char('r'), KeyModifiers::NONE) => Some(Action::Refresh),
-            (KeyCode::Esc, _) => Some(Action::ShowLogs), // Close overlay
+            (KeyCode::Char('v'), KeyModifiers::NONE) => Some(Action::ToggleLogSelection),
+            (KeyCode::Char('y'), KeyModifiers::NONE) => Some(Action::YankLogs),
+            (KeyCode::Char('e'), KeyModifiers::NONE) => Some(Action::ToggleStderrOnly),
+            (KeyCode::Char('K'), KeyModifiers::SHIFT) => Some(Action::StopProcess),
+            (KeyCode::Esc, _) => Some(Action::Back), // Cancel selection, then close overlay
             _ => None,
         };
     }
@@ -50,6 +60,7 @@ pub fn key_to_action(
         (KeyCode::Char('/'), KeyModifiers::NONE) => return Some(Action::StartSearch),
         (KeyCode::Char(';'), KeyModifiers::NONE) => return Some(Action::StartCommand),
         (KeyCode::Char('I'), KeyModifiers::SHIFT) => return Some(Action::ShowLogs),
+        (KeyCode::Char('P'), KeyModifiers::SHIFT) => return Some(Action::SwitchProject),
         (KeyCode::Esc, _) => return Some(Action::Back),
         _ => {}
     }
@@ -63,6 +74,8 @@ pub fn key_to_action(
         View::Sessions => sessions_bindings(key),
         View::Logs => logs_bindings(key),
         View::Search => search_bindings(key),
+        View::CiFailures => ci_failures_bindings(key),
+        View::Focus => focus_bindings(key),
     }
 }
 
@@ -93,6 +106,34 @@ fn search_bindings(key: KeyEvent) -> Option<Action> {
     }
 }
 
+/// Bindings while a task deletion is being confirmed. Only `y` deletes, so an
+/// Enter meant for the board cannot destroy a task by accident
+pub fn confirm_delete_bindings(key: KeyEvent) -> Option<Action> {
+    match key.code {
+        KeyCode::Char('y') => Some(Action::Select),
+        KeyCode::Esc | KeyCode::Char('n') => Some(Action::Back),
+        _ => None,
+    }
+}
+
+/// Where a bracketed paste goes: the text field of a modal, the command line
+/// or the search box; elsewhere it is dropped
+pub fn paste_to_action(
+    text: String,
+    in_modal: bool,
+    modal_input: bool,
+    search_active: bool,
+    command_active: bool,
+) -> Option<Action> {
+    if in_modal {
+        return modal_input.then_some(Action::SearchPaste(text));
+    }
+    if command_active {
+        return Some(Action::CommandPaste(text));
+    }
+    search_active.then_some(Action::SearchPaste(text))
+}
+
 fn command_bindings(key: KeyEvent) -> Option<Action> {
     match (key.code, key.modifiers) {
         // Esc to cancel command
@@ -114,6 +155,10 @@ fn logs_bindings(key: KeyEvent) -> Option<Action> {
         KeyCode::Char('j') | KeyCode::Down => Some(Action::Down),
         KeyCode::Char('k') | KeyCode::Up => Some(Action::Up),
         KeyCode::Char('r') => Some(Action::Refresh),
+        KeyCode::Char('v') => Some(Action::ToggleLogSelection),
+        KeyCode::Char('y') => Some(Action::YankLogs),
+        KeyCode::Char('e') => Some(Action::ToggleStderrOnly),
+        KeyCode::Char('K') => Some(Action::StopProcess),
         _ => None,
     }
 }
@@ -124,6 +169,7 @@ fn project_list_bindings(key: KeyEvent) -> Option<Action> {
         KeyCode::Char('j') | KeyCode::Down => Some(Action::Down),
         KeyCode::Char('k') | KeyCode::Up => Some(Action::Up),
         KeyCode::Enter | KeyCode::Char(' ') => Some(Action::Select),
+        KeyCode::Char('o') => Some(Action::OpenProjectDir),
         KeyCode::Char('r') => Some(Action::Refresh),
         _ => None,
     }
@@ -159,14 +205,52 @@ fn kanban_bindings(key: KeyEvent) -> Option<Action> {
         (KeyCode::Char('v'), KeyModifiers::NONE) => Some(Action::ViewPR),
         (KeyCode::Char('b'), KeyModifiers::NONE) => Some(Action::BindPR),
 
+        // Copy task as markdown (yank)
+        (KeyCode::Char('y'), KeyModifiers::NONE) => Some(Action::CopyTaskMarkdown),
+        (KeyCode::Char('Y'), KeyModifiers::SHIFT) => Some(Action::CopyTaskPrompt),
+
+        // Cycle card order in the current column
+        (KeyCode::Char('o'), KeyModifiers::NONE) => Some(Action::CycleSort),
+
+        // Show short task ids on cards
+        (KeyCode::Char('i'), KeyModifiers::NONE) => Some(Action::ToggleTaskIds),
+
+        // Only tasks with a worktree or live session
+        (KeyCode::Char('A'), KeyModifiers::SHIFT) => Some(Action::ToggleActiveWork),
+
+        // Set any status, including ones without a column
+        (KeyCode::Char('m'), KeyModifiers::NONE) => Some(Action::PickStatus),
+
+        // Move the selected task between status columns
+        (KeyCode::Char('>'), _) => Some(Action::MoveTaskRight),
+        (KeyCode::Char('<'), _) => Some(Action::MoveTaskLeft),
+
+        // Focus on one task's session
+        (KeyCode::Char('f'), KeyModifiers::NONE) => Some(Action::ToggleFocus),
+
+        // Jump to the task whose agent started most recently
+        (KeyCode::Char('a'), KeyModifiers::NONE) => Some(Action::JumpToActive),
+
         // Worktrees and sessions views
         (KeyCode::Char('w'), KeyModifiers::NONE) => Some(Action::ShowWorktrees),
         (KeyCode::Char('W'), KeyModifiers::SHIFT) => Some(Action::CreateWorktree),
         (KeyCode::Char('S'), KeyModifiers::SHIFT) => Some(Action::ShowSessions),
 
+        // PRs with failing checks
+        (KeyCode::Char('F'), KeyModifiers::SHIFT) => Some(Action::ShowCiFailures),
+
         // Linear sync
         (KeyCode::Char('L'), KeyModifiers::SHIFT) => Some(Action::SyncLinear),
 
+        // Server file search cache
+        (KeyCode::Char('C'), KeyModifiers::SHIFT) => Some(Action::WarmSearchCache),
+
+        // Bring the task's branch up to date with its target through the server
+        (KeyCode::Char('U'), KeyModifiers::SHIFT) => Some(Action::RebaseBranch),
+
+        // Project directory in the file manager
+        (KeyCode::Char('O'), KeyModifiers::SHIFT) => Some(Action::OpenProjectDir),
+
         // Refresh
         (KeyCode::Char('r'), KeyModifiers::NONE) => Some(Action::Refresh),
 
@@ -182,8 +266,13 @@ fn task_detail_bindings(key: KeyEvent) -> Option<Action> {
         KeyCode::Char('e') => Some(Action::EditTask),
         KeyCode::Char('g') => Some(Action::LaunchSession),
         KeyCode::Char('p') => Some(Action::LaunchSessionPlan),
+        KeyCode::Char('x') => Some(Action::RetrySession),
         KeyCode::Char('v') => Some(Action::ViewPR),
         KeyCode::Char('b') => Some(Action::BindPR),
+        KeyCode::Char('y') => Some(Action::CopyTaskMarkdown),
+        KeyCode::Char('Y') => Some(Action::CopyTaskPrompt),
+        KeyCode::Char('m') => Some(Action::PickStatus),
+        KeyCode::Char('f') => Some(Action::ToggleFocus),
         KeyCode::Char('r') => Some(Action::Refresh),
         KeyCode::Enter | KeyCode::Char(' ') => Some(Action::LaunchSession),
         KeyCode::Char('w') => Some(Action::ShowWorktrees),
@@ -199,6 +288,7 @@ fn worktrees_bindings(key: KeyEvent) -> Option<Action> {
         KeyCode::Enter | KeyCode::Char(' ') => Some(Action::SwitchWorktree),
         KeyCode::Char('g') => Some(Action::LaunchSession),
         KeyCode::Char('p') => Some(Action::LaunchSessionPlan),
+        KeyCode::Char('R') => Some(Action::ResumeSession),
         KeyCode::Char('W') => Some(Action::CreateWorktree),
         KeyCode::Char('S') => Some(Action::ShowSessions),
         KeyCode::Char('r') => Some(Action::Refresh),
@@ -217,3 +307,103 @@ fn sessions_bindings(key: KeyEvent) -> Option<Action> {
         _ => None,
     }
 }
+
+fn ci_failures_bindings(key: KeyEvent) -> Option<Action> {
+    match key.code {
+        KeyCode::Char('j') | KeyCode::Down => Some(Action::Down),
+        KeyCode::Char('k') | KeyCode::Up => Some(Action::Up),
+        KeyCode::Enter | KeyCode::Char('l') => Some(Action::Select),
+        KeyCode::Char('v') => Some(Action::ViewPR),
+        KeyCode::Char('r') => Some(Action::Refresh),
+        _ => None,
+    }
+}
+
+fn focus_bindings(key: KeyEvent) -> Option<Action> {
+    match key.code {
+        KeyCode::Char('f') => Some(Action::ToggleFocus),
+        KeyCode::Enter | KeyCode::Char('g') => Some(Action::Select),
+        KeyCode::Char('x') => Some(Action::RetrySession),
+        KeyCode::Char('m') => Some(Action::PickStatus),
+        KeyCode::Char('r') => Some(Action::Refresh),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn press(code: KeyCode, in_modal: bool) -> Option<Action> {
+        let key = KeyEvent::new(code, KeyModifiers::NONE);
+        key_to_action(key, View::Kanban, in_modal, false, false, false, false)
+    }
+
+    #[test]
+    fn test_delete_confirmation_routes_through_modal_bindings() {
+        assert_eq!(press(KeyCode::Char('d'), false), Some(Action::DeleteTask));
+
+        // While the confirmation is open, only y confirms and n/Esc cancel
+        let confirm = |code| confirm_delete_bindings(KeyEvent::new(code, KeyModifiers::NONE));
+        assert_eq!(confirm(KeyCode::Char('y')), Some(Action::Select));
+        assert_eq!(confirm(KeyCode::Enter), None);
+        assert_eq!(confirm(KeyCode::Char('n')), Some(Action::Back));
+        assert_eq!(confirm(KeyCode::Esc), Some(Action::Back));
+        assert_eq!(confirm(KeyCode::Char('d')), None);
+
+        // Other confirmations still take Enter
+        assert_eq!(press(KeyCode::Enter, true), Some(Action::Select));
+    }
+
+    #[test]
+    fn test_paste_goes_to_the_active_text_field() {
+        let paste = |in_modal, modal_input, search_active, command_active| {
+            paste_to_action(
+                "tail 1234".to_string(),
+                in_modal,
+                modal_input,
+                search_active,
+                command_active,
+            )
+        };
+        let text = || "tail 1234".to_string();
+
+        assert_eq!(
+            paste(false, false, false, true),
+            Some(Action::CommandPaste(text()))
+        );
+        assert_eq!(
+            paste(false, false, true, false),
+            Some(Action::SearchPaste(text()))
+        );
+        assert_eq!(
+            paste(true, true, false, false),
+            Some(Action::SearchPaste(text()))
+        );
+        assert_eq!(paste(true, false, false, true), None);
+        assert_eq!(paste(false, false, false, false), None);
+    }
+
+    #[test]
+    fn test_project_switcher_takes_typed_text() {
+        let shift_p = KeyEvent::new(KeyCode::Char('P'), KeyModifiers::SHIFT);
+        for view in [View::Kanban, View::TaskDetail, View::Worktrees] {
+            assert_eq!(
+                key_to_action(shift_p, view, false, false, false, false, false),
+                Some(Action::SwitchProject)
+            );
+        }
+
+        // Inside the switcher, letters that are bindings elsewhere go into the query
+        let n = KeyEvent::new(KeyCode::Char('n'), KeyModifiers::NONE);
+        assert_eq!(
+            key_to_action(n, View::Kanban, true, true, false, false, false),
+            Some(Action::SearchType('n'))
+        );
+        let enter = KeyEvent::new(KeyCode::Enter, KeyModifiers::NONE);
+        assert_eq!(
+            key_to_action(enter, View::Kanban, true, true, false, false, false),
+            Some(Action::Select)
+        );
+    }
+}
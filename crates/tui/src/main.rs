@@ -4,6 +4,7 @@ use std::sync::Mutex;
 use tracing_subscriber::{layer::SubscriberExt, util::SubscriberInitExt};
 
 mod app;
+mod config;
 mod external;
 mod input;
 mod state;
@@ -1,7 +1,17 @@
-use super::{LogsState, ProjectsState, SearchState, SessionsState, TasksState, WorktreesState};
-use crate::external::LinearIssue;
-
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+use ratatui::style::Color;
+use serde::{Deserialize, Serialize};
+
+use super::{
+    FocusState, LogsState, ProjectSwitcher, ProjectsState, SearchState, SessionsState, TasksState,
+    WorktreesState,
+};
+use crate::{
+    config::SpinnerConfig,
+    external::{ClaudeSessionInfo, LinearIssue},
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
 pub enum View {
     Projects,
     Kanban,
@@ -10,11 +20,48 @@ pub enum View {
     Sessions,
     Logs,
     Search,
+    CiFailures,
+    /// One task with its session status and live session output
+    Focus,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum Modal {
     Help,
+    /// Confirm killing a session whose agent still appears to be working
+    ConfirmKillSession {
+        session_name: String,
+    },
+    /// Confirm stopping the execution process tailed in the logs view
+    ConfirmStopProcess {
+        process_id: String,
+    },
+    /// Confirm deleting a task; `d` is a single keypress and there is no undo
+    ConfirmDelete {
+        task_id: String,
+        task_title: String,
+    },
+    /// Pick the status of a task from all statuses
+    PickStatus {
+        task_id: String,
+        task_title: String,
+        selected: usize,
+    },
+    /// Pick a past Claude conversation of a worktree to resume
+    ResumeSession {
+        branch: String,
+        sessions: Vec<ClaudeSessionInfo>,
+        selected: usize,
+    },
+    /// Fuzzy-find another project and make it the current one
+    SwitchProject(ProjectSwitcher),
+}
+
+/// Current spinner frame and its color, for views that animate busy state
+#[derive(Debug, Clone, Copy)]
+pub struct Spinner {
+    pub char: char,
+    pub color: Color,
 }
 
 pub struct AppState {
@@ -27,6 +74,8 @@ pub struct AppState {
     pub sessions: SessionsState,
     pub logs: LogsState,
     pub search: SearchState,
+    pub focus: FocusState,
+    pub task_detail: TaskDetailState,
 
     pub selected_project_id: Option<String>,
     pub selected_task_id: Option<String>,
@@ -40,10 +89,15 @@ pub struct AppState {
     pub backend_connected: bool,
     pub should_quit: bool,
 
-    pub animation_frame: u8,
+    pub animation_frame: usize,
+    pub spinner_frames: Vec<char>,
+    pub spinner_color: Color,
 
     pub linear_api_key_available: bool,
 
+    /// False when the project directory is missing or not a git repository
+    pub project_valid: bool,
+
     /// Linear issues in backlog that haven't been imported locally yet
     pub linear_pending_issues: Vec<LinearIssue>,
 
@@ -56,6 +110,9 @@ pub struct AppState {
     /// Vim-like command mode input (e.g., ";f" for search)
     /// None means not in command mode, Some(s) means currently typing command
     pub command_input: Option<String>,
+
+    /// Board hidden behind the lock screen after the idle timeout; the next key unlocks it
+    pub locked: bool,
 }
 
 impl AppState {
@@ -70,6 +127,8 @@ impl AppState {
             sessions: SessionsState::new(),
             logs: LogsState::new(),
             search: SearchState::new(),
+            focus: FocusState::new(),
+            task_detail: TaskDetailState::new(),
 
             selected_project_id: None,
             selected_task_id: None,
@@ -82,25 +141,43 @@ impl AppState {
             should_quit: false,
 
             animation_frame: 0,
+            spinner_frames: SpinnerConfig::default().frames(),
+            spinner_color: SpinnerConfig::default().color(),
 
             linear_api_key_available: false,
 
+            project_valid: true,
+
             linear_pending_issues: Vec::new(),
             linear_error: None,
 
             logs_overlay_visible: false,
 
             command_input: None,
+
+            locked: false,
         }
     }
 
+    pub fn set_spinner(&mut self, config: &SpinnerConfig) {
+        self.spinner_frames = config.frames();
+        self.spinner_color = config.color();
+        self.animation_frame = 0;
+    }
+
     pub fn tick_animation(&mut self) {
-        self.animation_frame = (self.animation_frame + 1) % 4;
+        self.animation_frame = (self.animation_frame + 1) % self.spinner_frames.len();
     }
 
     pub fn spinner_char(&self) -> char {
-        const SPINNER: [char; 4] = ['|', '/', '-', '\\'];
-        SPINNER[self.animation_frame as usize]
+        self.spinner_frames[self.animation_frame]
+    }
+
+    pub fn spinner(&self) -> Spinner {
+        Spinner {
+            char: self.spinner_char(),
+            color: self.spinner_color,
+        }
     }
 }
 
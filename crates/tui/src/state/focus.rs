@@ -0,0 +1,74 @@
+use super::View;
+
+/// Lines of the session screen kept for focus mode
+const MAX_SCREEN_LINES: usize = 500;
+
+/// Focus mode: one task with its session status and live session output
+pub struct FocusState {
+    /// Task being focused on
+    pub task_id: Option<String>,
+    /// View to return to when leaving focus mode
+    pub previous_view: View,
+    /// Last captured screen of the task's session, trailing blank lines removed
+    pub screen: Vec<String>,
+}
+
+impl FocusState {
+    pub fn new() -> Self {
+        Self {
+            task_id: None,
+            previous_view: View::Kanban,
+            screen: Vec::new(),
+        }
+    }
+
+    pub fn enter(&mut self, task_id: String, previous_view: View) {
+        self.task_id = Some(task_id);
+        self.previous_view = previous_view;
+        self.screen.clear();
+    }
+
+    /// Leave focus mode, returning the view to go back to
+    pub fn exit(&mut self) -> View {
+        self.task_id = None;
+        self.screen.clear();
+        self.previous_view
+    }
+
+    pub fn set_screen(&mut self, screen: Option<&str>) {
+        let Some(screen) = screen else {
+            self.screen.clear();
+            return;
+        };
+        let mut lines: Vec<String> = screen.lines().map(|l| l.trim_end().to_string()).collect();
+        while lines.last().is_some_and(|l| l.is_empty()) {
+            lines.pop();
+        }
+        let skip = lines.len().saturating_sub(MAX_SCREEN_LINES);
+        self.screen = lines.split_off(skip);
+    }
+}
+
+impl Default for FocusState {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_set_screen_trims_trailing_blank_lines() {
+        let mut state = FocusState::new();
+        state.enter("task-1".to_string(), View::TaskDetail);
+        state.set_screen(Some("> claude  \nworking...\n\n   \n"));
+        assert_eq!(state.screen, vec!["> claude", "working..."]);
+
+        state.set_screen(None);
+        assert!(state.screen.is_empty());
+        assert_eq!(state.exit(), View::TaskDetail);
+        assert_eq!(state.task_id, None);
+    }
+}
@@ -5,11 +5,27 @@ use std::path::PathBuf;
 
 const MAX_LINES: usize = 1000;
 
+/// A line of tailed process output
+struct TailLine {
+    stderr: bool,
+    text: String,
+}
+
 pub struct LogsState {
     pub lines: VecDeque<String>,
     pub scroll_offset: usize,
+    /// Line where the visual selection started; it runs to `scroll_offset`
+    pub selection_anchor: Option<usize>,
     pub log_path: PathBuf,
     last_position: u64,
+    /// Execution process whose output is followed from the server instead of the log file
+    pub tailing: Option<String>,
+    /// Connection state of the tail, shown in the title
+    pub tail_status: String,
+    /// Everything tailed so far, so `stderr_only` can be switched without reconnecting
+    tail_output: VecDeque<TailLine>,
+    /// Show only the tailed process's raw stderr, where spawn failures are reported
+    pub stderr_only: bool,
 }
 
 impl LogsState {
@@ -22,15 +38,24 @@ impl LogsState {
         Self {
             lines: VecDeque::new(),
             scroll_offset: 0,
+            selection_anchor: None,
             log_path,
             last_position: 0,
+            tailing: None,
+            tail_status: String::new(),
+            tail_output: VecDeque::new(),
+            stderr_only: false,
         }
     }
 
     pub fn load_logs(&mut self) {
+        if self.tailing.is_some() {
+            return;
+        }
         if let Ok(file) = File::open(&self.log_path) {
             let reader = BufReader::new(file);
             self.lines.clear();
+            self.selection_anchor = None;
 
             for line in reader.lines().flatten() {
                 self.lines.push_back(line);
@@ -45,6 +70,10 @@ impl LogsState {
     }
 
     pub fn refresh(&mut self) {
+        // A tail is pushed from the server, there is nothing to re-read
+        if self.tailing.is_some() {
+            return;
+        }
         // Read new lines from log file
         if let Ok(mut file) = File::open(&self.log_path) {
             // Seek to last known position
@@ -52,10 +81,7 @@ impl LogsState {
                 let reader = BufReader::new(&mut file);
 
                 for line in reader.lines().flatten() {
-                    self.lines.push_back(line);
-                    if self.lines.len() > MAX_LINES {
-                        self.lines.pop_front();
-                    }
+                    self.push_line(line);
                 }
 
                 // Update position
@@ -66,6 +92,83 @@ impl LogsState {
         }
     }
 
+    fn push_line(&mut self, line: String) {
+        self.lines.push_back(line);
+        if self.lines.len() > MAX_LINES {
+            self.lines.pop_front();
+            // Keep the selection on the same text as old lines drop off
+            self.selection_anchor = self.selection_anchor.map(|a| a.saturating_sub(1));
+        }
+    }
+
+    /// Show an execution process's output in place of the log file
+    pub fn start_tail(&mut self, process_id: &str) {
+        self.tailing = Some(process_id.to_string());
+        self.tail_status = "connecting".to_string();
+        self.clear_lines();
+    }
+
+    /// The server replays the whole output on every (re)connect
+    pub fn tail_connected(&mut self) {
+        self.tail_status = "live".to_string();
+        self.tail_output.clear();
+        self.clear_lines();
+    }
+
+    /// Append tailed output, following it while the cursor is on the last line
+    pub fn push_output(&mut self, output: &str, stderr: bool) {
+        let following = self.scroll_offset + 1 >= self.lines.len();
+        for line in output.lines() {
+            self.tail_output.push_back(TailLine {
+                stderr,
+                text: line.to_string(),
+            });
+            if self.tail_output.len() > MAX_LINES {
+                self.tail_output.pop_front();
+            }
+            if stderr || !self.stderr_only {
+                self.push_line(line.to_string());
+            }
+        }
+        if following {
+            self.scroll_offset = self.lines.len().saturating_sub(1);
+        }
+    }
+
+    /// Switch a tail between all output and raw stderr only. Returns whether
+    /// only stderr is shown now; without a tail there is nothing to switch.
+    pub fn toggle_stderr_only(&mut self) -> bool {
+        if self.tailing.is_none() {
+            return false;
+        }
+        self.stderr_only = !self.stderr_only;
+        self.clear_lines();
+        let lines: Vec<String> = self
+            .tail_output
+            .iter()
+            .filter(|line| line.stderr || !self.stderr_only)
+            .map(|line| line.text.clone())
+            .collect();
+        self.lines.extend(lines);
+        self.scroll_offset = self.lines.len().saturating_sub(1);
+        self.stderr_only
+    }
+
+    /// Go back to the log file
+    pub fn stop_tail(&mut self) {
+        self.tailing = None;
+        self.tail_status.clear();
+        self.tail_output.clear();
+        self.stderr_only = false;
+        self.load_logs();
+    }
+
+    fn clear_lines(&mut self) {
+        self.lines.clear();
+        self.scroll_offset = 0;
+        self.selection_anchor = None;
+    }
+
     pub fn scroll_up(&mut self) {
         self.scroll_offset = self.scroll_offset.saturating_sub(1);
     }
@@ -76,10 +179,55 @@ impl LogsState {
         }
     }
 
-    pub fn visible_lines(&self, height: usize) -> impl Iterator<Item = &String> {
+    /// Lines around the current one, with their index into `lines`
+    pub fn visible_lines(&self, height: usize) -> impl Iterator<Item = (usize, &String)> {
         let start = self.scroll_offset.saturating_sub(height / 2);
         let end = (start + height).min(self.lines.len());
-        self.lines.iter().skip(start).take(end - start)
+        self.lines
+            .iter()
+            .enumerate()
+            .skip(start)
+            .take(end.saturating_sub(start))
+    }
+
+    /// Start a visual selection at the current line, or cancel the one in progress
+    pub fn toggle_selection(&mut self) {
+        self.selection_anchor = match self.selection_anchor {
+            Some(_) => None,
+            None => Some(self.scroll_offset),
+        };
+    }
+
+    /// First and last selected line, inclusive
+    fn selection(&self) -> Option<(usize, usize)> {
+        let anchor = self.selection_anchor?;
+        Some((
+            anchor.min(self.scroll_offset),
+            anchor.max(self.scroll_offset),
+        ))
+    }
+
+    pub fn is_selected(&self, index: usize) -> bool {
+        self.selection()
+            .is_some_and(|(start, end)| (start..=end).contains(&index))
+    }
+
+    /// Number of selected lines, if a selection is in progress
+    pub fn selection_len(&self) -> Option<usize> {
+        self.selection().map(|(start, end)| end - start + 1)
+    }
+
+    /// Selected lines joined with newlines, ready to copy
+    pub fn selected_text(&self) -> Option<String> {
+        let (start, end) = self.selection()?;
+        let lines: Vec<&str> = self
+            .lines
+            .iter()
+            .skip(start)
+            .take(end - start + 1)
+            .map(String::as_str)
+            .collect();
+        (!lines.is_empty()).then(|| lines.join("\n"))
     }
 }
 
@@ -88,3 +236,69 @@ impl Default for LogsState {
         Self::new()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_selected_text_spans_anchor_to_cursor() {
+        let mut logs = LogsState::new();
+        logs.lines = ["a", "b", "c", "d"].iter().map(|s| s.to_string()).collect();
+        assert_eq!(logs.selected_text(), None);
+
+        logs.scroll_offset = 2;
+        logs.toggle_selection();
+        logs.scroll_up();
+        logs.scroll_up();
+        assert_eq!(logs.selected_text().as_deref(), Some("a\nb\nc"));
+        assert_eq!(logs.selection_len(), Some(3));
+        assert!(logs.is_selected(0) && !logs.is_selected(3));
+
+        logs.toggle_selection();
+        assert_eq!(logs.selected_text(), None);
+    }
+
+    #[test]
+    fn test_push_output_follows_the_tail() {
+        let mut logs = LogsState::new();
+        logs.start_tail("proc-1");
+        logs.tail_connected();
+        logs.push_output("Compiling vibe\nFinished\n", false);
+        assert_eq!(logs.lines, ["Compiling vibe", "Finished"]);
+        assert_eq!(logs.scroll_offset, 1);
+
+        // Scrolled up: new output must not pull the cursor back down
+        logs.scroll_up();
+        logs.push_output("Running tests\n", false);
+        assert_eq!(logs.lines.len(), 3);
+        assert_eq!(logs.scroll_offset, 0);
+
+        // Reconnecting replays the output from the start
+        logs.tail_connected();
+        assert!(logs.lines.is_empty());
+        logs.refresh();
+        assert!(logs.lines.is_empty());
+    }
+
+    #[test]
+    fn test_toggle_stderr_only() {
+        let mut logs = LogsState::new();
+        assert!(!logs.toggle_stderr_only());
+
+        logs.start_tail("proc-1");
+        logs.tail_connected();
+        logs.push_output("Starting agent\n", false);
+        logs.push_output("spawn claude: No such file or directory\n", true);
+        assert!(logs.toggle_stderr_only());
+        assert_eq!(logs.lines, ["spawn claude: No such file or directory"]);
+
+        logs.push_output("still starting\n", false);
+        logs.push_output("exit status 127\n", true);
+        assert_eq!(logs.lines.len(), 2);
+
+        assert!(!logs.toggle_stderr_only());
+        assert_eq!(logs.lines.len(), 4);
+        assert_eq!(logs.scroll_offset, 3);
+    }
+}
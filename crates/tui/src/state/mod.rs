@@ -1,15 +1,19 @@
 mod app_state;
+mod focus;
 mod logs;
 mod projects;
 mod search;
 mod sessions;
+mod task_detail;
 mod tasks;
 mod worktrees;
 
 pub use app_state::*;
+pub use focus::*;
 pub use logs::*;
 pub use projects::*;
 pub use search::*;
 pub use sessions::*;
+pub use task_detail::*;
 pub use tasks::*;
 pub use worktrees::*;
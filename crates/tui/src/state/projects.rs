@@ -1,5 +1,9 @@
+use std::path::{Path, PathBuf};
+
 use serde::{Deserialize, Serialize};
 
+use super::fuzzy_score;
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Project {
     pub id: String,
@@ -8,6 +12,32 @@ pub struct Project {
     pub dev_script_working_dir: Option<String>,
     pub default_agent_working_dir: Option<String>,
     pub remote_project_id: Option<String>,
+    /// False when the project's repository was moved or deleted on disk
+    #[serde(default = "default_valid")]
+    pub valid: bool,
+    /// Starred on the server; listed before other projects
+    #[serde(default)]
+    pub favorite: bool,
+}
+
+impl Project {
+    /// Name as shown in lists and the header, starred when it's a favorite
+    pub fn display_name(&self) -> String {
+        if self.favorite {
+            format!("★ {}", self.name)
+        } else {
+            self.name.clone()
+        }
+    }
+}
+
+fn default_valid() -> bool {
+    true
+}
+
+/// Project health check: the directory still exists and is a git repository
+pub fn is_valid_project_dir(path: &Path) -> bool {
+    path.is_dir() && path.join(".git").exists()
 }
 
 pub struct ProjectsState {
@@ -27,3 +57,171 @@ impl Default for ProjectsState {
         Self::new()
     }
 }
+
+/// Whether `dir` is one of `favorite_dirs`, comparing resolved paths
+fn is_favorite_dir(favorite_dirs: &[PathBuf], dir: &Path) -> bool {
+    let dir = dir.canonicalize().unwrap_or_else(|_| dir.to_path_buf());
+    favorite_dirs.contains(&dir)
+}
+
+/// A project the TUI has been opened in before, with the directory it was opened from
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct KnownProject {
+    pub name: String,
+    pub dir: PathBuf,
+}
+
+/// Quick project switcher: known projects fuzzy-filtered by the typed query
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ProjectSwitcher {
+    pub query: String,
+    /// Favorites of a running server first, then most recently used first
+    projects: Vec<KnownProject>,
+    /// Directories of the projects starred on the server
+    favorite_dirs: Vec<PathBuf>,
+    /// Indexes into `projects` matching the query, best match first
+    matches: Vec<usize>,
+    pub selected: usize,
+}
+
+impl ProjectSwitcher {
+    pub fn new(projects: Vec<KnownProject>) -> Self {
+        let mut switcher = Self {
+            query: String::new(),
+            projects,
+            favorite_dirs: Vec::new(),
+            matches: Vec::new(),
+            selected: 0,
+        };
+        switcher.update_matches();
+        switcher
+    }
+
+    pub fn matching(&self) -> impl Iterator<Item = &KnownProject> {
+        self.matches.iter().map(|&i| &self.projects[i])
+    }
+
+    pub fn selected_project(&self) -> Option<&KnownProject> {
+        self.matches.get(self.selected).map(|&i| &self.projects[i])
+    }
+
+    /// Move the projects in `dirs`, the repositories of the server's favorite
+    /// projects, to the top of the list
+    pub fn pin_favorites(&mut self, dirs: Vec<PathBuf>) {
+        self.favorite_dirs = dirs;
+        let favorite_dirs = &self.favorite_dirs;
+        self.projects
+            .sort_by_key(|p| !is_favorite_dir(favorite_dirs, &p.dir));
+        self.update_matches();
+    }
+
+    pub fn is_favorite(&self, project: &KnownProject) -> bool {
+        is_favorite_dir(&self.favorite_dirs, &project.dir)
+    }
+
+    pub fn type_char(&mut self, c: char) {
+        self.query.push(c);
+        self.update_matches();
+    }
+
+    pub fn type_str(&mut self, s: &str) {
+        self.query.push_str(s);
+        self.update_matches();
+    }
+
+    pub fn backspace(&mut self) {
+        self.query.pop();
+        self.update_matches();
+    }
+
+    pub fn clear_query(&mut self) {
+        self.query.clear();
+        self.update_matches();
+    }
+
+    pub fn select_next(&mut self) {
+        if !self.matches.is_empty() {
+            self.selected = (self.selected + 1) % self.matches.len();
+        }
+    }
+
+    pub fn select_prev(&mut self) {
+        if !self.matches.is_empty() {
+            self.selected = (self.selected + self.matches.len() - 1) % self.matches.len();
+        }
+    }
+
+    /// Rank by fuzzy score; equal scores keep favorites, then the most recently
+    /// used, first
+    fn update_matches(&mut self) {
+        let mut scored: Vec<_> = self
+            .projects
+            .iter()
+            .enumerate()
+            .filter_map(|(i, p)| fuzzy_score(&self.query, &p.name).map(|score| (score, i)))
+            .collect();
+        scored.sort_by_key(|&(score, _)| std::cmp::Reverse(score));
+        self.matches = scored.into_iter().map(|(_, i)| i).collect();
+        self.selected = 0;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_is_valid_project_dir() {
+        let dir = tempfile::tempdir().unwrap();
+        assert!(!is_valid_project_dir(dir.path()));
+
+        std::fs::create_dir(dir.path().join(".git")).unwrap();
+        assert!(is_valid_project_dir(dir.path()));
+
+        assert!(!is_valid_project_dir(&dir.path().join("moved")));
+    }
+
+    fn known(name: &str) -> KnownProject {
+        KnownProject {
+            name: name.to_string(),
+            dir: PathBuf::from("/src").join(name),
+        }
+    }
+
+    #[test]
+    fn test_project_switcher_filters_and_ranks() {
+        let mut switcher =
+            ProjectSwitcher::new(vec![known("vibe-kanban"), known("api"), known("kanban")]);
+
+        // No query: most recently used first, so Enter goes back to the last project
+        assert_eq!(switcher.selected_project(), Some(&known("vibe-kanban")));
+
+        for c in "kan".chars() {
+            switcher.type_char(c);
+        }
+        let names: Vec<_> = switcher.matching().map(|p| p.name.as_str()).collect();
+        assert_eq!(names, ["kanban", "vibe-kanban"]);
+
+        switcher.select_next();
+        assert_eq!(switcher.selected_project(), Some(&known("vibe-kanban")));
+        switcher.select_next();
+        assert_eq!(switcher.selected_project(), Some(&known("kanban")));
+
+        switcher.type_char('z');
+        assert_eq!(switcher.selected_project(), None);
+        switcher.backspace();
+        assert_eq!(switcher.matching().count(), 2);
+    }
+
+    #[test]
+    fn test_project_switcher_pins_favorites() {
+        let mut switcher =
+            ProjectSwitcher::new(vec![known("a"), known("b"), known("c"), known("d")]);
+        switcher.pin_favorites(vec![known("d").dir, known("b").dir]);
+
+        let names: Vec<_> = switcher.matching().map(|p| p.name.as_str()).collect();
+        assert_eq!(names, ["b", "d", "a", "c"]);
+        assert!(switcher.is_favorite(&known("b")));
+        assert!(!switcher.is_favorite(&known("a")));
+    }
+}
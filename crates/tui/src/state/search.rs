@@ -42,6 +42,11 @@ impl SearchState {
         self.update_results();
     }
 
+    pub fn type_str(&mut self, s: &str) {
+        self.query.push_str(s);
+        self.update_results();
+    }
+
     pub fn backspace(&mut self) {
         self.query.pop();
         self.update_results();
@@ -75,17 +80,10 @@ impl SearchState {
             tasks.sort_by(|a, b| b.updated_at.cmp(&a.updated_at));
             self.results = tasks.iter().map(|t| SearchResult::from_task(t)).collect();
         } else {
-            let query_lower = self.query.to_lowercase();
             self.results = self
                 .all_tasks
                 .iter()
-                .filter(|task| {
-                    task.title.to_lowercase().contains(&query_lower)
-                        || task
-                            .description
-                            .as_ref()
-                            .is_some_and(|d| d.to_lowercase().contains(&query_lower))
-                })
+                .filter(|task| task.matches_query(&self.query))
                 .map(SearchResult::from_task)
                 .collect();
         }
@@ -2,9 +2,12 @@ use crate::external::ZellijSession;
 
 pub struct SessionsState {
     pub sessions: Vec<ZellijSession>,
+    /// Index into `filtered_sessions()`
     pub selected_index: usize,
     pub loading: bool,
     pub error: Option<String>,
+    /// Case-insensitive name substring typed after `/`; empty shows every session
+    pub search_filter: String,
 }
 
 impl SessionsState {
@@ -14,37 +17,72 @@ impl SessionsState {
             selected_index: 0,
             loading: false,
             error: None,
+            search_filter: String::new(),
         }
     }
 
     pub fn set_sessions(&mut self, sessions: Vec<ZellijSession>) {
         self.sessions = sessions;
         self.error = None;
-        if self.selected_index >= self.sessions.len() {
-            self.selected_index = self.sessions.len().saturating_sub(1);
+        self.clamp_selection();
+    }
+
+    /// Sessions whose name contains the search filter, in listing order
+    pub fn filtered_sessions(&self) -> Vec<&ZellijSession> {
+        let filter = self.search_filter.to_lowercase();
+        self.sessions
+            .iter()
+            .filter(|s| s.name.to_lowercase().contains(&filter))
+            .collect()
+    }
+
+    /// Filter by name, keeping the selection on a matching session
+    pub fn set_search_filter(&mut self, filter: &str) {
+        self.search_filter = filter.to_string();
+        self.clamp_selection();
+    }
+
+    pub fn clear_search_filter(&mut self) {
+        self.set_search_filter("");
+    }
+
+    fn clamp_selection(&mut self) {
+        let len = self.filtered_sessions().len();
+        if self.selected_index >= len {
+            self.selected_index = len.saturating_sub(1);
         }
     }
 
     pub fn selected(&self) -> Option<&ZellijSession> {
-        self.sessions.get(self.selected_index)
+        self.filtered_sessions().get(self.selected_index).copied()
     }
 
     pub fn select_next(&mut self) {
-        if !self.sessions.is_empty() {
-            self.selected_index = (self.selected_index + 1) % self.sessions.len();
+        let len = self.filtered_sessions().len();
+        if len > 0 {
+            self.selected_index = (self.selected_index + 1) % len;
         }
     }
 
     pub fn select_prev(&mut self) {
-        if !self.sessions.is_empty() {
+        let len = self.filtered_sessions().len();
+        if len > 0 {
             self.selected_index = if self.selected_index == 0 {
-                self.sessions.len() - 1
+                len - 1
             } else {
                 self.selected_index - 1
             };
         }
     }
 
+    /// Sessions across the whole list (ignoring the filter) waiting for the user
+    pub fn waiting_count(&self) -> usize {
+        self.sessions
+            .iter()
+            .filter(|s| s.waiting_for_user())
+            .count()
+    }
+
     pub fn session_for_branch(&self, branch: &str) -> Option<&ZellijSession> {
         let sanitized = crate::external::session_name_for_branch(branch);
         self.sessions.iter().find(|s| s.name == sanitized)
@@ -56,3 +94,71 @@ impl Default for SessionsState {
         Self::new()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::external::ClaudeActivityState;
+
+    fn session(name: &str) -> ZellijSession {
+        ZellijSession {
+            name: name.to_string(),
+            is_current: false,
+            is_dead: false,
+            needs_attention: false,
+            claude_activity: ClaudeActivityState::Unknown,
+        }
+    }
+
+    #[test]
+    fn test_search_filter_limits_navigation() {
+        let mut state = SessionsState::new();
+        state.set_sessions(vec![
+            session("add-login"),
+            session("fix-ci"),
+            session("add-logging"),
+        ]);
+        state.selected_index = 2;
+
+        state.set_search_filter("LOG");
+        let names: Vec<&str> = state
+            .filtered_sessions()
+            .iter()
+            .map(|s| s.name.as_str())
+            .collect();
+        assert_eq!(names, ["add-login", "add-logging"]);
+        assert_eq!(state.selected().unwrap().name, "add-logging");
+
+        state.select_next();
+        assert_eq!(state.selected().unwrap().name, "add-login");
+        state.select_prev();
+        assert_eq!(state.selected().unwrap().name, "add-logging");
+
+        state.set_search_filter("nothing");
+        assert!(state.selected().is_none());
+
+        state.clear_search_filter();
+        assert_eq!(state.filtered_sessions().len(), 3);
+    }
+
+    #[test]
+    fn test_waiting_count_ignores_filter_and_dead_sessions() {
+        let mut waiting = session("waiting");
+        waiting.claude_activity = ClaudeActivityState::WaitingForUser;
+        let mut prompt = session("prompt");
+        prompt.needs_attention = true;
+        let mut dead = session("dead");
+        dead.is_dead = true;
+        dead.needs_attention = true;
+        let mut thinking = session("thinking");
+        thinking.claude_activity = ClaudeActivityState::Thinking;
+        thinking.needs_attention = true;
+
+        let mut state = SessionsState::new();
+        state.set_sessions(vec![waiting, prompt, dead, thinking]);
+        assert_eq!(state.waiting_count(), 2);
+
+        state.set_search_filter("thinking");
+        assert_eq!(state.waiting_count(), 2);
+    }
+}
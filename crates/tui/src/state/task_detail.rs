@@ -0,0 +1,153 @@
+use super::Task;
+use crate::external::TimelineEvent;
+
+/// A line of the task's activity, with its time already formatted for display
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ActivityEntry {
+    pub when: String,
+    pub summary: String,
+}
+
+pub struct TaskDetailState {
+    /// First description line shown
+    pub scroll_offset: usize,
+    /// Largest offset that still fills the pane; updated on each render
+    max_scroll: usize,
+    /// Task the activity belongs to, so a late server answer for another one is dropped
+    activity_task_id: Option<String>,
+    /// Chronological activity log, oldest first
+    pub activity: Vec<ActivityEntry>,
+}
+
+impl TaskDetailState {
+    pub fn new() -> Self {
+        Self {
+            scroll_offset: 0,
+            max_scroll: 0,
+            activity_task_id: None,
+            activity: Vec::new(),
+        }
+    }
+
+    /// What the task file tells: when it was created and last edited
+    pub fn set_local_activity(&mut self, task: &Task) {
+        self.activity_task_id = Some(task.id.clone());
+        self.activity = vec![ActivityEntry {
+            when: task.created_at.clone(),
+            summary: "Task created".to_string(),
+        }];
+        if task.updated_at != task.created_at {
+            self.activity.push(ActivityEntry {
+                when: task.updated_at.clone(),
+                summary: "Task file edited".to_string(),
+            });
+        }
+    }
+
+    /// Replace the local activity with the server's timeline for the same task
+    pub fn set_server_activity(&mut self, task_id: &str, events: &[TimelineEvent]) {
+        if self.activity_task_id.as_deref() != Some(task_id) || events.is_empty() {
+            return;
+        }
+        self.activity = events
+            .iter()
+            .map(|event| ActivityEntry {
+                when: format_event_time(&event.at),
+                summary: event.summary.clone(),
+            })
+            .collect();
+    }
+
+    pub fn reset(&mut self) {
+        self.scroll_offset = 0;
+    }
+
+    /// Record how many rendered lines there are and how many fit, clamping the
+    /// offset so a resize or shorter description never scrolls past the end
+    pub fn set_content_height(&mut self, content: usize, visible: usize) {
+        self.max_scroll = content.saturating_sub(visible);
+        self.scroll_offset = self.scroll_offset.min(self.max_scroll);
+    }
+
+    pub fn can_scroll(&self) -> bool {
+        self.max_scroll > 0
+    }
+
+    pub fn scroll_up(&mut self) {
+        self.scroll_offset = self.scroll_offset.saturating_sub(1);
+    }
+
+    pub fn scroll_down(&mut self) {
+        if self.scroll_offset < self.max_scroll {
+            self.scroll_offset += 1;
+        }
+    }
+}
+
+/// RFC 3339 server time in local time, to the minute
+fn format_event_time(at: &str) -> String {
+    chrono::DateTime::parse_from_rfc3339(at)
+        .map(|time| {
+            time.with_timezone(&chrono::Local)
+                .format("%Y-%m-%d %H:%M")
+                .to_string()
+        })
+        .unwrap_or_else(|_| at.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_scroll_clamps_to_content() {
+        let mut detail = TaskDetailState::new();
+        detail.set_content_height(5, 3);
+        assert!(detail.can_scroll());
+
+        detail.scroll_up();
+        assert_eq!(detail.scroll_offset, 0);
+        for _ in 0..5 {
+            detail.scroll_down();
+        }
+        assert_eq!(detail.scroll_offset, 2);
+
+        // The pane grew: the offset is pulled back so no blank space shows
+        detail.set_content_height(5, 4);
+        assert_eq!(detail.scroll_offset, 1);
+
+        detail.set_content_height(2, 4);
+        assert_eq!(detail.scroll_offset, 0);
+        assert!(!detail.can_scroll());
+        detail.scroll_down();
+        assert_eq!(detail.scroll_offset, 0);
+    }
+
+    #[test]
+    fn test_server_activity_replaces_local_for_same_task() {
+        let mut detail = TaskDetailState::new();
+        detail.activity_task_id = Some("task-1".to_string());
+        detail.activity = vec![ActivityEntry {
+            when: "2026-01-18".to_string(),
+            summary: "Task created".to_string(),
+        }];
+
+        let events = vec![
+            TimelineEvent {
+                at: "2026-01-18T10:00:00Z".to_string(),
+                summary: "Task created".to_string(),
+            },
+            TimelineEvent {
+                at: "not a time".to_string(),
+                summary: "Coding agent started".to_string(),
+            },
+        ];
+        detail.set_server_activity("task-2", &events);
+        assert_eq!(detail.activity.len(), 1);
+
+        detail.set_server_activity("task-1", &events);
+        assert_eq!(detail.activity.len(), 2);
+        assert_eq!(detail.activity[1].when, "not a time");
+        assert_eq!(detail.activity[1].summary, "Coding agent started");
+    }
+}
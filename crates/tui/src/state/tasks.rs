@@ -1,3 +1,5 @@
+use std::collections::HashSet;
+
 use serde::{Deserialize, Serialize};
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
@@ -12,6 +14,15 @@ pub enum TaskStatus {
 }
 
 impl TaskStatus {
+    pub const ALL: [TaskStatus; 6] = [
+        TaskStatus::Backlog,
+        TaskStatus::Todo,
+        TaskStatus::Inprogress,
+        TaskStatus::Inreview,
+        TaskStatus::Done,
+        TaskStatus::Cancelled,
+    ];
+
     pub const VISIBLE: [TaskStatus; 4] = [
         TaskStatus::Backlog,
         TaskStatus::Inprogress,
@@ -41,6 +52,9 @@ impl TaskStatus {
         }
     }
 
+    /// Representative status of a kanban column, used to pick which cards it shows.
+    /// Todo and Cancelled share columns with Backlog and Done, so this must not be
+    /// written back to a task that merely sits in the column.
     pub fn from_column_index(index: usize) -> Option<Self> {
         match index {
             0 => Some(TaskStatus::Backlog),
@@ -52,6 +66,62 @@ impl TaskStatus {
     }
 }
 
+/// Order of the cards within a kanban column
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum CardSort {
+    /// Order the task files were loaded in
+    #[default]
+    Default,
+    Created,
+    Updated,
+    Priority,
+    Title,
+}
+
+impl CardSort {
+    pub fn next(self) -> Self {
+        match self {
+            CardSort::Default => CardSort::Created,
+            CardSort::Created => CardSort::Updated,
+            CardSort::Updated => CardSort::Priority,
+            CardSort::Priority => CardSort::Title,
+            CardSort::Title => CardSort::Default,
+        }
+    }
+
+    pub fn label(self) -> &'static str {
+        match self {
+            CardSort::Default => "default",
+            CardSort::Created => "created",
+            CardSort::Updated => "updated",
+            CardSort::Priority => "priority",
+            CardSort::Title => "title",
+        }
+    }
+
+    /// Newest first for dates, most urgent first for priority (unset last).
+    /// Sorts are stable, so ties keep the default order.
+    fn sort(self, tasks: &mut [&Task]) {
+        match self {
+            CardSort::Default => {}
+            CardSort::Created => tasks.sort_by(|a, b| b.created_at.cmp(&a.created_at)),
+            CardSort::Updated => tasks.sort_by(|a, b| b.updated_at.cmp(&a.updated_at)),
+            CardSort::Priority => {
+                tasks.sort_by_key(|t| t.priority.filter(|p| *p > 0).unwrap_or(u8::MAX))
+            }
+            CardSort::Title => tasks.sort_by_key(|t| t.title.to_lowercase()),
+        }
+    }
+}
+
+/// State of the latest attempt for a task
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AttemptStatus {
+    Running,
+    Failed,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Task {
     pub id: String,
@@ -59,11 +129,28 @@ pub struct Task {
     pub title: String,
     pub description: Option<String>,
     pub status: TaskStatus,
+    /// Status was set by hand and wins over the one derived from PRs and worktrees
+    #[serde(default)]
+    pub status_pinned: bool,
     pub parent_workspace_id: Option<String>,
     pub shared_task_id: Option<String>,
     pub linear_issue_id: Option<String>,
     pub linear_url: Option<String>,
     pub linear_labels: Option<String>,
+    #[serde(default)]
+    pub tags: Vec<String>,
+    /// Due date as YYYY-MM-DD
+    #[serde(default)]
+    pub due_date: Option<String>,
+    /// Linear-style priority: 1 urgent, 2 high, 3 medium, 4 low
+    #[serde(default)]
+    pub priority: Option<u8>,
+    /// Number of the PR bound to this task, tracked even if its branch is renamed
+    #[serde(default)]
+    pub pr_number: Option<u64>,
+    /// Worktree branch recorded for the task; matched exactly instead of by title
+    #[serde(default)]
+    pub branch: Option<String>,
     pub created_at: String,
     pub updated_at: String,
 
@@ -81,13 +168,163 @@ pub struct Task {
     pub pr_has_conflicts: Option<bool>,
 }
 
+use super::bound_pr_key;
+use crate::config::WipConfig;
 use crate::external::BranchPrInfo;
 
+/// Today's local date as YYYY-MM-DD, the format used for task due dates
+pub fn today() -> String {
+    chrono::Local::now().format("%Y-%m-%d").to_string()
+}
+
+/// fzf-style case-insensitive match of the query's characters, in order but not
+/// necessarily adjacent, anywhere in `text`. Consecutive characters and word starts
+/// score extra; gaps and a late first match cost points. None when the text lacks
+/// one of the characters; an empty query matches with score 0.
+pub fn fuzzy_score(query: &str, text: &str) -> Option<i64> {
+    const MATCH: i64 = 16;
+    const CONSECUTIVE_BONUS: i64 = 8;
+    const WORD_START_BONUS: i64 = 8;
+
+    let mut query = query
+        .chars()
+        .filter(|c| !c.is_whitespace())
+        .flat_map(char::to_lowercase)
+        .peekable();
+    let mut score = 0;
+    let mut first_match = None;
+    let mut last_match: Option<usize> = None;
+    let mut prev: Option<char> = None;
+
+    for (i, c) in text.chars().flat_map(char::to_lowercase).enumerate() {
+        let Some(&wanted) = query.peek() else {
+            break;
+        };
+        if c == wanted {
+            score += MATCH;
+            match last_match {
+                Some(last) if last + 1 == i => score += CONSECUTIVE_BONUS,
+                Some(last) => score -= (i - last - 1) as i64,
+                None => {}
+            }
+            if prev.is_none_or(|p| !p.is_alphanumeric()) {
+                score += WORD_START_BONUS;
+            }
+            first_match.get_or_insert(i);
+            last_match = Some(i);
+            query.next();
+        }
+        prev = Some(c);
+    }
+
+    if query.peek().is_some() {
+        return None;
+    }
+    Some(score - first_match.unwrap_or(0) as i64)
+}
+
 impl Task {
-    pub fn effective_status(&self) -> TaskStatus {
+    /// Match a search query against this task. Terms of the form `tag:x`
+    /// require a local tag `x` and `due:overdue` keeps only overdue tasks;
+    /// the remaining text is matched against the title and description.
+    pub fn matches_query(&self, query: &str) -> bool {
+        self.search_score(query).is_some()
+    }
+
+    /// Like `matches_query`, but ranks the match: the best fuzzy score of the text
+    /// terms against the title or description, or None when the task doesn't match
+    pub fn search_score(&self, query: &str) -> Option<i64> {
+        let query = query.to_lowercase();
+        let mut text_terms = Vec::new();
+        for term in query.split_whitespace() {
+            if let Some(tag) = term.strip_prefix("tag:") {
+                if !tag.is_empty() && !self.tags.iter().any(|t| t.to_lowercase() == tag) {
+                    return None;
+                }
+            } else if term == "due:overdue" {
+                if !self.is_overdue(&today()) {
+                    return None;
+                }
+            } else {
+                text_terms.push(term);
+            }
+        }
+
+        if text_terms.is_empty() {
+            return Some(0);
+        }
+        let text = text_terms.concat();
+        let title = fuzzy_score(&text, &self.title);
+        let description = self
+            .description
+            .as_deref()
+            .and_then(|d| fuzzy_score(&text, d));
+        title.max(description)
+    }
+
+    /// Whether the due date is before `today` (YYYY-MM-DD) and the task is
+    /// still open; merged work never is. ISO dates compare correctly as strings.
+    pub fn is_overdue(&self, today: &str) -> bool {
+        let open = !matches!(
+            self.effective_status(true),
+            TaskStatus::Done | TaskStatus::Cancelled
+        );
+        open && self.due_date.as_deref().is_some_and(|due| due < today)
+    }
+
+    /// Format the task as a markdown snippet for pasting into docs/issues.
+    /// `pr_url` is passed in so callers can supply a locally detected PR.
+    pub fn to_markdown(&self, pr_url: Option<&str>, merged_is_done: bool) -> String {
+        let mut md = format!("# {}\n\n", self.title);
+        md.push_str(&format!(
+            "**Status:** {}\n",
+            self.effective_status(merged_is_done).label()
+        ));
+        if let Some(ref url) = self.linear_url {
+            md.push_str(&format!("**Linear:** {}\n", url));
+        }
+        if let Some(url) = pr_url {
+            md.push_str(&format!("**PR:** {}\n", url));
+        }
+        if !self.tags.is_empty() {
+            let tags: Vec<String> = self.tags.iter().map(|t| format!("#{}", t)).collect();
+            md.push_str(&format!("**Tags:** {}\n", tags.join(" ")));
+        }
+        if let Some(ref due) = self.due_date {
+            md.push_str(&format!("**Due:** {}\n", due));
+        }
+        if let Some(ref description) = self.description {
+            let description = description.trim();
+            if !description.is_empty() {
+                md.push('\n');
+                md.push_str(description);
+                md.push('\n');
+            }
+        }
+        md
+    }
+
+    /// Attempt status from the `has_in_progress_attempt` / `last_attempt_failed` flags
+    pub fn current_attempt_status(&self) -> Option<AttemptStatus> {
+        if self.has_in_progress_attempt {
+            Some(AttemptStatus::Running)
+        } else if self.last_attempt_failed {
+            Some(AttemptStatus::Failed)
+        } else {
+            None
+        }
+    }
+
+    /// Status the board shows. `merged_is_done` is the project's
+    /// `[status] merged_is_done` setting; when off, a merged PR leaves the
+    /// task where it is.
+    pub fn effective_status(&self, merged_is_done: bool) -> TaskStatus {
+        if self.status_pinned {
+            return self.status;
+        }
         if let Some(ref pr_status) = self.pr_status {
             match pr_status.as_str() {
-                "merged" => return TaskStatus::Done,
+                "merged" if merged_is_done => return TaskStatus::Done,
                 "closed" => return TaskStatus::Cancelled,
                 "open" => {
                     if self.pr_is_draft != Some(true) {
@@ -104,14 +341,15 @@ impl Task {
         &self,
         branch_pr: Option<&BranchPrInfo>,
         has_worktree: bool,
+        merged_is_done: bool,
     ) -> TaskStatus {
-        if self.pr_status.is_some() {
-            return self.effective_status();
+        if self.pr_status.is_some() || self.status_pinned {
+            return self.effective_status(merged_is_done);
         }
 
         if let Some(pr) = branch_pr {
             match pr.state.as_str() {
-                "MERGED" => return TaskStatus::Done,
+                "MERGED" if merged_is_done => return TaskStatus::Done,
                 "CLOSED" => return TaskStatus::Cancelled,
                 "OPEN" => {
                     if !pr.is_draft {
@@ -131,15 +369,84 @@ impl Task {
 
         self.status
     }
+
+    /// True when the task's PR, or the PR found for its worktree, was merged.
+    /// Such a task is Done whatever status is stored for it.
+    pub fn has_merged_pr_with_prs(
+        &self,
+        branch_prs: &std::collections::HashMap<String, BranchPrInfo>,
+        worktrees: &[crate::external::WorktreeInfo],
+    ) -> bool {
+        if self.pr_status.as_deref() == Some("merged") {
+            return true;
+        }
+        task_branch_pr(self, matching_worktree(self, worktrees), branch_prs)
+            .is_some_and(|pr| pr.state == "MERGED")
+    }
 }
 
 const NUM_VISIBLE_COLUMNS: usize = 4;
 
+/// A task whose open PR has failing checks, with the PR it was detected on
+#[derive(Debug, Clone)]
+pub struct FailingChecksEntry<'a> {
+    pub task: &'a Task,
+    pub pr_url: Option<&'a str>,
+    /// Branch of the matching worktree when detected locally via gh
+    pub branch: Option<&'a str>,
+}
+
+/// Find the task's worktree: the one on its recorded branch, or for tasks
+/// without one, a worktree whose branch matches the title slug
+pub fn matching_worktree<'a>(
+    task: &Task,
+    worktrees: &'a [crate::external::WorktreeInfo],
+) -> Option<&'a crate::external::WorktreeInfo> {
+    if let Some(branch) = &task.branch {
+        return worktrees.iter().find(|w| &w.branch == branch);
+    }
+    let task_slug = task.title.to_lowercase().replace(' ', "-");
+    worktrees.iter().find(|w| {
+        w.branch.to_lowercase().contains(&task_slug) || task_slug.contains(&w.branch.to_lowercase())
+    })
+}
+
+/// PR for a task detected locally via gh: the bound PR fetched by number if
+/// there is one, otherwise the PR of the worktree matching the title slug
+pub fn task_branch_pr<'a>(
+    task: &Task,
+    worktree: Option<&crate::external::WorktreeInfo>,
+    branch_prs: &'a std::collections::HashMap<String, BranchPrInfo>,
+) -> Option<&'a BranchPrInfo> {
+    task.pr_number
+        .and_then(|number| branch_prs.get(&bound_pr_key(number)))
+        .or_else(|| worktree.and_then(|wt| branch_prs.get(&wt.branch)))
+}
+
 pub struct TasksState {
     pub tasks: Vec<Task>,
     pub selected_column: usize,
     pub selected_card_per_column: [usize; NUM_VISIBLE_COLUMNS],
     pub search_filter: String,
+    /// Selection in the CI failures view
+    pub selected_failure: usize,
+    pub column_sort: [CardSort; NUM_VISIBLE_COLUMNS],
+    /// Per-column WIP limits from `[wip]` in the config
+    pub wip_limits: [Option<usize>; NUM_VISIBLE_COLUMNS],
+    /// Prefix cards with the first 8 chars of the task id
+    pub show_ids: bool,
+    /// Only show tasks with a matching worktree or a live session
+    pub active_work_only: bool,
+    /// Tasks with a live session, kept up to date by the app while
+    /// `active_work_only` is on
+    pub live_session_task_ids: HashSet<String>,
+    /// When the board was last left and when it was opened again; tasks
+    /// updated in between are marked new
+    pub unseen_window: Option<(String, String)>,
+    /// New tasks opened since, which lose their marker
+    pub seen_task_ids: HashSet<String>,
+    /// From the project's `[status] merged_is_done`: a merged PR puts the task in Done
+    pub merged_is_done: bool,
 }
 
 impl TasksState {
@@ -149,12 +456,80 @@ impl TasksState {
             selected_column: 0,
             selected_card_per_column: [0; NUM_VISIBLE_COLUMNS],
             search_filter: String::new(),
+            selected_failure: 0,
+            column_sort: [CardSort::Default; NUM_VISIBLE_COLUMNS],
+            wip_limits: [None; NUM_VISIBLE_COLUMNS],
+            show_ids: false,
+            active_work_only: false,
+            live_session_task_ids: HashSet::new(),
+            unseen_window: None,
+            seen_task_ids: HashSet::new(),
+            merged_is_done: true,
         }
     }
 
+    /// Mark tasks updated after `last_seen` (up to `now`) as new; `None` for a
+    /// board never seen before, where everything would be new
+    pub fn set_last_seen(&mut self, last_seen: Option<String>, now: String) {
+        self.unseen_window = last_seen.map(|last_seen| (last_seen, now));
+        self.seen_task_ids.clear();
+    }
+
+    /// Updated while the board was not being looked at, and not opened since.
+    /// Edits made after opening the board fall outside the window.
+    pub fn is_new(&self, task: &Task) -> bool {
+        self.unseen_window.as_ref().is_some_and(|(last_seen, opened)| {
+            task.updated_at > *last_seen && task.updated_at <= *opened
+        }) && !self.seen_task_ids.contains(&task.id)
+    }
+
+    pub fn new_count(&self) -> usize {
+        self.tasks.iter().filter(|task| self.is_new(task)).count()
+    }
+
+    pub fn mark_seen(&mut self, task_id: &str) {
+        self.seen_task_ids.insert(task_id.to_string());
+    }
+
+    pub fn set_wip_limits(&mut self, config: &WipConfig) {
+        self.wip_limits = TaskStatus::VISIBLE.map(|status| config.limit(status));
+    }
+
+    /// Switch the selected column to the next sort order and return it
+    pub fn cycle_column_sort(&mut self) -> CardSort {
+        let column = self.selected_column;
+        self.column_sort[column] = self.column_sort[column].next();
+        self.selected_card_per_column[column] = 0;
+        self.column_sort[column]
+    }
+
+    /// Switch between all tasks and only those with a worktree or live session
+    pub fn toggle_active_work_only(&mut self) -> bool {
+        self.active_work_only = !self.active_work_only;
+        self.selected_card_per_column = [0; NUM_VISIBLE_COLUMNS];
+        self.active_work_only
+    }
+
     pub fn set_tasks(&mut self, tasks: Vec<Task>) {
         self.tasks = tasks;
         self.selected_card_per_column = [0; NUM_VISIBLE_COLUMNS];
+        self.selected_failure = 0;
+    }
+
+    /// Replace the tasks but keep the selected card, following it to its new column
+    pub fn reload_tasks_with_prs(
+        &mut self,
+        tasks: Vec<Task>,
+        branch_prs: &std::collections::HashMap<String, BranchPrInfo>,
+        worktrees: &[crate::external::WorktreeInfo],
+    ) {
+        let selected = self
+            .selected_task_with_prs(branch_prs, worktrees)
+            .map(|t| t.id.clone());
+        self.set_tasks(tasks);
+        if let Some(task_id) = selected {
+            self.select_task_with_prs(&task_id, branch_prs, worktrees);
+        }
     }
 
     pub fn tasks_in_column_with_prs(
@@ -164,30 +539,48 @@ impl TasksState {
         worktrees: &[crate::external::WorktreeInfo],
     ) -> Vec<&Task> {
         let column_index = status.column_index();
-        self.tasks
+        let mut tasks: Vec<&Task> = self
+            .tasks
             .iter()
             .filter(|t| {
-                let task_slug = t.title.to_lowercase().replace(' ', "-");
-                let matching_branch = worktrees.iter().find(|w| {
-                    w.branch.to_lowercase().contains(&task_slug)
-                        || task_slug.contains(&w.branch.to_lowercase())
-                });
+                let matching_branch = matching_worktree(t, worktrees);
 
                 let has_worktree = matching_branch.is_some();
-                let branch_pr = matching_branch.and_then(|wt| branch_prs.get(&wt.branch));
-                t.effective_status_with_pr(branch_pr, has_worktree).column_index() == column_index
-            })
-            .filter(|t| {
-                if self.search_filter.is_empty() {
-                    return true;
+                if self.active_work_only
+                    && !has_worktree
+                    && !self.live_session_task_ids.contains(&t.id)
+                {
+                    return false;
                 }
-                let query = self.search_filter.to_lowercase();
-                t.title.to_lowercase().contains(&query)
-                    || t.description
-                        .as_ref()
-                        .is_some_and(|d| d.to_lowercase().contains(&query))
+                let branch_pr = task_branch_pr(t, matching_branch, branch_prs);
+                t.effective_status_with_pr(branch_pr, has_worktree, self.merged_is_done)
+                    .column_index()
+                    == column_index
             })
-            .collect()
+            .collect();
+        self.column_sort[column_index].sort(&mut tasks);
+        if self.search_filter.is_empty() {
+            return tasks;
+        }
+
+        // Best matches first; equal scores keep the column's sort order
+        let mut ranked: Vec<(i64, &Task)> = tasks
+            .into_iter()
+            .filter_map(|t| Some((t.search_score(&self.search_filter)?, t)))
+            .collect();
+        ranked.sort_by_key(|(score, _)| std::cmp::Reverse(*score));
+        ranked.into_iter().map(|(_, t)| t).collect()
+    }
+
+    /// Status for moving the selected card one column right (`forward`) or
+    /// left; None at the edges of the board
+    pub fn move_target(&self, forward: bool) -> Option<TaskStatus> {
+        let column = if forward {
+            self.selected_column + 1
+        } else {
+            self.selected_column.checked_sub(1)?
+        };
+        TaskStatus::from_column_index(column)
     }
 
     pub fn selected_task_with_prs(
@@ -253,6 +646,81 @@ impl TasksState {
         }
     }
 
+    /// Tasks with an open PR whose checks are failing, from backend PR info or
+    /// locally detected PRs on matching worktree branches
+    pub fn tasks_with_failing_checks<'a>(
+        &'a self,
+        branch_prs: &'a std::collections::HashMap<String, BranchPrInfo>,
+        worktrees: &'a [crate::external::WorktreeInfo],
+    ) -> Vec<FailingChecksEntry<'a>> {
+        self.tasks
+            .iter()
+            .filter_map(|task| {
+                if task.pr_url.is_some() {
+                    let open = task.pr_status.as_deref().is_none_or(|s| s == "open");
+                    return (open && task.pr_checks_status.as_deref() == Some("FAILURE")).then(
+                        || FailingChecksEntry {
+                            task,
+                            pr_url: task.pr_url.as_deref(),
+                            branch: None,
+                        },
+                    );
+                }
+
+                let wt = matching_worktree(task, worktrees);
+                let pr = task_branch_pr(task, wt, branch_prs)?;
+                (pr.state == "OPEN" && pr.checks_status().as_deref() == Some("FAILURE")).then(|| {
+                    FailingChecksEntry {
+                        task,
+                        pr_url: Some(pr.url.as_str()),
+                        branch: wt.map(|wt| wt.branch.as_str()),
+                    }
+                })
+            })
+            .collect()
+    }
+
+    /// Most recently updated task with an agent running, either per the backend
+    /// or as judged by `is_running` (e.g. a live session)
+    pub fn newest_active_task(&self, is_running: impl Fn(&Task) -> bool) -> Option<&Task> {
+        self.tasks
+            .iter()
+            .filter(|t| t.has_in_progress_attempt || is_running(t))
+            .max_by(|a, b| a.updated_at.cmp(&b.updated_at))
+    }
+
+    /// Move the selection to the task's card, switching column as needed.
+    /// Returns false when the task isn't on the board, e.g. hidden by a filter.
+    pub fn select_task_with_prs(
+        &mut self,
+        task_id: &str,
+        branch_prs: &std::collections::HashMap<String, BranchPrInfo>,
+        worktrees: &[crate::external::WorktreeInfo],
+    ) -> bool {
+        for (column, status) in TaskStatus::VISIBLE.into_iter().enumerate() {
+            let position = self
+                .tasks_in_column_with_prs(status, branch_prs, worktrees)
+                .iter()
+                .position(|t| t.id == task_id);
+            if let Some(card) = position {
+                self.selected_column = column;
+                self.selected_card_per_column[column] = card;
+                return true;
+            }
+        }
+        false
+    }
+
+    pub fn select_next_failure(&mut self, count: usize) {
+        if count > 0 {
+            self.selected_failure = (self.selected_failure + 1).min(count - 1);
+        }
+    }
+
+    pub fn select_prev_failure(&mut self) {
+        self.selected_failure = self.selected_failure.saturating_sub(1);
+    }
+
     pub fn select_next_column(&mut self) {
         self.selected_column = (self.selected_column + 1) % NUM_VISIBLE_COLUMNS;
     }
@@ -283,11 +751,17 @@ mod tests {
             title: "Test Task".to_string(),
             description: None,
             status,
+            status_pinned: false,
             parent_workspace_id: None,
             shared_task_id: None,
             linear_issue_id: None,
             linear_url: None,
             linear_labels: None,
+            tags: Vec::new(),
+            due_date: None,
+            priority: None,
+            pr_number: None,
+            branch: None,
             created_at: "2024-01-01".to_string(),
             updated_at: "2024-01-01".to_string(),
             has_in_progress_attempt: false,
@@ -305,7 +779,7 @@ mod tests {
     #[test]
     fn test_effective_status_no_pr() {
         let task = make_task(TaskStatus::Inprogress);
-        assert_eq!(task.effective_status(), TaskStatus::Inprogress);
+        assert_eq!(task.effective_status(true), TaskStatus::Inprogress);
     }
 
     #[test]
@@ -314,7 +788,7 @@ mod tests {
         task.pr_url = Some("https://github.com/org/repo/pull/1".to_string());
         task.pr_status = Some("open".to_string());
         task.pr_is_draft = Some(false);
-        assert_eq!(task.effective_status(), TaskStatus::Inreview);
+        assert_eq!(task.effective_status(true), TaskStatus::Inreview);
     }
 
     #[test]
@@ -323,7 +797,7 @@ mod tests {
         task.pr_url = Some("https://github.com/org/repo/pull/1".to_string());
         task.pr_status = Some("open".to_string());
         task.pr_is_draft = Some(true);
-        assert_eq!(task.effective_status(), TaskStatus::Inprogress);
+        assert_eq!(task.effective_status(true), TaskStatus::Inprogress);
     }
 
     #[test]
@@ -331,7 +805,38 @@ mod tests {
         let mut task = make_task(TaskStatus::Inprogress);
         task.pr_url = Some("https://github.com/org/repo/pull/1".to_string());
         task.pr_status = Some("merged".to_string());
-        assert_eq!(task.effective_status(), TaskStatus::Done);
+        assert_eq!(task.effective_status(true), TaskStatus::Done);
+        // Projects that opt out keep the stored status
+        assert_eq!(task.effective_status(false), TaskStatus::Inprogress);
+    }
+
+    #[test]
+    fn test_effective_status_with_merged_branch_pr() {
+        let task = make_task(TaskStatus::Todo);
+        let pr = BranchPrInfo {
+            number: 1,
+            url: "https://github.com/org/repo/pull/1".to_string(),
+            state: "MERGED".to_string(),
+            is_draft: false,
+            review_decision: None,
+            status_check_rollup: None,
+            mergeable: None,
+            head_ref_name: "test-task".to_string(),
+            additions: 0,
+            deletions: 0,
+        };
+        assert_eq!(
+            task.effective_status_with_pr(Some(&pr), true, true),
+            TaskStatus::Done
+        );
+        assert_eq!(
+            task.effective_status_with_pr(Some(&pr), true, false),
+            TaskStatus::Inprogress
+        );
+        assert_eq!(
+            task.effective_status_with_pr(Some(&pr), false, false),
+            TaskStatus::Todo
+        );
     }
 
     #[test]
@@ -339,7 +844,198 @@ mod tests {
         let mut task = make_task(TaskStatus::Inprogress);
         task.pr_url = Some("https://github.com/org/repo/pull/1".to_string());
         task.pr_status = Some("closed".to_string());
-        assert_eq!(task.effective_status(), TaskStatus::Cancelled);
+        assert_eq!(task.effective_status(true), TaskStatus::Cancelled);
+    }
+
+    #[test]
+    fn test_move_target_stops_at_board_edges() {
+        let mut state = TasksState::new();
+        assert_eq!(state.move_target(false), None);
+        assert_eq!(state.move_target(true), Some(TaskStatus::Inprogress));
+
+        state.selected_column = 3;
+        assert_eq!(state.move_target(false), Some(TaskStatus::Inreview));
+        assert_eq!(state.move_target(true), None);
+    }
+
+    #[test]
+    fn test_merged_pr_is_detected_even_when_pinned() {
+        let branch_prs = std::collections::HashMap::new();
+        let mut task = make_task(TaskStatus::Inreview);
+        task.status_pinned = true;
+        assert!(!task.has_merged_pr_with_prs(&branch_prs, &[]));
+
+        task.pr_status = Some("merged".to_string());
+        assert!(task.has_merged_pr_with_prs(&branch_prs, &[]));
+    }
+
+    #[test]
+    fn test_effective_status_pinned_wins_over_pr_and_worktree() {
+        let mut task = make_task(TaskStatus::Cancelled);
+        task.status_pinned = true;
+        task.pr_status = Some("open".to_string());
+        assert_eq!(task.effective_status(true), TaskStatus::Cancelled);
+        assert_eq!(
+            task.effective_status_with_pr(None, true, true),
+            TaskStatus::Cancelled
+        );
+    }
+
+    #[test]
+    fn test_to_markdown() {
+        let mut task = make_task(TaskStatus::Inprogress);
+        assert_eq!(
+            task.to_markdown(None, true),
+            "# Test Task\n\n**Status:** In Progress\n"
+        );
+
+        task.description = Some("Do the thing.\n".to_string());
+        task.linear_url = Some("https://linear.app/team/issue/ABC-1".to_string());
+        task.tags = vec!["infra".to_string()];
+        assert_eq!(
+            task.to_markdown(Some("https://github.com/org/repo/pull/1"), true),
+            "# Test Task\n\n\
+             **Status:** In Progress\n\
+             **Linear:** https://linear.app/team/issue/ABC-1\n\
+             **PR:** https://github.com/org/repo/pull/1\n\
+             **Tags:** #infra\n\
+             \n\
+             Do the thing.\n"
+        );
+    }
+
+    #[test]
+    fn test_current_attempt_status() {
+        let mut task = make_task(TaskStatus::Inprogress);
+        assert_eq!(task.current_attempt_status(), None);
+
+        task.has_in_progress_attempt = true;
+        assert_eq!(task.current_attempt_status(), Some(AttemptStatus::Running));
+
+        task.has_in_progress_attempt = false;
+        task.last_attempt_failed = true;
+        assert_eq!(task.current_attempt_status(), Some(AttemptStatus::Failed));
+    }
+
+    #[test]
+    fn test_matches_query_with_tags() {
+        let mut task = make_task(TaskStatus::Backlog);
+        task.title = "Fix login redirect".to_string();
+        task.tags = vec!["auth".to_string(), "bug".to_string()];
+
+        assert!(task.matches_query("login"));
+        assert!(task.matches_query("tag:auth"));
+        assert!(task.matches_query("tag:AUTH login"));
+        assert!(task.matches_query("tag:auth tag:bug"));
+        assert!(!task.matches_query("tag:frontend"));
+        assert!(!task.matches_query("tag:auth signup"));
+    }
+
+    #[test]
+    fn test_fuzzy_score_matches_characters_in_order() {
+        assert!(fuzzy_score("adbtn", "add button").is_some());
+        assert!(fuzzy_score("ADBTN", "Add Button").is_some());
+        assert!(fuzzy_score("add btn", "add button").is_some());
+        assert_eq!(fuzzy_score("", "anything"), Some(0));
+
+        // Every character is present, but not in query order
+        assert_eq!(fuzzy_score("ntb", "button"), None);
+        assert_eq!(fuzzy_score("dda", "add"), None);
+        assert_eq!(fuzzy_score("btnx", "button"), None);
+    }
+
+    #[test]
+    fn test_fuzzy_score_prefers_earlier_and_tighter_matches() {
+        // Same characters matched the same way; the earlier match wins the tie
+        let early = fuzzy_score("btn", "button x").unwrap();
+        let late = fuzzy_score("btn", "x button").unwrap();
+        assert!(early > late);
+
+        // Adjacent characters beat scattered ones
+        let tight = fuzzy_score("but", "button").unwrap();
+        let loose = fuzzy_score("but", "bring up toast").unwrap();
+        assert!(tight > loose);
+    }
+
+    #[test]
+    fn test_search_ranks_matches_best_first() {
+        let mut state = TasksState::new();
+        let mut scattered = make_task(TaskStatus::Backlog);
+        scattered.id = "scattered".to_string();
+        scattered.title = "Refactor dashboard button".to_string();
+        let mut exact = make_task(TaskStatus::Backlog);
+        exact.id = "exact".to_string();
+        exact.title = "Add button".to_string();
+        let mut other = make_task(TaskStatus::Backlog);
+        other.id = "other".to_string();
+        other.title = "Fix login".to_string();
+        state.set_tasks(vec![scattered, exact, other]);
+
+        let empty_prs = std::collections::HashMap::new();
+        let empty_wt: Vec<crate::external::WorktreeInfo> = vec![];
+        let ids = |state: &TasksState| -> Vec<String> {
+            state
+                .tasks_in_column_with_prs(TaskStatus::Backlog, &empty_prs, &empty_wt)
+                .iter()
+                .map(|t| t.id.clone())
+                .collect()
+        };
+
+        // No search: load order, nothing hidden
+        assert_eq!(ids(&state), ["scattered", "exact", "other"]);
+
+        state.search_filter = "adbtn".to_string();
+        assert_eq!(ids(&state), ["exact", "scattered"]);
+    }
+
+    #[test]
+    fn test_column_sort() {
+        let mut state = TasksState::new();
+        let mut a = make_task(TaskStatus::Backlog);
+        a.title = "beta".to_string();
+        a.created_at = "2024-01-02".to_string();
+        a.priority = Some(3);
+        let mut b = make_task(TaskStatus::Backlog);
+        b.title = "Alpha".to_string();
+        b.created_at = "2024-01-01".to_string();
+        let mut c = make_task(TaskStatus::Backlog);
+        c.title = "gamma".to_string();
+        c.created_at = "2024-01-03".to_string();
+        c.priority = Some(1);
+        state.set_tasks(vec![a, b, c]);
+
+        let empty_prs = std::collections::HashMap::new();
+        let empty_wt = Vec::new();
+        let titles = |state: &TasksState| -> Vec<String> {
+            state
+                .tasks_in_column_with_prs(TaskStatus::Backlog, &empty_prs, &empty_wt)
+                .iter()
+                .map(|t| t.title.clone())
+                .collect()
+        };
+
+        assert_eq!(titles(&state), ["beta", "Alpha", "gamma"]);
+        assert_eq!(state.cycle_column_sort(), CardSort::Created);
+        assert_eq!(titles(&state), ["gamma", "beta", "Alpha"]);
+        state.cycle_column_sort();
+        assert_eq!(state.cycle_column_sort(), CardSort::Priority);
+        assert_eq!(titles(&state), ["gamma", "beta", "Alpha"]);
+        assert_eq!(state.cycle_column_sort(), CardSort::Title);
+        assert_eq!(titles(&state), ["Alpha", "beta", "gamma"]);
+        assert_eq!(state.cycle_column_sort(), CardSort::Default);
+    }
+
+    #[test]
+    fn test_is_overdue() {
+        let mut task = make_task(TaskStatus::Todo);
+        assert!(!task.is_overdue("2024-03-10"));
+
+        task.due_date = Some("2024-03-09".to_string());
+        assert!(task.is_overdue("2024-03-10"));
+        assert!(!task.is_overdue("2024-03-09"));
+
+        task.pr_status = Some("merged".to_string());
+        assert!(!task.is_overdue("2024-03-10"));
     }
 
     #[test]
@@ -375,4 +1071,239 @@ mod tests {
         assert_eq!(done.len(), 1);
         assert_eq!(done[0].id, "task3");
     }
+
+    #[test]
+    fn test_column_navigation_keeps_stored_status() {
+        let mut state = TasksState::new();
+
+        let mut todo = make_task(TaskStatus::Todo);
+        todo.id = "todo".to_string();
+        let mut cancelled = make_task(TaskStatus::Cancelled);
+        cancelled.id = "cancelled".to_string();
+        state.set_tasks(vec![todo, cancelled]);
+
+        let empty_prs = std::collections::HashMap::new();
+        let empty_wt: Vec<crate::external::WorktreeInfo> = vec![];
+
+        let selected = state.selected_task_with_prs(&empty_prs, &empty_wt).unwrap();
+        assert_eq!(selected.id, "todo");
+
+        // Walk through every column and back to Backlog
+        for _ in 0..NUM_VISIBLE_COLUMNS {
+            state.select_next_card_with_prs(&empty_prs, &empty_wt);
+        }
+        state.selected_column = 0;
+
+        let selected = state.selected_task_with_prs(&empty_prs, &empty_wt).unwrap();
+        assert_eq!(selected.id, "todo");
+        assert_eq!(selected.status, TaskStatus::Todo);
+
+        state.selected_column = TaskStatus::Done.column_index();
+        let selected = state.selected_task_with_prs(&empty_prs, &empty_wt).unwrap();
+        assert_eq!(selected.id, "cancelled");
+        assert_eq!(selected.status, TaskStatus::Cancelled);
+    }
+
+    #[test]
+    fn test_jump_to_newest_active_task() {
+        let mut state = TasksState::new();
+
+        let mut idle = make_task(TaskStatus::Backlog);
+        idle.id = "idle".to_string();
+        idle.updated_at = "2024-01-03 09:00:00".to_string();
+        let mut older = make_task(TaskStatus::Inprogress);
+        older.id = "older".to_string();
+        older.has_in_progress_attempt = true;
+        older.updated_at = "2024-01-01 09:00:00".to_string();
+        let mut newer = make_task(TaskStatus::Inreview);
+        newer.id = "newer".to_string();
+        newer.has_in_progress_attempt = true;
+        newer.updated_at = "2024-01-02 09:00:00".to_string();
+        state.set_tasks(vec![idle, older, newer]);
+
+        let newest = state.newest_active_task(|_| false).unwrap();
+        assert_eq!(newest.id, "newer");
+        let newest = state.newest_active_task(|t| t.id == "idle").unwrap();
+        assert_eq!(newest.id, "idle");
+
+        let empty_prs = std::collections::HashMap::new();
+        let empty_wt: Vec<crate::external::WorktreeInfo> = vec![];
+        assert!(state.select_task_with_prs("newer", &empty_prs, &empty_wt));
+        assert_eq!(state.selected_column, TaskStatus::Inreview.column_index());
+        let selected = state.selected_task_with_prs(&empty_prs, &empty_wt).unwrap();
+        assert_eq!(selected.id, "newer");
+
+        state.search_filter = "nothing matches".to_string();
+        assert!(!state.select_task_with_prs("older", &empty_prs, &empty_wt));
+    }
+
+    #[test]
+    fn test_reload_keeps_selected_card() {
+        let mut state = TasksState::new();
+        let tasks: Vec<Task> = ["a", "b", "c"]
+            .into_iter()
+            .map(|id| {
+                let mut task = make_task(TaskStatus::Todo);
+                task.id = id.to_string();
+                task.title = id.to_string();
+                task
+            })
+            .collect();
+        state.set_tasks(tasks.clone());
+
+        let empty_prs = std::collections::HashMap::new();
+        let empty_wt: Vec<crate::external::WorktreeInfo> = vec![];
+        assert!(state.select_task_with_prs("b", &empty_prs, &empty_wt));
+
+        state.reload_tasks_with_prs(tasks.clone(), &empty_prs, &empty_wt);
+        let selected = state.selected_task_with_prs(&empty_prs, &empty_wt).unwrap();
+        assert_eq!(selected.id, "b");
+
+        // A task that changed column keeps the selection
+        let mut moved = tasks;
+        moved[1].status = TaskStatus::Done;
+        state.reload_tasks_with_prs(moved, &empty_prs, &empty_wt);
+        assert_eq!(state.selected_column, TaskStatus::Done.column_index());
+        let selected = state.selected_task_with_prs(&empty_prs, &empty_wt).unwrap();
+        assert_eq!(selected.id, "b");
+    }
+
+    #[test]
+    fn test_tasks_with_failing_checks() {
+        let mut state = TasksState::new();
+
+        let mut failing = make_task(TaskStatus::Inreview);
+        failing.id = "failing".to_string();
+        failing.pr_url = Some("https://github.com/o/r/pull/1".to_string());
+        failing.pr_status = Some("open".to_string());
+        failing.pr_checks_status = Some("FAILURE".to_string());
+
+        let mut passing = make_task(TaskStatus::Inreview);
+        passing.id = "passing".to_string();
+        passing.pr_url = Some("https://github.com/o/r/pull/2".to_string());
+        passing.pr_status = Some("open".to_string());
+        passing.pr_checks_status = Some("SUCCESS".to_string());
+
+        let mut merged = make_task(TaskStatus::Done);
+        merged.id = "merged".to_string();
+        merged.pr_url = Some("https://github.com/o/r/pull/3".to_string());
+        merged.pr_status = Some("merged".to_string());
+        merged.pr_checks_status = Some("FAILURE".to_string());
+
+        state.set_tasks(vec![failing, passing, merged]);
+
+        let empty_prs = std::collections::HashMap::new();
+        let empty_wt: Vec<crate::external::WorktreeInfo> = vec![];
+
+        let failures = state.tasks_with_failing_checks(&empty_prs, &empty_wt);
+        assert_eq!(failures.len(), 1);
+        assert_eq!(failures[0].task.id, "failing");
+        assert_eq!(failures[0].pr_url, Some("https://github.com/o/r/pull/1"));
+    }
+
+    #[test]
+    fn test_active_work_only_keeps_tasks_with_worktree_or_session() {
+        let mut state = TasksState::new();
+
+        let mut idle = make_task(TaskStatus::Inprogress);
+        idle.id = "idle".to_string();
+        idle.title = "Idle task".to_string();
+        let mut in_worktree = make_task(TaskStatus::Inprogress);
+        in_worktree.id = "in-worktree".to_string();
+        in_worktree.title = "Fix login".to_string();
+        let mut in_session = make_task(TaskStatus::Inprogress);
+        in_session.id = "in-session".to_string();
+        in_session.title = "Add export".to_string();
+        state.set_tasks(vec![idle, in_worktree, in_session]);
+
+        let empty_prs = std::collections::HashMap::new();
+        let worktrees: Vec<crate::external::WorktreeInfo> =
+            serde_json::from_str(r#"[{ "branch": "fix-login", "path": "/tmp/fix-login" }]"#)
+                .unwrap();
+        state.live_session_task_ids.insert("in-session".to_string());
+
+        let ids = |state: &TasksState| -> Vec<String> {
+            state
+                .tasks_in_column_with_prs(TaskStatus::Inprogress, &empty_prs, &worktrees)
+                .iter()
+                .map(|t| t.id.clone())
+                .collect()
+        };
+        assert_eq!(ids(&state).len(), 3);
+
+        assert!(state.toggle_active_work_only());
+        assert_eq!(ids(&state), ["in-worktree", "in-session"]);
+
+        assert!(!state.toggle_active_work_only());
+        assert_eq!(ids(&state).len(), 3);
+    }
+
+    #[test]
+    fn test_matching_worktree_prefers_recorded_branch() {
+        let worktrees: Vec<crate::external::WorktreeInfo> = serde_json::from_str(
+            r#"[
+                { "branch": "add-login", "path": "/tmp/add-login" },
+                { "branch": "add-logging", "path": "/tmp/add-logging" }
+            ]"#,
+        )
+        .unwrap();
+
+        let mut login = make_task(TaskStatus::Inprogress);
+        login.title = "Add login".to_string();
+        let mut logging = make_task(TaskStatus::Inprogress);
+        logging.title = "Add logging".to_string();
+
+        // The slug heuristic hands both tasks the first worktree
+        assert_eq!(
+            matching_worktree(&login, &worktrees).unwrap().branch,
+            "add-login"
+        );
+        assert_eq!(
+            matching_worktree(&logging, &worktrees).unwrap().branch,
+            "add-login"
+        );
+
+        logging.branch = Some("add-logging".to_string());
+        assert_eq!(
+            matching_worktree(&logging, &worktrees).unwrap().branch,
+            "add-logging"
+        );
+
+        // A recorded branch without a worktree matches nothing
+        login.branch = Some("add-login-v2".to_string());
+        assert!(matching_worktree(&login, &worktrees).is_none());
+    }
+
+    #[test]
+    fn test_tasks_updated_while_away_are_new() {
+        let updated = |id: &str, updated_at: &str| {
+            let mut task = make_task(TaskStatus::Todo);
+            task.id = id.to_string();
+            task.updated_at = updated_at.to_string();
+            task
+        };
+        let mut state = TasksState::new();
+        state.set_tasks(vec![
+            updated("before", "2024-01-01 09:00:00"),
+            updated("away", "2024-01-02 12:00:00"),
+            updated("created-away", "2024-01-03"),
+            updated("edited-here", "2024-01-05 10:00:01"),
+        ]);
+
+        // A board never seen before marks nothing
+        state.set_last_seen(None, "2024-01-05 10:00:00".to_string());
+        assert_eq!(state.new_count(), 0);
+
+        state.set_last_seen(
+            Some("2024-01-01 18:00:00".to_string()),
+            "2024-01-05 10:00:00".to_string(),
+        );
+        let new: Vec<_> = state.tasks.iter().filter(|t| state.is_new(t)).collect();
+        assert_eq!(new.len(), 2);
+        assert_eq!(new[0].id, "away");
+        assert_eq!(new[1].id, "created-away");
+
+        state.mark_seen("away");
+        assert_eq!(state.new_count(), 1);
+    }
 }
@@ -2,6 +2,12 @@ use std::collections::HashMap;
 
 use crate::external::{BranchPrInfo, WorktreeInfo};
 
+/// Key in `branch_prs` for a PR fetched by number for a bound task.
+/// `#` can't start a branch name, so these never collide with branches.
+pub fn bound_pr_key(number: u64) -> String {
+    format!("#{}", number)
+}
+
 pub struct WorktreesState {
     pub worktrees: Vec<WorktreeInfo>,
     pub selected_index: usize,
@@ -1,3 +1,5 @@
 mod tasks;
+mod tui_state;
 
 pub use tasks::*;
+pub use tui_state::TuiState;
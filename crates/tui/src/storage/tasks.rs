@@ -2,10 +2,16 @@
 
 use anyhow::{Context, Result};
 use serde::{Deserialize, Serialize};
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
 use crate::external::LinearIssue;
-use crate::state::{Task, TaskStatus};
+use crate::state::{KnownProject, Task, TaskStatus};
+
+/// Editor content for new tasks when no template file exists
+pub const DEFAULT_NEW_TASK_TEMPLATE: &str = "# New Task\n\nDescription here...";
+const NEW_TASK_TEMPLATE_FILE: &str = "new-task-template.md";
+/// Holds the directory the project was last opened from, for the project switcher
+const PROJECT_DIR_FILE: &str = "project-dir";
 
 /// File-based task storage.
 /// Tasks are stored as markdown files in ~/.vibe/projects/{project}/tasks/
@@ -25,6 +31,22 @@ pub struct TaskFrontmatter {
     pub linear_url: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub linear_labels: Option<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub tags: Vec<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub due: Option<String>,
+    /// 1 urgent, 2 high, 3 medium, 4 low
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub priority: Option<u8>,
+    /// Set by hand from the status picker; otherwise derived from worktree/PR state
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<TaskStatus>,
+    /// PR bound to the task once detected; followed by number so branch renames don't lose it
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pr: Option<u64>,
+    /// Worktree branch, recorded when the first session is launched
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub branch: Option<String>,
     pub created: String,
 }
 
@@ -38,7 +60,17 @@ impl TaskStorage {
             .ok_or_else(|| anyhow::anyhow!("Invalid directory name"))?
             .to_string();
 
-        Self::new(&project_name)
+        let storage = Self::new(&project_name)?;
+        // Rewritten on every start so the file's mtime orders projects by last use
+        if let Some(project_dir) = storage.tasks_dir.parent()
+            && let Err(e) = std::fs::write(
+                project_dir.join(PROJECT_DIR_FILE),
+                cwd.to_string_lossy().as_bytes(),
+            )
+        {
+            tracing::warn!("Failed to record project directory: {}", e);
+        }
+        Ok(storage)
     }
 
     /// Create storage for a specific project name
@@ -69,6 +101,28 @@ impl TaskStorage {
         &self.tasks_dir
     }
 
+    /// Editor content for a new task (first line is the title).
+    /// `~/.vibe/projects/{project}/new-task-template.md` overrides the global
+    /// `~/.vibe/new-task-template.md`; falls back to the built-in default.
+    pub fn new_task_template(&self) -> String {
+        let project_dir = self.tasks_dir.parent();
+        let vibe_dir = project_dir.and_then(Path::parent).and_then(Path::parent);
+        let candidates = [project_dir, vibe_dir]
+            .into_iter()
+            .flatten()
+            .map(|dir| dir.join(NEW_TASK_TEMPLATE_FILE));
+
+        read_first_template(candidates).unwrap_or_else(|| DEFAULT_NEW_TASK_TEMPLATE.to_string())
+    }
+
+    /// Other projects the TUI has been opened in, most recently used first
+    pub fn known_projects(&self) -> Vec<KnownProject> {
+        match self.tasks_dir.parent().and_then(Path::parent) {
+            Some(projects_dir) => read_known_projects(projects_dir, &self.project_name),
+            None => Vec::new(),
+        }
+    }
+
     /// List all tasks from markdown files
     pub fn list_tasks(&self) -> Result<Vec<Task>> {
         let pattern = format!("{}/*.md", self.tasks_dir.display());
@@ -113,6 +167,12 @@ impl TaskStorage {
             linear_id: None,
             linear_url: None,
             linear_labels: None,
+            tags: Vec::new(),
+            due: None,
+            priority: None,
+            status: None,
+            pr: None,
+            branch: None,
             created: created.clone(),
         };
 
@@ -132,11 +192,17 @@ impl TaskStorage {
             title: title.to_string(),
             description: description.map(String::from),
             status: TaskStatus::Backlog,
+            status_pinned: false,
             parent_workspace_id: None,
             shared_task_id: None,
             linear_issue_id: None,
             linear_url: None,
             linear_labels: None,
+            tags: Vec::new(),
+            due_date: None,
+            priority: None,
+            pr_number: None,
+            branch: None,
             created_at: created.clone(),
             updated_at: created,
             has_in_progress_attempt: false,
@@ -177,6 +243,12 @@ impl TaskStorage {
             linear_id: Some(issue.identifier.clone()),
             linear_url: Some(issue.url.clone()),
             linear_labels: labels_str.clone(),
+            tags: Vec::new(),
+            due: None,
+            priority: None,
+            status: None,
+            pr: None,
+            branch: None,
             created: created.clone(),
         };
 
@@ -196,11 +268,17 @@ impl TaskStorage {
             title: issue.title.clone(),
             description: issue.description.clone(),
             status: TaskStatus::Backlog,
+            status_pinned: false,
             parent_workspace_id: None,
             shared_task_id: None,
             linear_issue_id: Some(issue.identifier.clone()),
             linear_url: Some(issue.url.clone()),
             linear_labels: labels_str,
+            tags: Vec::new(),
+            due_date: None,
+            priority: None,
+            pr_number: None,
+            branch: None,
             created_at: created.clone(),
             updated_at: created,
             has_in_progress_attempt: false,
@@ -246,14 +324,20 @@ impl TaskStorage {
             project_id: self.project_name.clone(),
             title: title.to_string(),
             description: description.map(String::from),
-            status: TaskStatus::Backlog,
+            status: frontmatter.status.unwrap_or(TaskStatus::Backlog),
+            status_pinned: frontmatter.status.is_some(),
             parent_workspace_id: None,
             shared_task_id: None,
             linear_issue_id: frontmatter.linear_id.take(),
             linear_url: frontmatter.linear_url.take(),
             linear_labels: frontmatter.linear_labels.take(),
+            tags: std::mem::take(&mut frontmatter.tags),
+            due_date: frontmatter.due.take(),
+            priority: frontmatter.priority,
+            pr_number: frontmatter.pr,
+            branch: frontmatter.branch.take(),
             created_at: frontmatter.created.clone(),
-            updated_at: chrono::Utc::now().format("%Y-%m-%d").to_string(),
+            updated_at: chrono::Local::now().format(UPDATED_AT_FORMAT).to_string(),
             has_in_progress_attempt: false,
             last_attempt_failed: false,
             executor: String::new(),
@@ -266,6 +350,44 @@ impl TaskStorage {
         })
     }
 
+    /// Pin a task to a kanban column across restarts, keeping its title and description
+    pub fn set_status(&self, task_id: &str, status: TaskStatus) -> Result<()> {
+        self.update_frontmatter(task_id, |frontmatter| frontmatter.status = Some(status))
+    }
+
+    /// Remember the PR found for a task so it is followed by number from now on
+    pub fn bind_task_pr(&self, task_id: &str, number: u64) -> Result<()> {
+        self.update_frontmatter(task_id, |frontmatter| frontmatter.pr = Some(number))
+    }
+
+    pub fn set_task_branch(&self, task_id: &str, branch: &str) -> Result<()> {
+        self.update_frontmatter(task_id, |frontmatter| {
+            frontmatter.branch = Some(branch.to_string())
+        })
+    }
+
+    /// Rewrite a task file's frontmatter; the body after it is kept byte for byte
+    fn update_frontmatter(
+        &self,
+        task_id: &str,
+        update: impl FnOnce(&mut TaskFrontmatter),
+    ) -> Result<()> {
+        let (path, mut frontmatter) = self.find_task_file(task_id)?;
+        update(&mut frontmatter);
+
+        let existing = std::fs::read_to_string(&path)
+            .with_context(|| format!("Failed to read task file: {:?}", path))?;
+        let yaml = serde_yaml::to_string(&frontmatter).unwrap_or_default();
+        let content = match existing.splitn(3, "---").collect::<Vec<_>>()[..] {
+            ["", _, body] => format!("---\n{}---{}", yaml, body),
+            _ => format!("---\n{}---\n\n{}", yaml, existing),
+        };
+
+        std::fs::write(&path, &content)
+            .with_context(|| format!("Failed to write task file: {:?}", path))?;
+        Ok(())
+    }
+
     /// Delete a task by ID
     pub fn delete_task(&self, task_id: &str) -> Result<()> {
         let (path, _) = self.find_task_file(task_id)?;
@@ -297,14 +419,21 @@ impl TaskStorage {
             project_id: self.project_name.clone(),
             title,
             description,
-            status: TaskStatus::Backlog, // Status derived from git/PR state
+            // Status derived from git/PR state unless set by hand
+            status: frontmatter.status.unwrap_or(TaskStatus::Backlog),
+            status_pinned: frontmatter.status.is_some(),
             parent_workspace_id: None,
             shared_task_id: None,
             linear_issue_id: frontmatter.linear_id,
             linear_url: frontmatter.linear_url,
             linear_labels: frontmatter.linear_labels,
-            created_at: frontmatter.created.clone(),
-            updated_at: frontmatter.created,
+            tags: frontmatter.tags,
+            due_date: frontmatter.due,
+            priority: frontmatter.priority,
+            pr_number: frontmatter.pr,
+            branch: frontmatter.branch,
+            updated_at: file_modified_at(path).unwrap_or_else(|| frontmatter.created.clone()),
+            created_at: frontmatter.created,
             has_in_progress_attempt: false,
             last_attempt_failed: false,
             executor: String::new(),
@@ -334,6 +463,12 @@ impl TaskStorage {
                         linear_id: None,
                         linear_url: None,
                         linear_labels: None,
+                        tags: Vec::new(),
+                        due: None,
+                        priority: None,
+                        status: None,
+                        pr: None,
+                        branch: None,
                         created: chrono::Utc::now().format("%Y-%m-%d").to_string(),
                     });
                 (fm, body.to_string())
@@ -368,6 +503,52 @@ impl TaskStorage {
     }
 }
 
+/// Timestamp format for `updated_at`, precise enough to sort tasks edited on the same day
+pub const UPDATED_AT_FORMAT: &str = "%Y-%m-%d %H:%M:%S";
+
+/// Last modification time of a task file, used as the task's updated timestamp
+fn file_modified_at(path: &Path) -> Option<String> {
+    let modified = std::fs::metadata(path).ok()?.modified().ok()?;
+    let modified: chrono::DateTime<chrono::Local> = modified.into();
+    Some(modified.format(UPDATED_AT_FORMAT).to_string())
+}
+
+/// Content of the first existing, non-empty template file
+fn read_first_template(paths: impl IntoIterator<Item = PathBuf>) -> Option<String> {
+    paths.into_iter().find_map(|path| {
+        let content = std::fs::read_to_string(&path).ok()?;
+        (!content.trim().is_empty()).then_some(content)
+    })
+}
+
+/// Projects under `projects_dir` with a recorded directory that still exists,
+/// most recently used first, leaving out `exclude`
+fn read_known_projects(projects_dir: &Path, exclude: &str) -> Vec<KnownProject> {
+    let Ok(entries) = std::fs::read_dir(projects_dir) else {
+        return Vec::new();
+    };
+
+    let mut projects: Vec<_> = entries
+        .filter_map(Result::ok)
+        .filter_map(|entry| {
+            let name = entry.file_name().to_str()?.to_string();
+            if name == exclude {
+                return None;
+            }
+            let path = entry.path().join(PROJECT_DIR_FILE);
+            let dir = PathBuf::from(std::fs::read_to_string(&path).ok()?.trim());
+            if !dir.is_dir() {
+                return None;
+            }
+            let last_used = std::fs::metadata(&path).ok()?.modified().ok()?;
+            Some((last_used, KnownProject { name, dir }))
+        })
+        .collect();
+
+    projects.sort_by(|a, b| b.0.cmp(&a.0));
+    projects.into_iter().map(|(_, project)| project).collect()
+}
+
 /// Convert a title to a filename-safe slug
 fn slugify(title: &str) -> String {
     title
@@ -393,6 +574,66 @@ mod tests {
         assert_eq!(slugify("  Spaces  everywhere  "), "spaces-everywhere");
     }
 
+    #[test]
+    fn test_read_first_template() {
+        let dir = tempfile::tempdir().unwrap();
+        let project = dir.path().join("project.md");
+        let global = dir.path().join("global.md");
+        let missing = dir.path().join("missing.md");
+        std::fs::write(&global, "# Title\n\n## Acceptance criteria\n").unwrap();
+
+        assert_eq!(
+            read_first_template([missing.clone(), global.clone()]),
+            Some("# Title\n\n## Acceptance criteria\n".to_string())
+        );
+
+        // Project template wins when present, empty files are skipped
+        std::fs::write(&project, "  \n").unwrap();
+        assert_eq!(
+            read_first_template([project.clone(), global.clone()]),
+            Some("# Title\n\n## Acceptance criteria\n".to_string())
+        );
+        std::fs::write(&project, "# Project task\n").unwrap();
+        assert_eq!(
+            read_first_template([project, global]),
+            Some("# Project task\n".to_string())
+        );
+
+        assert_eq!(read_first_template([missing]), None);
+    }
+
+    #[test]
+    fn test_read_known_projects() {
+        let vibe = tempfile::tempdir().unwrap();
+        let repos = tempfile::tempdir().unwrap();
+        let record = |name: &str, dir: &Path, age_secs: u64| {
+            let project_dir = vibe.path().join(name);
+            std::fs::create_dir_all(&project_dir).unwrap();
+            let path = project_dir.join(PROJECT_DIR_FILE);
+            std::fs::write(&path, format!("{}\n", dir.display())).unwrap();
+            let last_used = std::time::SystemTime::now() - std::time::Duration::from_secs(age_secs);
+            std::fs::File::options()
+                .write(true)
+                .open(&path)
+                .unwrap()
+                .set_modified(last_used)
+                .unwrap();
+        };
+
+        record("current", repos.path(), 0);
+        record("older", repos.path(), 60);
+        record("newer", repos.path(), 10);
+        record("removed", &repos.path().join("gone"), 5);
+        // Opened before directories were recorded
+        std::fs::create_dir_all(vibe.path().join("unrecorded")).unwrap();
+
+        let names: Vec<_> = read_known_projects(vibe.path(), "current")
+            .into_iter()
+            .map(|p| p.name)
+            .collect();
+        assert_eq!(names, ["newer", "older"]);
+    }
+
     #[test]
     fn test_parse_frontmatter() {
         let yaml = r#"
@@ -404,5 +645,85 @@ created: 2024-01-15
         assert_eq!(fm.id, "abc123");
         assert_eq!(fm.linear_id, Some("TEAM-456".to_string()));
         assert_eq!(fm.created, "2024-01-15");
+        assert!(fm.tags.is_empty());
+    }
+
+    #[test]
+    fn test_parse_frontmatter_tags() {
+        let yaml = r#"
+id: abc123
+tags: [auth, bug]
+due: 2024-02-01
+created: 2024-01-15
+"#;
+        let fm: TaskFrontmatter = serde_yaml::from_str(yaml).unwrap();
+        assert_eq!(fm.tags, vec!["auth".to_string(), "bug".to_string()]);
+        assert_eq!(fm.due, Some("2024-02-01".to_string()));
+
+        let serialized = serde_yaml::to_string(&TaskFrontmatter {
+            id: "abc123".to_string(),
+            created: "2024-01-15".to_string(),
+            ..Default::default()
+        })
+        .unwrap();
+        assert!(!serialized.contains("tags"));
+        assert!(!serialized.contains("due"));
+        assert!(!serialized.contains("status"));
+    }
+
+    #[test]
+    fn test_parse_frontmatter_status() {
+        let yaml = r#"
+id: abc123
+status: cancelled
+created: 2024-01-15
+"#;
+        let fm: TaskFrontmatter = serde_yaml::from_str(yaml).unwrap();
+        assert_eq!(fm.status, Some(TaskStatus::Cancelled));
+    }
+
+    #[test]
+    fn test_status_round_trip() {
+        let dir = tempfile::tempdir().unwrap();
+        let storage = TaskStorage {
+            tasks_dir: dir.path().to_path_buf(),
+            project_name: "project".to_string(),
+        };
+        let task = storage
+            .create_task("Ship it", Some("Body\n\n  - kept as written  "))
+            .unwrap();
+        assert_eq!(task.status, TaskStatus::Backlog);
+        assert!(!task.status_pinned);
+
+        let path = dir.path().join("ship-it.md");
+        let body = |content: &str| content.splitn(3, "---").nth(2).unwrap().to_string();
+        let before = body(&std::fs::read_to_string(&path).unwrap());
+
+        storage.set_status(&task.id, TaskStatus::Done).unwrap();
+        assert_eq!(body(&std::fs::read_to_string(&path).unwrap()), before);
+
+        let tasks = storage.list_tasks().unwrap();
+        assert_eq!(tasks.len(), 1);
+        assert_eq!(tasks[0].status, TaskStatus::Done);
+        assert!(tasks[0].status_pinned);
+        assert_eq!(tasks[0].title, "Ship it");
+
+        // Editing the text keeps the status
+        let edited = storage
+            .update_task(&task.id, "Ship it", Some("New body"))
+            .unwrap();
+        assert_eq!(edited.status, TaskStatus::Done);
+        assert_eq!(storage.list_tasks().unwrap()[0].status, TaskStatus::Done);
+    }
+
+    #[test]
+    fn test_parse_frontmatter_bound_pr() {
+        let yaml = r#"
+id: abc123
+pr: 42
+created: 2024-01-15
+"#;
+        let fm: TaskFrontmatter = serde_yaml::from_str(yaml).unwrap();
+        assert_eq!(fm.pr, Some(42));
     }
 }
@@ -0,0 +1,127 @@
+use std::{collections::BTreeMap, path::PathBuf};
+
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+
+use super::UPDATED_AT_FORMAT;
+use crate::state::{AppState, CardSort, View};
+
+/// How the board was left, restored on the next start from `~/.vibe/tui-state.toml`
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct TuiState {
+    /// View to open on launch; views that need a selected task fall back to the board
+    pub view: Option<View>,
+    pub selected_column: usize,
+    /// Card order of each kanban column, left to right
+    pub column_sort: Vec<CardSort>,
+    pub show_ids: bool,
+    pub active_work_only: bool,
+    /// When each project's board was last left, to mark what changed since
+    pub last_seen: BTreeMap<String, String>,
+}
+
+impl TuiState {
+    /// Now, in the format of task `updated_at` timestamps
+    pub fn now() -> String {
+        chrono::Local::now().format(UPDATED_AT_FORMAT).to_string()
+    }
+
+    pub fn path() -> PathBuf {
+        dirs::home_dir()
+            .unwrap_or_else(|| PathBuf::from("."))
+            .join(".vibe")
+            .join("tui-state.toml")
+    }
+
+    /// Load the saved state, starting fresh if it is missing or invalid
+    pub fn load() -> Self {
+        let path = Self::path();
+        let Ok(content) = std::fs::read_to_string(&path) else {
+            return Self::default();
+        };
+
+        match toml::from_str(&content) {
+            Ok(state) => state,
+            Err(e) => {
+                tracing::warn!("Ignoring invalid TUI state {:?}: {}", path, e);
+                Self::default()
+            }
+        }
+    }
+
+    pub fn save(&self) -> Result<()> {
+        let path = Self::path();
+        if let Some(dir) = path.parent() {
+            std::fs::create_dir_all(dir)
+                .with_context(|| format!("Failed to create directory: {:?}", dir))?;
+        }
+        let content = toml::to_string(self).context("Failed to serialize TUI state")?;
+        std::fs::write(&path, content).with_context(|| format!("Failed to write {:?}", path))
+    }
+
+    pub fn capture(state: &AppState) -> Self {
+        Self {
+            view: Some(state.view),
+            selected_column: state.tasks.selected_column,
+            column_sort: state.tasks.column_sort.to_vec(),
+            show_ids: state.tasks.show_ids,
+            active_work_only: state.tasks.active_work_only,
+            last_seen: BTreeMap::new(),
+        }
+    }
+
+    pub fn restore(&self, state: &mut AppState) {
+        if let Some(view @ (View::Kanban | View::Worktrees | View::Sessions | View::CiFailures)) =
+            self.view
+        {
+            state.view = view;
+        }
+        let columns = state.tasks.column_sort.len();
+        state.tasks.selected_column = self.selected_column.min(columns - 1);
+        for (sort, saved) in state.tasks.column_sort.iter_mut().zip(&self.column_sort) {
+            *sort = *saved;
+        }
+        state.tasks.show_ids = self.show_ids;
+        state.tasks.active_work_only = self.active_work_only;
+        if let Some(project) = &state.selected_project_id {
+            let last_seen = self.last_seen.get(project).cloned();
+            state.tasks.set_last_seen(last_seen, Self::now());
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_round_trip_through_toml() {
+        let mut state = AppState::new();
+        state.view = View::Sessions;
+        state.tasks.selected_column = 2;
+        state.tasks.column_sort[1] = CardSort::Priority;
+        state.tasks.show_ids = true;
+
+        let content = toml::to_string(&TuiState::capture(&state)).unwrap();
+        let saved: TuiState = toml::from_str(&content).unwrap();
+
+        let mut restored = AppState::new();
+        saved.restore(&mut restored);
+        assert_eq!(restored.view, View::Sessions);
+        assert_eq!(restored.tasks.selected_column, 2);
+        assert_eq!(restored.tasks.column_sort[1], CardSort::Priority);
+        assert!(restored.tasks.show_ids);
+        assert!(!restored.tasks.active_work_only);
+    }
+
+    #[test]
+    fn test_restore_skips_views_needing_a_task() {
+        let saved: TuiState = toml::from_str("view = \"focus\"\nselected_column = 9\n").unwrap();
+        let mut state = AppState::new();
+        state.view = View::Kanban;
+        saved.restore(&mut state);
+        assert_eq!(state.view, View::Kanban);
+        assert_eq!(state.tasks.selected_column, 3);
+    }
+}
@@ -0,0 +1,90 @@
+use ratatui::{
+    Frame,
+    layout::Rect,
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, List, ListItem, Paragraph},
+};
+
+use super::render_loading;
+use crate::state::{TasksState, WorktreesState};
+
+pub fn render_ci_failures(
+    frame: &mut Frame,
+    area: Rect,
+    tasks: &TasksState,
+    worktrees: &WorktreesState,
+    spinner_char: char,
+    spinner_color: Color,
+) {
+    let failures = tasks.tasks_with_failing_checks(&worktrees.branch_prs, &worktrees.worktrees);
+
+    if failures.is_empty() && worktrees.loading {
+        render_loading(
+            frame,
+            area,
+            " CI Failures ",
+            "Loading worktrees",
+            spinner_char,
+            spinner_color,
+        );
+        return;
+    }
+
+    if failures.is_empty() {
+        let empty = Paragraph::new("No open PRs with failing checks.").block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title(" CI Failures ")
+                .border_style(Style::default().fg(Color::DarkGray)),
+        );
+        frame.render_widget(empty, area);
+        return;
+    }
+
+    let items: Vec<ListItem> = failures
+        .iter()
+        .enumerate()
+        .map(|(i, entry)| {
+            let is_selected = i == tasks.selected_failure;
+
+            let title_style = if is_selected {
+                Style::default()
+                    .fg(Color::Cyan)
+                    .add_modifier(Modifier::BOLD)
+            } else {
+                Style::default()
+            };
+
+            let mut spans = vec![
+                Span::raw(if is_selected { "> " } else { "  " }),
+                Span::styled("[x] ", Style::default().fg(Color::Red)),
+                Span::styled(entry.task.title.clone(), title_style),
+            ];
+
+            if let Some(branch) = entry.branch {
+                spans.push(Span::styled(
+                    format!(" ({})", branch),
+                    Style::default().fg(Color::DarkGray),
+                ));
+            }
+            if let Some(url) = entry.pr_url {
+                spans.push(Span::styled(
+                    format!(" {}", url),
+                    Style::default().fg(Color::DarkGray),
+                ));
+            }
+
+            ListItem::new(Line::from(spans))
+        })
+        .collect();
+
+    let list = List::new(items).block(
+        Block::default()
+            .borders(Borders::ALL)
+            .title(format!(" CI Failures ({}) ", failures.len()))
+            .border_style(Style::default().fg(Color::Red)),
+    );
+
+    frame.render_widget(list, area);
+}
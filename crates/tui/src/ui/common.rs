@@ -6,7 +6,8 @@ use ratatui::{
     Frame,
 };
 
-use crate::state::{linear_env_var_name, AppState};
+use crate::external::ClaudeSessionInfo;
+use crate::state::{linear_env_var_name, AppState, ProjectSwitcher, TaskStatus};
 
 const LOGO: &str = r#"
  __   _(_) |__   ___
@@ -14,6 +15,36 @@ const LOGO: &str = r#"
   \ V /| | |_) |  __/
    \_/ |_|_.__/ \___|"#;
 
+/// Shown next to the project name when its directory is gone or not a git repository
+const INVALID_PROJECT_HINT: &str = " (missing or not a git repo)";
+
+/// Placeholder for a view whose first background load hasn't finished yet
+pub fn render_loading(
+    frame: &mut Frame,
+    area: Rect,
+    title: &str,
+    label: &str,
+    spinner_char: char,
+    spinner_color: Color,
+) {
+    let loading = Paragraph::new(Line::from(vec![
+        Span::styled(
+            format!("{} ", spinner_char),
+            Style::default()
+                .fg(spinner_color)
+                .add_modifier(Modifier::BOLD),
+        ),
+        Span::styled(format!("{}…", label), Style::default().fg(Color::DarkGray)),
+    ]))
+    .block(
+        Block::default()
+            .borders(Borders::ALL)
+            .title(title)
+            .border_style(Style::default().fg(Color::DarkGray)),
+    );
+    frame.render_widget(loading, area);
+}
+
 pub fn render_header(frame: &mut Frame, area: Rect, state: &AppState) {
     // If area is tall enough, render the ASCII logo
     if area.height >= 5 {
@@ -23,34 +54,59 @@ pub fn render_header(frame: &mut Frame, area: Rect, state: &AppState) {
     }
 }
 
+/// Name and display name of the selected project, or None before one is selected
+fn selected_project(state: &AppState) -> Option<(String, String)> {
+    let id = state
+        .selected_project_id
+        .as_ref()
+        .filter(|id| !id.is_empty())?;
+    // Try to find the project name in the projects list, otherwise use the id directly
+    // (in standalone mode, the projects list is empty and id is the project name)
+    match state.projects.projects.iter().find(|p| &p.id == id) {
+        Some(project) => Some((project.name.clone(), project.display_name())),
+        None => Some((id.clone(), id.clone())),
+    }
+}
+
+/// `[N waiting]` in red while any session waits for the user, so it shows from every view
+fn waiting_badge(state: &AppState) -> Option<Span<'static>> {
+    let waiting = state.sessions.waiting_count();
+    (waiting > 0).then(|| {
+        Span::styled(
+            format!("[{} waiting]", waiting),
+            Style::default().fg(Color::Red).add_modifier(Modifier::BOLD),
+        )
+    })
+}
+
+fn new_badge(state: &AppState) -> Option<Span<'static>> {
+    let new = state.tasks.new_count();
+    (new > 0).then(|| {
+        Span::styled(
+            format!("[{} new]", new),
+            Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD),
+        )
+    })
+}
+
+fn header_badges(state: &AppState) -> impl Iterator<Item = Span<'static>> {
+    [waiting_badge(state), new_badge(state)]
+        .into_iter()
+        .flatten()
+}
+
 fn render_header_with_logo(frame: &mut Frame, area: Rect, state: &AppState) {
-    let (project_info, project_name) = match &state.selected_project_id {
-        Some(id) => {
-            // Try to find the project name in the projects list, otherwise use the id directly
-            // (in standalone mode, the projects list is empty and id is the project name)
-            let name = state
-                .projects
-                .projects
-                .iter()
-                .find(|p| &p.id == id)
-                .map(|p| p.name.as_str())
-                .unwrap_or(id.as_str());
-            (format!("Project: {}", name), Some(name.to_string()))
-        }
-        None => (String::new(), None),
-    };
+    let project = selected_project(state);
 
-    // Linear API key status
-    let linear_info = if let Some(ref name) = project_name {
+    // Linear API key status, only meaningful once a project is selected
+    let linear_info = project.as_ref().map(|(name, _)| {
         let env_var = linear_env_var_name(name);
         if state.linear_api_key_available {
-            Some((format!("Linear: {} set", env_var), Color::Green))
+            (format!("Linear: {} set", env_var), Color::Green)
         } else {
-            Some((format!("Linear: {} not set", env_var), Color::DarkGray))
+            (format!("Linear: {} not set", env_var), Color::DarkGray)
         }
-    } else {
-        None
-    };
+    });
 
     let status_text = if state.backend_connected {
         "Connected"
@@ -77,12 +133,30 @@ fn render_header_with_logo(frame: &mut Frame, area: Rect, state: &AppState) {
         if i == 0 {
             spans.push(Span::raw("  "));
             spans.push(Span::styled(status_text, Style::default().fg(status_color)));
-        } else if i == 1 && !project_info.is_empty() {
+            for badge in header_badges(state) {
+                spans.push(Span::raw("  "));
+                spans.push(badge);
+            }
+        } else if i == 1 {
             spans.push(Span::raw("  "));
-            spans.push(Span::styled(
-                &project_info,
-                Style::default().fg(Color::Yellow),
-            ));
+            match &project {
+                Some((_, display_name)) => {
+                    spans.push(Span::styled(
+                        format!("Project: {}", display_name),
+                        Style::default().fg(Color::Yellow),
+                    ));
+                    if !state.project_valid {
+                        spans.push(Span::styled(
+                            INVALID_PROJECT_HINT,
+                            Style::default().fg(Color::Red),
+                        ));
+                    }
+                }
+                None => spans.push(Span::styled(
+                    "No project",
+                    Style::default().fg(Color::DarkGray),
+                )),
+            }
         } else if i == 2 {
             if let Some((ref linear_text, linear_color)) = linear_info {
                 spans.push(Span::raw("  "));
@@ -99,19 +173,9 @@ fn render_header_with_logo(frame: &mut Frame, area: Rect, state: &AppState) {
 }
 
 fn render_header_compact(frame: &mut Frame, area: Rect, state: &AppState) {
-    let title = match &state.selected_project_id {
-        Some(id) => {
-            // Try to find the project name in the projects list, otherwise use the id directly
-            // (in standalone mode, the projects list is empty and id is the project name)
-            let project_name = state
-                .projects
-                .projects
-                .iter()
-                .find(|p| &p.id == id)
-                .map(|p| p.name.as_str())
-                .unwrap_or(id.as_str());
-            format!(" vibe - {} ", project_name)
-        }
+    let project = selected_project(state);
+    let title = match &project {
+        Some((_, display_name)) => format!(" vibe - {} ", display_name),
         None => " vibe ".to_string(),
     };
 
@@ -121,11 +185,23 @@ fn render_header_compact(frame: &mut Frame, area: Rect, state: &AppState) {
         Span::styled(" Disconnected ", Style::default().fg(Color::Red))
     };
 
-    let header = Paragraph::new(Line::from(vec![
-        Span::styled(&title, Style::default().add_modifier(Modifier::BOLD)),
-        Span::raw(" | "),
-        status,
-    ]))
+    let mut spans = vec![Span::styled(&title, Style::default().add_modifier(Modifier::BOLD))];
+    if project.is_none() {
+        spans.push(Span::styled(
+            "no project",
+            Style::default().fg(Color::DarkGray),
+        ));
+    } else if !state.project_valid {
+        spans.push(Span::styled(INVALID_PROJECT_HINT, Style::default().fg(Color::Red)));
+    }
+    spans.push(Span::raw(" | "));
+    spans.push(status);
+    for badge in header_badges(state) {
+        spans.push(Span::raw(" "));
+        spans.push(badge);
+    }
+
+    let header = Paragraph::new(Line::from(spans))
     .block(Block::default().borders(Borders::BOTTOM));
 
     frame.render_widget(header, area);
@@ -180,19 +256,20 @@ pub fn render_footer(frame: &mut Frame, area: Rect, state: &AppState) {
         }
         crate::state::View::Kanban => {
             format!(
-                "{}h/j/k/l: nav | Enter: details | /: search | s: session | Esc: back",
+                "{}h/j/k/l: nav | Enter: details | /: search | s: session | P: project | Esc: back",
                 search_indicator
             )
         }
         crate::state::View::TaskDetail => {
             format!(
-                "{}e: edit | r: refresh | s/Enter: session | /: search | Esc: back",
+                "{}j/k: scroll | e: edit | m: status | x: retry | r: refresh | g/Enter: session | \
+                 Esc: back",
                 search_indicator
             )
         }
         crate::state::View::Worktrees => {
             format!(
-                "{}j/k: nav | Enter: switch | s: session | /: search | Esc: back",
+                "{}j/k: nav | Enter: switch | s: session | R: resume | /: search | Esc: back",
                 search_indicator
             )
         }
@@ -203,11 +280,18 @@ pub fn render_footer(frame: &mut Frame, area: Rect, state: &AppState) {
             )
         }
         crate::state::View::Logs => {
-            "j/k: scroll | r: refresh | Esc: back".to_string()
+            "j/k: scroll | v: select | y: copy | e: stderr | K: stop | r: refresh | Esc: back"
+                .to_string()
         }
         crate::state::View::Search => {
             "j/k/Ctrl-j/k: nav | Enter: select | Esc: cancel".to_string()
         }
+        crate::state::View::CiFailures => {
+            "j/k: nav | Enter: details | v: view PR | r: refresh | Esc: back".to_string()
+        }
+        crate::state::View::Focus => {
+            "g/Enter: session | x: retry | m: status | r: refresh | f/Esc: leave focus".to_string()
+        }
     };
 
     let footer = Paragraph::new(hints)
@@ -239,12 +323,23 @@ pub fn render_help_modal(frame: &mut Frame, area: Rect) {
         Line::from("  c                  Create task"),
         Line::from("  e                  Edit task (nvim)"),
         Line::from("  d                  Delete task"),
+        Line::from("  y                  Copy task as markdown"),
+        Line::from("  Y                  Copy task as launch prompt"),
+        Line::from("  o                  Cycle column sort (created/updated/priority/title)"),
+        Line::from("  i                  Show short task ids"),
+        Line::from("  A                  Only tasks with a worktree or live session"),
+        Line::from("  m                  Set task status"),
+        Line::from("  < / >              Move task to prev/next column"),
+        Line::from("  f                  Focus mode (task, session, live output)"),
+        Line::from("  a                  Jump to the newest active task"),
+        Line::from("  x                  Retry: restart an exited session fresh (detail/focus)"),
         Line::from(""),
         Line::from(vec![
             Span::styled("Worktrees", Style::default().add_modifier(Modifier::BOLD)),
         ]),
         Line::from("  w                  Show worktrees"),
         Line::from("  W                  Create worktree"),
+        Line::from("  R                  Resume a past conversation"),
         Line::from(""),
         Line::from(vec![
             Span::styled("Sessions", Style::default().add_modifier(Modifier::BOLD)),
@@ -252,9 +347,11 @@ pub fn render_help_modal(frame: &mut Frame, area: Rect) {
         Line::from("  g                  Gas it (launch Claude)"),
         Line::from("  p                  Plan it (launch in plan mode)"),
         Line::from("  v                  View PR"),
+        Line::from("  F                  PRs with failing checks"),
         Line::from("  S                  Show sessions"),
         Line::from("  a / Enter          Attach to session"),
         Line::from("  K                  Kill session"),
+        Line::from("  /                  Filter sessions by name (sessions view)"),
         Line::from(""),
         Line::from(vec![
             Span::styled("Linear", Style::default().add_modifier(Modifier::BOLD)),
@@ -265,7 +362,14 @@ pub fn render_help_modal(frame: &mut Frame, area: Rect) {
             Span::styled("Other", Style::default().add_modifier(Modifier::BOLD)),
         ]),
         Line::from("  / or ;f            Search"),
+        Line::from("  ;t <id>            Tail a process's logs (e: stderr)"),
+        Line::from("  K (tailing)        Stop the tailed process"),
         Line::from("  r                  Refresh"),
+        Line::from("  C                  Warm server search cache"),
+        Line::from("  U                  Rebase branch onto its target"),
+        Line::from("  O                  Open project directory"),
+        Line::from("  P                  Switch project"),
+        Line::from("  I                  Logs (v: select lines, y: copy)"),
         Line::from("  ?                  This help"),
         Line::from(""),
         Line::from(vec![Span::styled(
@@ -294,3 +398,254 @@ pub fn render_help_modal(frame: &mut Frame, area: Rect) {
 
     frame.render_widget(help, modal_area);
 }
+
+/// Render a yes/no confirmation dialog centered on screen; `confirm_keys` is
+/// the hint for the keys that confirm
+pub fn render_confirm_modal(
+    frame: &mut Frame,
+    area: Rect,
+    title: &str,
+    message: &str,
+    confirm_keys: &str,
+) {
+    let text = vec![
+        Line::from(""),
+        Line::from(message.to_string()),
+        Line::from(""),
+        Line::from(vec![
+            Span::styled(
+                confirm_keys.to_string(),
+                Style::default().fg(Color::Red).add_modifier(Modifier::BOLD),
+            ),
+            Span::raw(": confirm  "),
+            Span::styled("n/Esc", Style::default().add_modifier(Modifier::BOLD)),
+            Span::raw(": cancel"),
+        ]),
+    ];
+
+    let modal_width = (message.chars().count() as u16 + 4).max(40).min(area.width);
+    let modal_height = text.len() as u16 + 2;
+    let x = (area.width.saturating_sub(modal_width)) / 2;
+    let y = (area.height.saturating_sub(modal_height)) / 2;
+    let modal_area = Rect::new(x, y, modal_width, modal_height);
+
+    let clear = Block::default().style(Style::default().bg(Color::Black));
+    frame.render_widget(clear, modal_area);
+
+    let confirm = Paragraph::new(text)
+        .alignment(ratatui::layout::Alignment::Center)
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title(title.to_string())
+                .border_style(Style::default().fg(Color::Red)),
+        );
+
+    frame.render_widget(confirm, modal_area);
+}
+
+/// Render the idle lock screen in place of the whole board, so nothing on it shows
+pub fn render_lock_screen(frame: &mut Frame, area: Rect, state: &AppState) {
+    let project = selected_project(state)
+        .map(|(_, display_name)| display_name)
+        .unwrap_or_default();
+    let text = vec![
+        Line::from(Span::styled(
+            "Locked",
+            Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD),
+        )),
+        Line::from(Span::styled(project, Style::default().fg(Color::DarkGray))),
+        Line::from(""),
+        Line::from("Press any key to resume"),
+    ];
+
+    let height = text.len() as u16 + 2;
+    let y = area.y + area.height.saturating_sub(height) / 2;
+    let text_area = Rect::new(area.x, y, area.width, height.min(area.height));
+
+    let clear = Block::default().style(Style::default().bg(Color::Black));
+    frame.render_widget(clear, area);
+    frame.render_widget(
+        Paragraph::new(text).alignment(ratatui::layout::Alignment::Center),
+        text_area,
+    );
+}
+
+/// Render the list of all task statuses to pick one for a task
+pub fn render_status_picker_modal(
+    frame: &mut Frame,
+    area: Rect,
+    task_title: &str,
+    selected: usize,
+) {
+    let mut text = vec![Line::from("")];
+    for (i, status) in TaskStatus::ALL.iter().enumerate() {
+        let style = if i == selected {
+            Style::default().fg(Color::Black).bg(Color::Cyan)
+        } else {
+            Style::default()
+        };
+        text.push(Line::from(Span::styled(format!("  {:<14}", status.label()), style)));
+    }
+    text.push(Line::from(""));
+    text.push(Line::from(vec![
+        Span::styled("Enter", Style::default().add_modifier(Modifier::BOLD)),
+        Span::raw(": set  "),
+        Span::styled("Esc", Style::default().add_modifier(Modifier::BOLD)),
+        Span::raw(": cancel"),
+    ]));
+
+    let modal_width = (task_title.chars().count() as u16 + 14).max(30).min(area.width);
+    let modal_height = text.len() as u16 + 2;
+    let x = (area.width.saturating_sub(modal_width)) / 2;
+    let y = (area.height.saturating_sub(modal_height)) / 2;
+    let modal_area = Rect::new(x, y, modal_width, modal_height);
+
+    let clear = Block::default().style(Style::default().bg(Color::Black));
+    frame.render_widget(clear, modal_area);
+
+    let picker = Paragraph::new(text).block(
+        Block::default()
+            .borders(Borders::ALL)
+            .title(format!(" Status - {} ", task_title))
+            .border_style(Style::default().fg(Color::Cyan)),
+    );
+
+    frame.render_widget(picker, modal_area);
+}
+
+/// Render the list of past conversations of a worktree to pick one to resume
+pub fn render_resume_session_modal(
+    frame: &mut Frame,
+    area: Rect,
+    branch: &str,
+    sessions: &[ClaudeSessionInfo],
+    selected: usize,
+) {
+    let mut text = vec![Line::from("")];
+    if sessions.is_empty() {
+        text.push(Line::from(Span::styled(
+            "  No past conversations for this worktree",
+            Style::default().fg(Color::DarkGray),
+        )));
+    }
+    for (i, session) in sessions.iter().enumerate() {
+        let style = if i == selected {
+            Style::default().fg(Color::Black).bg(Color::Cyan)
+        } else {
+            Style::default()
+        };
+        text.push(Line::from(vec![
+            Span::styled(
+                format!(" {} ", session.modified_at),
+                style.add_modifier(Modifier::DIM),
+            ),
+            Span::styled(format!(" {} ", session.preview), style),
+        ]));
+    }
+    text.push(Line::from(""));
+    text.push(Line::from(vec![
+        Span::styled("j/k", Style::default().add_modifier(Modifier::BOLD)),
+        Span::raw(": move  "),
+        Span::styled("Enter", Style::default().add_modifier(Modifier::BOLD)),
+        Span::raw(": resume  "),
+        Span::styled("Esc", Style::default().add_modifier(Modifier::BOLD)),
+        Span::raw(": cancel"),
+    ]));
+
+    let modal_width = 110.min(area.width);
+    let modal_height = (text.len() as u16 + 2).min(area.height);
+    let x = (area.width.saturating_sub(modal_width)) / 2;
+    let y = (area.height.saturating_sub(modal_height)) / 2;
+    let modal_area = Rect::new(x, y, modal_width, modal_height);
+
+    // Keep the selected conversation visible when the list is taller than the screen
+    let inner_height = modal_height.saturating_sub(2);
+    let scroll = (selected as u16 + 2).saturating_sub(inner_height);
+
+    let clear = Block::default().style(Style::default().bg(Color::Black));
+    frame.render_widget(clear, modal_area);
+
+    let picker = Paragraph::new(text).scroll((scroll, 0)).block(
+        Block::default()
+            .borders(Borders::ALL)
+            .title(format!(" Resume conversation - {} ", branch))
+            .border_style(Style::default().fg(Color::Cyan)),
+    );
+
+    frame.render_widget(picker, modal_area);
+}
+
+/// Render the project switcher: the query being typed above the matching projects
+pub fn render_project_switcher_modal(frame: &mut Frame, area: Rect, switcher: &ProjectSwitcher) {
+    let mut text = vec![
+        Line::from(vec![
+            Span::styled("> ", Style::default().fg(Color::Yellow)),
+            Span::raw(switcher.query.as_str()),
+            Span::styled("_", Style::default().fg(Color::Yellow)), // cursor
+        ]),
+        Line::from(""),
+    ];
+    let mut matches = switcher.matching().peekable();
+    if matches.peek().is_none() {
+        let message = if switcher.query.is_empty() {
+            "  No other projects yet - run vibe in a project once to list it"
+        } else {
+            "  No matching projects"
+        };
+        text.push(Line::from(Span::styled(
+            message,
+            Style::default().fg(Color::DarkGray),
+        )));
+    }
+    for (i, project) in matches.enumerate() {
+        let style = if i == switcher.selected {
+            Style::default().fg(Color::Black).bg(Color::Cyan)
+        } else {
+            Style::default()
+        };
+        let name = if switcher.is_favorite(project) {
+            format!("★ {}", project.name)
+        } else {
+            project.name.clone()
+        };
+        text.push(Line::from(vec![
+            Span::styled(format!(" {} ", name), style),
+            Span::styled(
+                format!(" {}", project.dir.display()),
+                Style::default().fg(Color::DarkGray),
+            ),
+        ]));
+    }
+    text.push(Line::from(""));
+    text.push(Line::from(vec![
+        Span::styled("Ctrl-j/k", Style::default().add_modifier(Modifier::BOLD)),
+        Span::raw(": move  "),
+        Span::styled("Enter", Style::default().add_modifier(Modifier::BOLD)),
+        Span::raw(": switch  "),
+        Span::styled("Esc", Style::default().add_modifier(Modifier::BOLD)),
+        Span::raw(": cancel"),
+    ]));
+
+    let modal_width = 80.min(area.width);
+    let modal_height = (text.len() as u16 + 2).min(area.height);
+    let x = (area.width.saturating_sub(modal_width)) / 2;
+    let y = (area.height.saturating_sub(modal_height)) / 2;
+    let modal_area = Rect::new(x, y, modal_width, modal_height);
+
+    // Keep the selected project visible when the list is taller than the screen
+    let inner_height = modal_height.saturating_sub(2);
+    let scroll = (switcher.selected as u16 + 3).saturating_sub(inner_height);
+
+    let clear = Block::default().style(Style::default().bg(Color::Black));
+    frame.render_widget(clear, modal_area);
+
+    let picker = Paragraph::new(text).scroll((scroll, 0)).block(
+        Block::default()
+            .borders(Borders::ALL)
+            .title(" Switch project ")
+            .border_style(Style::default().fg(Color::Cyan)),
+    );
+
+    frame.render_widget(picker, modal_area);
+}
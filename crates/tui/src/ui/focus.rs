@@ -0,0 +1,113 @@
+use ratatui::{
+    Frame,
+    layout::{Constraint, Direction, Layout, Rect},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Paragraph},
+};
+
+use super::render_task_detail;
+use crate::{
+    external::{ClaudeActivityState, ZellijSession},
+    state::{FocusState, Task},
+};
+
+/// Render focus mode: the task, its session status and the live session screen
+pub fn render_focus(
+    frame: &mut Frame,
+    area: Rect,
+    task: &Task,
+    session: Option<&ZellijSession>,
+    focus: &FocusState,
+    spinner_char: char,
+    spinner_color: Color,
+) {
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Percentage(35), // Task detail
+            Constraint::Length(3),      // Session status
+            Constraint::Min(0),         // Session output
+        ])
+        .split(area);
+
+    render_task_detail(frame, chunks[0], task, None, None);
+
+    let status = Paragraph::new(session_status_line(session, spinner_char, spinner_color)).block(
+        Block::default()
+            .borders(Borders::ALL)
+            .title(" Session ")
+            .border_style(Style::default().fg(Color::DarkGray)),
+    );
+    frame.render_widget(status, chunks[1]);
+
+    // Show the bottom of the screen, where the agent is currently writing
+    let height = chunks[2].height.saturating_sub(2) as usize;
+    let start = focus.screen.len().saturating_sub(height);
+    let output: Vec<Line> = if focus.screen.is_empty() {
+        vec![Line::from(Span::styled(
+            "No session output",
+            Style::default().fg(Color::DarkGray),
+        ))]
+    } else {
+        focus.screen[start..]
+            .iter()
+            .map(|line| Line::from(line.as_str()))
+            .collect()
+    };
+
+    let output = Paragraph::new(output).block(
+        Block::default()
+            .borders(Borders::ALL)
+            .title(" Live Output ")
+            .border_style(Style::default().fg(Color::Cyan)),
+    );
+    frame.render_widget(output, chunks[2]);
+}
+
+fn session_status_line(
+    session: Option<&ZellijSession>,
+    spinner_char: char,
+    spinner_color: Color,
+) -> Line<'static> {
+    let Some(session) = session else {
+        return Line::from(Span::styled(
+            "No session - press g on the task to launch one",
+            Style::default().fg(Color::DarkGray),
+        ));
+    };
+
+    let (label, style) = if session.is_dead {
+        ("exited".to_string(), Style::default().fg(Color::DarkGray))
+    } else {
+        match session.claude_activity {
+            ClaudeActivityState::Thinking => (
+                format!("{} working", spinner_char),
+                Style::default()
+                    .fg(spinner_color)
+                    .add_modifier(Modifier::BOLD),
+            ),
+            ClaudeActivityState::WaitingForUser => (
+                "waiting for input".to_string(),
+                Style::default().fg(Color::Red).add_modifier(Modifier::BOLD),
+            ),
+            ClaudeActivityState::Idle => ("idle".to_string(), Style::default().fg(Color::DarkGray)),
+            ClaudeActivityState::Unknown if session.needs_attention => (
+                "needs attention".to_string(),
+                Style::default().fg(Color::Red).add_modifier(Modifier::BOLD),
+            ),
+            ClaudeActivityState::Unknown => {
+                ("running".to_string(), Style::default().fg(Color::Green))
+            }
+        }
+    };
+
+    Line::from(vec![
+        Span::styled(
+            session.name.clone(),
+            Style::default().add_modifier(Modifier::BOLD),
+        ),
+        Span::raw("  "),
+        Span::styled(label, style),
+    ])
+}
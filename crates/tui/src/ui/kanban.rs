@@ -7,7 +7,10 @@ use ratatui::{
 };
 
 use crate::external::ClaudeActivityState;
-use crate::state::{SessionsState, TaskStatus, TasksState, WorktreesState};
+use crate::state::{
+    bound_pr_key, matching_worktree, today, AttemptStatus, CardSort, SessionsState, Spinner,
+    TaskStatus, TasksState, WorktreesState,
+};
 
 pub fn render_kanban_board(
     frame: &mut Frame,
@@ -15,7 +18,7 @@ pub fn render_kanban_board(
     tasks: &TasksState,
     worktrees: &WorktreesState,
     sessions: &SessionsState,
-    spinner_char: char,
+    spinner: Spinner,
     linear_pending_count: usize,
 ) {
     // Split into 4 horizontal rows (Backlog, In Progress, In Review, Done)
@@ -32,21 +35,29 @@ pub fn render_kanban_board(
     for (i, status) in TaskStatus::VISIBLE.iter().enumerate() {
         let is_selected = tasks.selected_column == i;
         let pending = if *status == TaskStatus::Backlog { linear_pending_count } else { 0 };
-        render_row(frame, rows[i], tasks, worktrees, sessions, *status, is_selected, spinner_char, pending);
+        let (list, mut list_state) = row_list(
+            tasks,
+            worktrees,
+            sessions,
+            *status,
+            is_selected,
+            spinner,
+            pending,
+        );
+        frame.render_stateful_widget(list, rows[i], &mut list_state);
     }
 }
 
-fn render_row(
-    frame: &mut Frame,
-    area: Rect,
+/// The list widget of one status row, with the selected card highlighted
+fn row_list(
     tasks_state: &TasksState,
     worktrees: &WorktreesState,
     sessions: &SessionsState,
     status: TaskStatus,
     is_selected: bool,
-    spinner_char: char,
+    spinner: Spinner,
     linear_pending: usize,
-) {
+) -> (List<'static>, ListState) {
     let tasks = tasks_state.tasks_in_column_with_prs(
         status,
         &worktrees.branch_prs,
@@ -55,10 +66,34 @@ fn render_row(
     let count = tasks.len();
     let column_index = status.column_index();
 
+    let sort = tasks_state.column_sort[column_index];
+    let mut title_info = if sort == CardSort::Default {
+        String::new()
+    } else {
+        format!(" by {}", sort.label())
+    };
+    if tasks_state.active_work_only {
+        title_info.push_str(" with active work");
+    }
     let title = if linear_pending > 0 {
-        format!(" {} ({}) - Linear (+{}) ", status.label(), count, linear_pending)
+        format!(
+            " {} ({}){} - Linear (+{}) ",
+            status.label(),
+            count,
+            title_info,
+            linear_pending
+        )
     } else {
-        format!(" {} ({}) ", status.label(), count)
+        format!(" {} ({}){} ", status.label(), count, title_info)
+    };
+
+    let over_wip = tasks_state.wip_limits[column_index].filter(|limit| count > *limit);
+    let title = match over_wip {
+        Some(limit) => Line::from(Span::styled(
+            format!("{}- over WIP ({}/{}) ", title, count, limit),
+            Style::default().fg(Color::Red).add_modifier(Modifier::BOLD),
+        )),
+        None => Line::from(title),
     };
 
     let border_color = if is_selected {
@@ -67,39 +102,66 @@ fn render_row(
         Color::DarkGray
     };
 
+    let today = today();
+    let column_open = !matches!(status, TaskStatus::Done | TaskStatus::Cancelled);
+
     // For horizontal rows, show tasks in a single-line compact format
     let items: Vec<ListItem> = tasks
         .iter()
         .map(|task| {
             let mut spans: Vec<Span> = vec![];
 
-            // Activity indicator
-            if task.has_in_progress_attempt {
+            if tasks_state.show_ids {
+                let short_id: String = task.id.chars().take(8).collect();
                 spans.push(Span::styled(
-                    format!("[{}] ", spinner_char),
-                    Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD),
+                    format!("{} ", short_id),
+                    Style::default().fg(Color::DarkGray),
                 ));
-            } else if task.last_attempt_failed {
+            }
+
+            // Activity indicator
+            match task.current_attempt_status() {
+                Some(AttemptStatus::Running) => {
+                    spans.push(Span::styled(
+                        format!("[{}] ", spinner.char),
+                        Style::default().fg(spinner.color).add_modifier(Modifier::BOLD),
+                    ));
+                }
+                Some(AttemptStatus::Failed) => {
+                    spans.push(Span::styled(
+                        "[!] ",
+                        Style::default().fg(Color::Red).add_modifier(Modifier::BOLD),
+                    ));
+                }
+                None => {}
+            }
+
+            // Updated while the board was not being looked at
+            if tasks_state.is_new(task) {
                 spans.push(Span::styled(
-                    "[!] ",
-                    Style::default().fg(Color::Red).add_modifier(Modifier::BOLD),
+                    "new ",
+                    Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD),
                 ));
             }
 
+            // Priority (urgent/high stand out)
+            if let Some(priority) = task.priority.filter(|p| *p > 0) {
+                let color = if priority <= 2 { Color::Red } else { Color::Yellow };
+                spans.push(Span::styled(format!("P{} ", priority), Style::default().fg(color)));
+            }
+
             // Title
             spans.push(Span::raw(task.title.clone()));
 
             // Worktree/branch info - find it first so we can use it for PR lookup
-            let task_slug = task.title.to_lowercase().replace(' ', "-");
-            let matching_worktree = worktrees
-                .worktrees
-                .iter()
-                .find(|w| w.branch.to_lowercase().contains(&task_slug) || task_slug.contains(&w.branch.to_lowercase()));
+            let matching_worktree = matching_worktree(task, &worktrees.worktrees);
 
             // PR status - check backend first, then local gh detection
             let has_backend_pr = task.pr_url.is_some();
-            let branch_pr = matching_worktree
-                .and_then(|wt| worktrees.pr_for_branch(&wt.branch));
+            let branch_pr = task
+                .pr_number
+                .and_then(|n| worktrees.pr_for_branch(&bound_pr_key(n)))
+                .or_else(|| matching_worktree.and_then(|wt| worktrees.pr_for_branch(&wt.branch)));
 
             if has_backend_pr {
                 // Use backend PR info
@@ -140,6 +202,17 @@ fn render_row(
                 if pr.has_conflicts() {
                     spans.push(Span::styled(" !", Style::default().fg(Color::Red)));
                 }
+                // Diff size, to gauge review effort
+                if pr.state == "OPEN" && (pr.additions > 0 || pr.deletions > 0) {
+                    spans.push(Span::styled(
+                        format!(" +{}", pr.additions),
+                        Style::default().fg(Color::Green),
+                    ));
+                    spans.push(Span::styled(
+                        format!("/-{}", pr.deletions),
+                        Style::default().fg(Color::Red),
+                    ));
+                }
             }
 
             // Linear indicator
@@ -147,6 +220,24 @@ fn render_row(
                 spans.push(Span::styled(" [L]", Style::default().fg(Color::Blue)));
             }
 
+            // Local tags
+            for tag in &task.tags {
+                spans.push(Span::styled(
+                    format!(" #{}", tag),
+                    Style::default().fg(Color::LightMagenta),
+                ));
+            }
+
+            // Due date, highlighted once it has passed
+            if let Some(ref due) = task.due_date {
+                let style = if column_open && task.is_overdue(&today) {
+                    Style::default().fg(Color::Red).add_modifier(Modifier::BOLD)
+                } else {
+                    Style::default().fg(Color::DarkGray)
+                };
+                spans.push(Span::styled(format!(" due {}", due), style));
+            }
+
             // Worktree/branch display
             if let Some(wt) = matching_worktree {
                 spans.push(Span::styled(
@@ -158,8 +249,8 @@ fn render_row(
                     match session.claude_activity {
                         ClaudeActivityState::Thinking => {
                             spans.push(Span::styled(
-                                format!(" [{}]", spinner_char),
-                                Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD),
+                                format!(" [{}]", spinner.char),
+                                Style::default().fg(spinner.color).add_modifier(Modifier::BOLD),
                             ));
                         }
                         ClaudeActivityState::WaitingForUser => {
@@ -209,5 +300,5 @@ fn render_row(
         list_state.select(Some(tasks_state.selected_card_per_column[column_index]));
     }
 
-    frame.render_stateful_widget(list, area, &mut list_state);
+    (list, list_state)
 }
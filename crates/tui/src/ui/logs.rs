@@ -8,34 +8,61 @@ use ratatui::{
 
 use crate::state::LogsState;
 
+/// A log line colored by level, reversed while it is part of the visual selection
+fn log_line(logs: &LogsState, index: usize, line: &str) -> Line<'static> {
+    let mut style = if line.contains("ERROR") {
+        Style::default().fg(Color::Red)
+    } else if line.contains("WARN") {
+        Style::default().fg(Color::Yellow)
+    } else if line.contains("INFO") {
+        Style::default().fg(Color::Green)
+    } else if line.contains("DEBUG") {
+        Style::default().fg(Color::Blue)
+    } else {
+        Style::default().fg(Color::DarkGray)
+    };
+    if logs.is_selected(index) {
+        style = style.add_modifier(Modifier::REVERSED);
+    }
+
+    Line::from(Span::styled(line.to_string(), style))
+}
+
+fn logs_title(logs: &LogsState) -> String {
+    let title = match &logs.tailing {
+        Some(process_id) if logs.stderr_only => format!(
+            " Process {} stderr ({}) - {} ",
+            process_id,
+            logs.lines.len(),
+            logs.tail_status
+        ),
+        Some(process_id) => format!(
+            " Process {} ({}) - {} ",
+            process_id,
+            logs.lines.len(),
+            logs.tail_status
+        ),
+        None => format!(
+            " Logs ({}) - {} ",
+            logs.lines.len(),
+            logs.log_path.display()
+        ),
+    };
+    match logs.selection_len() {
+        Some(count) => format!("{}- VISUAL ({} lines) ", title, count),
+        None => title,
+    }
+}
+
 pub fn render_logs(frame: &mut Frame, area: Rect, logs: &LogsState) {
     let height = area.height.saturating_sub(2) as usize; // Account for borders
 
     let lines: Vec<Line> = logs
         .visible_lines(height)
-        .map(|line| {
-            // Color based on log level
-            let style = if line.contains("ERROR") {
-                Style::default().fg(Color::Red)
-            } else if line.contains("WARN") {
-                Style::default().fg(Color::Yellow)
-            } else if line.contains("INFO") {
-                Style::default().fg(Color::Green)
-            } else if line.contains("DEBUG") {
-                Style::default().fg(Color::Blue)
-            } else {
-                Style::default().fg(Color::DarkGray)
-            };
-
-            Line::from(Span::styled(line.clone(), style))
-        })
+        .map(|(index, line)| log_line(logs, index, line))
         .collect();
 
-    let title = format!(
-        " Logs ({}) - {} ",
-        logs.lines.len(),
-        logs.log_path.display()
-    );
+    let title = logs_title(logs);
 
     let paragraph = Paragraph::new(lines)
         .block(
@@ -49,7 +76,7 @@ pub fn render_logs(frame: &mut Frame, area: Rect, logs: &LogsState) {
     frame.render_widget(paragraph, area);
 
     // Render help at bottom
-    let help_text = " j/k: scroll | r: refresh | Esc: back ";
+    let help_text = " j/k: scroll | v: select | y: copy | r: refresh | Esc: back ";
     let help_line = Line::from(vec![Span::styled(
         help_text,
         Style::default()
@@ -60,7 +87,7 @@ pub fn render_logs(frame: &mut Frame, area: Rect, logs: &LogsState) {
     let help_area = Rect {
         x: area.x + 1,
         y: area.y + area.height - 1,
-        width: help_text.len() as u16,
+        width: (help_text.len() as u16).min(area.width.saturating_sub(2)),
         height: 1,
     };
 
@@ -90,27 +117,10 @@ pub fn render_logs_overlay(frame: &mut Frame, area: Rect, logs: &LogsState) {
 
     let lines: Vec<Line> = logs
         .visible_lines(height)
-        .map(|line| {
-            let style = if line.contains("ERROR") {
-                Style::default().fg(Color::Red)
-            } else if line.contains("WARN") {
-                Style::default().fg(Color::Yellow)
-            } else if line.contains("INFO") {
-                Style::default().fg(Color::Green)
-            } else if line.contains("DEBUG") {
-                Style::default().fg(Color::Blue)
-            } else {
-                Style::default().fg(Color::DarkGray)
-            };
-            Line::from(Span::styled(line.clone(), style))
-        })
+        .map(|(index, line)| log_line(logs, index, line))
         .collect();
 
-    let title = format!(
-        " Logs ({}) - {} ",
-        logs.lines.len(),
-        logs.log_path.display()
-    );
+    let title = logs_title(logs);
 
     let paragraph = Paragraph::new(lines)
         .block(
@@ -124,7 +134,7 @@ pub fn render_logs_overlay(frame: &mut Frame, area: Rect, logs: &LogsState) {
     frame.render_widget(paragraph, overlay_area);
 
     // Render help at bottom of overlay
-    let help_text = " j/k: scroll | r: refresh | Shift+I/Esc: close ";
+    let help_text = " j/k: scroll | v: select | y: copy | r: refresh | Shift+I/Esc: close ";
     let help_line = Line::from(vec![Span::styled(
         help_text,
         Style::default()
@@ -135,7 +145,7 @@ pub fn render_logs_overlay(frame: &mut Frame, area: Rect, logs: &LogsState) {
     let help_area = Rect {
         x: overlay_area.x + 1,
         y: overlay_area.y + overlay_area.height - 1,
-        width: help_text.len() as u16,
+        width: (help_text.len() as u16).min(overlay_area.width.saturating_sub(2)),
         height: 1,
     };
 
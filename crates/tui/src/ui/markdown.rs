@@ -0,0 +1,197 @@
+use ratatui::{
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+};
+
+/// Render markdown as lines wrapped to `width`: paragraphs are reflowed, headings
+/// and list items styled, and fenced code blocks kept verbatim in a dim style.
+pub fn render_markdown(text: &str, width: usize) -> Vec<Line<'static>> {
+    let width = width.max(1);
+    let mut lines = Vec::new();
+    let mut paragraph: Vec<&str> = Vec::new();
+    let mut in_code_block = false;
+
+    for raw in text.lines() {
+        let trimmed = raw.trim_start();
+
+        if trimmed.starts_with("```") || trimmed.starts_with("~~~") {
+            flush_paragraph(&mut lines, &mut paragraph, width);
+            in_code_block = !in_code_block;
+            continue;
+        }
+        if in_code_block {
+            lines.push(Line::from(Span::styled(
+                format!("  {}", raw),
+                Style::default().fg(Color::DarkGray),
+            )));
+            continue;
+        }
+
+        if trimmed.is_empty() {
+            flush_paragraph(&mut lines, &mut paragraph, width);
+            if lines.last().is_some_and(|line| line.width() > 0) {
+                lines.push(Line::default());
+            }
+        } else if let Some((level, heading)) = parse_heading(trimmed) {
+            flush_paragraph(&mut lines, &mut paragraph, width);
+            let style = if level == 1 {
+                Style::default()
+                    .fg(Color::Cyan)
+                    .add_modifier(Modifier::BOLD)
+            } else {
+                Style::default()
+                    .fg(Color::Yellow)
+                    .add_modifier(Modifier::BOLD)
+            };
+            for line in wrap_words(heading, width) {
+                lines.push(Line::from(Span::styled(line, style)));
+            }
+        } else if let Some((marker, item)) = parse_list_item(trimmed) {
+            flush_paragraph(&mut lines, &mut paragraph, width);
+            let indent = " ".repeat(raw.len() - trimmed.len());
+            let bullet = format!("{}{} ", indent, marker);
+            let hanging = " ".repeat(bullet.chars().count());
+            let item_width = width.saturating_sub(bullet.chars().count());
+            for (i, line) in wrap_words(item, item_width).into_iter().enumerate() {
+                let prefix = if i == 0 { &bullet } else { &hanging };
+                lines.push(Line::from(vec![
+                    Span::styled(prefix.clone(), Style::default().fg(Color::Cyan)),
+                    Span::raw(line),
+                ]));
+            }
+        } else {
+            paragraph.push(trimmed);
+        }
+    }
+    flush_paragraph(&mut lines, &mut paragraph, width);
+
+    // Trailing blank lines only add empty scroll room
+    while lines.last().is_some_and(|line| line.width() == 0) {
+        lines.pop();
+    }
+    lines
+}
+
+fn flush_paragraph(lines: &mut Vec<Line<'static>>, paragraph: &mut Vec<&str>, width: usize) {
+    if paragraph.is_empty() {
+        return;
+    }
+    let text = paragraph.join(" ");
+    lines.extend(wrap_words(&text, width).into_iter().map(Line::from));
+    paragraph.clear();
+}
+
+/// `# Title` → (1, "Title"); requires the space after the hashes
+fn parse_heading(line: &str) -> Option<(usize, &str)> {
+    let level = line.chars().take_while(|c| *c == '#').count();
+    if !(1..=6).contains(&level) {
+        return None;
+    }
+    line[level..]
+        .strip_prefix(' ')
+        .map(|heading| (level, heading.trim()))
+}
+
+/// `- item`, `* item`, `+ item` → ("•", "item"); `2. item` → ("2.", "item")
+fn parse_list_item(line: &str) -> Option<(String, &str)> {
+    for bullet in ["- ", "* ", "+ "] {
+        if let Some(item) = line.strip_prefix(bullet) {
+            return Some(("•".to_string(), item.trim()));
+        }
+    }
+
+    let digits = line.chars().take_while(|c| c.is_ascii_digit()).count();
+    if digits == 0 {
+        return None;
+    }
+    line[digits..]
+        .strip_prefix(". ")
+        .map(|item| (line[..=digits].to_string(), item.trim()))
+}
+
+/// Greedy word wrap; words longer than `width` are split across lines
+fn wrap_words(text: &str, width: usize) -> Vec<String> {
+    let width = width.max(1);
+    let mut lines = Vec::new();
+    let mut current = String::new();
+    let mut current_len = 0;
+
+    for word in text.split_whitespace() {
+        let mut word: Vec<char> = word.chars().collect();
+        while !word.is_empty() {
+            let separator = usize::from(current_len > 0);
+            if current_len + separator + word.len() <= width {
+                if separator == 1 {
+                    current.push(' ');
+                }
+                current.extend(word.iter());
+                current_len += separator + word.len();
+                break;
+            }
+            if current_len > 0 {
+                lines.push(std::mem::take(&mut current));
+                current_len = 0;
+                continue;
+            }
+            // The word alone doesn't fit: hard-split it
+            let rest = word.split_off(width);
+            lines.push(word.into_iter().collect());
+            word = rest;
+        }
+    }
+    if current_len > 0 {
+        lines.push(current);
+    }
+    lines
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn plain(lines: &[Line]) -> Vec<String> {
+        lines
+            .iter()
+            .map(|line| line.spans.iter().map(|s| s.content.as_ref()).collect())
+            .collect()
+    }
+
+    #[test]
+    fn reflows_paragraphs_to_width() {
+        let lines = render_markdown("one two\nthree four five\n\nsix", 10);
+        assert_eq!(plain(&lines), ["one two", "three four", "five", "", "six"]);
+    }
+
+    #[test]
+    fn styles_headings_and_lists() {
+        let lines = render_markdown("## Plan\n- first step\n  * nested\n1. numbered", 40);
+        assert_eq!(
+            plain(&lines),
+            ["Plan", "• first step", "  • nested", "1. numbered"]
+        );
+        assert!(
+            lines[0].spans[0]
+                .style
+                .add_modifier
+                .contains(Modifier::BOLD)
+        );
+    }
+
+    #[test]
+    fn wraps_list_items_with_hanging_indent() {
+        let lines = render_markdown("- alpha beta gamma", 10);
+        assert_eq!(plain(&lines), ["• alpha", "  beta", "  gamma"]);
+    }
+
+    #[test]
+    fn keeps_code_blocks_verbatim_and_dim() {
+        let lines = render_markdown("Run:\n```sh\ncargo   test  --all\n```\nDone", 8);
+        assert_eq!(plain(&lines), ["Run:", "  cargo   test  --all", "Done"]);
+        assert_eq!(lines[1].spans[0].style.fg, Some(Color::DarkGray));
+    }
+
+    #[test]
+    fn splits_words_longer_than_the_width() {
+        assert_eq!(wrap_words("abcdefgh ij", 3), ["abc", "def", "gh", "ij"]);
+    }
+}
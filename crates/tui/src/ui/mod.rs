@@ -1,12 +1,17 @@
+mod ci_failures;
 mod common;
+mod focus;
 mod kanban;
 mod logs;
+mod markdown;
 mod search;
 mod sessions;
 mod task_detail;
 mod worktrees;
 
+pub use ci_failures::*;
 pub use common::*;
+pub use focus::*;
 pub use kanban::*;
 pub use logs::*;
 pub use search::*;
@@ -6,10 +6,17 @@ use ratatui::{
     Frame,
 };
 
+use super::render_loading;
 use crate::external::ClaudeActivityState;
 use crate::state::SessionsState;
 
-pub fn render_sessions(frame: &mut Frame, area: Rect, state: &SessionsState, spinner_char: char) {
+pub fn render_sessions(
+    frame: &mut Frame,
+    area: Rect,
+    state: &SessionsState,
+    spinner_char: char,
+    spinner_color: Color,
+) {
     if let Some(error) = &state.error {
         let error_msg = Paragraph::new(format!("Error: {}", error))
             .style(Style::default().fg(Color::Red))
@@ -23,14 +30,15 @@ pub fn render_sessions(frame: &mut Frame, area: Rect, state: &SessionsState, spi
         return;
     }
 
-    if state.loading {
-        let loading = Paragraph::new("Loading sessions...").block(
-            Block::default()
-                .borders(Borders::ALL)
-                .title(" Zellij Sessions ")
-                .border_style(Style::default().fg(Color::DarkGray)),
+    if state.loading && state.sessions.is_empty() {
+        render_loading(
+            frame,
+            area,
+            " Zellij Sessions ",
+            "Loading sessions",
+            spinner_char,
+            spinner_color,
         );
-        frame.render_widget(loading, area);
         return;
     }
 
@@ -46,8 +54,20 @@ pub fn render_sessions(frame: &mut Frame, area: Rect, state: &SessionsState, spi
         return;
     }
 
-    let items: Vec<ListItem> = state
-        .sessions
+    let sessions = state.filtered_sessions();
+    if sessions.is_empty() {
+        let empty = Paragraph::new(format!("No sessions match \"{}\".", state.search_filter))
+            .block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .title(" Zellij Sessions ")
+                    .border_style(Style::default().fg(Color::DarkGray)),
+            );
+        frame.render_widget(empty, area);
+        return;
+    }
+
+    let items: Vec<ListItem> = sessions
         .iter()
         .enumerate()
         .map(|(i, session)| {
@@ -70,7 +90,7 @@ pub fn render_sessions(frame: &mut Frame, area: Rect, state: &SessionsState, spi
                 match session.claude_activity {
                     ClaudeActivityState::Thinking => Span::styled(
                         format!(" [{}]", spinner_char),
-                        Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD),
+                        Style::default().fg(spinner_color).add_modifier(Modifier::BOLD),
                     ),
                     ClaudeActivityState::WaitingForUser => Span::styled(
                         " [!]",
@@ -102,22 +122,21 @@ pub fn render_sessions(frame: &mut Frame, area: Rect, state: &SessionsState, spi
         })
         .collect();
 
-    let waiting_count = state
-        .sessions
-        .iter()
-        .filter(|s| {
-            matches!(s.claude_activity, ClaudeActivityState::WaitingForUser)
-                || (matches!(s.claude_activity, ClaudeActivityState::Unknown) && s.needs_attention)
-        })
-        .count();
+    let waiting_count = sessions.iter().filter(|s| s.waiting_for_user()).count();
+    let count = if state.search_filter.is_empty() {
+        state.sessions.len().to_string()
+    } else {
+        format!("{}/{}", sessions.len(), state.sessions.len())
+    };
     let title = if waiting_count > 0 {
-        format!(
-            " Zellij Sessions ({}) - {} waiting ",
-            state.sessions.len(),
-            waiting_count
-        )
+        format!(" Zellij Sessions ({}) - {} waiting ", count, waiting_count)
+    } else {
+        format!(" Zellij Sessions ({}) ", count)
+    };
+    let title = if state.loading {
+        format!("{}{} refreshing… ", title, spinner_char)
     } else {
-        format!(" Zellij Sessions ({}) ", state.sessions.len())
+        title
     };
 
     let list = List::new(items).block(
@@ -6,12 +6,31 @@ use ratatui::{
     widgets::{Block, Borders, Paragraph, Wrap},
 };
 
-use crate::state::Task;
+use super::markdown::render_markdown;
+use crate::state::{ActivityEntry, AttemptStatus, Task, TaskDetailState};
 
-pub fn render_task_detail(frame: &mut Frame, area: Rect, task: &Task, plan: Option<&str>) {
+/// Most recent activity entries shown; older ones are cut off at the top
+const MAX_ACTIVITY_ROWS: usize = 8;
+
+/// `detail` carries the description scroll position and the task's activity;
+/// without it the description is shown from the top and no activity is listed
+pub fn render_task_detail(
+    frame: &mut Frame,
+    area: Rect,
+    task: &Task,
+    plan: Option<&str>,
+    detail: Option<&mut TaskDetailState>,
+) {
     let has_linear = task.linear_url.is_some() || task.linear_issue_id.is_some();
     let has_pr = task.pr_url.is_some();
     let has_plan = plan.is_some();
+    let activity: Vec<ActivityEntry> = detail
+        .as_deref()
+        .map(|detail| {
+            let skip = detail.activity.len().saturating_sub(MAX_ACTIVITY_ROWS);
+            detail.activity[skip..].to_vec()
+        })
+        .unwrap_or_default();
 
     let mut constraints = vec![Constraint::Length(3)]; // Title with status
     if has_linear {
@@ -24,6 +43,9 @@ pub fn render_task_detail(frame: &mut Frame, area: Rect, task: &Task, plan: Opti
         // Plan section takes up to 50% of remaining space
         constraints.push(Constraint::Percentage(50));
     }
+    if !activity.is_empty() {
+        constraints.push(Constraint::Length(activity.len() as u16 + 2)); // Activity
+    }
     constraints.push(Constraint::Min(0)); // Description
 
     let chunks = Layout::default()
@@ -56,12 +78,16 @@ pub fn render_task_detail(frame: &mut Frame, area: Rect, task: &Task, plan: Opti
     ];
 
     // Add attempt status inline
-    if task.has_in_progress_attempt {
-        title_spans.push(Span::raw(" "));
-        title_spans.push(Span::styled("Running", Style::default().fg(Color::Yellow)));
-    } else if task.last_attempt_failed {
-        title_spans.push(Span::raw(" "));
-        title_spans.push(Span::styled("Failed", Style::default().fg(Color::Red)));
+    match task.current_attempt_status() {
+        Some(AttemptStatus::Running) => {
+            title_spans.push(Span::raw(" "));
+            title_spans.push(Span::styled("Running", Style::default().fg(Color::Yellow)));
+        }
+        Some(AttemptStatus::Failed) => {
+            title_spans.push(Span::raw(" "));
+            title_spans.push(Span::styled("Failed", Style::default().fg(Color::Red)));
+        }
+        None => {}
     }
 
     let title = Paragraph::new(Line::from(title_spans)).block(
@@ -123,19 +149,69 @@ pub fn render_task_detail(frame: &mut Frame, area: Rect, task: &Task, plan: Opti
         chunk_idx += 1;
     }
 
-    // Description
-    let description_text = task.description.as_deref().unwrap_or("No description");
+    // Activity, oldest first
+    if !activity.is_empty() {
+        let lines: Vec<Line> = activity
+            .into_iter()
+            .map(|entry| {
+                Line::from(vec![
+                    Span::styled(
+                        format!("{:<16}  ", entry.when),
+                        Style::default().fg(Color::DarkGray),
+                    ),
+                    Span::raw(entry.summary),
+                ])
+            })
+            .collect();
+        let activity_widget = Paragraph::new(lines).block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title(" Activity ")
+                .border_style(Style::default().fg(Color::DarkGray)),
+        );
+        frame.render_widget(activity_widget, chunks[chunk_idx]);
+        chunk_idx += 1;
+    }
+
+    // Description, rendered as markdown and pre-wrapped so the scroll offset
+    // counts screen lines
+    let description_area = chunks[chunk_idx];
+    let inner_width = description_area.width.saturating_sub(2) as usize;
+    let inner_height = description_area.height.saturating_sub(2) as usize;
+    let lines = match task.description.as_deref().filter(|d| !d.trim().is_empty()) {
+        Some(description) => render_markdown(description, inner_width),
+        None => vec![Line::styled(
+            "No description",
+            Style::default().fg(Color::DarkGray),
+        )],
+    };
+    let line_count = lines.len();
+
+    let mut title = " Description ".to_string();
+    let mut scroll_offset = 0;
+    if let Some(detail) = detail {
+        detail.set_content_height(line_count, inner_height);
+        scroll_offset = detail.scroll_offset;
+        if detail.can_scroll() {
+            title = format!(
+                " Description ({}-{}/{}) ",
+                scroll_offset + 1,
+                (scroll_offset + inner_height).min(line_count),
+                line_count
+            );
+        }
+    }
 
-    let description = Paragraph::new(description_text)
-        .wrap(Wrap { trim: false })
+    let description = Paragraph::new(lines)
+        .scroll((scroll_offset as u16, 0))
         .block(
             Block::default()
                 .borders(Borders::ALL)
-                .title(" Description ")
+                .title(title)
                 .border_style(Style::default().fg(Color::DarkGray)),
         );
 
-    frame.render_widget(description, chunks[chunk_idx]);
+    frame.render_widget(description, description_area);
 }
 
 pub fn render_task_detail_with_actions(
@@ -143,6 +219,7 @@ pub fn render_task_detail_with_actions(
     area: Rect,
     task: &Task,
     plan: Option<&str>,
+    detail: &mut TaskDetailState,
 ) {
     let chunks = Layout::default()
         .direction(Direction::Vertical)
@@ -152,7 +229,7 @@ pub fn render_task_detail_with_actions(
         ])
         .split(area);
 
-    render_task_detail(frame, chunks[0], task, plan);
+    render_task_detail(frame, chunks[0], task, plan, Some(detail));
 
     // Actions bar
     let actions = Paragraph::new(Line::from(vec![
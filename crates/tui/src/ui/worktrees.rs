@@ -6,9 +6,16 @@ use ratatui::{
     Frame,
 };
 
+use super::render_loading;
 use crate::state::WorktreesState;
 
-pub fn render_worktrees(frame: &mut Frame, area: Rect, state: &WorktreesState) {
+pub fn render_worktrees(
+    frame: &mut Frame,
+    area: Rect,
+    state: &WorktreesState,
+    spinner_char: char,
+    spinner_color: Color,
+) {
     if let Some(error) = &state.error {
         let error_msg = Paragraph::new(format!("Error: {}", error))
             .style(Style::default().fg(Color::Red))
@@ -22,14 +29,16 @@ pub fn render_worktrees(frame: &mut Frame, area: Rect, state: &WorktreesState) {
         return;
     }
 
-    if state.loading {
-        let loading = Paragraph::new("Loading worktrees...").block(
-            Block::default()
-                .borders(Borders::ALL)
-                .title(" Worktrees ")
-                .border_style(Style::default().fg(Color::DarkGray)),
+    // A reload keeps showing the previous list; only the first load has nothing to show
+    if state.loading && state.worktrees.is_empty() {
+        render_loading(
+            frame,
+            area,
+            " Worktrees ",
+            "Loading worktrees",
+            spinner_char,
+            spinner_color,
         );
-        frame.render_widget(loading, area);
         return;
     }
 
@@ -113,10 +122,20 @@ pub fn render_worktrees(frame: &mut Frame, area: Rect, state: &WorktreesState) {
         })
         .collect();
 
+    let title = if state.loading {
+        format!(
+            " Worktrees ({}) {} refreshing… ",
+            state.worktrees.len(),
+            spinner_char
+        )
+    } else {
+        format!(" Worktrees ({}) ", state.worktrees.len())
+    };
+
     let list = List::new(items).block(
         Block::default()
             .borders(Borders::ALL)
-            .title(format!(" Worktrees ({}) ", state.worktrees.len()))
+            .title(title)
             .border_style(Style::default().fg(Color::Cyan)),
     );
 
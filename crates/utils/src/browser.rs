@@ -1,16 +1,217 @@
+use std::{fmt, io, process::Stdio, time::Duration};
+
+use thiserror::Error;
+
 use crate::is_wsl2;
 
-/// Open URL in browser with WSL2 support
-pub async fn open_browser(url: &str) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
-    if is_wsl2() {
-        // In WSL2, use PowerShell to open the browser
-        tokio::process::Command::new("powershell.exe")
-            .arg("-Command")
-            .arg(format!("Start-Process '{url}'"))
-            .spawn()?;
-        Ok(())
+/// How long an opener gets to fail. Some `xdg-open` handlers only return once the
+/// browser exits, so one still running after this is taken as a success.
+const OPENER_GRACE_PERIOD: Duration = Duration::from_secs(5);
+
+/// Why one way of opening the browser didn't work
+#[derive(Debug)]
+pub struct OpenAttempt {
+    pub opener: String,
+    pub error: String,
+}
+
+impl fmt::Display for OpenAttempt {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: {}", self.opener, self.error)
+    }
+}
+
+#[derive(Debug, Error)]
+#[error("could not open {url} in a browser ({})", display_attempts(.attempts))]
+pub struct OpenBrowserError {
+    pub url: String,
+    pub attempts: Vec<OpenAttempt>,
+}
+
+fn display_attempts(attempts: &[OpenAttempt]) -> String {
+    if attempts.is_empty() {
+        return "no opener available on this platform".to_string();
+    }
+    attempts
+        .iter()
+        .map(ToString::to_string)
+        .collect::<Vec<_>>()
+        .join("; ")
+}
+
+/// A command that opens a URL, with the arguments that go before it
+#[derive(Debug)]
+struct Opener {
+    program: String,
+    args: Vec<String>,
+}
+
+impl Opener {
+    fn new(program: &str, args: &[&str]) -> Self {
+        Self {
+            program: program.to_string(),
+            args: args.iter().map(|arg| arg.to_string()).collect(),
+        }
+    }
+
+    fn label(&self) -> String {
+        std::iter::once(self.program.as_str())
+            .chain(self.args.iter().map(String::as_str))
+            .collect::<Vec<_>>()
+            .join(" ")
+    }
+}
+
+/// Openers to try in order, given what the environment offers
+fn openers(wsl: bool, browser_env: Option<&str>, has_display: bool) -> Vec<Opener> {
+    let mut openers = Vec::new();
+    // $BROWSER is a colon-separated list of commands, honoured like xdg-open does
+    if let Some(browsers) = browser_env {
+        openers.extend(
+            browsers
+                .split(':')
+                .map(str::trim)
+                .filter(|b| !b.is_empty())
+                .map(|b| Opener::new(b, &[])),
+        );
+    }
+
+    if wsl {
+        openers.push(Opener::new("wslview", &[]));
+        openers.push(Opener::new("explorer.exe", &[]));
+        openers.push(Opener::new("powershell.exe", &["-NoProfile", "-Command"]));
+    } else if cfg!(target_os = "macos") {
+        openers.push(Opener::new("open", &[]));
+    } else if cfg!(unix) && has_display {
+        openers.push(Opener::new("xdg-open", &[]));
+        openers.push(Opener::new("gio", &["open"]));
+    }
+    openers
+}
+
+async fn try_opener(opener: &Opener, url: &str) -> Result<(), String> {
+    let url_arg = if opener.program == "powershell.exe" {
+        // Quoted so PowerShell doesn't treat `&` or `;` in the URL as syntax
+        format!("Start-Process '{}'", url.replace('\'', "''"))
+    } else {
+        url.to_string()
+    };
+    let mut child = tokio::process::Command::new(&opener.program)
+        .args(&opener.args)
+        .arg(url_arg)
+        .stdin(Stdio::null())
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .spawn()
+        .map_err(|e| match e.kind() {
+            io::ErrorKind::NotFound => "not installed".to_string(),
+            _ => e.to_string(),
+        })?;
+
+    match tokio::time::timeout(OPENER_GRACE_PERIOD, child.wait()).await {
+        Ok(Ok(status)) if status.success() => Ok(()),
+        // explorer.exe exits with 1 even when it opened the URL
+        Ok(Ok(_)) if opener.program == "explorer.exe" => Ok(()),
+        Ok(Ok(status)) => Err(format!("exited with {status}")),
+        Ok(Err(e)) => Err(e.to_string()),
+        Err(_) => Ok(()),
+    }
+}
+
+/// Open URL in the user's browser, trying each opener available on this platform
+/// (`$BROWSER`, then `wslview`/`explorer.exe`/`powershell.exe` under WSL, `open` on
+/// macOS, `xdg-open`/`gio` on Linux with a graphical session). The error lists
+/// every opener tried and why it failed.
+pub async fn open_browser(url: &str) -> Result<(), OpenBrowserError> {
+    let has_display =
+        std::env::var_os("DISPLAY").is_some() || std::env::var_os("WAYLAND_DISPLAY").is_some();
+    let browser_env = std::env::var("BROWSER").ok();
+    let wsl = is_wsl2();
+    let openers = openers(wsl, browser_env.as_deref(), has_display);
+
+    let mut attempts = Vec::new();
+    for opener in &openers {
+        match try_opener(opener, url).await {
+            Ok(()) => {
+                tracing::debug!("Opened {} with {}", url, opener.label());
+                return Ok(());
+            }
+            Err(error) => attempts.push(OpenAttempt {
+                opener: opener.label(),
+                error,
+            }),
+        }
+    }
+
+    if cfg!(unix) {
+        if !has_display && !wsl && !cfg!(target_os = "macos") {
+            attempts.push(OpenAttempt {
+                opener: "xdg-open".to_string(),
+                error: "skipped, neither DISPLAY nor WAYLAND_DISPLAY is set".to_string(),
+            });
+        }
+    } else if let Err(e) = open::that(url) {
+        // Windows and anything else without a known opener command
+        attempts.push(OpenAttempt {
+            opener: "system default".to_string(),
+            error: e.to_string(),
+        });
     } else {
-        // Use the standard open crate for other platforms
-        open::that(url).map_err(|e| e.into())
+        return Ok(());
+    }
+
+    Err(OpenBrowserError {
+        url: url.to_string(),
+        attempts,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn programs(openers: &[Opener]) -> Vec<&str> {
+        openers.iter().map(|o| o.program.as_str()).collect()
+    }
+
+    #[test]
+    fn test_openers_under_wsl() {
+        assert_eq!(
+            programs(&openers(true, None, false)),
+            ["wslview", "explorer.exe", "powershell.exe"]
+        );
+        assert_eq!(
+            programs(&openers(true, Some("firefox:"), false))[0],
+            "firefox"
+        );
+    }
+
+    #[cfg(target_os = "linux")]
+    #[test]
+    fn test_openers_need_a_display_on_linux() {
+        assert_eq!(programs(&openers(false, None, true)), ["xdg-open", "gio"]);
+        assert!(openers(false, None, false).is_empty());
+    }
+
+    #[test]
+    fn test_error_lists_attempts() {
+        let error = OpenBrowserError {
+            url: "http://127.0.0.1:3000".to_string(),
+            attempts: vec![
+                OpenAttempt {
+                    opener: "wslview".to_string(),
+                    error: "not installed".to_string(),
+                },
+                OpenAttempt {
+                    opener: "explorer.exe".to_string(),
+                    error: "exited with exit status: 2".to_string(),
+                },
+            ],
+        };
+        assert_eq!(
+            error.to_string(),
+            "could not open http://127.0.0.1:3000 in a browser \
+             (wslview: not installed; explorer.exe: exited with exit status: 2)"
+        );
     }
 }
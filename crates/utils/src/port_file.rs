@@ -2,25 +2,143 @@ use std::{env, path::PathBuf};
 
 use tokio::fs;
 
+/// Contents of the port file. The PID lets readers detect a file left behind by a
+/// server that crashed without cleaning up.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PortFileInfo {
+    pub port: u16,
+    /// Missing in port files written by older versions
+    pub pid: Option<u32>,
+}
+
+fn port_file_path(app_name: &str) -> PathBuf {
+    env::temp_dir()
+        .join(app_name)
+        .join(format!("{app_name}.port"))
+}
+
 pub async fn write_port_file(port: u16) -> std::io::Result<PathBuf> {
-    let dir = env::temp_dir().join("vibe");
-    let path = dir.join("vibe.port");
-    tracing::debug!("Writing port {} to {:?}", port, path);
-    fs::create_dir_all(&dir).await?;
-    fs::write(&path, port.to_string()).await?;
+    let path = port_file_path("vibe");
+    let pid = std::process::id();
+    tracing::debug!("Writing port {} (pid {}) to {:?}", port, pid, path);
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir).await?;
+    }
+    fs::write(&path, format!("{port}\n{pid}\n")).await?;
     Ok(path)
 }
 
+/// Remove the port file on shutdown, unless another server instance has since
+/// overwritten it with its own PID.
+pub async fn remove_port_file() -> std::io::Result<()> {
+    let path = port_file_path("vibe");
+    let content = match fs::read_to_string(&path).await {
+        Ok(content) => content,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(()),
+        Err(e) => return Err(e),
+    };
+
+    let info = parse_port_file(&content)?;
+    if info.pid.is_some_and(|pid| pid != std::process::id()) {
+        tracing::debug!("Port file {:?} belongs to another server, leaving it", path);
+        return Ok(());
+    }
+
+    tracing::debug!("Removing port file {:?}", path);
+    fs::remove_file(&path).await
+}
+
 pub async fn read_port_file(app_name: &str) -> std::io::Result<u16> {
-    let dir = env::temp_dir().join(app_name);
-    let path = dir.join(format!("{app_name}.port"));
+    let path = port_file_path(app_name);
     tracing::debug!("Reading port from {:?}", path);
 
     let content = fs::read_to_string(&path).await?;
-    let port: u16 = content
-        .trim()
-        .parse()
-        .map_err(|e| std::io::Error::new(std::io::ErrorKind::InvalidData, e))?;
+    let info = parse_port_file(&content)?;
+
+    if let Some(pid) = info.pid
+        && !process_is_alive(pid)
+    {
+        return Err(std::io::Error::new(
+            std::io::ErrorKind::NotFound,
+            format!(
+                "stale port file {path:?}: server process {pid} (port {}) is not running",
+                info.port
+            ),
+        ));
+    }
+
+    Ok(info.port)
+}
+
+/// Parse `<port>\n<pid>`; the PID line is optional for older files.
+pub fn parse_port_file(content: &str) -> std::io::Result<PortFileInfo> {
+    let invalid =
+        |e: std::num::ParseIntError| std::io::Error::new(std::io::ErrorKind::InvalidData, e);
+
+    let mut lines = content.lines().map(str::trim).filter(|l| !l.is_empty());
+    let port = lines.next().unwrap_or_default().parse().map_err(invalid)?;
+    let pid = lines.next().map(str::parse).transpose().map_err(invalid)?;
+
+    Ok(PortFileInfo { port, pid })
+}
+
+#[cfg(unix)]
+fn process_is_alive(pid: u32) -> bool {
+    use nix::{errno::Errno, sys::signal::kill, unistd::Pid};
+
+    // Signal 0 only checks whether the process exists; EPERM means it exists
+    // but belongs to someone else
+    match kill(Pid::from_raw(pid as i32), None) {
+        Ok(()) => true,
+        Err(Errno::EPERM) => true,
+        Err(_) => false,
+    }
+}
+
+#[cfg(not(unix))]
+fn process_is_alive(_pid: u32) -> bool {
+    // No cheap liveness check; trust the file
+    true
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_port_file_with_pid() {
+        let info = parse_port_file("3000\n4242\n").unwrap();
+        assert_eq!(
+            info,
+            PortFileInfo {
+                port: 3000,
+                pid: Some(4242)
+            }
+        );
+    }
+
+    #[test]
+    fn test_parse_port_file_legacy() {
+        let info = parse_port_file("3000").unwrap();
+        assert_eq!(
+            info,
+            PortFileInfo {
+                port: 3000,
+                pid: None
+            }
+        );
+    }
+
+    #[test]
+    fn test_parse_port_file_invalid() {
+        assert!(parse_port_file("").is_err());
+        assert!(parse_port_file("not-a-port").is_err());
+        assert!(parse_port_file("3000\nnot-a-pid").is_err());
+    }
 
-    Ok(port)
+    #[cfg(unix)]
+    #[test]
+    fn test_process_is_alive() {
+        assert!(process_is_alive(std::process::id()));
+    }
 }
@@ -115,6 +115,8 @@ const ImportFromClaudeDialogImpl =
 							taskTitle:
 								session.firstUserMessage ?? session.slug ?? session.summary,
 							defaultStatus: "todo",
+							fromUuid: null,
+							toUuid: null,
 						});
 						modal.resolve(result);
 						modal.hide();
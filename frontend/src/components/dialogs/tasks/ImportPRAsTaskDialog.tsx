@@ -8,6 +8,8 @@ import {
 } from "@/components/ui/dialog";
 import { Button } from "@/components/ui/button";
 import { Input } from "@/components/ui/input";
+import { Checkbox } from "@/components/ui/checkbox";
+import { Label } from "@radix-ui/react-label";
 import { Alert } from "@/components/ui/alert";
 import { useCallback, useEffect, useMemo, useRef, useState } from "react";
 import { repoApi, tasksApi } from "@/lib/api";
@@ -34,7 +36,8 @@ import {
 	SelectTrigger,
 	SelectValue,
 } from "@/components/ui/select";
-import { useProjectRepos } from "@/hooks";
+import { useProjectRepos, useRepoBranches } from "@/hooks";
+import BranchSelector from "@/components/tasks/BranchSelector";
 
 interface ImportPRAsTaskDialogProps {
 	projectId: string;
@@ -82,6 +85,11 @@ const ImportPRAsTaskDialogImpl = NiceModal.create<ImportPRAsTaskDialogProps>(
 		const [error, setError] = useState<string | null>(null);
 		const [selectedPr, setSelectedPr] = useState<PrListItem | null>(null);
 		const [isPlanMode, setIsPlanMode] = useState(false);
+		const [targetBranch, setTargetBranch] = useState<string | null>(null);
+		const [includeReviewComments, setIncludeReviewComments] = useState(false);
+		const { data: branches = [] } = useRepoBranches(selectedRepoId, {
+			enabled: modal.visible,
+		});
 		const debounceTimerRef = useRef<ReturnType<typeof setTimeout> | null>(null);
 
 		// Set default repo when data loads
@@ -137,6 +145,8 @@ const ImportPRAsTaskDialogImpl = NiceModal.create<ImportPRAsTaskDialogProps>(
 				setSearchQuery("");
 				setDebouncedQuery("");
 				setSelectedPr(null);
+				setTargetBranch(null);
+				setIncludeReviewComments(false);
 				setError(null);
 			}
 		}, [modal.visible]);
@@ -158,6 +168,8 @@ const ImportPRAsTaskDialogImpl = NiceModal.create<ImportPRAsTaskDialogProps>(
 					repoId: selectedRepoId,
 					prNumber: selectedPr.number,
 					executorProfileId,
+					targetBranch,
+					includeReviewComments,
 				});
 
 				if (result.success) {
@@ -198,7 +210,17 @@ const ImportPRAsTaskDialogImpl = NiceModal.create<ImportPRAsTaskDialogProps>(
 			} finally {
 				setImporting(false);
 			}
-		}, [projectId, selectedRepoId, selectedPr, isPlanMode, modal, navigate, t]);
+		}, [
+			projectId,
+			selectedRepoId,
+			selectedPr,
+			isPlanMode,
+			targetBranch,
+			includeReviewComments,
+			modal,
+			navigate,
+			t,
+		]);
 
 		const handleCancel = useCallback(() => {
 			modal.reject("canceled");
@@ -207,6 +229,8 @@ const ImportPRAsTaskDialogImpl = NiceModal.create<ImportPRAsTaskDialogProps>(
 
 		const handleSelectPr = useCallback((pr: PrListItem) => {
 			setSelectedPr(pr);
+			// Default to the branch the PR merges into rather than assuming "main"
+			setTargetBranch(pr.baseRefName);
 			setError(null);
 		}, []);
 
@@ -329,6 +353,38 @@ const ImportPRAsTaskDialogImpl = NiceModal.create<ImportPRAsTaskDialogProps>(
 							)}
 						</div>
 
+						{selectedPr && (
+							<div className="flex items-center gap-2">
+								<span className="text-sm text-muted-foreground min-w-[80px]">
+									{t("importPrDialog.targetBranch")}
+								</span>
+								<BranchSelector
+									branches={branches}
+									selectedBranch={targetBranch}
+									onBranchSelect={setTargetBranch}
+									placeholder={selectedPr.baseRefName}
+									className="flex-1"
+									excludeCurrentBranch={false}
+								/>
+							</div>
+						)}
+
+						{selectedPr && (
+							<div className="flex items-center space-x-2">
+								<Checkbox
+									id="import-pr-review-comments"
+									checked={includeReviewComments}
+									onCheckedChange={setIncludeReviewComments}
+								/>
+								<Label
+									htmlFor="import-pr-review-comments"
+									className="cursor-pointer text-sm"
+								>
+									{t("importPrDialog.includeReviewComments")}
+								</Label>
+							</div>
+						)}
+
 						{selectedPr && selectedPr.body && (
 							<div className="rounded-md border p-3 bg-muted/30">
 								<div className="text-xs text-muted-foreground mb-1">
@@ -37,6 +37,7 @@ import {
 	useProjectRepos,
 	useRepoBranchSelection,
 } from "@/hooks";
+import { useProjects } from "@/hooks/useProjects";
 import {
 	useKeySubmitTask,
 	useKeySubmitTaskAlt,
@@ -132,6 +133,9 @@ const TaskFormDialogImpl = NiceModal.create<TaskFormDialogProps>((props) => {
 	const { data: projectRepos = [] } = useProjectRepos(activeProjectId, {
 		enabled: modal.visible && !!activeProjectId,
 	});
+	const { projectsById } = useProjects();
+	const allowRepoless =
+		projectsById[activeProjectId]?.allow_repoless_tasks ?? false;
 	const initialBranch =
 		mode === "subtask" ? props.initialBaseBranch : undefined;
 	const { configs: repoBranchConfigs, isLoading: branchesLoading } =
@@ -200,6 +204,7 @@ const TaskFormDialogImpl = NiceModal.create<TaskFormDialogProps>((props) => {
 						parent_workspace_id: null,
 						image_ids: images.length > 0 ? images.map((img) => img.id) : null,
 						sync_to_linear: false,
+						due_date: null,
 					},
 				},
 				{ onSuccess: () => modal.remove() },
@@ -246,8 +251,9 @@ const TaskFormDialogImpl = NiceModal.create<TaskFormDialogProps>((props) => {
 	const validator = (value: TaskFormValues): string | undefined => {
 		if (!value.title.trim().length) return "need title";
 		if (value.autoStart && !forceCreateOnlyRef.current) {
+			// Projects that allow repo-less tasks start them in a scratch directory
 			if (
-				value.repoBranches.length === 0 ||
+				(value.repoBranches.length === 0 && !allowRepoless) ||
 				value.repoBranches.some((rb) => !rb.branch)
 			) {
 				return "need branch for all repos";
@@ -119,6 +119,7 @@ const TaskPanel = ({ task, onAttemptClick }: TaskPanelProps) => {
 						parent_workspace_id: task.parent_workspace_id || null,
 						image_ids: null,
 						sync_to_linear: false,
+						due_date: null,
 					},
 				});
 			}, 500);
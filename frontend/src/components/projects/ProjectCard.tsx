@@ -18,6 +18,7 @@ import {
 	FolderOpen,
 	Link2,
 	MoreHorizontal,
+	Star,
 	Trash2,
 	Unlink,
 } from "lucide-react";
@@ -98,6 +99,15 @@ function ProjectCard({ project, isFocused, setError, onEdit }: Props) {
 		}
 	};
 
+	const handleToggleFavorite = async () => {
+		try {
+			await projectsApi.toggleFavorite(project.id);
+		} catch (error) {
+			console.error("Failed to update favorite:", error);
+			setError("Failed to update favorite");
+		}
+	};
+
 	const handleUnlinkProject = () => {
 		const confirmed = window.confirm(
 			`Are you sure you want to unlink "${project.name}"? The local project will remain, but it will no longer be linked to the remote project.`,
@@ -116,7 +126,10 @@ function ProjectCard({ project, isFocused, setError, onEdit }: Props) {
 		>
 			<CardHeader>
 				<div className="flex items-start justify-between">
-					<CardTitle className="text-lg">
+					<CardTitle className="text-lg flex items-center gap-2">
+						{project.favorite && (
+							<Star className="h-4 w-4 fill-yellow-400 text-yellow-400" />
+						)}
 						{privacyMode ? maskText(project.name) : project.name}
 					</CardTitle>
 					<div className="flex items-center gap-2">
@@ -136,6 +149,15 @@ function ProjectCard({ project, isFocused, setError, onEdit }: Props) {
 									<ExternalLink className="mr-2 h-4 w-4" />
 									{t("viewProject")}
 								</DropdownMenuItem>
+								<DropdownMenuItem
+									onClick={(e) => {
+										e.stopPropagation();
+										handleToggleFavorite();
+									}}
+								>
+									<Star className="mr-2 h-4 w-4" />
+									{project.favorite ? t("unfavorite") : t("favorite")}
+								</DropdownMenuItem>
 								{isSingleRepoProject && (
 									<DropdownMenuItem
 										onClick={(e) => {
@@ -40,6 +40,15 @@ function parseLinearLabels(labelsJson: string | null): LinearLabel[] {
 	}
 }
 
+function parseTags(tagsJson: string | null): string[] {
+	if (!tagsJson) return [];
+	try {
+		return JSON.parse(tagsJson) as string[];
+	} catch {
+		return [];
+	}
+}
+
 function extractLinearIssueId(linearUrl: string | null): string | null {
 	if (!linearUrl) return null;
 	// URL format: https://linear.app/team-name/issue/AMB-738/issue-title
@@ -210,6 +219,8 @@ export function TaskCard({
 		[task.linear_labels],
 	);
 
+	const tags = useMemo(() => parseTags(task.tags), [task.tags]);
+
 	useEffect(() => {
 		if (!isOpen || !localRef.current) return;
 		const el = localRef.current;
@@ -393,6 +404,18 @@ export function TaskCard({
 						))}
 					</div>
 				)}
+				{tags.length > 0 && (
+					<div className="flex flex-wrap gap-1">
+						{tags.map((tag) => (
+							<span
+								key={tag}
+								className="text-[10px] font-medium px-1.5 py-0.5 rounded border text-muted-foreground"
+							>
+								#{tag}
+							</span>
+						))}
+					</div>
+				)}
 			</div>
 		</KanbanCard>
 	);
@@ -38,6 +38,7 @@ function ProcessesTab({ attemptId }: ProcessesTabProps) {
     Record<string, ExecutionProcess>
   >({});
   const [copied, setCopied] = useState(false);
+  const [commandCopied, setCommandCopied] = useState(false);
 
   const selectedProcess = selectedProcessId
     ? localProcessDetails[selectedProcessId] ||
@@ -64,6 +65,18 @@ function ProcessesTab({ attemptId }: ProcessesTabProps) {
     }
   }, [logs]);
 
+  const handleCopyCommand = useCallback(async () => {
+    if (!selectedProcess?.command) return;
+
+    try {
+      await navigator.clipboard.writeText(selectedProcess.command);
+      setCommandCopied(true);
+      setTimeout(() => setCommandCopied(false), 2000);
+    } catch (err) {
+      console.warn('Copy to clipboard failed:', err);
+    }
+  }, [selectedProcess?.command]);
+
   const getStatusIcon = (status: ExecutionProcessStatus) => {
     switch (status) {
       case 'running':
@@ -209,6 +222,16 @@ function ProcessesTab({ attemptId }: ProcessesTabProps) {
                         >
                           {t('processes.processId', { id: process.id })}
                         </p>
+                        {process.source_path && (
+                          <p
+                            className="text-sm text-muted-foreground mt-1 truncate"
+                            title={process.source_path}
+                          >
+                            {t('processes.importedFrom', {
+                              path: process.source_path,
+                            })}
+                          </p>
+                        )}
                         {process.dropped && (
                           <span
                             className="inline-block mt-1 text-[10px] px-1.5 py-0.5 rounded-full bg-amber-100 text-amber-700 border border-amber-200"
@@ -280,6 +303,21 @@ function ProcessesTab({ attemptId }: ProcessesTabProps) {
               {t('processes.detailsTitle')}
             </h2>
             <div className="flex items-center gap-2">
+              {selectedProcess?.command && (
+                <button
+                  onClick={handleCopyCommand}
+                  title={selectedProcess.command}
+                  className={`flex items-center gap-2 px-3 py-2 text-sm font-medium rounded-md border border-border transition-colors ${
+                    commandCopied
+                      ? 'text-success'
+                      : 'text-muted-foreground hover:text-foreground hover:bg-muted/50'
+                  }`}
+                >
+                  {commandCopied
+                    ? t('processes.logsCopied')
+                    : t('processes.copyCommand')}
+                </button>
+              )}
               <button
                 onClick={handleCopyLogs}
                 disabled={logs.length === 0}
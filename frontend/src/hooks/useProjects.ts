@@ -30,8 +30,9 @@ export function useProjects(): UseProjectsResult {
   const projects = useMemo(() => {
     return Object.values(projectsById).sort(
       (a, b) =>
+        Number(b.favorite) - Number(a.favorite) ||
         new Date(b.created_at as unknown as string).getTime() -
-        new Date(a.created_at as unknown as string).getTime()
+          new Date(a.created_at as unknown as string).getTime()
     );
   }, [projectsById]);
 
@@ -11,6 +11,7 @@ import {
 	CreateGitHubPrRequest,
 	CreateTask,
 	CreateAndStartTaskRequest,
+	CreateTasksBatchRequest,
 	CreateTaskAttemptBody,
 	DirectoryListResponse,
 	DirectoryEntry,
@@ -87,9 +88,13 @@ import {
 	MergeTaskAttemptRequest,
 	PushTaskAttemptRequest,
 	RepoBranchStatus,
+	WorkspaceMergeability,
+	RepoCommits,
 	AbortConflictsRequest,
 	Session,
 	SlashCommand,
+	SlashCommandSource,
+	SlashCommandDetail,
 	Workspace,
 	LinearIssueStateResponse,
 	ListRecentPrsResponse,
@@ -102,6 +107,8 @@ import {
 	ImportFromClaudeSessionResponse,
 	ImportWithHistoryRequest,
 	ImportWithHistoryResponse,
+	ImportAllSessionsRequest,
+	ImportAllSessionsResponse,
 	ListClaudeSessionsResponse,
 } from "shared/types";
 import type { WorkspaceWithSession } from "@/types/attempt";
@@ -418,6 +425,57 @@ export const projectsApi = {
 		}>(response);
 	},
 
+	importVibeTasks: async (
+		projectId: string,
+	): Promise<{
+		created_count: number;
+		updated_count: number;
+		skipped_count: number;
+	}> => {
+		const response = await makeRequest(
+			`/api/projects/${projectId}/vibe/import`,
+			{
+				method: "POST",
+			},
+		);
+		return handleApiResponse<{
+			created_count: number;
+			updated_count: number;
+			skipped_count: number;
+		}>(response);
+	},
+
+	warmSearchCache: async (
+		projectId: string,
+	): Promise<{ repo_count: number }> => {
+		const response = await makeRequest(
+			`/api/projects/${projectId}/search/warm`,
+			{ method: "POST" },
+		);
+		return handleApiResponse<{ repo_count: number }>(response);
+	},
+
+	toggleFavorite: async (projectId: string): Promise<Project> => {
+		const response = await makeRequest(`/api/projects/${projectId}/favorite`, {
+			method: "POST",
+		});
+		return handleApiResponse<Project>(response);
+	},
+
+	archiveCompleted: async (
+		projectId: string,
+		olderThanDays?: number,
+	): Promise<{ archived_count: number }> => {
+		const response = await makeRequest(
+			`/api/projects/${projectId}/archive-completed`,
+			{
+				method: "POST",
+				body: JSON.stringify({ older_than_days: olderThanDays ?? null }),
+			},
+		);
+		return handleApiResponse<{ archived_count: number }>(response);
+	},
+
 	validateLinearAssignee: async (
 		projectId: string,
 		assigneeId: string,
@@ -454,6 +512,14 @@ export const tasksApi = {
 		return handleApiResponse<Task>(response);
 	},
 
+	createBatch: async (data: CreateTasksBatchRequest): Promise<Task[]> => {
+		const response = await makeRequest(`/api/tasks/batch`, {
+			method: "POST",
+			body: JSON.stringify(data),
+		});
+		return handleApiResponse<Task[]>(response);
+	},
+
 	createAndStart: async (
 		data: CreateAndStartTaskRequest,
 	): Promise<TaskWithAttemptStatus> => {
@@ -600,6 +666,20 @@ export const tasksApi = {
 		);
 		return handleApiResponse<ImportWithHistoryResponse>(response);
 	},
+
+	importAllSessions: async (
+		projectId: string,
+		data: ImportAllSessionsRequest,
+	): Promise<ImportAllSessionsResponse> => {
+		const response = await makeRequest(
+			`/api/tasks/import-all-sessions?project_id=${encodeURIComponent(projectId)}`,
+			{
+				method: "POST",
+				body: JSON.stringify(data),
+			},
+		);
+		return handleApiResponse<ImportAllSessionsResponse>(response);
+	},
 };
 
 // Sessions API
@@ -717,6 +797,20 @@ export const attemptsApi = {
 		return handleApiResponse<RepoBranchStatus[]>(response);
 	},
 
+	getMergeability: async (
+		attemptId: string,
+	): Promise<WorkspaceMergeability> => {
+		const response = await makeRequest(
+			`/api/task-attempts/${attemptId}/mergeability`,
+		);
+		return handleApiResponse<WorkspaceMergeability>(response);
+	},
+
+	getCommits: async (attemptId: string): Promise<RepoCommits[]> => {
+		const response = await makeRequest(`/api/task-attempts/${attemptId}/commits`);
+		return handleApiResponse<RepoCommits[]>(response);
+	},
+
 	getRepos: async (attemptId: string): Promise<RepoWithTargetBranch[]> => {
 		const response = await makeRequest(`/api/task-attempts/${attemptId}/repos`);
 		return handleApiResponse<RepoWithTargetBranch[]>(response);
@@ -1423,12 +1517,29 @@ export const queueApi = {
 // Slash Commands API for discovering available Claude Code commands
 export const slashCommandsApi = {
 	/**
-	 * Get all available slash commands
+	 * Get available slash commands, optionally only one source or matching `q`
 	 */
-	list: async (): Promise<SlashCommand[]> => {
-		const response = await makeRequest("/api/slash-commands");
+	list: async (options?: {
+		source?: SlashCommandSource;
+		q?: string;
+	}): Promise<SlashCommand[]> => {
+		const params = new URLSearchParams();
+		if (options?.source) params.set("source", options.source);
+		if (options?.q) params.set("q", options.q);
+		const query = params.toString() ? `?${params.toString()}` : "";
+		const response = await makeRequest(`/api/slash-commands${query}`);
 		return handleApiResponse<SlashCommand[]>(response);
 	},
+
+	/**
+	 * Get the markdown a command injects, for previewing it before running it
+	 */
+	get: async (qualifiedName: string): Promise<SlashCommandDetail> => {
+		const response = await makeRequest(
+			`/api/slash-commands/${encodeURIComponent(qualifiedName)}`,
+		);
+		return handleApiResponse<SlashCommandDetail>(response);
+	},
 };
 
 // Commander API for managing project-scoped Claude Code chat sessions
@@ -417,6 +417,7 @@ export function AllProjectTasks() {
 					parent_workspace_id: task.parent_workspace_id,
 					image_ids: null,
 					sync_to_linear: syncToLinear,
+					due_date: null,
 				});
 			} catch (err) {
 				console.error("Failed to update task status:", err);
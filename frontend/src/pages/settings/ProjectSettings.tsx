@@ -40,6 +40,16 @@ interface ProjectFormState {
 	dev_script_working_dir: string;
 	default_agent_working_dir: string;
 	linear_api_key: string;
+	linear_auto_sync: boolean;
+	linear_state_map: string;
+	setup_profiles: string;
+	webhook_url: string;
+	webhook_secret: string;
+	allow_repoless_tasks: boolean;
+	auto_done_on_merge: boolean;
+	git_author_name: string;
+	git_author_email: string;
+	commit_message_template: string;
 }
 
 interface RepoScriptsFormState {
@@ -58,6 +68,17 @@ function projectToFormState(project: Project): ProjectFormState {
 		// Note: linear_api_key is not returned from the server for security,
 		// so we use empty string to indicate "unchanged" vs null for "cleared"
 		linear_api_key: "",
+		linear_auto_sync: project.linear_auto_sync,
+		linear_state_map: project.linear_state_map ?? "",
+		setup_profiles: project.setup_profiles ?? "",
+		webhook_url: project.webhook_url ?? "",
+		// Like linear_api_key, the secret is never returned; empty keeps the current one
+		webhook_secret: "",
+		allow_repoless_tasks: project.allow_repoless_tasks,
+		auto_done_on_merge: project.auto_done_on_merge,
+		git_author_name: project.git_author_name ?? "",
+		git_author_email: project.git_author_email ?? "",
+		commit_message_template: project.commit_message_template ?? "",
 	};
 }
 
@@ -405,6 +426,16 @@ export function ProjectSettings() {
 				// Only send linear_api_key if it was modified (not empty)
 				linear_api_key: draft.linear_api_key.trim() || null,
 				linear_assignee_id: null,
+				linear_auto_sync: draft.linear_auto_sync,
+				linear_state_map: draft.linear_state_map.trim() || null,
+				setup_profiles: draft.setup_profiles.trim() || null,
+				webhook_url: draft.webhook_url.trim() || null,
+				webhook_secret: draft.webhook_secret.trim() || null,
+				allow_repoless_tasks: draft.allow_repoless_tasks,
+				auto_done_on_merge: draft.auto_done_on_merge,
+				git_author_name: draft.git_author_name.trim() || null,
+				git_author_email: draft.git_author_email.trim() || null,
+				commit_message_template: draft.commit_message_template.trim() || null,
 			};
 
 			updateProject.mutate({
@@ -661,6 +692,57 @@ export function ProjectSettings() {
 								</p>
 							</div>
 
+							<div className="space-y-2">
+								<Label htmlFor="setup-profiles">Setup profiles (JSON)</Label>
+								<AutoExpandingTextarea
+									id="setup-profiles"
+									value={draft.setup_profiles}
+									onChange={(e) =>
+										updateDraft({ setup_profiles: e.target.value })
+									}
+									placeholder='{"node18": "nvm install 18 && nvm alias default 18"}'
+									maxRows={8}
+									className="w-full px-3 py-2 border border-input bg-background text-foreground rounded-md focus:outline-none focus:ring-2 focus:ring-ring font-mono"
+								/>
+								<p className="text-sm text-muted-foreground">
+									Named scripts a task can select to run before the coding
+									agent, e.g. to pin a toolchain version.
+								</p>
+							</div>
+
+							<div className="space-y-2">
+								<Label htmlFor="webhook-url">Status webhook URL</Label>
+								<Input
+									id="webhook-url"
+									value={draft.webhook_url}
+									onChange={(e) => updateDraft({ webhook_url: e.target.value })}
+									placeholder="https://hooks.example.com/vibe-kanban"
+									className="font-mono"
+								/>
+								<p className="text-sm text-muted-foreground">
+									Receives a JSON POST with the task id, old and new status and
+									PR URL whenever a task in this project changes status.
+								</p>
+							</div>
+
+							<div className="space-y-2">
+								<Label htmlFor="webhook-secret">Webhook signing secret</Label>
+								<Input
+									id="webhook-secret"
+									type="password"
+									value={draft.webhook_secret}
+									onChange={(e) =>
+										updateDraft({ webhook_secret: e.target.value })
+									}
+									placeholder="Leave empty to keep the current secret"
+									className="font-mono"
+								/>
+								<p className="text-sm text-muted-foreground">
+									When set, requests carry an X-Vibe-Signature header with the
+									sha256 HMAC of the body.
+								</p>
+							</div>
+
 							<div className="space-y-2">
 								<Label htmlFor="agent-working-dir">
 									{t("settings.projects.scripts.agentWorkingDir.label")}
@@ -681,6 +763,39 @@ export function ProjectSettings() {
 								</p>
 							</div>
 
+							<div className="flex items-center space-x-2">
+								<Checkbox
+									id="allow-repoless-tasks"
+									checked={draft.allow_repoless_tasks}
+									onCheckedChange={(checked) =>
+										updateDraft({ allow_repoless_tasks: checked === true })
+									}
+								/>
+								<Label
+									htmlFor="allow-repoless-tasks"
+									className="text-sm font-normal cursor-pointer"
+								>
+									Allow starting tasks without repositories in an empty scratch
+									directory
+								</Label>
+							</div>
+
+							<div className="flex items-center space-x-2">
+								<Checkbox
+									id="auto-done-on-merge"
+									checked={draft.auto_done_on_merge}
+									onCheckedChange={(checked) =>
+										updateDraft({ auto_done_on_merge: checked === true })
+									}
+								/>
+								<Label
+									htmlFor="auto-done-on-merge"
+									className="text-sm font-normal cursor-pointer"
+								>
+									Move tasks to Done when their pull request is merged
+								</Label>
+							</div>
+
 							{/* Save Button */}
 							<div className="flex items-center justify-between pt-4 border-t">
 								{hasUnsavedProjectChanges ? (
@@ -755,6 +870,95 @@ export function ProjectSettings() {
 									{t("settings.projects.linear.apiKey.helper")}
 								</p>
 							</div>
+							<div className="flex items-center space-x-2">
+								<Checkbox
+									id="linear-auto-sync"
+									checked={draft.linear_auto_sync}
+									onCheckedChange={(checked) =>
+										updateDraft({ linear_auto_sync: checked === true })
+									}
+								/>
+								<Label
+									htmlFor="linear-auto-sync"
+									className="text-sm font-normal cursor-pointer"
+								>
+									Automatically refresh status of linked Linear issues
+								</Label>
+							</div>
+							<div className="space-y-2">
+								<Label htmlFor="linear-state-map">State mapping (JSON)</Label>
+								<AutoExpandingTextarea
+									id="linear-state-map"
+									value={draft.linear_state_map}
+									onChange={(e) =>
+										updateDraft({ linear_state_map: e.target.value })
+									}
+									placeholder='{"In Review": "inreview", "Blocked": "todo"}'
+									maxRows={8}
+									className="w-full px-3 py-2 border border-input bg-background text-foreground rounded-md focus:outline-none focus:ring-2 focus:ring-ring font-mono"
+								/>
+								<p className="text-sm text-muted-foreground">
+									Map Linear state names or types to task statuses (todo,
+									inprogress, inreview, done, cancelled). Unmapped states use
+									the default mapping.
+								</p>
+							</div>
+						</CardContent>
+					</Card>
+
+					{/* Git Identity Section */}
+					<Card>
+						<CardHeader>
+							<CardTitle>Git Identity</CardTitle>
+							<CardDescription>
+								Author used for commits made by agents in this project. Leave
+								empty to use your global git config.
+							</CardDescription>
+						</CardHeader>
+						<CardContent className="space-y-4">
+							<div className="space-y-2">
+								<Label htmlFor="git-author-name">Author name</Label>
+								<Input
+									id="git-author-name"
+									value={draft.git_author_name}
+									onChange={(e) =>
+										updateDraft({ git_author_name: e.target.value })
+									}
+									placeholder="Jane Doe"
+								/>
+							</div>
+							<div className="space-y-2">
+								<Label htmlFor="git-author-email">Author email</Label>
+								<Input
+									id="git-author-email"
+									type="email"
+									value={draft.git_author_email}
+									onChange={(e) =>
+										updateDraft({ git_author_email: e.target.value })
+									}
+									placeholder="jane@example.com"
+								/>
+							</div>
+							<div className="space-y-2">
+								<Label htmlFor="commit-message-template">
+									Commit message template
+								</Label>
+								<Input
+									id="commit-message-template"
+									value={draft.commit_message_template}
+									onChange={(e) =>
+										updateDraft({ commit_message_template: e.target.value })
+									}
+									placeholder="[{short_task_id}] {message}"
+									className="font-mono"
+								/>
+								<p className="text-sm text-muted-foreground">
+									Applied to the subject of every agent commit by a commit-msg
+									hook. Placeholders: {"{task_title}"}, {"{task_id}"},{" "}
+									{"{short_task_id}"}, {"{branch}"} and {"{message}"} for the
+									agent's own subject (appended when left out).
+								</p>
+							</div>
 						</CardContent>
 					</Card>
 
@@ -12,11 +12,121 @@ export type SharedTask = { id: string, organization_id: string, project_id: stri
 
 export type UserData = { user_id: string, first_name: string | null, last_name: string | null, username: string | null, };
 
-export type Project = { id: string, name: string, dev_script: string | null, dev_script_working_dir: string | null, default_agent_working_dir: string | null, remote_project_id: string | null, created_at: Date, updated_at: Date, };
+export type Project = { id: string, name: string, dev_script: string | null, dev_script_working_dir: string | null, default_agent_working_dir: string | null, remote_project_id: string | null, 
+/**
+ * Git author name used for agent commits in this project (falls back to global git config)
+ */
+git_author_name: string | null, 
+/**
+ * Git author email used for agent commits in this project (falls back to global git config)
+ */
+git_author_email: string | null, 
+/**
+ * Subject line convention for agent commits. `{task_title}`, `{task_id}`,
+ * `{short_task_id}` and `{branch}` are filled in; `{message}` stands for the
+ * agent's own subject, which is appended when the placeholder is missing.
+ */
+commit_message_template: string | null, 
+/**
+ * Periodically refresh the status of Linear-linked tasks from Linear
+ */
+linear_auto_sync: boolean, 
+/**
+ * JSON object mapping Linear state names or types to task statuses,
+ * e.g. `{"In Review": "inreview"}`. Consulted before the built-in mapping.
+ */
+linear_state_map: string | null, 
+/**
+ * JSON object of named setup profiles (name -> script), e.g. `{"node18": "nvm install 18"}`.
+ * A task's selected profile runs before the coding agent.
+ */
+setup_profiles: string | null, 
+/**
+ * URL that receives a POST whenever a task in this project changes status
+ */
+webhook_url: string | null, 
+/**
+ * Start tasks without repositories in an empty scratch directory instead of rejecting them
+ */
+allow_repoless_tasks: boolean, 
+/**
+ * Move tasks to Done when their PR merges; when off, merged tasks keep their status
+ */
+auto_done_on_merge: boolean, 
+/**
+ * Starred projects are listed first
+ */
+favorite: boolean, created_at: Date, updated_at: Date, };
+
+export type ProjectWithHealth = { 
+/**
+ * False when a repository path no longer exists or is no longer a git repository
+ */
+valid: boolean, id: string, name: string, dev_script: string | null, dev_script_working_dir: string | null, default_agent_working_dir: string | null, remote_project_id: string | null, 
+/**
+ * Git author name used for agent commits in this project (falls back to global git config)
+ */
+git_author_name: string | null, 
+/**
+ * Git author email used for agent commits in this project (falls back to global git config)
+ */
+git_author_email: string | null, 
+/**
+ * Subject line convention for agent commits. `{task_title}`, `{task_id}`,
+ * `{short_task_id}` and `{branch}` are filled in; `{message}` stands for the
+ * agent's own subject, which is appended when the placeholder is missing.
+ */
+commit_message_template: string | null, 
+/**
+ * Periodically refresh the status of Linear-linked tasks from Linear
+ */
+linear_auto_sync: boolean, 
+/**
+ * JSON object mapping Linear state names or types to task statuses,
+ * e.g. `{"In Review": "inreview"}`. Consulted before the built-in mapping.
+ */
+linear_state_map: string | null, 
+/**
+ * JSON object of named setup profiles (name -> script), e.g. `{"node18": "nvm install 18"}`.
+ * A task's selected profile runs before the coding agent.
+ */
+setup_profiles: string | null, 
+/**
+ * URL that receives a POST whenever a task in this project changes status
+ */
+webhook_url: string | null, 
+/**
+ * Start tasks without repositories in an empty scratch directory instead of rejecting them
+ */
+allow_repoless_tasks: boolean, 
+/**
+ * Move tasks to Done when their PR merges; when off, merged tasks keep their status
+ */
+auto_done_on_merge: boolean, 
+/**
+ * Starred projects are listed first
+ */
+favorite: boolean, created_at: Date, updated_at: Date, };
 
 export type CreateProject = { name: string, repositories: Array<CreateProjectRepo>, };
 
-export type UpdateProject = { name: string | null, dev_script: string | null, dev_script_working_dir: string | null, default_agent_working_dir: string | null, linear_api_key: string | null, linear_assignee_id: string | null, };
+export type UpdateProject = { name: string | null, dev_script: string | null, dev_script_working_dir: string | null, default_agent_working_dir: string | null, linear_api_key: string | null, linear_assignee_id: string | null, git_author_name: string | null, git_author_email: string | null, commit_message_template: string | null, 
+/**
+ * None keeps the current setting
+ */
+linear_auto_sync: boolean | null, linear_state_map: string | null, setup_profiles: string | null, webhook_url: string | null, 
+/**
+ * Key for the `X-Vibe-Signature` HMAC; None keeps the current secret
+ */
+webhook_secret: string | null, 
+/**
+ * None keeps the current setting
+ */
+allow_repoless_tasks: boolean | null, 
+/**
+ * None keeps the current setting
+ */
+auto_done_on_merge: boolean | null, };
 
 export type SearchResult = { path: string, is_file: boolean, match_type: SearchMatchType, };
 
@@ -44,9 +154,9 @@ export type UpdateTag = { tag_name: string | null, content: string | null, };
 
 export type TaskStatus = "backlog" | "todo" | "inprogress" | "inreview" | "done" | "cancelled";
 
-export type Task = { id: string, project_id: string, title: string, description: string | null, status: TaskStatus, parent_workspace_id: string | null, shared_task_id: string | null, linear_issue_id: string | null, linear_url: string | null, linear_labels: string | null, created_at: string, updated_at: string, };
+export type Task = { id: string, project_id: string, title: string, description: string | null, status: TaskStatus, parent_workspace_id: string | null, shared_task_id: string | null, linear_issue_id: string | null, linear_url: string | null, linear_labels: string | null, tags: string | null, setup_profile: string | null, due_date: string | null, created_at: string, updated_at: string, };
 
-export type TaskWithAttemptStatus = { has_in_progress_attempt: boolean, last_attempt_failed: boolean, executor: string, pr_url: string | null, pr_status: MergeStatus | null, pr_is_draft: boolean | null, pr_review_decision: ReviewDecision | null, pr_checks_status: ChecksStatus | null, pr_has_conflicts: boolean | null, id: string, project_id: string, title: string, description: string | null, status: TaskStatus, parent_workspace_id: string | null, shared_task_id: string | null, linear_issue_id: string | null, linear_url: string | null, linear_labels: string | null, created_at: string, updated_at: string, };
+export type TaskWithAttemptStatus = { has_in_progress_attempt: boolean, last_attempt_failed: boolean, executor: string, pr_url: string | null, pr_status: MergeStatus | null, pr_is_draft: boolean | null, pr_review_decision: ReviewDecision | null, pr_checks_status: ChecksStatus | null, pr_has_conflicts: boolean | null, id: string, project_id: string, title: string, description: string | null, status: TaskStatus, parent_workspace_id: string | null, shared_task_id: string | null, linear_issue_id: string | null, linear_url: string | null, linear_labels: string | null, tags: string | null, setup_profile: string | null, due_date: string | null, created_at: string, updated_at: string, };
 
 export type TaskRelationships = { parent_task: Task | null, current_workspace: Workspace, children: Array<Task>, };
 
@@ -56,7 +166,19 @@ export type UpdateTask = { title: string | null, description: string | null, sta
 /**
  * If true, sync the status change to Linear (for tasks with linear_issue_id)
  */
-sync_to_linear: boolean, };
+sync_to_linear: boolean, 
+/**
+ * Due date as YYYY-MM-DD; empty string clears it, omitted keeps the current value
+ */
+due_date: string | null, };
+
+export type TaskTimelineEventKind = "created" | "status_changed" | "attempt_started" | "process_started" | "process_completed" | "process_failed" | "process_stopped" | "pr_opened" | "pr_merged" | "merged";
+
+export type TaskTimelineEvent = { kind: TaskTimelineEventKind, at: string, 
+/**
+ * What happened, e.g. "Coding agent finished" or "PR #12 merged"
+ */
+summary: string, workspace_id: string | null, execution_process_id: string | null, };
 
 export type DraftFollowUpData = { message: string, variant: string | null, };
 
@@ -92,7 +214,19 @@ commander_session_id: string | null, run_reason: ExecutionProcessRunReason, exec
  * history view (due to restore/trimming). Hidden from logs/timeline;
  * still listed in the Processes tab.
  */
-dropped: boolean, started_at: string, completed_at: string | null, created_at: string, updated_at: string, };
+dropped: boolean, 
+/**
+ * Resolved command line the coding agent was started with
+ */
+command: string | null, 
+/**
+ * Claude Code session file an imported process was read from
+ */
+source_path: string | null, 
+/**
+ * Slug of that Claude Code session
+ */
+source_slug: string | null, started_at: string, completed_at: string | null, created_at: string, updated_at: string, };
 
 export enum ExecutionProcessStatus { running = "running", completed = "completed", failed = "failed", killed = "killed" }
 
@@ -246,11 +380,43 @@ export type AssignSharedTaskRequest = { new_assignee_user_id: string | null, };
 
 export type CreateFollowUpRequest = { prompt: string, variant: string | null, };
 
+export type UpdateTaskStatusRequest = { status: TaskStatus, };
+
+export type SetTaskTagsRequest = { tags: Array<string>, };
+
+export type SetTaskSetupProfileRequest = { 
+/**
+ * Name of a setup profile defined on the project; None clears the selection
+ */
+setup_profile: string | null, };
+
+export type MoveTaskRequest = { project_id: string, };
+
 export type ShareTaskResponse = { shared_task_id: string, };
 
-export type CreateAndStartTaskRequest = { task: CreateTask, executor_profile_id: ExecutorProfileId, repos: Array<WorkspaceRepoInput>, };
+export type CreateTasksBatchRequest = { tasks: Array<CreateTask>, };
 
-export type ImportTaskFromPrRequest = { projectId: string, repoId: string, prNumber: bigint, executorProfileId: ExecutorProfileId, };
+export type AgentPipelineStep = { executor_profile_id: ExecutorProfileId, 
+/**
+ * Instructions for this step, added after the task description
+ */
+prompt: string | null, };
+
+export type CreateAndStartTaskRequest = { task: CreateTask, executor_profile_id: ExecutorProfileId, repos: Array<WorkspaceRepoInput>, 
+/**
+ * Agents that run in order after `executor_profile_id`, in the same workspace
+ */
+pipeline?: Array<AgentPipelineStep>, };
+
+export type ImportTaskFromPrRequest = { projectId: string, repoId: string, prNumber: bigint, executorProfileId: ExecutorProfileId, 
+/**
+ * Branch the attempt targets; defaults to the PR's base branch
+ */
+targetBranch: string | null, 
+/**
+ * Add the PR's conversation and review comments to the task description
+ */
+includeReviewComments: boolean, };
 
 export type ImportTaskFromPrError = { "type": "github_cli_not_installed" } | { "type": "github_cli_not_logged_in" } | { "type": "pr_not_found_or_no_access", pr_number: bigint, };
 
@@ -258,19 +424,53 @@ export type LinearIssueStateResponse = { issue: LinearIssueWithState, mapped_sta
 
 export type ExtractedTask = { id: string, title: string, description: string | null, timestamp: string, branch: string | null, sessionId: string | null, };
 
+export type SessionFormat = "claude" | "codex";
+
 export type SessionInfo = { path: string, sessionId: string, lastModified: string, summary: string | null, messageCount: number, gitBranch: string | null, firstUserMessage: string | null, slug: string | null, };
 
-export type PreviewClaudeSessionRequest = { sessionPath: string, };
+export type PreviewClaudeSessionRequest = { sessionPath: string, 
+/**
+ * Agent that wrote the session file; Claude Code when omitted
+ */
+format?: SessionFormat, };
 
 export type PreviewClaudeSessionResponse = { items: Array<ExtractedTask>, sessionSummary: string | null, };
 
-export type ImportFromClaudeSessionRequest = { sessionPath: string, selectedItemIds: Array<string>, defaultStatus: string | null, };
+export type ImportFromClaudeSessionRequest = { sessionPath: string, selectedItemIds: Array<string>, defaultStatus: string | null, 
+/**
+ * Agent that wrote the session file; Claude Code when omitted
+ */
+format?: SessionFormat, };
 
 export type ImportFromClaudeSessionResponse = { importedCount: number, errors: Array<string>, };
 
-export type ImportWithHistoryRequest = { sessionPath: string, taskTitle: string | null, defaultStatus: string | null, };
+export type ImportWithHistoryRequest = { sessionPath: string, taskTitle: string | null, defaultStatus: string | null, 
+/**
+ * Import starting at the message with this uuid (inclusive)
+ */
+fromUuid: string | null, 
+/**
+ * Stop importing after the message with this uuid (inclusive)
+ */
+toUuid: string | null, };
+
+export type ImportWithHistoryResponse = { taskId: string, workspaceId: string, sessionId: string, executionProcessId: string, logLinesImported: number, 
+/**
+ * The session had already been imported into the project; the ids are the earlier import's
+ */
+alreadyImported: boolean, };
+
+export type ImportAllSessionsRequest = { 
+/**
+ * Project directory whose Claude Code sessions should be imported
+ */
+projectPath: string, defaultStatus: string | null, };
 
-export type ImportWithHistoryResponse = { taskId: string, workspaceId: string, sessionId: string, executionProcessId: string, logLinesImported: number, };
+export type ImportAllSessionsResponse = { importedCount: number, 
+/**
+ * Sessions skipped because they were already imported into this project
+ */
+skippedCount: number, errors: Array<string>, };
 
 export type ListClaudeSessionsRequest = { projectPath: string | null, };
 
@@ -300,11 +500,19 @@ export type RunAgentSetupResponse = Record<string, never>;
 
 export type GhCliSetupError = "BREW_MISSING" | "SETUP_HELPER_NOT_SUPPORTED" | { "OTHER": { message: string, } };
 
-export type RebaseTaskAttemptRequest = { repo_id: string, old_base_branch: string | null, new_base_branch: string | null, };
+export type RebaseTaskAttemptRequest = { 
+/**
+ * Repository to rebase; every repository of the attempt onto its target branch when omitted
+ */
+repo_id: string | null, old_base_branch: string | null, new_base_branch: string | null, };
 
 export type AbortConflictsRequest = { repo_id: string, };
 
-export type GitOperationError = { "type": "merge_conflicts", message: string, op: ConflictOp, } | { "type": "rebase_in_progress" };
+export type GitOperationError = { "type": "merge_conflicts", message: string, op: ConflictOp, 
+/**
+ * Files left unmerged in the worktree
+ */
+conflicted_files: Array<string>, } | { "type": "rebase_in_progress" };
 
 export type PushError = { "type": "force_push_required" };
 
@@ -348,7 +556,11 @@ export type ListRecentPrsResponse = { prs: Array<PrListItem>, };
 
 export type ListRecentPrsError = { "type": "github_cli_not_installed" } | { "type": "github_cli_not_logged_in" };
 
-export type PrListItem = { number: bigint, url: string, state: string, title: string, body: string, author: PrListAuthor, createdAt: string, headRefName: string, };
+export type PrListItem = { number: bigint, url: string, state: string, title: string, body: string, author: PrListAuthor, createdAt: string, headRefName: string, 
+/**
+ * Branch the PR merges into
+ */
+baseRefName: string, };
 
 export type PrListAuthor = { login: string, };
 
@@ -366,11 +578,40 @@ conflict_op: ConflictOp | null,
  */
 conflicted_files: Array<string>, };
 
+export type MergeBlocker = { "type": "no_commits" } | { "type": "behind_target", commits: number, } | { "type": "uncommitted_changes" } | { "type": "unresolved_conflicts" } | { "type": "pr_has_conflicts" } | { "type": "checks_failing" } | { "type": "checks_pending" };
+
+export type RepoMergeability = { repo_id: string, repo_name: string, target_branch_name: string, commits_ahead: number, commits_behind: number, 
+/**
+ * Latest open PR from this repo, refreshed from GitHub when reachable
+ */
+pr: PullRequestInfo | null, 
+/**
+ * Empty when the repo can be merged as-is
+ */
+blockers: Array<MergeBlocker>, };
+
+export type WorkspaceMergeability = { 
+/**
+ * True when no repo has blockers
+ */
+mergeable: boolean, repos: Array<RepoMergeability>, };
+
+export type RepoCommits = { repo_id: string, repo_name: string, target_branch_name: string, 
+/**
+ * Commits on the workspace branch since it left the target branch, newest first
+ */
+commits: Array<GitCommitInfo>, };
+
 export type DirectoryEntry = { name: string, path: string, is_directory: boolean, is_git_repo: boolean, last_modified: bigint | null, };
 
 export type DirectoryListResponse = { entries: Array<DirectoryEntry>, current_path: string, };
 
-export type Config = { config_version: string, theme: ThemeMode, executor_profile: ExecutorProfileId, disclaimer_acknowledged: boolean, onboarding_acknowledged: boolean, notifications: NotificationConfig, editor: EditorConfig, github: GitHubConfig, workspace_dir: string | null, last_app_version: string | null, show_release_notes: boolean, language: UiLanguage, git_branch_prefix: string, showcases: ShowcaseState, pr_auto_description_enabled: boolean, pr_auto_description_prompt: string | null, };
+export type Config = { config_version: string, theme: ThemeMode, executor_profile: ExecutorProfileId, disclaimer_acknowledged: boolean, onboarding_acknowledged: boolean, notifications: NotificationConfig, editor: EditorConfig, github: GitHubConfig, workspace_dir: string | null, last_app_version: string | null, show_release_notes: boolean, language: UiLanguage, git_branch_prefix: string, showcases: ShowcaseState, pr_auto_description_enabled: boolean, pr_auto_description_prompt: string | null, 
+/**
+ * Port the server tries first when `PORT` isn't set, falling back to a random
+ * free port when it's taken. None always uses a random port.
+ */
+preferred_port: number | null, };
 
 export type NotificationConfig = { sound_enabled: boolean, push_enabled: boolean, sound_file: SoundFile, };
 
@@ -392,6 +633,8 @@ export type ShowcaseState = { seen_features: Array<string>, };
 
 export type GitBranch = { name: string, is_current: boolean, is_remote: boolean, last_commit_date: Date, };
 
+export type GitCommitInfo = { sha: string, message: string, author_name: string, author_email: string, time: Date, };
+
 export type SharedTaskDetails = { id: string, project_id: string, title: string, description: string | null, status: TaskStatus, };
 
 export type QueuedMessage = { 
@@ -410,6 +653,20 @@ queued_at: string, };
 
 export type QueueStatus = { "status": "empty" } | { "status": "queued", message: QueuedMessage, };
 
+export type PrMonitorStatus = { poll_interval_secs: bigint, 
+/**
+ * When the monitor last finished a pass over open PRs
+ */
+last_run_at: string | null, 
+/**
+ * When a pass last completed without any errors
+ */
+last_success_at: string | null, last_error: string | null, last_error_at: string | null, 
+/**
+ * Set by the admin pause endpoint; polling is skipped until resumed
+ */
+paused: boolean, };
+
 export type ConflictOp = "rebase" | "merge" | "cherry_pick" | "revert";
 
 export type ExecutorAction = { typ: ExecutorActionType, next_action: ExecutorAction | null, };
@@ -461,13 +718,13 @@ export type ExecutorConfigs = { executors: { [key in BaseCodingAgent]?: Executor
 
 export enum BaseAgentCapability { SESSION_FORK = "SESSION_FORK", SETUP_HELPER = "SETUP_HELPER" }
 
-export type ClaudeCode = { append_prompt: AppendPrompt, claude_code_router?: boolean | null, plan?: boolean | null, approvals?: boolean | null, model?: string | null, dangerously_skip_permissions?: boolean | null, disable_api_key?: boolean | null, base_command_override?: string | null, additional_params?: Array<string> | null, env?: { [key in string]?: string } | null, };
+export type ClaudeCode = { append_prompt: AppendPrompt, claude_code_router?: boolean | null, plan?: boolean | null, approvals?: boolean | null, model?: string | null, dangerously_skip_permissions?: boolean | null, disable_api_key?: boolean | null, base_command_override?: string | null, additional_params?: Array<string> | null, env?: { [key in string]?: string } | null, max_runtime_minutes?: number | null, };
 
-export type Gemini = { append_prompt: AppendPrompt, model?: string | null, yolo?: boolean | null, base_command_override?: string | null, additional_params?: Array<string> | null, env?: { [key in string]?: string } | null, };
+export type Gemini = { append_prompt: AppendPrompt, model?: string | null, yolo?: boolean | null, base_command_override?: string | null, additional_params?: Array<string> | null, env?: { [key in string]?: string } | null, max_runtime_minutes?: number | null, };
 
-export type Amp = { append_prompt: AppendPrompt, dangerously_allow_all?: boolean | null, base_command_override?: string | null, additional_params?: Array<string> | null, env?: { [key in string]?: string } | null, };
+export type Amp = { append_prompt: AppendPrompt, dangerously_allow_all?: boolean | null, base_command_override?: string | null, additional_params?: Array<string> | null, env?: { [key in string]?: string } | null, max_runtime_minutes?: number | null, };
 
-export type Codex = { append_prompt: AppendPrompt, sandbox?: SandboxMode | null, ask_for_approval?: AskForApproval | null, oss?: boolean | null, model?: string | null, model_reasoning_effort?: ReasoningEffort | null, model_reasoning_summary?: ReasoningSummary | null, model_reasoning_summary_format?: ReasoningSummaryFormat | null, profile?: string | null, base_instructions?: string | null, include_apply_patch_tool?: boolean | null, model_provider?: string | null, compact_prompt?: string | null, developer_instructions?: string | null, base_command_override?: string | null, additional_params?: Array<string> | null, env?: { [key in string]?: string } | null, };
+export type Codex = { append_prompt: AppendPrompt, sandbox?: SandboxMode | null, ask_for_approval?: AskForApproval | null, oss?: boolean | null, model?: string | null, model_reasoning_effort?: ReasoningEffort | null, model_reasoning_summary?: ReasoningSummary | null, model_reasoning_summary_format?: ReasoningSummaryFormat | null, profile?: string | null, base_instructions?: string | null, include_apply_patch_tool?: boolean | null, model_provider?: string | null, compact_prompt?: string | null, developer_instructions?: string | null, base_command_override?: string | null, additional_params?: Array<string> | null, env?: { [key in string]?: string } | null, max_runtime_minutes?: number | null, };
 
 export type SandboxMode = "auto" | "read-only" | "workspace-write" | "danger-full-access";
 
@@ -479,19 +736,19 @@ export type ReasoningSummary = "auto" | "concise" | "detailed" | "none";
 
 export type ReasoningSummaryFormat = "none" | "experimental";
 
-export type CursorAgent = { append_prompt: AppendPrompt, force?: boolean | null, model?: string | null, base_command_override?: string | null, additional_params?: Array<string> | null, env?: { [key in string]?: string } | null, };
+export type CursorAgent = { append_prompt: AppendPrompt, force?: boolean | null, model?: string | null, base_command_override?: string | null, additional_params?: Array<string> | null, env?: { [key in string]?: string } | null, max_runtime_minutes?: number | null, };
 
-export type Copilot = { append_prompt: AppendPrompt, model?: string | null, allow_all_tools?: boolean | null, allow_tool?: string | null, deny_tool?: string | null, add_dir?: Array<string> | null, disable_mcp_server?: Array<string> | null, base_command_override?: string | null, additional_params?: Array<string> | null, env?: { [key in string]?: string } | null, };
+export type Copilot = { append_prompt: AppendPrompt, model?: string | null, allow_all_tools?: boolean | null, allow_tool?: string | null, deny_tool?: string | null, add_dir?: Array<string> | null, disable_mcp_server?: Array<string> | null, base_command_override?: string | null, additional_params?: Array<string> | null, env?: { [key in string]?: string } | null, max_runtime_minutes?: number | null, };
 
 export type Opencode = { append_prompt: AppendPrompt, model?: string | null, mode?: string | null, 
 /**
  * Auto-approve agent actions
  */
-auto_approve: boolean, base_command_override?: string | null, additional_params?: Array<string> | null, env?: { [key in string]?: string } | null, };
+auto_approve: boolean, base_command_override?: string | null, additional_params?: Array<string> | null, env?: { [key in string]?: string } | null, max_runtime_minutes?: number | null, };
 
-export type QwenCode = { append_prompt: AppendPrompt, yolo?: boolean | null, base_command_override?: string | null, additional_params?: Array<string> | null, env?: { [key in string]?: string } | null, };
+export type QwenCode = { append_prompt: AppendPrompt, yolo?: boolean | null, base_command_override?: string | null, additional_params?: Array<string> | null, env?: { [key in string]?: string } | null, max_runtime_minutes?: number | null, };
 
-export type Droid = { append_prompt: AppendPrompt, autonomy: Autonomy, model?: string | null, reasoning_effort?: DroidReasoningEffort | null, base_command_override?: string | null, additional_params?: Array<string> | null, env?: { [key in string]?: string } | null, };
+export type Droid = { append_prompt: AppendPrompt, autonomy: Autonomy, model?: string | null, reasoning_effort?: DroidReasoningEffort | null, base_command_override?: string | null, additional_params?: Array<string> | null, env?: { [key in string]?: string } | null, max_runtime_minutes?: number | null, };
 
 export type Autonomy = "normal" | "low" | "medium" | "high" | "skip-permissions-unsafe";
 
@@ -587,6 +844,22 @@ source: SlashCommandSource, };
 
 export type SlashCommandSource = "builtin" | "user" | "plugin";
 
+export type SlashCommandsQuery = { 
+/**
+ * Only commands from this source
+ */
+source: SlashCommandSource | null, 
+/**
+ * Case-insensitive match against the qualified name and description
+ */
+q: string | null, };
+
+export type SlashCommandDetail = { command: SlashCommand, 
+/**
+ * Markdown the command injects, without its frontmatter; a builtin's is its description
+ */
+body: string, };
+
 export const DEFAULT_PR_DESCRIPTION_PROMPT = `Update the GitHub PR that was just created with a better title and description.
 The PR number is #{pr_number} and the URL is {pr_url}.
 